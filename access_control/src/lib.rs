@@ -0,0 +1,227 @@
+#![no_std]
+
+//! Role-based access control shared by the factory, router, pair and
+//! staking programs. A program seeds the roles in its constructor, checks
+//! them with [`has_role`] and exposes [`AccessControlService`] by extending
+//! its own service with it.
+
+use sails_rs::{collections::HashMap, gstd::msg, prelude::*};
+
+pub static mut ACCESS_CONTROL: Option<AccessControl> = None;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Role {
+    /// Grants and revokes roles and changes the program's configuration.
+    DefaultAdmin,
+    /// Pauses and unpauses trading.
+    Pauser,
+    /// Sets fees and where they go.
+    FeeManager,
+    /// Lists tokens and edits their metadata and status.
+    AssetManager,
+    /// Moves funds held by the program.
+    Treasurer,
+    /// Sets staking rewards.
+    RewardManager,
+}
+
+impl Role {
+    pub const ALL: [Role; 6] = [
+        Role::DefaultAdmin,
+        Role::Pauser,
+        Role::FeeManager,
+        Role::AssetManager,
+        Role::Treasurer,
+        Role::RewardManager,
+    ];
+}
+
+#[derive(Debug, Default)]
+pub struct AccessControl {
+    /// Members of each role in the order they were granted it.
+    pub members: HashMap<Role, Vec<ActorId>>,
+}
+
+impl AccessControl {
+    pub fn get_mut() -> &'static mut Self {
+        unsafe { ACCESS_CONTROL.as_mut().expect("State AccessControl Error") }
+    }
+    pub fn get() -> &'static Self {
+        unsafe { ACCESS_CONTROL.as_ref().expect("State AccessControl Error") }
+    }
+
+    pub fn has_role(&self, role: Role, account: ActorId) -> bool {
+        self.members
+            .get(&role)
+            .is_some_and(|members| members.contains(&account))
+    }
+
+    /// Returns whether `account` did not hold the role yet.
+    pub fn grant(&mut self, role: Role, account: ActorId) -> bool {
+        let members = self.members.entry(role).or_default();
+        if members.contains(&account) {
+            return false;
+        }
+        members.push(account);
+        true
+    }
+
+    /// Returns whether `account` held the role.
+    pub fn revoke(&mut self, role: Role, account: ActorId) -> bool {
+        let Some(members) = self.members.get_mut(&role) else {
+            return false;
+        };
+        let Some(index) = members.iter().position(|member| *member == account) else {
+            return false;
+        };
+        members.remove(index);
+        true
+    }
+
+    pub fn roles_of(&self, account: ActorId) -> Vec<Role> {
+        Role::ALL
+            .into_iter()
+            .filter(|role| self.has_role(*role, account))
+            .collect()
+    }
+
+    /// Moves every role of `from` to `to`, used when a program's `admin`
+    /// changes.
+    pub fn transfer_roles(&mut self, from: ActorId, to: ActorId) {
+        for role in self.roles_of(from) {
+            self.revoke(role, from);
+            self.grant(role, to);
+        }
+    }
+}
+
+pub fn has_role(role: Role, account: ActorId) -> bool {
+    AccessControl::get().has_role(role, account)
+}
+
+#[derive(Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum AccessControlEvent {
+    RoleGranted {
+        role: Role,
+        account: ActorId,
+        sender: ActorId,
+    },
+    RoleRevoked {
+        role: Role,
+        account: ActorId,
+        sender: ActorId,
+    },
+}
+
+#[derive(Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum AccessControlError {
+    /// The caller does not hold `Role::DefaultAdmin`.
+    MissingAdminRole,
+    /// The account does not hold the role.
+    RoleNotHeld,
+    /// `DefaultAdmin` only changes hands through the program's
+    /// `propose_admin`/`accept_admin`.
+    AdminRoleNotGrantable,
+}
+
+#[derive(Clone, Default)]
+pub struct AccessControlService(());
+
+impl AccessControlService {
+    /// Gives `admin` every role, so it can hand them to other keys and
+    /// renounce the ones it should not hold.
+    pub fn seed(admin: ActorId) {
+        let mut access_control = AccessControl::default();
+        for role in Role::ALL {
+            access_control.grant(role, admin);
+        }
+        unsafe {
+            ACCESS_CONTROL = Some(access_control);
+        }
+    }
+}
+
+#[service(events = AccessControlEvent)]
+impl AccessControlService {
+    pub fn new() -> Self {
+        Self(())
+    }
+
+    /// Grants any role but `DefaultAdmin`, which the new holder has to
+    /// accept through the program's `propose_admin`/`accept_admin`.
+    pub fn grant_role(&mut self, role: Role, account: ActorId) -> Result<(), AccessControlError> {
+        let sender = msg::source();
+        let access_control = AccessControl::get_mut();
+        if !access_control.has_role(Role::DefaultAdmin, sender) {
+            return Err(AccessControlError::MissingAdminRole);
+        }
+        if role == Role::DefaultAdmin {
+            return Err(AccessControlError::AdminRoleNotGrantable);
+        }
+        if access_control.grant(role, account) {
+            self.emit_event(AccessControlEvent::RoleGranted {
+                role,
+                account,
+                sender,
+            })
+            .ok();
+        }
+        Ok(())
+    }
+
+    pub fn revoke_role(&mut self, role: Role, account: ActorId) -> Result<(), AccessControlError> {
+        let sender = msg::source();
+        let access_control = AccessControl::get_mut();
+        if !access_control.has_role(Role::DefaultAdmin, sender) {
+            return Err(AccessControlError::MissingAdminRole);
+        }
+        if !access_control.revoke(role, account) {
+            return Err(AccessControlError::RoleNotHeld);
+        }
+        self.emit_event(AccessControlEvent::RoleRevoked {
+            role,
+            account,
+            sender,
+        })
+        .ok();
+        Ok(())
+    }
+
+    /// Drops a role of the caller. Renouncing the last `DefaultAdmin` leaves
+    /// the roles fixed for good.
+    pub fn renounce_role(&mut self, role: Role) -> Result<(), AccessControlError> {
+        let sender = msg::source();
+        if !AccessControl::get_mut().revoke(role, sender) {
+            return Err(AccessControlError::RoleNotHeld);
+        }
+        self.emit_event(AccessControlEvent::RoleRevoked {
+            role,
+            account: sender,
+            sender,
+        })
+        .ok();
+        Ok(())
+    }
+
+    pub fn has_role(&self, role: Role, account: ActorId) -> bool {
+        has_role(role, account)
+    }
+
+    pub fn get_role_members(&self, role: Role) -> Vec<ActorId> {
+        AccessControl::get()
+            .members
+            .get(&role)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_account_roles(&self, account: ActorId) -> Vec<Role> {
+        AccessControl::get().roles_of(account)
+    }
+}
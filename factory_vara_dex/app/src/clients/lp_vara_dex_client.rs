@@ -0,0 +1,1293 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct LpVaraDexFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> LpVaraDexFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::LpVaraDexFactory for LpVaraDexFactory<R> {
+    type Args = R::Args;
+    fn new(
+        &self,
+        factory: ActorId,
+        token_a: ActorId,
+        token_b: ActorId,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        admin: ActorId,
+        router: ActorId,
+    ) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, lp_vara_dex_factory::io::New>::new(
+            self.remoting.clone(),
+            (
+                factory, token_a, token_b, name, symbol, decimals, admin, router,
+            ),
+        )
+    }
+}
+
+pub mod lp_vara_dex_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                factory: ActorId,
+                token_a: ActorId,
+                token_b: ActorId,
+                name: String,
+                symbol: String,
+                decimals: u8,
+                admin: ActorId,
+                router: ActorId,
+            ) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&(
+                    factory, token_a, token_b, name, symbol, decimals, admin, router,
+                ))
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = (
+                ActorId,
+                ActorId,
+                ActorId,
+                String,
+                String,
+                u8,
+                ActorId,
+                ActorId,
+            );
+            type Reply = ();
+        }
+    }
+}
+pub struct LpService<R> {
+    remoting: R,
+}
+impl<R> LpService<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::LpService for LpService<R> {
+    type Args = R::Args;
+    fn accept_admin(&mut self) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::AcceptAdmin>::new(self.remoting.clone(), ())
+    }
+    /// Burns the LP tokens sent to the pair and pays out their share of
+    /// both tokens to `to`. When `to` is the caller, native VARA comes with
+    /// the reply, since a program cannot take it as a plain message.
+    fn burn(
+        &mut self,
+        to: ActorId,
+    ) -> impl Call<Output = Result<(U256, U256), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Burn>::new(self.remoting.clone(), to)
+    }
+    fn cancel_admin_transfer(&mut self) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::CancelAdminTransfer>::new(self.remoting.clone(), ())
+    }
+    /// Hands the caller's voting weight, current and future balance, to
+    /// `delegatee`. Delegating to oneself takes it back.
+    fn delegate(
+        &mut self,
+        delegatee: ActorId,
+    ) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Delegate>::new(self.remoting.clone(), delegatee)
+    }
+    /// Mints LP tokens for what was added to the pair since its last
+    /// update. VARA attached to the message counts for a native pair and
+    /// comes back with the reply if minting fails.
+    fn mint(&mut self, to: ActorId) -> impl Call<Output = Result<U256, LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Mint>::new(self.remoting.clone(), to)
+    }
+    /// Sets `spender`'s allowance over `owner`'s LP tokens to `value` from
+    /// `owner`'s signature of the permit payload, so no `approve` message is
+    /// needed. Anyone can submit it.
+    fn permit(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Permit>::new(
+            self.remoting.clone(),
+            (owner, spender, value, deadline, signature),
+        )
+    }
+    /// Starts an admin handover that `accept_admin` completes.
+    fn propose_admin(
+        &mut self,
+        new_admin: ActorId,
+    ) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::ProposeAdmin>::new(self.remoting.clone(), new_admin)
+    }
+    /// Sends `to` what the pair holds of `token` beyond its reserve and
+    /// returns the amount. Only the router may call it, to give back the
+    /// input of a swap it could not finish; unlike `skim` it works while
+    /// the pair is paused or blocked.
+    fn return_excess(
+        &mut self,
+        token: ActorId,
+        to: ActorId,
+    ) -> impl Call<Output = Result<U256, LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::ReturnExcess>::new(self.remoting.clone(), (token, to))
+    }
+    fn set_router(
+        &mut self,
+        new_router: ActorId,
+    ) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::SetRouter>::new(self.remoting.clone(), new_router)
+    }
+    fn skim(&mut self, to: ActorId) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Skim>::new(self.remoting.clone(), to)
+    }
+    /// Sends out `amount0_out`/`amount1_out` against the input added since
+    /// the last update. For a native pair the VARA input can come attached
+    /// to this message, and comes back with the reply if the swap fails.
+    fn swap(
+        &mut self,
+        amount0_out: U256,
+        amount1_out: U256,
+        to: ActorId,
+    ) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Swap>::new(
+            self.remoting.clone(),
+            (amount0_out, amount1_out, to),
+        )
+    }
+    fn sync(&mut self) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Sync>::new(self.remoting.clone(), ())
+    }
+    /// Same as the VFT `transfer`, also recording balance and voting weight
+    /// checkpoints.
+    fn transfer(&mut self, to: ActorId, value: U256) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Transfer>::new(self.remoting.clone(), (to, value))
+    }
+    /// Same as the VFT `transfer_from`, also recording balance and voting
+    /// weight checkpoints.
+    fn transfer_from(
+        &mut self,
+        from: ActorId,
+        to: ActorId,
+        value: U256,
+    ) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::TransferFrom>::new(
+            self.remoting.clone(),
+            (from, to, value),
+        )
+    }
+    fn approve(
+        &mut self,
+        spender: ActorId,
+        value: U256,
+    ) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Approve>::new(self.remoting.clone(), (spender, value))
+    }
+    /// Grants any role but `DefaultAdmin`, which the new holder has to
+    /// accept through the program's `propose_admin`/`accept_admin`.
+    fn grant_role(
+        &mut self,
+        role: Role,
+        account: ActorId,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GrantRole>::new(self.remoting.clone(), (role, account))
+    }
+    /// Drops a role of the caller. Renouncing the last `DefaultAdmin` leaves
+    /// the roles fixed for good.
+    fn renounce_role(
+        &mut self,
+        role: Role,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::RenounceRole>::new(self.remoting.clone(), role)
+    }
+    fn revoke_role(
+        &mut self,
+        role: Role,
+        account: ActorId,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::RevokeRole>::new(self.remoting.clone(), (role, account))
+    }
+    /// Balance of `account` at the end of `block`.
+    fn balance_of_at(
+        &self,
+        account: ActorId,
+        block: u32,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::BalanceOfAt>::new(
+            self.remoting.clone(),
+            (account, block),
+        )
+    }
+    fn get_admin(&self) -> impl Query<Output = ActorId, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetAdmin>::new(self.remoting.clone(), ())
+    }
+    /// Account holding the voting weight of `account`'s balance, itself
+    /// unless it delegated.
+    fn get_delegate(&self, account: ActorId) -> impl Query<Output = ActorId, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetDelegate>::new(self.remoting.clone(), account)
+    }
+    fn get_factory(&self) -> impl Query<Output = ActorId, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetFactory>::new(self.remoting.clone(), ())
+    }
+    fn get_pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetPendingAdmin>::new(self.remoting.clone(), ())
+    }
+    fn get_reserves(&self) -> impl Query<Output = (U256, U256, u64), Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetReserves>::new(self.remoting.clone(), ())
+    }
+    fn get_router(&self) -> impl Query<Output = ActorId, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetRouter>::new(self.remoting.clone(), ())
+    }
+    /// The pair's tokens, larger id first.
+    fn get_tokens(&self) -> impl Query<Output = (ActorId, ActorId), Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetTokens>::new(self.remoting.clone(), ())
+    }
+    /// Current voting weight of `account`: its own balance unless
+    /// delegated, plus balances delegated to it.
+    fn get_votes(&self, account: ActorId) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetVotes>::new(self.remoting.clone(), account)
+    }
+    /// Voting weight of `account` at the end of `block`.
+    fn get_votes_at(
+        &self,
+        account: ActorId,
+        block: u32,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetVotesAt>::new(
+            self.remoting.clone(),
+            (account, block),
+        )
+    }
+    /// Current nonce of `owner`, which its next permit signature must cover.
+    fn nonces(&self, owner: ActorId) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Nonces>::new(self.remoting.clone(), owner)
+    }
+    /// Total supply at the end of `block`.
+    fn total_supply_at(&self, block: u32) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::TotalSupplyAt>::new(self.remoting.clone(), block)
+    }
+    fn allowance(
+        &self,
+        owner: ActorId,
+        spender: ActorId,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Allowance>::new(self.remoting.clone(), (owner, spender))
+    }
+    fn balance_of(&self, account: ActorId) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::BalanceOf>::new(self.remoting.clone(), account)
+    }
+    fn decimals(&self) -> impl Query<Output = u8, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Decimals>::new(self.remoting.clone(), ())
+    }
+    fn name(&self) -> impl Query<Output = String, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Name>::new(self.remoting.clone(), ())
+    }
+    fn symbol(&self) -> impl Query<Output = String, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Symbol>::new(self.remoting.clone(), ())
+    }
+    fn total_supply(&self) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::TotalSupply>::new(self.remoting.clone(), ())
+    }
+    fn get_account_roles(
+        &self,
+        account: ActorId,
+    ) -> impl Query<Output = Vec<Role>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetAccountRoles>::new(self.remoting.clone(), account)
+    }
+    fn get_role_members(&self, role: Role) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetRoleMembers>::new(self.remoting.clone(), role)
+    }
+    fn has_role(&self, role: Role, account: ActorId) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::HasRole>::new(self.remoting.clone(), (role, account))
+    }
+}
+
+pub mod lp_service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct AcceptAdmin(());
+        impl AcceptAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AcceptAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AcceptAdmin {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 44, 65, 99, 99, 101, 112, 116, 65,
+                100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct Burn(());
+        impl Burn {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId) -> Vec<u8> {
+                <Burn as ActionIo>::encode_call(&to)
+            }
+        }
+        impl ActionIo for Burn {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 16, 66, 117, 114, 110,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(U256, U256), super::LpError>;
+        }
+        pub struct CancelAdminTransfer(());
+        impl CancelAdminTransfer {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <CancelAdminTransfer as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CancelAdminTransfer {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 76, 67, 97, 110, 99, 101, 108, 65,
+                100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct Delegate(());
+        impl Delegate {
+            #[allow(dead_code)]
+            pub fn encode_call(delegatee: ActorId) -> Vec<u8> {
+                <Delegate as ActionIo>::encode_call(&delegatee)
+            }
+        }
+        impl ActionIo for Delegate {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 32, 68, 101, 108, 101, 103, 97, 116,
+                101,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct Mint(());
+        impl Mint {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId) -> Vec<u8> {
+                <Mint as ActionIo>::encode_call(&to)
+            }
+        }
+        impl ActionIo for Mint {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 16, 77, 105, 110, 116,
+            ];
+            type Params = ActorId;
+            type Reply = Result<U256, super::LpError>;
+        }
+        pub struct Permit(());
+        impl Permit {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+                deadline: u64,
+                signature: super::PermitSignature,
+            ) -> Vec<u8> {
+                <Permit as ActionIo>::encode_call(&(owner, spender, value, deadline, signature))
+            }
+        }
+        impl ActionIo for Permit {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 24, 80, 101, 114, 109, 105, 116,
+            ];
+            type Params = (ActorId, ActorId, U256, u64, super::PermitSignature);
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct ProposeAdmin(());
+        impl ProposeAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call(new_admin: ActorId) -> Vec<u8> {
+                <ProposeAdmin as ActionIo>::encode_call(&new_admin)
+            }
+        }
+        impl ActionIo for ProposeAdmin {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 48, 80, 114, 111, 112, 111, 115, 101,
+                65, 100, 109, 105, 110,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct ReturnExcess(());
+        impl ReturnExcess {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, to: ActorId) -> Vec<u8> {
+                <ReturnExcess as ActionIo>::encode_call(&(token, to))
+            }
+        }
+        impl ActionIo for ReturnExcess {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 48, 82, 101, 116, 117, 114, 110, 69,
+                120, 99, 101, 115, 115,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = Result<U256, super::LpError>;
+        }
+        pub struct SetRouter(());
+        impl SetRouter {
+            #[allow(dead_code)]
+            pub fn encode_call(new_router: ActorId) -> Vec<u8> {
+                <SetRouter as ActionIo>::encode_call(&new_router)
+            }
+        }
+        impl ActionIo for SetRouter {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 36, 83, 101, 116, 82, 111, 117, 116,
+                101, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct Skim(());
+        impl Skim {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId) -> Vec<u8> {
+                <Skim as ActionIo>::encode_call(&to)
+            }
+        }
+        impl ActionIo for Skim {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 16, 83, 107, 105, 109,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct Swap(());
+        impl Swap {
+            #[allow(dead_code)]
+            pub fn encode_call(amount0_out: U256, amount1_out: U256, to: ActorId) -> Vec<u8> {
+                <Swap as ActionIo>::encode_call(&(amount0_out, amount1_out, to))
+            }
+        }
+        impl ActionIo for Swap {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 16, 83, 119, 97, 112,
+            ];
+            type Params = (U256, U256, ActorId);
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct Sync(());
+        impl Sync {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Sync as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Sync {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 16, 83, 121, 110, 99,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct Transfer(());
+        impl Transfer {
+            #[allow(dead_code)]
+            pub fn encode_call(to: ActorId, value: U256) -> Vec<u8> {
+                <Transfer as ActionIo>::encode_call(&(to, value))
+            }
+        }
+        impl ActionIo for Transfer {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 32, 84, 114, 97, 110, 115, 102, 101,
+                114,
+            ];
+            type Params = (ActorId, U256);
+            type Reply = bool;
+        }
+        pub struct TransferFrom(());
+        impl TransferFrom {
+            #[allow(dead_code)]
+            pub fn encode_call(from: ActorId, to: ActorId, value: U256) -> Vec<u8> {
+                <TransferFrom as ActionIo>::encode_call(&(from, to, value))
+            }
+        }
+        impl ActionIo for TransferFrom {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 48, 84, 114, 97, 110, 115, 102, 101,
+                114, 70, 114, 111, 109,
+            ];
+            type Params = (ActorId, ActorId, U256);
+            type Reply = bool;
+        }
+        pub struct Approve(());
+        impl Approve {
+            #[allow(dead_code)]
+            pub fn encode_call(spender: ActorId, value: U256) -> Vec<u8> {
+                <Approve as ActionIo>::encode_call(&(spender, value))
+            }
+        }
+        impl ActionIo for Approve {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 28, 65, 112, 112, 114, 111, 118, 101,
+            ];
+            type Params = (ActorId, U256);
+            type Reply = bool;
+        }
+        pub struct GrantRole(());
+        impl GrantRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <GrantRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for GrantRole {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 36, 71, 114, 97, 110, 116, 82, 111,
+                108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct RenounceRole(());
+        impl RenounceRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role) -> Vec<u8> {
+                <RenounceRole as ActionIo>::encode_call(&role)
+            }
+        }
+        impl ActionIo for RenounceRole {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 48, 82, 101, 110, 111, 117, 110, 99,
+                101, 82, 111, 108, 101,
+            ];
+            type Params = super::Role;
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct RevokeRole(());
+        impl RevokeRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <RevokeRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for RevokeRole {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 40, 82, 101, 118, 111, 107, 101, 82,
+                111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct BalanceOfAt(());
+        impl BalanceOfAt {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId, block: u32) -> Vec<u8> {
+                <BalanceOfAt as ActionIo>::encode_call(&(account, block))
+            }
+        }
+        impl ActionIo for BalanceOfAt {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 44, 66, 97, 108, 97, 110, 99, 101,
+                79, 102, 65, 116,
+            ];
+            type Params = (ActorId, u32);
+            type Reply = U256;
+        }
+        pub struct GetAdmin(());
+        impl GetAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetAdmin {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 32, 71, 101, 116, 65, 100, 109, 105,
+                110,
+            ];
+            type Params = ();
+            type Reply = ActorId;
+        }
+        pub struct GetDelegate(());
+        impl GetDelegate {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetDelegate as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for GetDelegate {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 44, 71, 101, 116, 68, 101, 108, 101,
+                103, 97, 116, 101,
+            ];
+            type Params = ActorId;
+            type Reply = ActorId;
+        }
+        pub struct GetFactory(());
+        impl GetFactory {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetFactory as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetFactory {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 40, 71, 101, 116, 70, 97, 99, 116,
+                111, 114, 121,
+            ];
+            type Params = ();
+            type Reply = ActorId;
+        }
+        pub struct GetPendingAdmin(());
+        impl GetPendingAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPendingAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPendingAdmin {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101, 116, 80, 101, 110, 100,
+                105, 110, 103, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Option<ActorId>;
+        }
+        pub struct GetReserves(());
+        impl GetReserves {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetReserves as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetReserves {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 44, 71, 101, 116, 82, 101, 115, 101,
+                114, 118, 101, 115,
+            ];
+            type Params = ();
+            type Reply = (U256, U256, u64);
+        }
+        pub struct GetRouter(());
+        impl GetRouter {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetRouter as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetRouter {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 36, 71, 101, 116, 82, 111, 117, 116,
+                101, 114,
+            ];
+            type Params = ();
+            type Reply = ActorId;
+        }
+        pub struct GetTokens(());
+        impl GetTokens {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetTokens as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetTokens {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 36, 71, 101, 116, 84, 111, 107, 101,
+                110, 115,
+            ];
+            type Params = ();
+            type Reply = (ActorId, ActorId);
+        }
+        pub struct GetVotes(());
+        impl GetVotes {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetVotes as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for GetVotes {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 32, 71, 101, 116, 86, 111, 116, 101,
+                115,
+            ];
+            type Params = ActorId;
+            type Reply = U256;
+        }
+        pub struct GetVotesAt(());
+        impl GetVotesAt {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId, block: u32) -> Vec<u8> {
+                <GetVotesAt as ActionIo>::encode_call(&(account, block))
+            }
+        }
+        impl ActionIo for GetVotesAt {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 40, 71, 101, 116, 86, 111, 116, 101,
+                115, 65, 116,
+            ];
+            type Params = (ActorId, u32);
+            type Reply = U256;
+        }
+        pub struct Nonces(());
+        impl Nonces {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <Nonces as ActionIo>::encode_call(&owner)
+            }
+        }
+        impl ActionIo for Nonces {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 24, 78, 111, 110, 99, 101, 115,
+            ];
+            type Params = ActorId;
+            type Reply = u64;
+        }
+        pub struct TotalSupplyAt(());
+        impl TotalSupplyAt {
+            #[allow(dead_code)]
+            pub fn encode_call(block: u32) -> Vec<u8> {
+                <TotalSupplyAt as ActionIo>::encode_call(&block)
+            }
+        }
+        impl ActionIo for TotalSupplyAt {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 52, 84, 111, 116, 97, 108, 83, 117,
+                112, 112, 108, 121, 65, 116,
+            ];
+            type Params = u32;
+            type Reply = U256;
+        }
+        pub struct Allowance(());
+        impl Allowance {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId, spender: ActorId) -> Vec<u8> {
+                <Allowance as ActionIo>::encode_call(&(owner, spender))
+            }
+        }
+        impl ActionIo for Allowance {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 36, 65, 108, 108, 111, 119, 97, 110,
+                99, 101,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = U256;
+        }
+        pub struct BalanceOf(());
+        impl BalanceOf {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <BalanceOf as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for BalanceOf {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 36, 66, 97, 108, 97, 110, 99, 101,
+                79, 102,
+            ];
+            type Params = ActorId;
+            type Reply = U256;
+        }
+        pub struct Decimals(());
+        impl Decimals {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Decimals as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Decimals {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 32, 68, 101, 99, 105, 109, 97, 108,
+                115,
+            ];
+            type Params = ();
+            type Reply = u8;
+        }
+        pub struct Name(());
+        impl Name {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Name as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Name {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 16, 78, 97, 109, 101,
+            ];
+            type Params = ();
+            type Reply = String;
+        }
+        pub struct Symbol(());
+        impl Symbol {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <Symbol as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for Symbol {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 24, 83, 121, 109, 98, 111, 108,
+            ];
+            type Params = ();
+            type Reply = String;
+        }
+        pub struct TotalSupply(());
+        impl TotalSupply {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <TotalSupply as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for TotalSupply {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 44, 84, 111, 116, 97, 108, 83, 117,
+                112, 112, 108, 121,
+            ];
+            type Params = ();
+            type Reply = U256;
+        }
+        pub struct GetAccountRoles(());
+        impl GetAccountRoles {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetAccountRoles as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for GetAccountRoles {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101, 116, 65, 99, 99, 111,
+                117, 110, 116, 82, 111, 108, 101, 115,
+            ];
+            type Params = ActorId;
+            type Reply = Vec<super::Role>;
+        }
+        pub struct GetRoleMembers(());
+        impl GetRoleMembers {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role) -> Vec<u8> {
+                <GetRoleMembers as ActionIo>::encode_call(&role)
+            }
+        }
+        impl ActionIo for GetRoleMembers {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101, 116, 82, 111, 108, 101,
+                77, 101, 109, 98, 101, 114, 115,
+            ];
+            type Params = super::Role;
+            type Reply = Vec<ActorId>;
+        }
+        pub struct HasRole(());
+        impl HasRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <HasRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for HasRole {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 28, 72, 97, 115, 82, 111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = bool;
+        }
+    }
+
+    #[allow(dead_code)]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        use sails_rs::events::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum LpServiceEvents {
+            LPMint {
+                to: ActorId,
+                amount: U256,
+            },
+            LPBurn {
+                from: ActorId,
+                amount: U256,
+            },
+            Mint {
+                sender: ActorId,
+                amount: (U256, U256),
+            },
+            Burn {
+                sender: ActorId,
+                amount: (U256, U256),
+                to: ActorId,
+            },
+            /// Should be returned from
+            /// [`InnerAction::SwapExactTokensForTokens`]/[`InnerAction::SwapTokensForExactTokens`].
+            Swap {
+                sender: ActorId,
+                amount_in: (U256, U256),
+                amount_out: (U256, U256),
+                to: ActorId,
+            },
+            GetReserves {
+                reserve_a: u128,
+                reserve_b: u128,
+                block_timestamp_last: u64,
+            },
+            /// Should be returned from [`InnerAction::Sync`].
+            Sync {
+                /// The current amount of the A token in the contract's reserve.
+                reserve_a: U256,
+                /// The current amount of the B token in the contract's reserve.
+                reserve_b: U256,
+            },
+            /// Should be returned from [`InnerAction::Skim`].
+            Skim {
+                /// A skimmed amount of the A token.
+                amount_a: U256,
+                /// A skimmed amount of the A token.
+                amount_b: U256,
+                /// A recipient of skimmed tokens.
+                to: ActorId,
+            },
+            /// Emitted by `accept_admin`.
+            AdminSet(ActorId),
+            AdminTransferProposed(ActorId),
+            AdminTransferCancelled(ActorId),
+            RouterSet(ActorId),
+            DelegateChanged {
+                delegator: ActorId,
+                from_delegate: ActorId,
+                to_delegate: ActorId,
+            },
+            /// Emitted by `permit` instead of the VFT `Approval`.
+            Permit {
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+            },
+            Approval {
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+            },
+            Transfer {
+                from: ActorId,
+                to: ActorId,
+                value: U256,
+            },
+            RoleGranted {
+                role: Role,
+                account: ActorId,
+                sender: ActorId,
+            },
+            RoleRevoked {
+                role: Role,
+                account: ActorId,
+                sender: ActorId,
+            },
+        }
+        impl EventIo for LpServiceEvents {
+            const ROUTE: &'static [u8] = &[36, 76, 112, 83, 101, 114, 118, 105, 99, 101];
+            const EVENT_NAMES: &'static [&'static [u8]] = &[
+                &[24, 76, 80, 77, 105, 110, 116],
+                &[24, 76, 80, 66, 117, 114, 110],
+                &[16, 77, 105, 110, 116],
+                &[16, 66, 117, 114, 110],
+                &[16, 83, 119, 97, 112],
+                &[44, 71, 101, 116, 82, 101, 115, 101, 114, 118, 101, 115],
+                &[16, 83, 121, 110, 99],
+                &[16, 83, 107, 105, 109],
+                &[32, 65, 100, 109, 105, 110, 83, 101, 116],
+                &[
+                    84, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 80, 114, 111,
+                    112, 111, 115, 101, 100,
+                ],
+                &[
+                    88, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 67, 97, 110,
+                    99, 101, 108, 108, 101, 100,
+                ],
+                &[36, 82, 111, 117, 116, 101, 114, 83, 101, 116],
+                &[
+                    60, 68, 101, 108, 101, 103, 97, 116, 101, 67, 104, 97, 110, 103, 101, 100,
+                ],
+                &[24, 80, 101, 114, 109, 105, 116],
+                &[32, 65, 112, 112, 114, 111, 118, 97, 108],
+                &[32, 84, 114, 97, 110, 115, 102, 101, 114],
+                &[44, 82, 111, 108, 101, 71, 114, 97, 110, 116, 101, 100],
+                &[44, 82, 111, 108, 101, 82, 101, 118, 111, 107, 101, 100],
+            ];
+            type Event = Self;
+        }
+        pub fn listener<R: Listener<Vec<u8>>>(remoting: R) -> impl Listener<LpServiceEvents> {
+            RemotingListener::<_, LpServiceEvents>::new(remoting)
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum LpError {
+    /// An insufficient amount of the A or B token was provided.
+    InsufficientAmount,
+    /// A specified amount limit of the former tokens has been exceeded.
+    InsufficientFormerAmount,
+    /// A specified amount limit of the latter tokens has been exceeded.
+    InsufficientLatterAmount,
+    /// An insufficient amount of liquidity tokens was provided, or the contract
+    /// doesn't have enough of them to continue an action.
+    InsufficientLiquidity,
+    /// An invalid recipient was specified.
+    InvalidRecipient,
+    ZeroActorId,
+    /// One of the contract's FT contracts failed to complete a transfer
+    /// action.
+    ///
+    /// Most often, the reason is that a user didn't give an approval to the
+    /// contract or didn't have enough tokens to transfer.
+    TransferFailed,
+    /// An overflow occurred during calculations.
+    Overflow,
+    /// A specified deadline for an action was exceeded.
+    DeadlineExceeded,
+    IdenticalTokens,
+    /// linked Factory contract.
+    FeeToGettingFailed,
+    InvalidTokens,
+    InvalidRouter,
+    CanNotConnectToVft,
+    InsufficientLiquidityMinted,
+    InsufficientLiquidityBurned,
+    InsufficientOutputAmount,
+    InsufficientInputAmount,
+    KConstant,
+    InvalidTo,
+    CanNotConnectToFactory,
+    StatusIncorrect,
+    Unauthorized,
+    EmitEventFailed,
+    InvalidReserves,
+    InvalidAmount,
+    InvalidAdmin,
+    /// The factory has blocked one of the pair's tokens.
+    TokenBlocked,
+    /// The factory has paused the pair, or all pairs.
+    Paused,
+    /// There is no proposed admin to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin.
+    NotPendingAccount,
+    /// The permit signature is not the owner's over the permit payload
+    /// with its current nonce.
+    InvalidSignature,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PermitSignature {
+    /// Made with the `substrate` signing context, as wallets sign raw data.
+    Sr25519([u8; 64]),
+    Ed25519([u8; 64]),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Role {
+    /// Grants and revokes roles and changes the program's configuration.
+    DefaultAdmin,
+    /// Pauses and unpauses trading.
+    Pauser,
+    /// Sets fees and where they go.
+    FeeManager,
+    /// Lists tokens and edits their metadata and status.
+    AssetManager,
+    /// Moves funds held by the program.
+    Treasurer,
+    /// Sets staking rewards.
+    RewardManager,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum AccessControlError {
+    /// The caller does not hold `Role::DefaultAdmin`.
+    MissingAdminRole,
+    /// The account does not hold the role.
+    RoleNotHeld,
+    /// `DefaultAdmin` only changes hands through the program's
+    /// `propose_admin`/`accept_admin`.
+    AdminRoleNotGrantable,
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait LpVaraDexFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(
+            &self,
+            factory: ActorId,
+            token_a: ActorId,
+            token_b: ActorId,
+            name: String,
+            symbol: String,
+            decimals: u8,
+            admin: ActorId,
+            router: ActorId,
+        ) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait LpService {
+        type Args;
+        fn accept_admin(&mut self) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn burn(
+            &mut self,
+            to: ActorId,
+        ) -> impl Call<Output = Result<(U256, U256), LpError>, Args = Self::Args>;
+        fn cancel_admin_transfer(
+            &mut self,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn delegate(
+            &mut self,
+            delegatee: ActorId,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn mint(
+            &mut self,
+            to: ActorId,
+        ) -> impl Call<Output = Result<U256, LpError>, Args = Self::Args>;
+        fn permit(
+            &mut self,
+            owner: ActorId,
+            spender: ActorId,
+            value: U256,
+            deadline: u64,
+            signature: PermitSignature,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn propose_admin(
+            &mut self,
+            new_admin: ActorId,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn return_excess(
+            &mut self,
+            token: ActorId,
+            to: ActorId,
+        ) -> impl Call<Output = Result<U256, LpError>, Args = Self::Args>;
+        fn set_router(
+            &mut self,
+            new_router: ActorId,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn skim(
+            &mut self,
+            to: ActorId,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn swap(
+            &mut self,
+            amount0_out: U256,
+            amount1_out: U256,
+            to: ActorId,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn sync(&mut self) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn transfer(
+            &mut self,
+            to: ActorId,
+            value: U256,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn transfer_from(
+            &mut self,
+            from: ActorId,
+            to: ActorId,
+            value: U256,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn approve(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn grant_role(
+            &mut self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn renounce_role(
+            &mut self,
+            role: Role,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn revoke_role(
+            &mut self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn balance_of_at(
+            &self,
+            account: ActorId,
+            block: u32,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_admin(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_delegate(&self, account: ActorId)
+            -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_factory(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn get_reserves(&self) -> impl Query<Output = (U256, U256, u64), Args = Self::Args>;
+        fn get_router(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_tokens(&self) -> impl Query<Output = (ActorId, ActorId), Args = Self::Args>;
+        fn get_votes(&self, account: ActorId) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_votes_at(
+            &self,
+            account: ActorId,
+            block: u32,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
+        fn nonces(&self, owner: ActorId) -> impl Query<Output = u64, Args = Self::Args>;
+        fn total_supply_at(&self, block: u32) -> impl Query<Output = U256, Args = Self::Args>;
+        fn allowance(
+            &self,
+            owner: ActorId,
+            spender: ActorId,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
+        fn balance_of(&self, account: ActorId) -> impl Query<Output = U256, Args = Self::Args>;
+        fn decimals(&self) -> impl Query<Output = u8, Args = Self::Args>;
+        fn name(&self) -> impl Query<Output = String, Args = Self::Args>;
+        fn symbol(&self) -> impl Query<Output = String, Args = Self::Args>;
+        fn total_supply(&self) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_account_roles(
+            &self,
+            account: ActorId,
+        ) -> impl Query<Output = Vec<Role>, Args = Self::Args>;
+        fn get_role_members(
+            &self,
+            role: Role,
+        ) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn has_role(
+            &self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
+    }
+}
+
+#[cfg(feature = "with_mocks")]
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+#[cfg(feature = "with_mocks")]
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mockall {
+    use super::*;
+    use sails_rs::mockall::*;
+    mock! { pub LpService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::LpService for LpService<A> { type Args = A; fn accept_admin (&mut self, ) -> MockCall<A, Result<(), LpError>>;fn burn (&mut self, to: ActorId,) -> MockCall<A, Result<(U256,U256,), LpError>>;fn cancel_admin_transfer (&mut self, ) -> MockCall<A, Result<(), LpError>>;fn delegate (&mut self, delegatee: ActorId,) -> MockCall<A, Result<(), LpError>>;fn mint (&mut self, to: ActorId,) -> MockCall<A, Result<U256, LpError>>;fn permit (&mut self, owner: ActorId,spender: ActorId,value: U256,deadline: u64,signature: PermitSignature,) -> MockCall<A, Result<(), LpError>>;fn propose_admin (&mut self, new_admin: ActorId,) -> MockCall<A, Result<(), LpError>>;fn return_excess (&mut self, token: ActorId,to: ActorId,) -> MockCall<A, Result<U256, LpError>>;fn set_router (&mut self, new_router: ActorId,) -> MockCall<A, Result<(), LpError>>;fn skim (&mut self, to: ActorId,) -> MockCall<A, Result<(), LpError>>;fn swap (&mut self, amount0_out: U256,amount1_out: U256,to: ActorId,) -> MockCall<A, Result<(), LpError>>;fn sync (&mut self, ) -> MockCall<A, Result<(), LpError>>;fn transfer (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer_from (&mut self, from: ActorId,to: ActorId,value: U256,) -> MockCall<A, bool>;fn approve (&mut self, spender: ActorId,value: U256,) -> MockCall<A, bool>;fn grant_role (&mut self, role: Role,account: ActorId,) -> MockCall<A, Result<(), AccessControlError>>;fn renounce_role (&mut self, role: Role,) -> MockCall<A, Result<(), AccessControlError>>;fn revoke_role (&mut self, role: Role,account: ActorId,) -> MockCall<A, Result<(), AccessControlError>>;fn balance_of_at (& self, account: ActorId,block: u32,) -> MockQuery<A, U256>;fn get_admin (& self, ) -> MockQuery<A, ActorId>;fn get_delegate (& self, account: ActorId,) -> MockQuery<A, ActorId>;fn get_factory (& self, ) -> MockQuery<A, ActorId>;fn get_pending_admin (& self, ) -> MockQuery<A, Option<ActorId>>;fn get_reserves (& self, ) -> MockQuery<A, (U256,U256,u64,)>;fn get_router (& self, ) -> MockQuery<A, ActorId>;fn get_tokens (& self, ) -> MockQuery<A, (ActorId,ActorId,)>;fn get_votes (& self, account: ActorId,) -> MockQuery<A, U256>;fn get_votes_at (& self, account: ActorId,block: u32,) -> MockQuery<A, U256>;fn nonces (& self, owner: ActorId,) -> MockQuery<A, u64>;fn total_supply_at (& self, block: u32,) -> MockQuery<A, U256>;fn allowance (& self, owner: ActorId,spender: ActorId,) -> MockQuery<A, U256>;fn balance_of (& self, account: ActorId,) -> MockQuery<A, U256>;fn decimals (& self, ) -> MockQuery<A, u8>;fn name (& self, ) -> MockQuery<A, String>;fn symbol (& self, ) -> MockQuery<A, String>;fn total_supply (& self, ) -> MockQuery<A, U256>;fn get_account_roles (& self, account: ActorId,) -> MockQuery<A, Vec<Role>>;fn get_role_members (& self, role: Role,) -> MockQuery<A, Vec<ActorId>>;fn has_role (& self, role: Role,account: ActorId,) -> MockQuery<A, bool>; } }
+}
@@ -1 +1,2 @@
-pub mod extended_new_vft;
\ No newline at end of file
+pub mod extended_new_vft;
+pub mod lp_vara_dex_client;
\ No newline at end of file
@@ -9,6 +9,7 @@ use sails_rs::{
 };
 use services::factory_service::FactoryService;
 use clients::extended_new_vft::Vft as VftClient;
+use clients::lp_vara_dex_client::LpService as LpServiceClient;
 
 #[derive(Default)]
 pub struct FactoryVaraDexProgram;
@@ -23,6 +24,7 @@ impl FactoryVaraDexProgram {
     #[route("FactoryService")]
     pub fn factory_service(&self) -> FactoryService {
         let vft_client = VftClient::new(GStdRemoting);
-        FactoryService::new(vft_client)
+        let lp_client = LpServiceClient::new(GStdRemoting);
+        FactoryService::new(vft_client, lp_client)
     }
 }
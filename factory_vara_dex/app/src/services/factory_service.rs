@@ -1,78 +1,169 @@
-use core::str::FromStr;
-
 use gstd::exec;
-use gstd::prog::ProgramGenerator;
+use gstd::prog;
 use sails_rs::calls::{Call, Query};
-use sails_rs::gstd::calls::GStdRemoting;
-use sails_rs::{collections::HashMap, gstd::msg, prelude::*};
+use sails_rs::gstd::{calls::GStdRemoting, CommandReply};
+use sails_rs::{collections::{HashMap, HashSet}, gstd::msg, prelude::*};
 
+use access_control::{has_role, AccessControl, AccessControlService, Role};
 use crate::clients::extended_new_vft::traits::Vft;
 use crate::clients::extended_new_vft::Vft as VftClient;
-use crate::states::factory_state::{FactoryError, FactoryEvent, InitPair, StateFactory, FACTORY, BridgedAsset};
+use crate::clients::lp_vara_dex_client::traits::LpService;
+use crate::clients::lp_vara_dex_client::LpService as LpServiceClient;
+use crate::states::factory_state::{FactoryError, FactoryEvent, InitPair, StateFactory, FACTORY, BridgedAsset, ConfigChange, CreatorPolicy, PairCreationPolicy, PairMigration, PairTradingStatus, QueuedOperation, TokenMetadata, TokenStatus, lp_decimals, MAX_PAIRS_PAGE, MAX_TIMELOCK_DELAY, NATIVE_VARA, DEFAULT_PAIR_GAS_LIMIT, DEFAULT_PAIR_REPLY_GAS, pair_salt};
 
 pub struct FactoryService {
     pub vft_client: VftClient<GStdRemoting>,
+    pub lp_client: LpServiceClient<GStdRemoting>,
+    pub access_control: AccessControlService,
 }
 
 impl FactoryService {
     pub fn seed(code_id_pair: CodeId, fee_to: ActorId, fee_to_setter: ActorId, admin: ActorId) {
-        let mut seed_factory = StateFactory {
+        let seed_factory = StateFactory {
             code_id_pair,
                 fee_to,
                 fee_to_setter,
                 admin,
+                pending_admin: None,
+                pending_fee_to_setter: None,
                 router: ActorId::zero(),
                 pairs: HashMap::new(),
                 bridged_assets: HashMap::new(),
+                pair_gas_limit: DEFAULT_PAIR_GAS_LIMIT,
+                pair_reply_gas: DEFAULT_PAIR_REPLY_GAS,
+                all_pairs: Vec::new(),
+                pair_indexes: HashMap::new(),
+                token_pairs: HashMap::new(),
+                creator_policy: CreatorPolicy::Permissionless,
+                allowed_creators: HashSet::new(),
+                creation_fee: 0,
+                accrued_creation_fees: 0,
+                token_statuses: HashMap::new(),
+                status_tokens: HashMap::new(),
+                token_metadata: HashMap::new(),
+                pair_history: HashMap::new(),
+                deprecated_pairs: HashMap::new(),
+                paused_pairs: HashSet::new(),
+                all_paused: false,
+                timelock_delay: 0,
+                queued_operations: Vec::new(),
+                next_operation_id: 0,
         };
-        seed_factory.bridged_assets.insert(
-            ActorId::from_str("0x46896891852a364d9fe38b52e4061da85a00f6118415715c40d86a1f4ca00414").unwrap(), 
-            BridgedAsset {
-            name: String::from_str("Wrapped USDC").unwrap(),
-            symbol: String::from_str("WUSDC").unwrap(),
-            decimals: 6,
-        });
-        seed_factory.bridged_assets.insert(
-            ActorId::from_str("0x77e704ac1c6129d147ecc6b5ddadc80dd1945505bb9654b23e41153ed0737e49").unwrap(), 
-            BridgedAsset {
-            name: String::from_str("Wrapped USDT").unwrap(),
-            symbol: String::from_str("WUSDT").unwrap(),
-            decimals: 6,
-        });
-        seed_factory.bridged_assets.insert(
-            ActorId::from_str("0x1f2af47fa11e08e9a7e9bf81d84d166e425e1f13104690b33f4fd4c4ddca6709").unwrap(), 
-            BridgedAsset {
-            name: String::from_str("Wrapped Ethereum").unwrap(),
-            symbol: String::from_str("WETH").unwrap(),
-            decimals: 18,
-        });
-
-
         unsafe {
                 FACTORY = Some(seed_factory);
         }
+        AccessControlService::seed(admin);
+    }
+
+    /// Pushes a pair change to the router's pair cache; a zero `pair_address`
+    /// evicts the entry. Fire-and-forget, the admin can resync on failure.
+    fn sync_router_pair(token_pair: (ActorId, ActorId), pair_address: ActorId) {
+        let router = StateFactory::get().router;
+        if router.is_zero() {
+            return;
+        }
+        let payload = [
+            "RouterService".encode(),
+            "SyncPair".encode(),
+            (token_pair.0, token_pair.1, pair_address).encode(),
+        ]
+        .concat();
+        let _ = msg::send_bytes(router, payload, 0);
+    }
+
+    fn queue_operation(&mut self, change: ConfigChange) -> u64 {
+        let operation = StateFactory::get_mut().queue_operation(change, exec::block_timestamp());
+        self.emit_event(FactoryEvent::OperationQueued {
+            id: operation.id,
+            change: operation.change,
+            eta: operation.eta,
+        })
+        .ok();
+        operation.id
     }
 }
-#[service(events = FactoryEvent)]
+
+#[service(extends = AccessControlService, events = FactoryEvent)]
 impl FactoryService {
-    pub fn new(vft_client: VftClient<GStdRemoting>) -> Self {
-        Self { vft_client }
+    pub fn new(vft_client: VftClient<GStdRemoting>, lp_client: LpServiceClient<GStdRemoting>) -> Self {
+        Self {
+            vft_client,
+            lp_client,
+            access_control: AccessControlService::new(),
+        }
     }
 
-    pub fn set_fee_to(&mut self, new_fee_to: ActorId) -> Result<(), FactoryError> {
+    /// Queues the change and returns its operation id; see `execute_operation`.
+    pub fn set_fee_to(&mut self, new_fee_to: ActorId) -> Result<u64, FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
-        if caller != factory_state.fee_to_setter {
+        if caller != factory_state.fee_to_setter && !has_role(Role::FeeManager, caller) {
             return Err(FactoryError::Unauthorized);
         };
-        factory_state.fee_to = new_fee_to;
+        Ok(self.queue_operation(ConfigChange::FeeTo(new_fee_to)))
+    }
+
+    /// Queued like the setters, so the current delay is the notice for
+    /// lowering it.
+    pub fn set_timelock_delay(&mut self, delay: u64) -> Result<u64, FactoryError> {
+        if !has_role(Role::DefaultAdmin, msg::source()) {
+            return Err(FactoryError::Unauthorized);
+        }
+        if delay > MAX_TIMELOCK_DELAY {
+            return Err(FactoryError::InvalidTimelockDelay);
+        }
+        Ok(self.queue_operation(ConfigChange::TimelockDelay(delay)))
+    }
+
+    /// Applies a queued configuration change once its `eta` has passed.
+    /// Anyone can call it.
+    pub fn execute_operation(&mut self, id: u64) -> Result<(), FactoryError> {
+        let factory_state = StateFactory::get_mut();
+        let Some(operation) = factory_state.queued_operations.iter().find(|op| op.id == id) else {
+            return Err(FactoryError::OperationNotFound);
+        };
+        if exec::block_timestamp() < operation.eta {
+            return Err(FactoryError::OperationNotReady);
+        }
+        let operation = factory_state.take_operation(id).expect("operation was found");
+        let event = match operation.change {
+            ConfigChange::FeeTo(fee_to) => {
+                factory_state.fee_to = fee_to;
+                FactoryEvent::FeeToSet(fee_to)
+            }
+            ConfigChange::Router(router) => {
+                factory_state.router = router;
+                FactoryEvent::RouterSet(router)
+            }
+            ConfigChange::CodeIdPair(code_id) => {
+                factory_state.code_id_pair = code_id;
+                FactoryEvent::CodeIdPairUpdated(code_id)
+            }
+            ConfigChange::TimelockDelay(delay) => {
+                factory_state.timelock_delay = delay;
+                FactoryEvent::TimelockDelaySet(delay)
+            }
+        };
+        self.emit_event(event).ok();
+        self.emit_event(FactoryEvent::OperationExecuted(id)).ok();
+        Ok(())
+    }
+
+    pub fn cancel_operation(&mut self, id: u64) -> Result<(), FactoryError> {
+        if !has_role(Role::DefaultAdmin, msg::source()) {
+            return Err(FactoryError::Unauthorized);
+        }
+        StateFactory::get_mut()
+            .take_operation(id)
+            .ok_or(FactoryError::OperationNotFound)?;
+        self.emit_event(FactoryEvent::OperationCancelled(id)).ok();
         Ok(())
     }
 
     pub fn add_bridged_asset(&mut self, token_address: ActorId, name: String, symbol: String, decimals: u8) -> Result<BridgedAsset, FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
-        if caller != factory_state.admin {
+        if !has_role(Role::AssetManager, caller) {
             return Err(FactoryError::Unauthorized);
         }
         // check exist
@@ -87,7 +178,7 @@ impl FactoryService {
     pub fn remove_bridged_asset(&mut self, token_address: ActorId) -> Result<(), FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
-        if caller != factory_state.admin {
+        if !has_role(Role::AssetManager, caller) {
             return Err(FactoryError::Unauthorized);
         }
         factory_state.bridged_assets.remove(&token_address);
@@ -95,10 +186,10 @@ impl FactoryService {
         Ok(())
     }
 
-    pub fn add_pair(&mut self, token_a: ActorId, token_b: ActorId, pair_address: ActorId) -> Result<ActorId, FactoryError> {
+    pub async fn add_pair(&mut self, token_a: ActorId, token_b: ActorId, pair_address: ActorId) -> Result<ActorId, FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
-        if caller != factory_state.admin {
+        if !has_role(Role::DefaultAdmin, caller) {
             return Err(FactoryError::Unauthorized);
         }
         let token_pair = if token_b > token_a {
@@ -112,35 +203,166 @@ impl FactoryService {
             return Err(FactoryError::PairExist);
         }
 
-        factory_state.pairs.insert(token_pair, pair_address);
+        // the candidate must be a pair of these tokens wired to this factory and router
+        let Ok(pair_tokens) = self.lp_client.get_tokens().recv(pair_address).await else {
+            return Err(FactoryError::PairQueryFailed);
+        };
+        if pair_tokens != token_pair {
+            return Err(FactoryError::PairTokensMismatch);
+        }
+        let Ok(pair_factory) = self.lp_client.get_factory().recv(pair_address).await else {
+            return Err(FactoryError::PairQueryFailed);
+        };
+        if pair_factory != exec::program_id() {
+            return Err(FactoryError::PairFactoryMismatch);
+        }
+        let Ok(pair_router) = self.lp_client.get_router().recv(pair_address).await else {
+            return Err(FactoryError::PairQueryFailed);
+        };
+        if pair_router != factory_state.router {
+            return Err(FactoryError::PairRouterMismatch);
+        }
+
+        // the same pair may have been registered while the queries were pending
+        if factory_state.pairs.contains_key(&token_pair) {
+            return Err(FactoryError::PairExist);
+        }
+
+        let pair_index = factory_state.register_pair(token_pair, pair_address);
+        Self::sync_router_pair(token_pair, pair_address);
+        self.emit_event(FactoryEvent::PairAdded {
+            token_pair,
+            pair_address,
+            pair_number: pair_index + 1,
+        })
+        .ok();
         Ok(pair_address)
     }
 
-    pub fn set_fee_to_setter(&mut self, new_fee_setter: ActorId) -> Result<(), FactoryError> {
+    /// First step of handing over `fee_to_setter`; it changes only once the
+    /// proposed account calls `accept_fee_to_setter`. A new proposal replaces
+    /// the pending one.
+    pub fn propose_fee_to_setter(&mut self, new_fee_setter: ActorId) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if caller != factory_state.fee_to_setter {
+            return Err(FactoryError::Unauthorized);
+        };
+        if new_fee_setter.is_zero() {
+            return Err(FactoryError::ZeroAddress);
+        }
+        factory_state.pending_fee_to_setter = Some(new_fee_setter);
+        self.emit_event(FactoryEvent::FeeToSetterTransferProposed(new_fee_setter))
+            .ok();
+        Ok(())
+    }
+
+    pub fn accept_fee_to_setter(&mut self) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        match factory_state.pending_fee_to_setter {
+            None => return Err(FactoryError::NoPendingTransfer),
+            Some(pending) if pending != caller => return Err(FactoryError::NotPendingAccount),
+            Some(_) => {}
+        }
+        factory_state.pending_fee_to_setter = None;
+        factory_state.fee_to_setter = caller;
+        self.emit_event(FactoryEvent::FeeToSetterSet(caller)).ok();
+        Ok(())
+    }
+
+    pub fn cancel_fee_to_setter_transfer(&mut self) -> Result<(), FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
         if caller != factory_state.fee_to_setter {
             return Err(FactoryError::Unauthorized);
         };
-        factory_state.fee_to_setter = new_fee_setter;
-        self.emit_event(FactoryEvent::FeeToSetterSet(new_fee_setter))
+        let pending = factory_state
+            .pending_fee_to_setter
+            .take()
+            .ok_or(FactoryError::NoPendingTransfer)?;
+        self.emit_event(FactoryEvent::FeeToSetterTransferCancelled(pending))
             .ok();
         Ok(())
     }
 
+    /// `msg::value()` pays the creation fee; anything above it, or everything
+    /// if the pair is not created, goes back to the caller with the reply.
     pub async fn create_pair(
         &mut self,
         token_a: ActorId,
         token_b: ActorId,
+    ) -> CommandReply<Result<ActorId, FactoryError>> {
+        let caller = msg::source();
+        let value = msg::value();
+        let creation_fee = StateFactory::get().creation_fee;
+
+        let res = self.try_create_pair(caller, value, creation_fee, token_a, token_b).await;
+        let refund = if res.is_ok() {
+            StateFactory::get_mut().accrued_creation_fees += creation_fee;
+            value - creation_fee
+        } else {
+            value
+        };
+        CommandReply::new(res).with_value(refund)
+    }
+
+    /// Deploys a pair with the current `code_id_pair` for an existing token
+    /// pair and registers it in place of the old one, which is kept as
+    /// deprecated in the pair history. LPs move with the router's
+    /// `migrate_liquidity`.
+    pub async fn migrate_pair(&mut self, token_a: ActorId, token_b: ActorId) -> Result<ActorId, FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        let token_pair = if token_b > token_a {
+            (token_b, token_a)
+        } else {
+            (token_a, token_b)
+        };
+        let Some(old_pair) = factory_state.pairs.get(&token_pair).copied() else {
+            return Err(FactoryError::PairNotExist);
+        };
+
+        let (new_pair, _, _) = self.deploy_pair(token_a, token_b).await?;
+
+        // the pair may have been removed or replaced meanwhile
+        if factory_state.pairs.get(&token_pair) != Some(&old_pair) {
+            return Err(FactoryError::PairNotExist);
+        }
+        factory_state.replace_pair(token_pair, new_pair, exec::block_timestamp());
+        Self::sync_router_pair(token_pair, new_pair);
+        self.emit_event(FactoryEvent::PairMigrated {
+            token_pair,
+            old_pair,
+            new_pair,
+        })
+        .ok();
+        Ok(new_pair)
+    }
+
+    async fn try_create_pair(
+        &mut self,
+        caller: ActorId,
+        value: u128,
+        creation_fee: u128,
+        token_a: ActorId,
+        token_b: ActorId,
     ) -> Result<ActorId, FactoryError> {
         let factory_state = StateFactory::get_mut();
 
-        if token_a == token_b {
-            return Err(FactoryError::VFTError);
+        if !factory_state.can_create_pair(caller) {
+            return Err(FactoryError::CreatorNotAllowed);
         }
 
-        if token_a.is_zero() || token_b.is_zero() {
-            return Err(FactoryError::VFTError);
+        if value < creation_fee {
+            return Err(FactoryError::InsufficientCreationFee);
+        }
+
+        if token_a == token_b {
+            return Err(FactoryError::IdenticalAddresses);
         }
 
         let token_pair = if token_b > token_a {
@@ -148,73 +370,69 @@ impl FactoryService {
         } else {
             (token_a, token_b)
         };
+        // a zero token is native VARA, see `NATIVE_VARA`, and may only take
+        // the token1 slot
+        if token_pair.0 == NATIVE_VARA {
+            return Err(FactoryError::ZeroAddress);
+        }
 
         //check pair exists
         if factory_state.pairs.contains_key(&token_pair) {
             return Err(FactoryError::PairExist);
         }
-        // check token a and token b is bridged asset , if not get from  contract
 
-        let mut token_a_name = String::new();
-        let mut token_a_symbol = String::new();
-        let mut token_b_name = String::new();
-        let mut token_b_symbol = String::new();
+        if factory_state.token_status(&token_a) == TokenStatus::Blocked
+            || factory_state.token_status(&token_b) == TokenStatus::Blocked
+        {
+            return Err(FactoryError::TokenBlocked);
+        }
+        let (pair_address, tokens_metadata, lp_decimals) = self.deploy_pair(token_a, token_b).await?;
 
-        if factory_state.bridged_assets.contains_key(&token_a) {
-            token_a_name = factory_state.bridged_assets.get(&token_a).unwrap().name.clone();
-            token_a_symbol = factory_state.bridged_assets.get(&token_a).unwrap().symbol.clone();
-        } else {
-            let token_a_name_res = self.vft_client.name().recv(token_a).await;
-            let Ok(token_a_name_get) = token_a_name_res else {
-                return Err(FactoryError::VFTError);
-            }; 
-            token_a_name = token_a_name_get;
-            let token_a_symbol_res = self.vft_client.symbol().recv(token_a).await;
-            let Ok(token_a_symbol_get) = token_a_symbol_res else {
-                return Err(FactoryError::VFTError);
-            };
-            token_a_symbol = token_a_symbol_get;
-        }
-
-        if factory_state.bridged_assets.contains_key(&token_b) {
-            token_b_name = factory_state.bridged_assets.get(&token_b).unwrap().name.clone();
-            token_b_symbol = factory_state.bridged_assets.get(&token_b).unwrap().symbol.clone();
+        // another create_pair for the same tokens may have finished meanwhile
+        if factory_state.pairs.contains_key(&token_pair) {
+            return Err(FactoryError::PairExist);
+        }
+
+        //insert new pair_address
+        let pair_index = factory_state.register_pair(token_pair, pair_address);
+
+        //position in all_pairs
+        let pair_number = pair_index + 1;
+
+        self.emit_event(FactoryEvent::PairCreated {
+            token_pair,
+            pair_address: pair_address.clone(),
+            pair_number,
+            tokens_metadata,
+            lp_decimals,
+        })
+        .ok();
+
+        Ok(pair_address)
+    }
+
+    /// Deploys a pair program with the current `code_id_pair`. Returns its
+    /// address, the metadata of `token_pair.0`/`token_pair.1` and the LP
+    /// decimals.
+    async fn deploy_pair(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+    ) -> Result<(ActorId, (TokenMetadata, TokenMetadata), u8), FactoryError> {
+        let token_pair = if token_b > token_a {
+            (token_b, token_a)
         } else {
-            let token_b_name_res = self.vft_client.name().recv(token_b).await;
-            let Ok(token_b_name_get) = token_b_name_res else {
-                return Err(FactoryError::VFTError);
-            };
-            token_b_name = token_b_name_get;
-            let token_b_symbol_res = self.vft_client.symbol().recv(token_b).await;
-            let Ok(token_b_symbol_get) = token_b_symbol_res else {
-                return Err(FactoryError::VFTError);
-            };
-            token_b_symbol = token_b_symbol_get;
-        }
-
-        // let token_a_name_res = self.vft_client.name().recv(token_a).await;
-        // let Ok(token_a_name) = token_a_name_res else {
-        //     return Err(FactoryError::VFTError);
-        // };
-        // let token_a_symbol_res = self.vft_client.symbol().recv(token_a).await;
-        // let Ok(token_a_symbol) = token_a_symbol_res else {
-        //     return Err(FactoryError::VFTError);
-        // };
-        
-        // let token_b_name_res = self.vft_client.name().recv(token_b).await;
-        // let Ok(token_b_name) = token_b_name_res else {
-        //     return Err(FactoryError::VFTError);
-        // };
-
-        // let token_b_symbol_res = self.vft_client.symbol().recv(token_b).await;
-        // let Ok(token_b_symbol) = token_b_symbol_res else {
-        //     return Err(FactoryError::VFTError);
-        // };
-
-        let lp_name = format!("{}_{}_{}", token_a_name, token_b_name, "LP".to_string());
-        let lp_symbol = format!("{}_{}_{}", token_a_symbol, token_b_symbol, "LP".to_string());
-        let lp_decimals = 12;
+            (token_a, token_b)
+        };
+        // bridged assets and known tokens skip the metadata queries
+        let token_a_metadata = self.fetch_token_metadata(token_a).await?;
+        let token_b_metadata = self.fetch_token_metadata(token_b).await?;
+
+        let lp_name = format!("{}_{}_{}", token_a_metadata.name, token_b_metadata.name, "LP".to_string());
+        let lp_symbol = format!("{}_{}_{}", token_a_metadata.symbol, token_b_metadata.symbol, "LP".to_string());
+        let lp_decimals = lp_decimals(token_a_metadata.decimals, token_b_metadata.decimals);
 
+        let factory_state = StateFactory::get();
         let payload = InitPair {
             name: lp_name.clone(),
             symbol: lp_symbol.clone(),
@@ -226,75 +444,324 @@ impl FactoryService {
             router: factory_state.router,
         };
         let payload_bytes = ["New".encode(), payload.encode()].concat();
-        let create_program_future_res = ProgramGenerator::create_program_bytes_with_gas_for_reply(
+        // the salt only depends on the tokens; Gear still mixes the id of this
+        // message into the program id, so pair addresses cannot be predicted
+        let create_program_future_res = prog::create_program_bytes_with_gas_for_reply(
             factory_state.code_id_pair,
+            pair_salt(token_pair),
             payload_bytes,
-            10_000_000_000, // gas limit
+            factory_state.pair_gas_limit,
             0,
-            10_000_000_000, // gas for reply
-        )
-        .map_err(|_| FactoryError::VFTError);
+            factory_state.pair_reply_gas,
+        );
 
         let Ok(create_program_future) = create_program_future_res else {
+            return Err(FactoryError::PairCreationFailed);
+        };
+
+        // register the pair only once its constructor has succeeded
+        let Ok((pair_address, _)) = create_program_future.await else {
+            return Err(FactoryError::PairCreationFailed);
+        };
+
+        let tokens_metadata = if token_pair.0 == token_a {
+            (token_a_metadata, token_b_metadata)
+        } else {
+            (token_b_metadata, token_a_metadata)
+        };
+        Ok((pair_address, tokens_metadata, lp_decimals))
+    }
+
+    /// Metadata of `token`, queried from the token and cached when unknown.
+    async fn fetch_token_metadata(&mut self, token: ActorId) -> Result<TokenMetadata, FactoryError> {
+        if let Some(metadata) = StateFactory::get().token_metadata(&token) {
+            return Ok(metadata);
+        }
+        let Ok(name) = self.vft_client.name().recv(token).await else {
             return Err(FactoryError::VFTError);
         };
+        let Ok(symbol) = self.vft_client.symbol().recv(token).await else {
+            return Err(FactoryError::VFTError);
+        };
+        let Ok(decimals) = self.vft_client.decimals().recv(token).await else {
+            return Err(FactoryError::VFTError);
+        };
+        let factory_state = StateFactory::get_mut();
+        factory_state.token_metadata.insert(
+            token,
+            TokenMetadata {
+                name,
+                symbol,
+                decimals,
+                logo_uri: None,
+                verified: false,
+            },
+        );
+        Ok(factory_state.token_metadata(&token).expect("metadata was just cached"))
+    }
 
-        let pair_address = create_program_future.program_id;
+    /// Queues the change and returns its operation id.
+    pub fn set_router(&mut self, router: ActorId) -> Result<u64, FactoryError> {
+        let caller = msg::source();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        Ok(self.queue_operation(ConfigChange::Router(router)))
+    }
 
-        //insert new pair_address
-        factory_state
-            .pairs
-            .insert(token_pair.clone(), pair_address.clone());
+    /// First step of handing over the admin; nothing changes until the
+    /// proposed account calls `accept_admin`. A new proposal replaces the
+    /// pending one.
+    pub fn propose_admin(&mut self, new_admin: ActorId) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        if new_admin.is_zero() {
+            return Err(FactoryError::ZeroAddress);
+        }
+        factory_state.pending_admin = Some(new_admin);
+        self.emit_event(FactoryEvent::AdminTransferProposed(new_admin)).ok();
+        Ok(())
+    }
 
-        //pair length
-        let pair_number = factory_state.pairs.len().try_into().unwrap();
+    pub fn accept_admin(&mut self) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        match factory_state.pending_admin {
+            None => return Err(FactoryError::NoPendingTransfer),
+            Some(pending) if pending != caller => return Err(FactoryError::NotPendingAccount),
+            Some(_) => {}
+        }
+        factory_state.pending_admin = None;
+        // the new admin takes over every role of the old one
+        AccessControl::get_mut().transfer_roles(factory_state.admin, caller);
+        factory_state.admin = caller;
+        self.emit_event(FactoryEvent::AdminSet(caller)).ok();
+        Ok(())
+    }
 
-        self.emit_event(FactoryEvent::PairCreated {
-            token_pair,
-            pair_address: pair_address.clone(),
-            pair_number,
-        })
-        .ok();
+    pub fn cancel_admin_transfer(&mut self) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        let pending = factory_state
+            .pending_admin
+            .take()
+            .ok_or(FactoryError::NoPendingTransfer)?;
+        self.emit_event(FactoryEvent::AdminTransferCancelled(pending)).ok();
+        Ok(())
+    }
 
-        Ok(pair_address)
+    pub fn set_pair_creation_gas(&mut self, gas_limit: u64, reply_gas: u64) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        factory_state.pair_gas_limit = gas_limit;
+        factory_state.pair_reply_gas = reply_gas;
+        self.emit_event(FactoryEvent::PairCreationGasSet { gas_limit, reply_gas }).ok();
+        Ok(())
+    }
+
+    pub fn set_creator_policy(&mut self, policy: CreatorPolicy) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        factory_state.creator_policy = policy;
+        self.emit_event(FactoryEvent::CreatorPolicySet(policy)).ok();
+        Ok(())
+    }
+
+    pub fn allow_creator(&mut self, creator: ActorId) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        factory_state.allowed_creators.insert(creator);
+        self.emit_event(FactoryEvent::CreatorAllowed(creator)).ok();
+        Ok(())
+    }
+
+    pub fn disallow_creator(&mut self, creator: ActorId) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        if !factory_state.allowed_creators.remove(&creator) {
+            return Err(FactoryError::NotFound);
+        }
+        self.emit_event(FactoryEvent::CreatorDisallowed(creator)).ok();
+        Ok(())
+    }
+
+    pub fn set_creation_fee(&mut self, creation_fee: u128) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::FeeManager, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        factory_state.creation_fee = creation_fee;
+        self.emit_event(FactoryEvent::CreationFeeSet(creation_fee)).ok();
+        Ok(())
+    }
+
+    /// Sets the metadata used for `token` instead of querying it.
+    pub fn set_token_metadata(
+        &mut self,
+        token: ActorId,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        logo_uri: Option<String>,
+    ) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::AssetManager, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        if token.is_zero() {
+            return Err(FactoryError::ZeroAddress);
+        }
+        factory_state.token_metadata.insert(
+            token,
+            TokenMetadata {
+                name,
+                symbol,
+                decimals,
+                logo_uri,
+                verified: false,
+            },
+        );
+        self.emit_event(FactoryEvent::TokenMetadataSet { token }).ok();
+        Ok(())
+    }
+
+    /// Drops the cached metadata so the next `create_pair` queries it again.
+    pub fn remove_token_metadata(&mut self, token: ActorId) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::AssetManager, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        if factory_state.token_metadata.remove(&token).is_none() {
+            return Err(FactoryError::NotFound);
+        }
+        self.emit_event(FactoryEvent::TokenMetadataRemoved { token }).ok();
+        Ok(())
+    }
+
+    pub fn set_token_status(&mut self, token: ActorId, status: TokenStatus) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::AssetManager, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        if token.is_zero() {
+            return Err(FactoryError::ZeroAddress);
+        }
+        factory_state.set_token_status(token, status);
+        self.emit_event(FactoryEvent::TokenStatusSet { token, status }).ok();
+        Ok(())
+    }
+
+    /// Stops `swap`, `mint`, `skim` and `sync` on the pair; `burn` stays open.
+    pub fn pause_pair(&mut self, pair: ActorId) -> Result<(), FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if !has_role(Role::Pauser, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        if !factory_state.paused_pairs.insert(pair) {
+            return Err(FactoryError::AlreadyPaused);
+        }
+        self.emit_event(FactoryEvent::PairPaused(pair)).ok();
+        Ok(())
     }
 
-    pub fn set_router(&mut self, router: ActorId) -> Result<(), FactoryError> {
+    pub fn unpause_pair(&mut self, pair: ActorId) -> Result<(), FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
-        if caller != factory_state.admin {
+        if !has_role(Role::Pauser, caller) {
             return Err(FactoryError::Unauthorized);
         }
-        factory_state.router = router;
-        self.emit_event(FactoryEvent::RouterSet(router)).ok();
+        if !factory_state.paused_pairs.remove(&pair) {
+            return Err(FactoryError::NotPaused);
+        }
+        self.emit_event(FactoryEvent::PairUnpaused(pair)).ok();
         Ok(())
     }
 
-    pub fn set_admin(&mut self, new_admin: ActorId) -> Result<(), FactoryError> {
+    /// Pauses every pair; pairs paused one by one stay paused after
+    /// `unpause_all`.
+    pub fn pause_all(&mut self) -> Result<(), FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
-        if caller != factory_state.admin {
+        if !has_role(Role::Pauser, caller) {
             return Err(FactoryError::Unauthorized);
         }
-        factory_state.admin = new_admin;
-        self.emit_event(FactoryEvent::AdminSet(new_admin)).ok();
+        if factory_state.all_paused {
+            return Err(FactoryError::AlreadyPaused);
+        }
+        factory_state.all_paused = true;
+        self.emit_event(FactoryEvent::AllPairsPaused).ok();
         Ok(())
     }
 
-    pub fn update_code_id_pair(&mut self, new_code_id_pair: CodeId) -> Result<(), FactoryError> {
+    pub fn unpause_all(&mut self) -> Result<(), FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
-        if caller != factory_state.admin {
+        if !has_role(Role::Pauser, caller) {
             return Err(FactoryError::Unauthorized);
         }
-        factory_state.code_id_pair = new_code_id_pair;
-        self.emit_event(FactoryEvent::CodeIdPairUpdated(new_code_id_pair)).ok();
+        if !factory_state.all_paused {
+            return Err(FactoryError::NotPaused);
+        }
+        factory_state.all_paused = false;
+        self.emit_event(FactoryEvent::AllPairsUnpaused).ok();
         Ok(())
     }
+
+    /// Sends the accrued creation fees to `fee_to`.
+    pub fn withdraw_creation_fees(&mut self) -> Result<u128, FactoryError> {
+        let caller = msg::source();
+        let factory_state = StateFactory::get_mut();
+        if caller != factory_state.fee_to && !has_role(Role::Treasurer, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        let amount = factory_state.accrued_creation_fees;
+        if amount == 0 {
+            return Err(FactoryError::NoFeesToWithdraw);
+        }
+        let to = factory_state.fee_to;
+        if msg::send_bytes(to, "Transfer Vara".encode(), amount).is_err() {
+            return Err(FactoryError::MessageSendError);
+        }
+        factory_state.accrued_creation_fees = 0;
+        self.emit_event(FactoryEvent::CreationFeesWithdrawn { to, amount }).ok();
+        Ok(amount)
+    }
+
+    /// Queues the change and returns its operation id.
+    pub fn update_code_id_pair(&mut self, new_code_id_pair: CodeId) -> Result<u64, FactoryError> {
+        let caller = msg::source();
+        if !has_role(Role::DefaultAdmin, caller) {
+            return Err(FactoryError::Unauthorized);
+        }
+        Ok(self.queue_operation(ConfigChange::CodeIdPair(new_code_id_pair)))
+    }
     pub  fn remove_pair(&mut self, token_a: ActorId, token_b: ActorId) -> Result<(), FactoryError> {
         let caller = msg::source();
         let factory_state = StateFactory::get_mut();
-        if caller != factory_state.admin {
+        if !has_role(Role::DefaultAdmin, caller) {
             return Err(FactoryError::Unauthorized);
         }
         let token_pair = if token_b > token_a {
@@ -302,7 +769,8 @@ impl FactoryService {
         } else {
             (token_a, token_b)
         };
-        factory_state.pairs.remove(&token_pair);
+        factory_state.unregister_pair(token_pair);
+        Self::sync_router_pair(token_pair, ActorId::zero());
         self.emit_event(FactoryEvent::PairRemoved { token_pair }).ok();
         Ok(())
     }
@@ -319,6 +787,21 @@ impl FactoryService {
         state.fee_to_setter
     }
 
+    pub fn get_pending_fee_to_setter(&self) -> Option<ActorId> {
+        let state = StateFactory::get();
+        state.pending_fee_to_setter
+    }
+
+    pub fn get_timelock_delay(&self) -> u64 {
+        let state = StateFactory::get();
+        state.timelock_delay
+    }
+
+    pub fn get_queued_operations(&self) -> Vec<QueuedOperation> {
+        let state = StateFactory::get();
+        state.queued_operations.clone()
+    }
+
     pub fn get_pair(&self, token_a: ActorId, token_b: ActorId) -> ActorId {
         let state = StateFactory::get();
 
@@ -339,12 +822,148 @@ impl FactoryService {
 
     pub fn get_all_pairs(&self) -> Vec<(ActorId, ActorId)> {
         let state = StateFactory::get();
-        state.pairs.keys().cloned().collect()
+        state
+            .all_pairs
+            .iter()
+            .filter(|(_, _, pair)| !pair.is_zero())
+            .map(|(token0, token1, _)| (*token0, *token1))
+            .collect()
     }
     pub fn get_all_pairs_address(&self) -> Vec<ActorId> {
         let state = StateFactory::get();
-        state.pairs.values().cloned().collect()
+        state
+            .all_pairs
+            .iter()
+            .filter(|(_, _, pair)| !pair.is_zero())
+            .map(|(_, _, pair)| *pair)
+            .collect()
+    }
+
+    /// Length of the `all_pairs` registry, removed pairs included.
+    pub fn get_all_pairs_length(&self) -> u64 {
+        let state = StateFactory::get();
+        state.all_pairs.len() as u64
+    }
+
+    /// Returns `(token0, token1, pair)`; `pair` is zero if it was removed.
+    pub fn get_pair_by_index(&self, index: u64) -> Option<(ActorId, ActorId, ActorId)> {
+        let state = StateFactory::get();
+        state.all_pairs.get(index as usize).copied()
+    }
+
+    /// Page of the `all_pairs` registry; `limit` is capped at `MAX_PAIRS_PAGE`.
+    pub fn get_pairs(&self, offset: u64, limit: u32) -> Vec<(ActorId, ActorId, ActorId)> {
+        let state = StateFactory::get();
+        state
+            .all_pairs
+            .iter()
+            .skip(offset as usize)
+            .take(limit.min(MAX_PAIRS_PAGE) as usize)
+            .copied()
+            .collect()
+    }
+
+    /// Page of the registered pairs containing `token`, in creation order.
+    pub fn get_pairs_for_token(
+        &self,
+        token: ActorId,
+        offset: u64,
+        limit: u32,
+    ) -> Vec<(ActorId, ActorId, ActorId)> {
+        let state = StateFactory::get();
+        let Some(indexes) = state.token_pairs.get(&token) else {
+            return Vec::new();
+        };
+        indexes
+            .iter()
+            .skip(offset as usize)
+            .take(limit.min(MAX_PAIRS_PAGE) as usize)
+            .map(|index| state.all_pairs[*index as usize])
+            .collect()
+    }
+    /// Migrations of the token pair, oldest first.
+    pub fn get_pair_history(&self, token_a: ActorId, token_b: ActorId) -> Vec<PairMigration> {
+        let state = StateFactory::get();
+        let token_pair = if token_b > token_a {
+            (token_b, token_a)
+        } else {
+            (token_a, token_b)
+        };
+        state.pair_history.get(&token_pair).cloned().unwrap_or_default()
+    }
+    /// The pair that replaced the deprecated `pair`, if any.
+    pub fn get_successor_pair(&self, pair: ActorId) -> Option<ActorId> {
+        StateFactory::get().deprecated_pairs.get(&pair).copied()
+    }
+    pub fn get_token_metadata(&self, token: ActorId) -> Option<TokenMetadata> {
+        StateFactory::get().token_metadata(&token)
+    }
+    /// Metadata of each token in `tokens`, in the same order.
+    pub fn get_tokens_metadata(&self, tokens: Vec<ActorId>) -> Vec<Option<TokenMetadata>> {
+        let state = StateFactory::get();
+        tokens
+            .iter()
+            .take(MAX_PAIRS_PAGE as usize)
+            .map(|token| state.token_metadata(token))
+            .collect()
+    }
+    pub fn get_token_status(&self, token: ActorId) -> TokenStatus {
+        StateFactory::get().token_status(&token)
+    }
+    /// Whether either token of the pair is blocked.
+    pub fn is_pair_blocked(&self, token_a: ActorId, token_b: ActorId) -> bool {
+        let state = StateFactory::get();
+        state.token_status(&token_a) == TokenStatus::Blocked
+            || state.token_status(&token_b) == TokenStatus::Blocked
+    }
+    pub fn is_pair_paused(&self, pair: ActorId) -> bool {
+        StateFactory::get().is_pair_paused(&pair)
+    }
+    pub fn get_paused_pairs(&self) -> Vec<ActorId> {
+        StateFactory::get().paused_pairs.iter().copied().collect()
+    }
+    pub fn is_all_paused(&self) -> bool {
+        StateFactory::get().all_paused
+    }
+    /// Pause and block checks of a pair in one query; pairs ask it before
+    /// `swap`, `mint`, `skim` and `sync`.
+    pub fn get_pair_trading_status(&self, pair: ActorId, token_a: ActorId, token_b: ActorId) -> PairTradingStatus {
+        let state = StateFactory::get();
+        if state.is_pair_paused(&pair) {
+            PairTradingStatus::Paused
+        } else if self.is_pair_blocked(token_a, token_b) {
+            PairTradingStatus::Blocked
+        } else {
+            PairTradingStatus::Active
+        }
+    }
+    pub fn get_tokens_by_status(&self, status: TokenStatus, offset: u32, limit: u32) -> Vec<ActorId> {
+        let state = StateFactory::get();
+        let Some(tokens) = state.status_tokens.get(&status) else {
+            return Vec::new();
+        };
+        tokens
+            .iter()
+            .skip(offset as usize)
+            .take(limit.min(MAX_PAIRS_PAGE) as usize)
+            .copied()
+            .collect()
+    }
+    /// Returns `(gas_limit, reply_gas)` used when creating pair programs.
+    pub fn get_pair_creation_gas(&self) -> (u64, u64) {
+        let state = StateFactory::get();
+        (state.pair_gas_limit, state.pair_reply_gas)
     }
+    pub fn get_pair_creation_policy(&self) -> PairCreationPolicy {
+        let state = StateFactory::get();
+        PairCreationPolicy {
+            policy: state.creator_policy,
+            allowed_creators: state.allowed_creators.iter().copied().collect(),
+            creation_fee: state.creation_fee,
+            accrued_fees: state.accrued_creation_fees,
+        }
+    }
+
     pub fn get_code_id_pair(&self) -> CodeId {
         let state = StateFactory::get();
         state.code_id_pair
@@ -353,8 +972,18 @@ impl FactoryService {
         let state = StateFactory::get();
         state.admin
     }
+    pub fn get_pending_admin(&self) -> Option<ActorId> {
+        let state = StateFactory::get();
+        state.pending_admin
+    }
     pub fn get_router(&self) -> ActorId {
         let state = StateFactory::get();
         state.router
     }
 }
+
+impl AsRef<AccessControlService> for FactoryService {
+    fn as_ref(&self) -> &AccessControlService {
+        &self.access_control
+    }
+}
@@ -1,7 +1,33 @@
-use sails_rs::{collections::HashMap, prelude::*};
+use access_control::Role;
+use sails_rs::{collections::{HashMap, HashSet}, prelude::*};
 
 pub static mut FACTORY: Option<StateFactory> = None;
 
+/// Upper bound on the number of entries returned by one paginated query.
+pub const MAX_PAIRS_PAGE: u32 = 100;
+
+pub const DEFAULT_PAIR_GAS_LIMIT: u64 = 10_000_000_000;
+pub const DEFAULT_PAIR_REPLY_GAS: u64 = 10_000_000_000;
+
+/// Longest timelock delay, in milliseconds, so a bad value cannot freeze the
+/// configuration for good.
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60 * 1000;
+
+/// Token id standing for native VARA: a pair created with it holds VARA as
+/// program value rather than as wVARA.
+pub const NATIVE_VARA: ActorId = ActorId::zero();
+
+/// Decimals of native VARA.
+pub const NATIVE_VARA_DECIMALS: u8 = 12;
+
+/// Prefix of the salt every pair program is created with.
+pub const PAIR_SALT_PREFIX: &[u8] = b"vara_dex_pair";
+
+/// Salt of the pair program for a sorted `(token0, token1)` pair.
+pub fn pair_salt(token_pair: (ActorId, ActorId)) -> Vec<u8> {
+    [PAIR_SALT_PREFIX, token_pair.0.as_ref(), token_pair.1.as_ref()].concat()
+}
+
 #[derive(Debug, Decode, Encode, TypeInfo)]
 #[codec(crate = gstd::codec)]
 #[scale_info(crate = gstd::scale_info)]
@@ -12,15 +38,155 @@ pub struct BridgedAsset {
 }
 
 
+/// Who may call `create_pair`. `Role::DefaultAdmin` holders may create pairs
+/// in every mode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum CreatorPolicy {
+    #[default]
+    Permissionless,
+    /// Only creators added with `allow_creator`.
+    Allowlisted,
+    /// Only the router, so every pair goes through its checks.
+    RouterOnly,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Decode, Encode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum TokenStatus {
+    /// No flag set; tokens without a flag are not listed by status.
+    #[default]
+    Unknown,
+    Verified,
+    /// No new pairs, and existing pairs refuse `swap`/`mint`; `burn` stays open.
+    Blocked,
+}
+
+/// What a pair allows, as answered to the pair itself. `burn` is open in
+/// every state so LPs can always exit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum PairTradingStatus {
+    Active,
+    /// Paused on its own or with all pairs.
+    Paused,
+    /// One of the tokens is `TokenStatus::Blocked`.
+    Blocked,
+}
+
+#[derive(Debug, Decode, Encode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct PairCreationPolicy {
+    pub policy: CreatorPolicy,
+    pub allowed_creators: Vec<ActorId>,
+    /// Native VARA `create_pair` must be sent with.
+    pub creation_fee: u128,
+    /// Creation fees held for `fee_to` until withdrawn.
+    pub accrued_fees: u128,
+}
+/// Metadata of a pair token, from `bridged_assets`, the admin, or fetched
+/// from the token on first use.
+#[derive(Debug, Clone, Decode, Encode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub logo_uri: Option<String>,
+    /// Mirrors the token's `TokenStatus::Verified` flag.
+    pub verified: bool,
+}
+
+/// A configuration change held back by the timelock.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum ConfigChange {
+    FeeTo(ActorId),
+    Router(ActorId),
+    CodeIdPair(CodeId),
+    /// Applies to operations queued after it executes.
+    TimelockDelay(u64),
+}
+
+#[derive(Debug, Clone, Decode, Encode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct QueuedOperation {
+    pub id: u64,
+    pub change: ConfigChange,
+    /// Block timestamp from which anyone can execute the change.
+    pub eta: u64,
+}
+
+#[derive(Debug, Clone, Decode, Encode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct PairMigration {
+    pub old_pair: ActorId,
+    pub new_pair: ActorId,
+    /// Code the new pair was deployed from.
+    pub code_id: CodeId,
+    /// Block timestamp of the migration.
+    pub migrated_at: u64,
+}
+
+/// LP supply starts at `sqrt(amount_a * amount_b)`, so it carries the mean
+/// of the token decimals, rounded up.
+pub fn lp_decimals(decimals_a: u8, decimals_b: u8) -> u8 {
+    (decimals_a as u16 + decimals_b as u16).div_ceil(2) as u8
+}
+
 #[derive(Debug, Default)]
 pub struct StateFactory {
     pub code_id_pair: CodeId,
     pub fee_to: ActorId,
     pub fee_to_setter: ActorId,
     pub admin: ActorId,
+    /// Proposed `admin`, set until it accepts or the proposal is cancelled.
+    pub pending_admin: Option<ActorId>,
+    /// Proposed `fee_to_setter`, same flow as `pending_admin`.
+    pub pending_fee_to_setter: Option<ActorId>,
     pub router: ActorId,
     pub pairs: HashMap<(ActorId, ActorId), ActorId>,
     pub bridged_assets: HashMap<ActorId, BridgedAsset>,
+    /// Gas given to a pair's constructor and reserved for its init reply.
+    pub pair_gas_limit: u64,
+    pub pair_reply_gas: u64,
+    /// Append-only `(token0, token1, pair)` list; removed pairs keep their
+    /// slot with a zero pair address so indexes stay stable.
+    pub all_pairs: Vec<(ActorId, ActorId, ActorId)>,
+    /// `all_pairs` index of every registered token pair.
+    pub pair_indexes: HashMap<(ActorId, ActorId), u64>,
+    /// `all_pairs` indexes of the registered pairs of each token.
+    pub token_pairs: HashMap<ActorId, Vec<u64>>,
+    pub creator_policy: CreatorPolicy,
+    pub allowed_creators: HashSet<ActorId>,
+    pub creation_fee: u128,
+    pub accrued_creation_fees: u128,
+    pub token_statuses: HashMap<ActorId, TokenStatus>,
+    /// Flagged tokens of each status in the order they were flagged.
+    pub status_tokens: HashMap<TokenStatus, Vec<ActorId>>,
+    /// Metadata cached by `create_pair` or set by the admin.
+    pub token_metadata: HashMap<ActorId, TokenMetadata>,
+    /// Migrations of each token pair, oldest first.
+    pub pair_history: HashMap<(ActorId, ActorId), Vec<PairMigration>>,
+    /// Deprecated pair to the pair that replaced it.
+    pub deprecated_pairs: HashMap<ActorId, ActorId>,
+    pub paused_pairs: HashSet<ActorId>,
+    /// Pauses every pair regardless of `paused_pairs`.
+    pub all_paused: bool,
+    /// Minimum time, in milliseconds, between queuing a configuration change
+    /// and executing it.
+    pub timelock_delay: u64,
+    /// Pending configuration changes, oldest first.
+    pub queued_operations: Vec<QueuedOperation>,
+    pub next_operation_id: u64,
 }
 
 impl StateFactory {
@@ -30,6 +196,122 @@ impl StateFactory {
     pub fn get() -> &'static Self {
         unsafe { FACTORY.as_ref().expect("State Factory Error") }
     }
+
+    /// Registers the pair and returns its `all_pairs` index.
+    pub fn register_pair(&mut self, token_pair: (ActorId, ActorId), pair_address: ActorId) -> u64 {
+        let index = self.all_pairs.len() as u64;
+        self.all_pairs.push((token_pair.0, token_pair.1, pair_address));
+        self.pairs.insert(token_pair, pair_address);
+        self.pair_indexes.insert(token_pair, index);
+        self.token_pairs.entry(token_pair.0).or_default().push(index);
+        self.token_pairs.entry(token_pair.1).or_default().push(index);
+        index
+    }
+
+    pub fn can_create_pair(&self, creator: ActorId) -> bool {
+        if access_control::has_role(Role::DefaultAdmin, creator) {
+            return true;
+        }
+        match self.creator_policy {
+            CreatorPolicy::Permissionless => true,
+            CreatorPolicy::Allowlisted => self.allowed_creators.contains(&creator),
+            CreatorPolicy::RouterOnly => creator == self.router,
+        }
+    }
+
+    /// Known metadata of `token`; bridged assets take precedence over the cache.
+    pub fn token_metadata(&self, token: &ActorId) -> Option<TokenMetadata> {
+        let mut metadata = match self.bridged_assets.get(token) {
+            Some(asset) => TokenMetadata {
+                name: asset.name.clone(),
+                symbol: asset.symbol.clone(),
+                decimals: asset.decimals,
+                logo_uri: self.token_metadata.get(token).and_then(|m| m.logo_uri.clone()),
+                verified: false,
+            },
+            None if *token == NATIVE_VARA => TokenMetadata {
+                name: "VARA".to_string(),
+                symbol: "VARA".to_string(),
+                decimals: NATIVE_VARA_DECIMALS,
+                logo_uri: None,
+                verified: false,
+            },
+            None => self.token_metadata.get(token)?.clone(),
+        };
+        metadata.verified = self.token_status(token) == TokenStatus::Verified;
+        Some(metadata)
+    }
+
+    pub fn token_status(&self, token: &ActorId) -> TokenStatus {
+        self.token_statuses.get(token).copied().unwrap_or_default()
+    }
+
+    pub fn set_token_status(&mut self, token: ActorId, status: TokenStatus) {
+        let previous = self.token_status(&token);
+        if previous == status {
+            return;
+        }
+        if let Some(tokens) = self.status_tokens.get_mut(&previous) {
+            tokens.retain(|t| *t != token);
+        }
+        if status == TokenStatus::Unknown {
+            self.token_statuses.remove(&token);
+        } else {
+            self.token_statuses.insert(token, status);
+            self.status_tokens.entry(status).or_default().push(token);
+        }
+    }
+
+    /// Queues `change` to be executable `timelock_delay` after `timestamp`.
+    pub fn queue_operation(&mut self, change: ConfigChange, timestamp: u64) -> QueuedOperation {
+        let operation = QueuedOperation {
+            id: self.next_operation_id,
+            change,
+            eta: timestamp.saturating_add(self.timelock_delay),
+        };
+        self.next_operation_id += 1;
+        self.queued_operations.push(operation.clone());
+        operation
+    }
+
+    pub fn take_operation(&mut self, id: u64) -> Option<QueuedOperation> {
+        let index = self.queued_operations.iter().position(|op| op.id == id)?;
+        Some(self.queued_operations.remove(index))
+    }
+
+    pub fn is_pair_paused(&self, pair: &ActorId) -> bool {
+        self.all_paused || self.paused_pairs.contains(pair)
+    }
+
+    /// Registers `new_pair` for a registered token pair in the old pair's
+    /// `all_pairs` slot and deprecates the old pair.
+    pub fn replace_pair(&mut self, token_pair: (ActorId, ActorId), new_pair: ActorId, timestamp: u64) {
+        let Some(old_pair) = self.pairs.insert(token_pair, new_pair) else {
+            return;
+        };
+        if let Some(index) = self.pair_indexes.get(&token_pair) {
+            self.all_pairs[*index as usize].2 = new_pair;
+        }
+        self.deprecated_pairs.insert(old_pair, new_pair);
+        self.pair_history.entry(token_pair).or_default().push(PairMigration {
+            old_pair,
+            new_pair,
+            code_id: self.code_id_pair,
+            migrated_at: timestamp,
+        });
+    }
+
+    pub fn unregister_pair(&mut self, token_pair: (ActorId, ActorId)) {
+        self.pairs.remove(&token_pair);
+        if let Some(index) = self.pair_indexes.remove(&token_pair) {
+            self.all_pairs[index as usize].2 = ActorId::zero();
+            for token in [token_pair.0, token_pair.1] {
+                if let Some(indexes) = self.token_pairs.get_mut(&token) {
+                    indexes.retain(|i| *i != index);
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Decode, Encode, TypeInfo)]
@@ -57,12 +339,15 @@ pub enum FactoryEvent {
         token_pair: (ActorId, ActorId),
         /// [`ActorId`] of a created Pair contract.
         pair_address: ActorId,
-        /// A number of Pair contracts (including a created one) inside the
-        /// Factory contract.
+        /// Position of the created pair in the `all_pairs` registry, counting
+        /// from 1; `get_pair_by_index(pair_number - 1)` returns it.
         pair_number: u64,
+        /// Metadata of `token_pair.0` and `token_pair.1`.
+        tokens_metadata: (TokenMetadata, TokenMetadata),
+        lp_decimals: u8,
     },
 
-    /// Should be returned from [`Action::FeeToSetter`].
+    /// Emitted by `accept_fee_to_setter`.
     FeeToSetterSet(
         /// New `fee_to_setter`.
         ActorId,
@@ -75,11 +360,31 @@ pub enum FactoryEvent {
     ),
     Pair(ActorId),
     RouterSet(ActorId),
+    /// Emitted by `accept_admin`.
     AdminSet(ActorId),
+    AdminTransferProposed(ActorId),
+    AdminTransferCancelled(ActorId),
+    FeeToSetterTransferProposed(ActorId),
+    FeeToSetterTransferCancelled(ActorId),
     CodeIdPairUpdated(CodeId),
+    PairCreationGasSet {
+        gas_limit: u64,
+        reply_gas: u64,
+    },
+    PairAdded {
+        token_pair: (ActorId, ActorId),
+        pair_address: ActorId,
+        /// Position of the pair in the `all_pairs` registry, counting from 1.
+        pair_number: u64,
+    },
     PairRemoved {
         token_pair: (ActorId, ActorId),
     },
+    PairMigrated {
+        token_pair: (ActorId, ActorId),
+        old_pair: ActorId,
+        new_pair: ActorId,
+    },
     BridgedAssetAdded {
         token_address: ActorId,
         name: String,
@@ -89,6 +394,36 @@ pub enum FactoryEvent {
     BridgedAssetRemoved {
         token_address: ActorId,
     },
+    CreatorPolicySet(CreatorPolicy),
+    CreatorAllowed(ActorId),
+    CreatorDisallowed(ActorId),
+    CreationFeeSet(u128),
+    CreationFeesWithdrawn {
+        to: ActorId,
+        amount: u128,
+    },
+    TokenStatusSet {
+        token: ActorId,
+        status: TokenStatus,
+    },
+    TokenMetadataSet {
+        token: ActorId,
+    },
+    TokenMetadataRemoved {
+        token: ActorId,
+    },
+    PairPaused(ActorId),
+    PairUnpaused(ActorId),
+    AllPairsPaused,
+    AllPairsUnpaused,
+    OperationQueued {
+        id: u64,
+        change: ConfigChange,
+        eta: u64,
+    },
+    OperationExecuted(u64),
+    OperationCancelled(u64),
+    TimelockDelaySet(u64),
 }
 
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
@@ -104,4 +439,26 @@ pub enum FactoryError {
     PairNotExist,
     VFTError,
     BridgedAssetExist,
+    /// The candidate of `add_pair` did not answer the pair queries.
+    PairQueryFailed,
+    PairTokensMismatch,
+    PairFactoryMismatch,
+    PairRouterMismatch,
+    CreatorNotAllowed,
+    InsufficientCreationFee,
+    NoFeesToWithdraw,
+    TokenBlocked,
+    AlreadyPaused,
+    NotPaused,
+    ZeroAddress,
+    /// There is no proposed admin or `fee_to_setter` to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin or `fee_to_setter`.
+    NotPendingAccount,
+    OperationNotFound,
+    /// The operation's `eta` has not been reached yet.
+    OperationNotReady,
+    InvalidTimelockDelay,
+    /// `create_pair` was given the same token twice.
+    IdenticalAddresses,
 }
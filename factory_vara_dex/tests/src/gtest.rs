@@ -2,7 +2,7 @@
 mod tests {
     use std::{path::PathBuf, env};
     use sails_rs::{
-        calls::{Activation, Call, Query}, futures::future::Remote, gtest::{calls::*, System}, prelude::*
+        calls::{Action, Activation, Call, Query}, futures::future::Remote, gtest::{calls::*, System}, prelude::*
     };
 
     use client::{
@@ -12,7 +12,13 @@ mod tests {
         },
         FactoryVaraDexFactory as Factory,
         FactoryService as FactoryDexClient,
-        FactoryError
+        ConfigChange,
+        CreatorPolicy,
+        FactoryError,
+        TokenStatus,
+        PairTradingStatus,
+        Role,
+        AccessControlError,
     };
     use factory_vara_dex::clients::extended_new_vft::{
         traits::{
@@ -23,7 +29,7 @@ mod tests {
         Vft as WVaraVftClient,
     };
 
-    use crate::utils;
+    use crate::utils::{self, MockPair};
     
     const ADMIN_ID: u64 = 10;
     const USER_ID: u64 = 11;
@@ -128,55 +134,263 @@ mod tests {
     #[tokio::test]
     async fn test_admin_functions() {
         let (program_space, factory_id) = init_factory().await;
-        let mut client = FactoryDexClient::new(program_space);
+        let mut client = FactoryDexClient::new(program_space.clone());
 
-        // set_fee_to
+        // set_fee_to, executed right away while the timelock delay is zero
         let new_fee_to: ActorId = 20.into();
-        client
+        let id = client
             .set_fee_to(new_fee_to)
-            .send(factory_id)
+            .send_recv(factory_id)
             .await
+            .unwrap()
             .unwrap();
+        client.execute_operation(id).send(factory_id).await.unwrap();
         let fee_to = client.get_fee_to().recv(factory_id).await.unwrap();
         assert_eq!(fee_to, new_fee_to);
 
-        // set_fee_to_setter
-        let new_fee_to_setter: ActorId = 21.into();
+        // propose_fee_to_setter / accept_fee_to_setter
+        let new_fee_to_setter: ActorId = FEE_TO_SETTER_ID.into();
         client
-            .set_fee_to_setter(new_fee_to_setter)
+            .propose_fee_to_setter(new_fee_to_setter)
+            .send(factory_id)
+            .await
+            .unwrap();
+        let mut fee_to_setter_client = FactoryDexClient::new(program_space.clone().with_actor_id(new_fee_to_setter));
+        fee_to_setter_client
+            .accept_fee_to_setter()
             .send(factory_id)
             .await
             .unwrap();
         let fee_to_setter = client.get_fee_to_setter().recv(factory_id).await.unwrap();
         assert_eq!(fee_to_setter, new_fee_to_setter);
+        let pending = client.get_pending_fee_to_setter().recv(factory_id).await.unwrap();
+        assert_eq!(pending, None);
 
         // set_router
         let new_router: ActorId = 22.into();
-        client
+        let id = client
             .set_router(new_router)
-            .send(factory_id)
+            .send_recv(factory_id)
             .await
+            .unwrap()
             .unwrap(); // from admin
+        client.execute_operation(id).send(factory_id).await.unwrap();
         let router = client.get_router().recv(factory_id).await.unwrap();
         assert_eq!(router, new_router);
 
         // update_code_id_pair
         let new_code_id_pair = CodeId::from([2; 32]);
-        client
+        let id = client
             .update_code_id_pair(new_code_id_pair)
-            .send(factory_id)
+            .send_recv(factory_id)
             .await
+            .unwrap()
             .unwrap();
+        client.execute_operation(id).send(factory_id).await.unwrap();
         let code_id_pair = client.get_code_id_pair().recv(factory_id).await.unwrap();
         assert_eq!(code_id_pair, new_code_id_pair);
 
-        // set_admin
-        let new_admin: ActorId = 23.into();
-        client.set_admin(new_admin).send(factory_id).await.unwrap(); // from old admin
+        // propose_admin / accept_admin
+        let new_admin: ActorId = USER_ID.into();
+        client.propose_admin(new_admin).send(factory_id).await.unwrap(); // from old admin
+        let admin = client.get_admin().recv(factory_id).await.unwrap();
+        assert_eq!(admin, ADMIN_ID.into());
+        let mut new_admin_client = FactoryDexClient::new(program_space.with_actor_id(new_admin));
+        new_admin_client.accept_admin().send(factory_id).await.unwrap();
         let admin = client.get_admin().recv(factory_id).await.unwrap();
         assert_eq!(admin, new_admin);
     }
 
+    #[tokio::test]
+    async fn test_two_step_transfers() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let mut user_client = FactoryDexClient::new(program_space.with_actor_id(USER_ID.into()));
+
+        let res = client
+            .propose_admin(ActorId::zero())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::ZeroAddress)));
+        let res = user_client
+            .propose_admin(USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+        let res = user_client
+            .accept_admin()
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::NoPendingTransfer)));
+
+        // only the proposed account can accept
+        client
+            .propose_admin(FEE_TO_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let pending = client.get_pending_admin().recv(factory_id).await.unwrap();
+        assert_eq!(pending, Some(FEE_TO_ID.into()));
+        let res = user_client
+            .accept_admin()
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::NotPendingAccount)));
+
+        // a cancelled proposal can no longer be accepted
+        let res = user_client
+            .cancel_admin_transfer()
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+        client
+            .cancel_admin_transfer()
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let pending = client.get_pending_admin().recv(factory_id).await.unwrap();
+        assert_eq!(pending, None);
+        let res = client
+            .cancel_admin_transfer()
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::NoPendingTransfer)));
+        let admin = client.get_admin().recv(factory_id).await.unwrap();
+        assert_eq!(admin, ADMIN_ID.into());
+
+        // fee_to_setter follows the same flow
+        let res = user_client
+            .propose_fee_to_setter(USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+        client
+            .propose_fee_to_setter(FEE_TO_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_client
+            .accept_fee_to_setter()
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::NotPendingAccount)));
+        client
+            .cancel_fee_to_setter_transfer()
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let pending = client.get_pending_fee_to_setter().recv(factory_id).await.unwrap();
+        assert_eq!(pending, None);
+        let fee_to_setter = client.get_fee_to_setter().recv(factory_id).await.unwrap();
+        assert_eq!(fee_to_setter, ADMIN_ID.into());
+    }
+
+    #[tokio::test]
+    async fn test_timelock() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let mut user_client = FactoryDexClient::new(program_space.clone().with_actor_id(USER_ID.into()));
+        let delay = 20 * 3000; // 20 blocks
+
+        let res = client
+            .set_timelock_delay(31 * 24 * 60 * 60 * 1000)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::InvalidTimelockDelay)));
+        let res = user_client
+            .set_timelock_delay(delay)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+        let id = client
+            .set_timelock_delay(delay)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        client
+            .execute_operation(id)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let timelock_delay = client.get_timelock_delay().recv(factory_id).await.unwrap();
+        assert_eq!(timelock_delay, delay);
+
+        // a queued change waits for its eta
+        let new_fee_to: ActorId = 20.into();
+        let id = client
+            .set_fee_to(new_fee_to)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let queued = client.get_queued_operations().recv(factory_id).await.unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].id, id);
+        assert_eq!(queued[0].change, ConfigChange::FeeTo(new_fee_to));
+        let res = user_client
+            .execute_operation(id)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::OperationNotReady)));
+
+        // only the admin cancels
+        let res = user_client
+            .cancel_operation(id)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+        client
+            .cancel_operation(id)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_client
+            .execute_operation(id)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::OperationNotFound)));
+
+        // anyone executes once the delay has passed
+        let id = client
+            .set_fee_to(new_fee_to)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let system = program_space.system();
+        system.run_to_block(system.block_height() + 20);
+        user_client
+            .execute_operation(id)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let fee_to = client.get_fee_to().recv(factory_id).await.unwrap();
+        assert_eq!(fee_to, new_fee_to);
+        let queued = client.get_queued_operations().recv(factory_id).await.unwrap();
+        assert!(queued.is_empty());
+    }
+
     #[tokio::test]
     async fn test_bridged_assets() {
         let (program_space, factory_id) = init_factory().await;
@@ -218,11 +432,16 @@ mod tests {
     #[tokio::test]
     async fn test_manual_pair_management() {
         let (program_space, factory_id) = init_factory().await;
-        let mut client = FactoryDexClient::new(program_space);
+        let mut client = FactoryDexClient::new(program_space.clone());
 
         let token_a: ActorId = 200.into();
         let token_b: ActorId = 201.into();
-        let pair_address: ActorId = 300.into();
+        let pair_address = utils::deploy_mock_pair(
+            &program_space,
+            300,
+            ADMIN_ID,
+            MockPair::new(token_a, token_b, factory_id),
+        );
 
         let added_pair_address = client
             .add_pair(token_a, token_b, pair_address)
@@ -247,6 +466,737 @@ mod tests {
         assert_eq!(pair_length, 0);
     }
 
+    #[tokio::test]
+    async fn test_add_pair_validation() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+
+        let token_a: ActorId = 200.into();
+        let token_b: ActorId = 201.into();
+
+        // not a pair at all
+        let res = client
+            .add_pair(token_a, token_b, factory_id)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairQueryFailed)));
+
+        let wrong_tokens = utils::deploy_mock_pair(
+            &program_space,
+            300,
+            ADMIN_ID,
+            MockPair::new(token_a, 202.into(), factory_id),
+        );
+        let res = client
+            .add_pair(token_a, token_b, wrong_tokens)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairTokensMismatch)));
+
+        let wrong_factory = utils::deploy_mock_pair(
+            &program_space,
+            301,
+            ADMIN_ID,
+            MockPair::new(token_a, token_b, ADMIN_ID.into()),
+        );
+        let res = client
+            .add_pair(token_b, token_a, wrong_factory)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairFactoryMismatch)));
+
+        let mut mock = MockPair::new(token_a, token_b, factory_id);
+        mock.router = ROUTER_ID.into();
+        let wrong_router = utils::deploy_mock_pair(&program_space, 302, ADMIN_ID, mock);
+        let res = client
+            .add_pair(token_a, token_b, wrong_router)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairRouterMismatch)));
+
+        let pair_length = client.get_pair_length().recv(factory_id).await.unwrap();
+        assert_eq!(pair_length, 0);
+
+        let id = client
+            .set_router(ROUTER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        client
+            .execute_operation(id)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let added = client
+            .add_pair(token_a, token_b, wrong_router)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(added, wrong_router);
+    }
+
+    #[tokio::test]
+    async fn test_create_pair_with_failing_code_id() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+
+        // bridged assets skip the token metadata queries
+        let token_a: ActorId = 100.into();
+        let token_b: ActorId = 101.into();
+        for token in [token_a, token_b] {
+            client
+                .add_bridged_asset(token, "Bridged".to_string(), "BRG".to_string(), 6)
+                .send_recv(factory_id)
+                .await
+                .unwrap()
+                .unwrap();
+        }
+
+        // no code is uploaded under the zero code id
+        let res = client
+            .create_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairCreationFailed)));
+
+        // a program whose constructor rejects the pair init payload
+        let vft_code_id = program_space.system().submit_code_file(
+            PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+                .join("src")
+                .join("extended_vft.opt.wasm"),
+        );
+        let id = client
+            .update_code_id_pair(vft_code_id)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        client
+            .execute_operation(id)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = client
+            .create_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairCreationFailed)));
+
+        let pair = client.get_pair(token_a, token_b).recv(factory_id).await.unwrap();
+        assert_eq!(pair, ActorId::zero());
+        let pair_length = client.get_pair_length().recv(factory_id).await.unwrap();
+        assert_eq!(pair_length, 0);
+    }
+
+    #[tokio::test]
+    async fn test_native_vara_pair() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let token: ActorId = 100.into();
+        client
+            .add_bridged_asset(token, "Bridged".to_string(), "BRG".to_string(), 6)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let metadata = client
+            .get_token_metadata(ActorId::zero())
+            .recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.symbol, "VARA");
+        assert_eq!(metadata.decimals, 12);
+
+        // the zero token passes validation and metadata; only the missing
+        // pair code stops the deployment
+        let res = client
+            .create_pair(token, ActorId::zero())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairCreationFailed)));
+        let res = client
+            .create_pair(ActorId::zero(), ActorId::zero())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::IdenticalAddresses)));
+        let res = client
+            .set_token_status(ActorId::zero(), TokenStatus::Blocked)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::ZeroAddress)));
+    }
+
+    #[tokio::test]
+    async fn test_creator_policy_and_creation_fee() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let mut user_client = FactoryDexClient::new(program_space.clone().with_actor_id(USER_ID.into()));
+
+        let token_a: ActorId = 100.into();
+        let token_b: ActorId = 101.into();
+        for token in [token_a, token_b] {
+            client
+                .add_bridged_asset(token, "Bridged".to_string(), "BRG".to_string(), 6)
+                .send_recv(factory_id)
+                .await
+                .unwrap()
+                .unwrap();
+        }
+
+        let res = user_client
+            .set_creator_policy(CreatorPolicy::RouterOnly)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+
+        client
+            .set_creator_policy(CreatorPolicy::RouterOnly)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_client
+            .create_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::CreatorNotAllowed)));
+
+        client
+            .set_creator_policy(CreatorPolicy::Allowlisted)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_client
+            .create_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::CreatorNotAllowed)));
+
+        // an allowed creator gets past the policy; no pair code is uploaded
+        client
+            .allow_creator(USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_client
+            .create_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairCreationFailed)));
+
+        let creation_fee = 10 * utils::ONE_TOKEN;
+        client
+            .set_creation_fee(creation_fee)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_client
+            .create_pair(token_a, token_b)
+            .with_value(creation_fee - 1)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::InsufficientCreationFee)));
+
+        // a failed creation keeps nothing
+        let factory_balance = program_space.system().balance_of(factory_id);
+        let res = user_client
+            .create_pair(token_a, token_b)
+            .with_value(creation_fee)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::PairCreationFailed)));
+        assert_eq!(program_space.system().balance_of(factory_id), factory_balance);
+
+        let policy = client.get_pair_creation_policy().recv(factory_id).await.unwrap();
+        assert_eq!(policy.policy, CreatorPolicy::Allowlisted);
+        assert_eq!(policy.allowed_creators, vec![ActorId::from(USER_ID)]);
+        assert_eq!(policy.creation_fee, creation_fee);
+        assert_eq!(policy.accrued_fees, 0);
+
+        let res = client.withdraw_creation_fees().send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::NoFeesToWithdraw)));
+    }
+
+    #[tokio::test]
+    async fn test_token_status() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let mut user_client = FactoryDexClient::new(program_space.clone().with_actor_id(USER_ID.into()));
+
+        let token_a: ActorId = 100.into();
+        let token_b: ActorId = 101.into();
+
+        let res = user_client
+            .set_token_status(token_b, TokenStatus::Blocked)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+
+        client
+            .set_token_status(token_a, TokenStatus::Verified)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        for token in [token_b, 102.into(), 103.into()] {
+            client
+                .set_token_status(token, TokenStatus::Blocked)
+                .send_recv(factory_id)
+                .await
+                .unwrap()
+                .unwrap();
+        }
+
+        let status = client.get_token_status(token_a).recv(factory_id).await.unwrap();
+        assert_eq!(status, TokenStatus::Verified);
+        let verified = client
+            .get_tokens_by_status(TokenStatus::Verified, 0, 10)
+            .recv(factory_id)
+            .await
+            .unwrap();
+        assert_eq!(verified, vec![token_a]);
+        let blocked = client
+            .get_tokens_by_status(TokenStatus::Blocked, 1, 1)
+            .recv(factory_id)
+            .await
+            .unwrap();
+        assert_eq!(blocked, vec![ActorId::from(102)]);
+
+        let is_blocked = client.is_pair_blocked(token_a, token_b).recv(factory_id).await.unwrap();
+        assert!(is_blocked);
+        let res = client
+            .create_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::TokenBlocked)));
+
+        // clearing the flag unlists the token
+        client
+            .set_token_status(token_b, TokenStatus::Unknown)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let is_blocked = client.is_pair_blocked(token_a, token_b).recv(factory_id).await.unwrap();
+        assert!(!is_blocked);
+        let blocked = client
+            .get_tokens_by_status(TokenStatus::Blocked, 0, 10)
+            .recv(factory_id)
+            .await
+            .unwrap();
+        assert_eq!(blocked, vec![ActorId::from(102), ActorId::from(103)]);
+    }
+
+    #[tokio::test]
+    async fn test_token_metadata() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let mut user_client = FactoryDexClient::new(program_space.clone().with_actor_id(USER_ID.into()));
+
+        let token_a: ActorId = 100.into();
+        let token_b: ActorId = 101.into();
+
+        let res = user_client
+            .set_token_metadata(token_a, "Token".to_string(), "TKN".to_string(), 18, None)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+
+        client
+            .set_token_metadata(
+                token_a,
+                "Token".to_string(),
+                "TKN".to_string(),
+                18,
+                Some("https://example.com/tkn.png".to_string()),
+            )
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        client
+            .add_bridged_asset(token_b, "Bridged".to_string(), "BRG".to_string(), 6)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        client
+            .set_token_status(token_a, TokenStatus::Verified)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let metadata = client
+            .get_tokens_metadata(vec![token_a, token_b, 102.into()])
+            .recv(factory_id)
+            .await
+            .unwrap();
+        assert_eq!(metadata.len(), 3);
+        let metadata_a = metadata[0].as_ref().unwrap();
+        assert_eq!(metadata_a.symbol, "TKN");
+        assert_eq!(metadata_a.decimals, 18);
+        assert_eq!(metadata_a.logo_uri.as_deref(), Some("https://example.com/tkn.png"));
+        assert!(metadata_a.verified);
+        let metadata_b = metadata[1].as_ref().unwrap();
+        assert_eq!(metadata_b.decimals, 6);
+        assert!(!metadata_b.verified);
+        assert!(metadata[2].is_none());
+
+        // 18 and 6 decimals give the former fixed 12
+        assert_eq!(factory_vara_dex::states::factory_state::lp_decimals(18, 6), 12);
+        assert_eq!(factory_vara_dex::states::factory_state::lp_decimals(6, 9), 8);
+
+        client
+            .remove_token_metadata(token_a)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let metadata = client.get_token_metadata(token_a).recv(factory_id).await.unwrap();
+        assert!(metadata.is_none());
+        let res = client.remove_token_metadata(token_a).send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_migrate_pair() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let mut user_client = FactoryDexClient::new(program_space.clone().with_actor_id(USER_ID.into()));
+
+        let token_a: ActorId = 100.into();
+        let token_b: ActorId = 101.into();
+        for token in [token_a, token_b] {
+            client
+                .add_bridged_asset(token, "Bridged".to_string(), "BRG".to_string(), 6)
+                .send_recv(factory_id)
+                .await
+                .unwrap()
+                .unwrap();
+        }
+
+        let res = client.migrate_pair(token_a, token_b).send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::PairNotExist)));
+
+        let old_pair = utils::deploy_mock_pair(
+            &program_space,
+            300,
+            ADMIN_ID,
+            MockPair::new(token_a, token_b, factory_id),
+        );
+        client
+            .add_pair(token_a, token_b, old_pair)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let res = user_client.migrate_pair(token_a, token_b).send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+
+        // no code is uploaded under the zero code id, so the old pair stays
+        let res = client.migrate_pair(token_b, token_a).send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::PairCreationFailed)));
+        let pair = client.get_pair(token_a, token_b).recv(factory_id).await.unwrap();
+        assert_eq!(pair, old_pair);
+        let history = client.get_pair_history(token_a, token_b).recv(factory_id).await.unwrap();
+        assert!(history.is_empty());
+        let successor = client.get_successor_pair(old_pair).recv(factory_id).await.unwrap();
+        assert!(successor.is_none());
+
+        // replacing keeps the `all_pairs` slot and records the history
+        let mut state = factory_vara_dex::states::factory_state::StateFactory::default();
+        let token_pair = (token_b, token_a);
+        let new_pair: ActorId = 301.into();
+        state.register_pair(token_pair, old_pair);
+        state.replace_pair(token_pair, new_pair, 42);
+        assert_eq!(state.pairs.get(&token_pair), Some(&new_pair));
+        assert_eq!(state.all_pairs.len(), 1);
+        assert_eq!(state.all_pairs[0].2, new_pair);
+        assert_eq!(state.deprecated_pairs.get(&old_pair), Some(&new_pair));
+        let history = &state.pair_history[&token_pair];
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].old_pair, old_pair);
+        assert_eq!(history[0].migrated_at, 42);
+    }
+
+    #[tokio::test]
+    async fn test_pause_pairs() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let mut user_client = FactoryDexClient::new(program_space.clone().with_actor_id(USER_ID.into()));
+
+        let pair: ActorId = 300.into();
+        let other_pair: ActorId = 301.into();
+        let token_a: ActorId = 100.into();
+        let token_b: ActorId = 101.into();
+
+        let res = user_client.pause_pair(pair).send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+
+        client
+            .grant_role(Role::Pauser, USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        user_client.pause_pair(pair).send_recv(factory_id).await.unwrap().unwrap();
+        let res = user_client.pause_pair(pair).send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::AlreadyPaused)));
+
+        let status = client
+            .get_pair_trading_status(pair, token_a, token_b)
+            .recv(factory_id)
+            .await
+            .unwrap();
+        assert_eq!(status, PairTradingStatus::Paused);
+        let status = client
+            .get_pair_trading_status(other_pair, token_a, token_b)
+            .recv(factory_id)
+            .await
+            .unwrap();
+        assert_eq!(status, PairTradingStatus::Active);
+
+        // a global pause covers every pair and keeps single pauses afterwards
+        client.pause_all().send_recv(factory_id).await.unwrap().unwrap();
+        assert!(client.is_pair_paused(other_pair).recv(factory_id).await.unwrap());
+        client.unpause_all().send_recv(factory_id).await.unwrap().unwrap();
+        assert!(!client.is_pair_paused(other_pair).recv(factory_id).await.unwrap());
+        assert!(client.is_pair_paused(pair).recv(factory_id).await.unwrap());
+        let res = client.unpause_all().send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::NotPaused)));
+
+        // blocked tokens report Blocked once the pair is unpaused
+        client
+            .set_token_status(token_b, TokenStatus::Blocked)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        user_client.unpause_pair(pair).send_recv(factory_id).await.unwrap().unwrap();
+        let status = client
+            .get_pair_trading_status(pair, token_a, token_b)
+            .recv(factory_id)
+            .await
+            .unwrap();
+        assert_eq!(status, PairTradingStatus::Blocked);
+        let paused = client.get_paused_pairs().recv(factory_id).await.unwrap();
+        assert!(paused.is_empty());
+
+        client
+            .revoke_role(Role::Pauser, USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_client.pause_all().send_recv(factory_id).await.unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+    }
+
+    #[tokio::test]
+    async fn test_roles() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+        let mut user_client = FactoryDexClient::new(program_space.clone().with_actor_id(USER_ID.into()));
+        let token: ActorId = 100.into();
+
+        // the admin starts with every role
+        let roles = client.get_account_roles(ADMIN_ID.into()).recv(factory_id).await.unwrap();
+        assert_eq!(roles.len(), 6);
+
+        let res = user_client
+            .grant_role(Role::AssetManager, USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(AccessControlError::MissingAdminRole)));
+        let res = user_client
+            .set_token_status(token, TokenStatus::Verified)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+        // the admin role moves only through propose_admin/accept_admin
+        let res = client
+            .grant_role(Role::DefaultAdmin, USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(AccessControlError::AdminRoleNotGrantable)));
+
+        client
+            .grant_role(Role::AssetManager, USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        user_client
+            .set_token_status(token, TokenStatus::Verified)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        // an asset manager holds no admin powers
+        let res = user_client
+            .set_router(USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+        let members = client.get_role_members(Role::AssetManager).recv(factory_id).await.unwrap();
+        assert_eq!(members, vec![ADMIN_ID.into(), USER_ID.into()]);
+
+        user_client
+            .renounce_role(Role::AssetManager)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!client.has_role(Role::AssetManager, USER_ID.into()).recv(factory_id).await.unwrap());
+        let res = client
+            .revoke_role(Role::AssetManager, USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(AccessControlError::RoleNotHeld)));
+
+        // a new admin takes over the old admin's roles
+        client
+            .propose_admin(USER_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        user_client
+            .accept_admin()
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let roles = client.get_account_roles(ADMIN_ID.into()).recv(factory_id).await.unwrap();
+        assert!(roles.is_empty());
+        assert!(client.has_role(Role::DefaultAdmin, USER_ID.into()).recv(factory_id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_pair_creation_gas() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+
+        client
+            .set_pair_creation_gas(5_000_000_000, 2_000_000_000)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let gas = client.get_pair_creation_gas().recv(factory_id).await.unwrap();
+        assert_eq!(gas, (5_000_000_000, 2_000_000_000));
+
+        let mut user_client = FactoryDexClient::new(program_space.with_actor_id(USER_ID.into()));
+        let res = user_client
+            .set_pair_creation_gas(1, 1)
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(FactoryError::Unauthorized)));
+    }
+
+    #[tokio::test]
+    async fn test_pair_registry_pagination() {
+        let (program_space, factory_id) = init_factory().await;
+        let mut client = FactoryDexClient::new(program_space.clone());
+
+        let token: ActorId = 200.into();
+        for i in 0..3u64 {
+            let pair = utils::deploy_mock_pair(
+                &program_space,
+                300 + i,
+                ADMIN_ID,
+                MockPair::new(token, (201 + i).into(), factory_id),
+            );
+            client
+                .add_pair(token, (201 + i).into(), pair)
+                .send_recv(factory_id)
+                .await
+                .unwrap()
+                .unwrap();
+        }
+        let pair = utils::deploy_mock_pair(
+            &program_space,
+            310,
+            ADMIN_ID,
+            MockPair::new(210.into(), 211.into(), factory_id),
+        );
+        client
+            .add_pair(210.into(), 211.into(), pair)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let pairs = client.get_pairs(1, 2).recv(factory_id).await.unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[0].2, 301.into());
+        assert_eq!(pairs[1].2, 302.into());
+
+        let pairs = client
+            .get_pairs_for_token(token, 0, 10)
+            .recv(factory_id)
+            .await
+            .unwrap();
+        assert_eq!(pairs.len(), 3);
+
+        // removing keeps the indexes of the other pairs stable
+        client
+            .remove_pair(token, 202.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let removed = client.get_pair_by_index(1).recv(factory_id).await.unwrap();
+        assert_eq!(removed.map(|(_, _, pair)| pair), Some(ActorId::zero()));
+        let last = client.get_pair_by_index(3).recv(factory_id).await.unwrap();
+        assert_eq!(last.map(|(_, _, pair)| pair), Some(310.into()));
+        let length = client.get_all_pairs_length().recv(factory_id).await.unwrap();
+        assert_eq!(length, 4);
+
+        let pairs = client
+            .get_pairs_for_token(token, 0, 10)
+            .recv(factory_id)
+            .await
+            .unwrap();
+        let addresses: Vec<ActorId> = pairs.iter().map(|(_, _, pair)| *pair).collect();
+        assert_eq!(addresses, vec![300.into(), 302.into()]);
+    }
+
 
 
 
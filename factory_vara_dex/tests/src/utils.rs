@@ -5,9 +5,10 @@ use std::{
 use sails_rs::CodeId;
 use sails_rs::{
     prelude::*,
-    gtest::{calls::*, System},
+    gtest::{calls::*, Program, System},
     calls::Remoting
 };
+use gtest::WasmProgram;
 
 pub const ONE_TOKEN: u128 = 1_000_000_000_000;
 
@@ -38,7 +39,7 @@ pub fn path_to_opt_wasm_target() -> String {
         .join("target")
         .join("wasm32-gear")
         .join("release")
-        .join("application_builder.opt.wasm");
+        .join("factory_vara_dex_wasm.opt.wasm");
     let wasm_path_str = wasm_path.to_str().unwrap();
 
     wasm_path_str.to_string()
@@ -55,4 +56,70 @@ pub fn workspace_cargo_toml_path() -> PathBuf {
 
 pub fn crate_cargo_toml_path() -> PathBuf {
     PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
-}
\ No newline at end of file
+}
+
+/// Stand-in for a deployed pair: answers the `LpService` queries the factory
+/// checks in `add_pair`.
+#[derive(Debug, Clone)]
+pub struct MockPair {
+    pub tokens: (ActorId, ActorId),
+    pub factory: ActorId,
+    pub router: ActorId,
+}
+
+impl MockPair {
+    pub fn new(token_a: ActorId, token_b: ActorId, factory: ActorId) -> Self {
+        let tokens = if token_b > token_a {
+            (token_b, token_a)
+        } else {
+            (token_a, token_b)
+        };
+        Self {
+            tokens,
+            factory,
+            router: ActorId::zero(),
+        }
+    }
+}
+
+impl WasmProgram for MockPair {
+    fn init(&mut self, _payload: Vec<u8>) -> Result<Option<Vec<u8>>, &'static str> {
+        Ok(None)
+    }
+
+    fn handle(&mut self, payload: Vec<u8>) -> Result<Option<Vec<u8>>, &'static str> {
+        let mut input = payload.as_slice();
+        let service = String::decode(&mut input).map_err(|_| "invalid route")?;
+        let method = String::decode(&mut input).map_err(|_| "invalid route")?;
+        if service != "LpService" {
+            return Err("unknown service");
+        }
+        let reply = match method.as_str() {
+            "GetTokens" => self.tokens.encode(),
+            "GetFactory" => self.factory.encode(),
+            "GetRouter" => self.router.encode(),
+            _ => return Err("unknown method"),
+        };
+        Ok(Some([service.encode(), method.encode(), reply].concat()))
+    }
+
+    fn handle_reply(&mut self, _payload: Vec<u8>) -> Result<(), &'static str> {
+        Ok(())
+    }
+
+    fn handle_signal(&mut self, _payload: Vec<u8>) -> Result<(), &'static str> {
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<u8>, &'static str> {
+        Err("no state")
+    }
+}
+
+pub fn deploy_mock_pair(program_space: &GTestRemoting, pair_id: u64, from: u64, mock: MockPair) -> ActorId {
+    let system = program_space.system();
+    let program = Program::mock_with_id(system, pair_id, mock);
+    program.send_bytes(from, b"init");
+    system.run_next_block();
+    pair_id.into()
+}
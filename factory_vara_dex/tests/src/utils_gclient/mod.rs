@@ -57,7 +57,7 @@ pub async fn upload_factory_vara_dex(api: &GearApi) -> (MessageId, ProgramId) {
         .join("target")
         .join("wasm32-gear")
         .join("release")
-        .join("application_builder.opt.wasm");
+        .join("factory_vara_dex_wasm.opt.wasm");
 
     let constructor = (
         CodeId::from_str("0x5e19577af7f15f5ed22d2e7e9243e803ebec514d793c5badd514ee280478624b").unwrap(), 
@@ -68,7 +68,7 @@ pub async fn upload_factory_vara_dex(api: &GearApi) -> (MessageId, ProgramId) {
 
     let request = ["New".encode(), constructor.encode()].concat();
 
-    // let path = "./../../../target/wasm32-gear/release/application_builder.opt.wasm";
+    // let path = "./../../../target/wasm32-gear/release/factory_vara_dex_wasm.opt.wasm";
     let gas_info = api
         .calculate_upload_gas(
             None,
@@ -67,6 +67,14 @@ impl<R> FactoryService<R> {
 }
 impl<R: Remoting + Clone> traits::FactoryService for FactoryService<R> {
     type Args = R::Args;
+    fn accept_admin(&mut self) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::AcceptAdmin>::new(self.remoting.clone(), ())
+    }
+    fn accept_fee_to_setter(
+        &mut self,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::AcceptFeeToSetter>::new(self.remoting.clone(), ())
+    }
     fn add_bridged_asset(
         &mut self,
         token_address: ActorId,
@@ -90,6 +98,36 @@ impl<R: Remoting + Clone> traits::FactoryService for FactoryService<R> {
             (token_a, token_b, pair_address),
         )
     }
+    fn allow_creator(
+        &mut self,
+        creator: ActorId,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::AllowCreator>::new(self.remoting.clone(), creator)
+    }
+    fn cancel_admin_transfer(
+        &mut self,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::CancelAdminTransfer>::new(
+            self.remoting.clone(),
+            (),
+        )
+    }
+    fn cancel_fee_to_setter_transfer(
+        &mut self,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::CancelFeeToSetterTransfer>::new(
+            self.remoting.clone(),
+            (),
+        )
+    }
+    fn cancel_operation(
+        &mut self,
+        id: u64,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::CancelOperation>::new(self.remoting.clone(), id)
+    }
+    /// `msg::value()` pays the creation fee; anything above it, or everything
+    /// if the pair is not created, goes back to the caller with the reply.
     fn create_pair(
         &mut self,
         token_a: ActorId,
@@ -100,6 +138,73 @@ impl<R: Remoting + Clone> traits::FactoryService for FactoryService<R> {
             (token_a, token_b),
         )
     }
+    fn disallow_creator(
+        &mut self,
+        creator: ActorId,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::DisallowCreator>::new(
+            self.remoting.clone(),
+            creator,
+        )
+    }
+    /// Applies a queued configuration change once its `eta` has passed.
+    /// Anyone can call it.
+    fn execute_operation(
+        &mut self,
+        id: u64,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::ExecuteOperation>::new(self.remoting.clone(), id)
+    }
+    /// Deploys a pair with the current `code_id_pair` for an existing token
+    /// pair and registers it in place of the old one, which is kept as
+    /// deprecated in the pair history. LPs move with the router's
+    /// `migrate_liquidity`.
+    fn migrate_pair(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+    ) -> impl Call<Output = Result<ActorId, FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::MigratePair>::new(
+            self.remoting.clone(),
+            (token_a, token_b),
+        )
+    }
+    /// Pauses every pair; pairs paused one by one stay paused after
+    /// `unpause_all`.
+    fn pause_all(&mut self) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::PauseAll>::new(self.remoting.clone(), ())
+    }
+    /// Stops `swap`, `mint`, `skim` and `sync` on the pair; `burn` stays open.
+    fn pause_pair(
+        &mut self,
+        pair: ActorId,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::PausePair>::new(self.remoting.clone(), pair)
+    }
+    /// First step of handing over the admin; nothing changes until the
+    /// proposed account calls `accept_admin`. A new proposal replaces the
+    /// pending one.
+    fn propose_admin(
+        &mut self,
+        new_admin: ActorId,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::ProposeAdmin>::new(
+            self.remoting.clone(),
+            new_admin,
+        )
+    }
+    /// First step of handing over `fee_to_setter`; it changes only once the
+    /// proposed account calls `accept_fee_to_setter`. A new proposal replaces
+    /// the pending one.
+    fn propose_fee_to_setter(
+        &mut self,
+        new_fee_setter: ActorId,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::ProposeFeeToSetter>::new(
+            self.remoting.clone(),
+            new_fee_setter,
+        )
+    }
     fn remove_bridged_asset(
         &mut self,
         token_address: ActorId,
@@ -119,42 +224,151 @@ impl<R: Remoting + Clone> traits::FactoryService for FactoryService<R> {
             (token_a, token_b),
         )
     }
-    fn set_admin(
+    /// Drops the cached metadata so the next `create_pair` queries it again.
+    fn remove_token_metadata(
         &mut self,
-        new_admin: ActorId,
+        token: ActorId,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::RemoveTokenMetadata>::new(
+            self.remoting.clone(),
+            token,
+        )
+    }
+    fn set_creation_fee(
+        &mut self,
+        creation_fee: u128,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::SetCreationFee>::new(
+            self.remoting.clone(),
+            creation_fee,
+        )
+    }
+    fn set_creator_policy(
+        &mut self,
+        policy: CreatorPolicy,
     ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
-        RemotingAction::<_, factory_service::io::SetAdmin>::new(self.remoting.clone(), new_admin)
+        RemotingAction::<_, factory_service::io::SetCreatorPolicy>::new(
+            self.remoting.clone(),
+            policy,
+        )
     }
+    /// Queues the change and returns its operation id; see `execute_operation`.
     fn set_fee_to(
         &mut self,
         new_fee_to: ActorId,
-    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+    ) -> impl Call<Output = Result<u64, FactoryError>, Args = R::Args> {
         RemotingAction::<_, factory_service::io::SetFeeTo>::new(self.remoting.clone(), new_fee_to)
     }
-    fn set_fee_to_setter(
+    fn set_pair_creation_gas(
         &mut self,
-        new_fee_setter: ActorId,
+        gas_limit: u64,
+        reply_gas: u64,
     ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
-        RemotingAction::<_, factory_service::io::SetFeeToSetter>::new(
+        RemotingAction::<_, factory_service::io::SetPairCreationGas>::new(
             self.remoting.clone(),
-            new_fee_setter,
+            (gas_limit, reply_gas),
         )
     }
+    /// Queues the change and returns its operation id.
     fn set_router(
         &mut self,
         router: ActorId,
-    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+    ) -> impl Call<Output = Result<u64, FactoryError>, Args = R::Args> {
         RemotingAction::<_, factory_service::io::SetRouter>::new(self.remoting.clone(), router)
     }
+    /// Queued like the setters, so the current delay is the notice for
+    /// lowering it.
+    fn set_timelock_delay(
+        &mut self,
+        delay: u64,
+    ) -> impl Call<Output = Result<u64, FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::SetTimelockDelay>::new(
+            self.remoting.clone(),
+            delay,
+        )
+    }
+    /// Sets the metadata used for `token` instead of querying it.
+    fn set_token_metadata(
+        &mut self,
+        token: ActorId,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        logo_uri: Option<String>,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::SetTokenMetadata>::new(
+            self.remoting.clone(),
+            (token, name, symbol, decimals, logo_uri),
+        )
+    }
+    fn set_token_status(
+        &mut self,
+        token: ActorId,
+        status: TokenStatus,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::SetTokenStatus>::new(
+            self.remoting.clone(),
+            (token, status),
+        )
+    }
+    fn unpause_all(&mut self) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::UnpauseAll>::new(self.remoting.clone(), ())
+    }
+    fn unpause_pair(
+        &mut self,
+        pair: ActorId,
+    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::UnpausePair>::new(self.remoting.clone(), pair)
+    }
+    /// Queues the change and returns its operation id.
     fn update_code_id_pair(
         &mut self,
         new_code_id_pair: CodeId,
-    ) -> impl Call<Output = Result<(), FactoryError>, Args = R::Args> {
+    ) -> impl Call<Output = Result<u64, FactoryError>, Args = R::Args> {
         RemotingAction::<_, factory_service::io::UpdateCodeIdPair>::new(
             self.remoting.clone(),
             new_code_id_pair,
         )
     }
+    /// Sends the accrued creation fees to `fee_to`.
+    fn withdraw_creation_fees(
+        &mut self,
+    ) -> impl Call<Output = Result<u128, FactoryError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::WithdrawCreationFees>::new(
+            self.remoting.clone(),
+            (),
+        )
+    }
+    /// Grants any role but `DefaultAdmin`, which the new holder has to
+    /// accept through the program's `propose_admin`/`accept_admin`.
+    fn grant_role(
+        &mut self,
+        role: Role,
+        account: ActorId,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GrantRole>::new(
+            self.remoting.clone(),
+            (role, account),
+        )
+    }
+    /// Drops a role of the caller. Renouncing the last `DefaultAdmin` leaves
+    /// the roles fixed for good.
+    fn renounce_role(
+        &mut self,
+        role: Role,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::RenounceRole>::new(self.remoting.clone(), role)
+    }
+    fn revoke_role(
+        &mut self,
+        role: Role,
+        account: ActorId,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::RevokeRole>::new(
+            self.remoting.clone(),
+            (role, account),
+        )
+    }
     fn get_admin(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, factory_service::io::GetAdmin>::new(self.remoting.clone(), ())
     }
@@ -164,6 +378,10 @@ impl<R: Remoting + Clone> traits::FactoryService for FactoryService<R> {
     fn get_all_pairs_address(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
         RemotingAction::<_, factory_service::io::GetAllPairsAddress>::new(self.remoting.clone(), ())
     }
+    /// Length of the `all_pairs` registry, removed pairs included.
+    fn get_all_pairs_length(&self) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetAllPairsLength>::new(self.remoting.clone(), ())
+    }
     fn get_code_id_pair(&self) -> impl Query<Output = CodeId, Args = R::Args> {
         RemotingAction::<_, factory_service::io::GetCodeIdPair>::new(self.remoting.clone(), ())
     }
@@ -183,12 +401,172 @@ impl<R: Remoting + Clone> traits::FactoryService for FactoryService<R> {
             (token_a, token_b),
         )
     }
+    /// Returns `(token0, token1, pair)`; `pair` is zero if it was removed.
+    fn get_pair_by_index(
+        &self,
+        index: u64,
+    ) -> impl Query<Output = Option<(ActorId, ActorId, ActorId)>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPairByIndex>::new(self.remoting.clone(), index)
+    }
+    /// Returns `(gas_limit, reply_gas)` used when creating pair programs.
+    fn get_pair_creation_gas(&self) -> impl Query<Output = (u64, u64), Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPairCreationGas>::new(self.remoting.clone(), ())
+    }
+    fn get_pair_creation_policy(&self) -> impl Query<Output = PairCreationPolicy, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPairCreationPolicy>::new(
+            self.remoting.clone(),
+            (),
+        )
+    }
+    /// Migrations of the token pair, oldest first.
+    fn get_pair_history(
+        &self,
+        token_a: ActorId,
+        token_b: ActorId,
+    ) -> impl Query<Output = Vec<PairMigration>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPairHistory>::new(
+            self.remoting.clone(),
+            (token_a, token_b),
+        )
+    }
     fn get_pair_length(&self) -> impl Query<Output = u64, Args = R::Args> {
         RemotingAction::<_, factory_service::io::GetPairLength>::new(self.remoting.clone(), ())
     }
+    /// Pause and block checks of a pair in one query; pairs ask it before
+    /// `swap`, `mint`, `skim` and `sync`.
+    fn get_pair_trading_status(
+        &self,
+        pair: ActorId,
+        token_a: ActorId,
+        token_b: ActorId,
+    ) -> impl Query<Output = PairTradingStatus, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPairTradingStatus>::new(
+            self.remoting.clone(),
+            (pair, token_a, token_b),
+        )
+    }
+    /// Page of the `all_pairs` registry; `limit` is capped at `MAX_PAIRS_PAGE`.
+    fn get_pairs(
+        &self,
+        offset: u64,
+        limit: u32,
+    ) -> impl Query<Output = Vec<(ActorId, ActorId, ActorId)>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPairs>::new(
+            self.remoting.clone(),
+            (offset, limit),
+        )
+    }
+    /// Page of the registered pairs containing `token`, in creation order.
+    fn get_pairs_for_token(
+        &self,
+        token: ActorId,
+        offset: u64,
+        limit: u32,
+    ) -> impl Query<Output = Vec<(ActorId, ActorId, ActorId)>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPairsForToken>::new(
+            self.remoting.clone(),
+            (token, offset, limit),
+        )
+    }
+    fn get_paused_pairs(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPausedPairs>::new(self.remoting.clone(), ())
+    }
+    fn get_pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPendingAdmin>::new(self.remoting.clone(), ())
+    }
+    fn get_pending_fee_to_setter(&self) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetPendingFeeToSetter>::new(
+            self.remoting.clone(),
+            (),
+        )
+    }
+    fn get_queued_operations(&self) -> impl Query<Output = Vec<QueuedOperation>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetQueuedOperations>::new(
+            self.remoting.clone(),
+            (),
+        )
+    }
     fn get_router(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, factory_service::io::GetRouter>::new(self.remoting.clone(), ())
     }
+    /// The pair that replaced the deprecated `pair`, if any.
+    fn get_successor_pair(
+        &self,
+        pair: ActorId,
+    ) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetSuccessorPair>::new(self.remoting.clone(), pair)
+    }
+    fn get_timelock_delay(&self) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetTimelockDelay>::new(self.remoting.clone(), ())
+    }
+    fn get_token_metadata(
+        &self,
+        token: ActorId,
+    ) -> impl Query<Output = Option<TokenMetadata>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetTokenMetadata>::new(
+            self.remoting.clone(),
+            token,
+        )
+    }
+    fn get_token_status(&self, token: ActorId) -> impl Query<Output = TokenStatus, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetTokenStatus>::new(self.remoting.clone(), token)
+    }
+    fn get_tokens_by_status(
+        &self,
+        status: TokenStatus,
+        offset: u32,
+        limit: u32,
+    ) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetTokensByStatus>::new(
+            self.remoting.clone(),
+            (status, offset, limit),
+        )
+    }
+    /// Metadata of each token in `tokens`, in the same order.
+    fn get_tokens_metadata(
+        &self,
+        tokens: Vec<ActorId>,
+    ) -> impl Query<Output = Vec<Option<TokenMetadata>>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetTokensMetadata>::new(
+            self.remoting.clone(),
+            tokens,
+        )
+    }
+    fn is_all_paused(&self) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::IsAllPaused>::new(self.remoting.clone(), ())
+    }
+    /// Whether either token of the pair is blocked.
+    fn is_pair_blocked(
+        &self,
+        token_a: ActorId,
+        token_b: ActorId,
+    ) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::IsPairBlocked>::new(
+            self.remoting.clone(),
+            (token_a, token_b),
+        )
+    }
+    fn is_pair_paused(&self, pair: ActorId) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::IsPairPaused>::new(self.remoting.clone(), pair)
+    }
+    fn get_account_roles(
+        &self,
+        account: ActorId,
+    ) -> impl Query<Output = Vec<Role>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetAccountRoles>::new(
+            self.remoting.clone(),
+            account,
+        )
+    }
+    fn get_role_members(&self, role: Role) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::GetRoleMembers>::new(self.remoting.clone(), role)
+    }
+    fn has_role(&self, role: Role, account: ActorId) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, factory_service::io::HasRole>::new(
+            self.remoting.clone(),
+            (role, account),
+        )
+    }
 }
 
 pub mod factory_service {
@@ -197,6 +575,36 @@ pub mod factory_service {
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
+        pub struct AcceptAdmin(());
+        impl AcceptAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AcceptAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AcceptAdmin {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 44, 65, 99,
+                99, 101, 112, 116, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct AcceptFeeToSetter(());
+        impl AcceptFeeToSetter {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AcceptFeeToSetter as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AcceptFeeToSetter {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 68, 65, 99,
+                99, 101, 112, 116, 70, 101, 101, 84, 111, 83, 101, 116, 116, 101, 114,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::FactoryError>;
+        }
         pub struct AddBridgedAsset(());
         impl AddBridgedAsset {
             #[allow(dead_code)]
@@ -236,107 +644,386 @@ pub mod factory_service {
             type Params = (ActorId, ActorId, ActorId);
             type Reply = Result<ActorId, super::FactoryError>;
         }
-        pub struct CreatePair(());
-        impl CreatePair {
+        pub struct AllowCreator(());
+        impl AllowCreator {
             #[allow(dead_code)]
-            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
-                <CreatePair as ActionIo>::encode_call(&(token_a, token_b))
+            pub fn encode_call(creator: ActorId) -> Vec<u8> {
+                <AllowCreator as ActionIo>::encode_call(&creator)
             }
         }
-        impl ActionIo for CreatePair {
+        impl ActionIo for AllowCreator {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 40, 67, 114,
-                101, 97, 116, 101, 80, 97, 105, 114,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 48, 65, 108,
+                108, 111, 119, 67, 114, 101, 97, 116, 111, 114,
             ];
-            type Params = (ActorId, ActorId);
-            type Reply = Result<ActorId, super::FactoryError>;
+            type Params = ActorId;
+            type Reply = Result<(), super::FactoryError>;
         }
-        pub struct RemoveBridgedAsset(());
-        impl RemoveBridgedAsset {
+        pub struct CancelAdminTransfer(());
+        impl CancelAdminTransfer {
             #[allow(dead_code)]
-            pub fn encode_call(token_address: ActorId) -> Vec<u8> {
-                <RemoveBridgedAsset as ActionIo>::encode_call(&token_address)
+            pub fn encode_call() -> Vec<u8> {
+                <CancelAdminTransfer as ActionIo>::encode_call(&())
             }
         }
-        impl ActionIo for RemoveBridgedAsset {
+        impl ActionIo for CancelAdminTransfer {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 72, 82, 101,
-                109, 111, 118, 101, 66, 114, 105, 100, 103, 101, 100, 65, 115, 115, 101, 116,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 76, 67, 97,
+                110, 99, 101, 108, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114,
             ];
-            type Params = ActorId;
+            type Params = ();
             type Reply = Result<(), super::FactoryError>;
         }
-        pub struct RemovePair(());
-        impl RemovePair {
+        pub struct CancelFeeToSetterTransfer(());
+        impl CancelFeeToSetterTransfer {
             #[allow(dead_code)]
-            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
-                <RemovePair as ActionIo>::encode_call(&(token_a, token_b))
+            pub fn encode_call() -> Vec<u8> {
+                <CancelFeeToSetterTransfer as ActionIo>::encode_call(&())
             }
         }
-        impl ActionIo for RemovePair {
+        impl ActionIo for CancelFeeToSetterTransfer {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 40, 82, 101,
-                109, 111, 118, 101, 80, 97, 105, 114,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 100, 67, 97,
+                110, 99, 101, 108, 70, 101, 101, 84, 111, 83, 101, 116, 116, 101, 114, 84, 114, 97,
+                110, 115, 102, 101, 114,
             ];
-            type Params = (ActorId, ActorId);
+            type Params = ();
             type Reply = Result<(), super::FactoryError>;
         }
-        pub struct SetAdmin(());
-        impl SetAdmin {
+        pub struct CancelOperation(());
+        impl CancelOperation {
             #[allow(dead_code)]
-            pub fn encode_call(new_admin: ActorId) -> Vec<u8> {
-                <SetAdmin as ActionIo>::encode_call(&new_admin)
+            pub fn encode_call(id: u64) -> Vec<u8> {
+                <CancelOperation as ActionIo>::encode_call(&id)
             }
         }
-        impl ActionIo for SetAdmin {
+        impl ActionIo for CancelOperation {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 32, 83, 101,
-                116, 65, 100, 109, 105, 110,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 60, 67, 97,
+                110, 99, 101, 108, 79, 112, 101, 114, 97, 116, 105, 111, 110,
             ];
-            type Params = ActorId;
+            type Params = u64;
             type Reply = Result<(), super::FactoryError>;
         }
-        pub struct SetFeeTo(());
-        impl SetFeeTo {
+        pub struct CreatePair(());
+        impl CreatePair {
             #[allow(dead_code)]
-            pub fn encode_call(new_fee_to: ActorId) -> Vec<u8> {
-                <SetFeeTo as ActionIo>::encode_call(&new_fee_to)
+            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
+                <CreatePair as ActionIo>::encode_call(&(token_a, token_b))
             }
         }
-        impl ActionIo for SetFeeTo {
+        impl ActionIo for CreatePair {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 32, 83, 101,
-                116, 70, 101, 101, 84, 111,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 40, 67, 114,
+                101, 97, 116, 101, 80, 97, 105, 114,
             ];
-            type Params = ActorId;
-            type Reply = Result<(), super::FactoryError>;
+            type Params = (ActorId, ActorId);
+            type Reply = Result<ActorId, super::FactoryError>;
         }
-        pub struct SetFeeToSetter(());
-        impl SetFeeToSetter {
+        pub struct DisallowCreator(());
+        impl DisallowCreator {
             #[allow(dead_code)]
-            pub fn encode_call(new_fee_setter: ActorId) -> Vec<u8> {
-                <SetFeeToSetter as ActionIo>::encode_call(&new_fee_setter)
+            pub fn encode_call(creator: ActorId) -> Vec<u8> {
+                <DisallowCreator as ActionIo>::encode_call(&creator)
             }
         }
-        impl ActionIo for SetFeeToSetter {
+        impl ActionIo for DisallowCreator {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 83, 101,
-                116, 70, 101, 101, 84, 111, 83, 101, 116, 116, 101, 114,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 60, 68, 105,
+                115, 97, 108, 108, 111, 119, 67, 114, 101, 97, 116, 111, 114,
             ];
             type Params = ActorId;
             type Reply = Result<(), super::FactoryError>;
         }
-        pub struct SetRouter(());
-        impl SetRouter {
+        pub struct ExecuteOperation(());
+        impl ExecuteOperation {
             #[allow(dead_code)]
-            pub fn encode_call(router: ActorId) -> Vec<u8> {
-                <SetRouter as ActionIo>::encode_call(&router)
+            pub fn encode_call(id: u64) -> Vec<u8> {
+                <ExecuteOperation as ActionIo>::encode_call(&id)
             }
         }
-        impl ActionIo for SetRouter {
+        impl ActionIo for ExecuteOperation {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 36, 83, 101,
-                116, 82, 111, 117, 116, 101, 114,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 64, 69, 120,
+                101, 99, 117, 116, 101, 79, 112, 101, 114, 97, 116, 105, 111, 110,
+            ];
+            type Params = u64;
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct MigratePair(());
+        impl MigratePair {
+            #[allow(dead_code)]
+            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
+                <MigratePair as ActionIo>::encode_call(&(token_a, token_b))
+            }
+        }
+        impl ActionIo for MigratePair {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 44, 77, 105,
+                103, 114, 97, 116, 101, 80, 97, 105, 114,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = Result<ActorId, super::FactoryError>;
+        }
+        pub struct PauseAll(());
+        impl PauseAll {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <PauseAll as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for PauseAll {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 32, 80, 97,
+                117, 115, 101, 65, 108, 108,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct PausePair(());
+        impl PausePair {
+            #[allow(dead_code)]
+            pub fn encode_call(pair: ActorId) -> Vec<u8> {
+                <PausePair as ActionIo>::encode_call(&pair)
+            }
+        }
+        impl ActionIo for PausePair {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 36, 80, 97,
+                117, 115, 101, 80, 97, 105, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct ProposeAdmin(());
+        impl ProposeAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call(new_admin: ActorId) -> Vec<u8> {
+                <ProposeAdmin as ActionIo>::encode_call(&new_admin)
+            }
+        }
+        impl ActionIo for ProposeAdmin {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 48, 80, 114,
+                111, 112, 111, 115, 101, 65, 100, 109, 105, 110,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct ProposeFeeToSetter(());
+        impl ProposeFeeToSetter {
+            #[allow(dead_code)]
+            pub fn encode_call(new_fee_setter: ActorId) -> Vec<u8> {
+                <ProposeFeeToSetter as ActionIo>::encode_call(&new_fee_setter)
+            }
+        }
+        impl ActionIo for ProposeFeeToSetter {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 72, 80, 114,
+                111, 112, 111, 115, 101, 70, 101, 101, 84, 111, 83, 101, 116, 116, 101, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct RemoveBridgedAsset(());
+        impl RemoveBridgedAsset {
+            #[allow(dead_code)]
+            pub fn encode_call(token_address: ActorId) -> Vec<u8> {
+                <RemoveBridgedAsset as ActionIo>::encode_call(&token_address)
+            }
+        }
+        impl ActionIo for RemoveBridgedAsset {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 72, 82, 101,
+                109, 111, 118, 101, 66, 114, 105, 100, 103, 101, 100, 65, 115, 115, 101, 116,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct RemovePair(());
+        impl RemovePair {
+            #[allow(dead_code)]
+            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
+                <RemovePair as ActionIo>::encode_call(&(token_a, token_b))
+            }
+        }
+        impl ActionIo for RemovePair {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 40, 82, 101,
+                109, 111, 118, 101, 80, 97, 105, 114,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct RemoveTokenMetadata(());
+        impl RemoveTokenMetadata {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId) -> Vec<u8> {
+                <RemoveTokenMetadata as ActionIo>::encode_call(&token)
+            }
+        }
+        impl ActionIo for RemoveTokenMetadata {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 76, 82, 101,
+                109, 111, 118, 101, 84, 111, 107, 101, 110, 77, 101, 116, 97, 100, 97, 116, 97,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct SetCreationFee(());
+        impl SetCreationFee {
+            #[allow(dead_code)]
+            pub fn encode_call(creation_fee: u128) -> Vec<u8> {
+                <SetCreationFee as ActionIo>::encode_call(&creation_fee)
+            }
+        }
+        impl ActionIo for SetCreationFee {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 83, 101,
+                116, 67, 114, 101, 97, 116, 105, 111, 110, 70, 101, 101,
+            ];
+            type Params = u128;
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct SetCreatorPolicy(());
+        impl SetCreatorPolicy {
+            #[allow(dead_code)]
+            pub fn encode_call(policy: super::CreatorPolicy) -> Vec<u8> {
+                <SetCreatorPolicy as ActionIo>::encode_call(&policy)
+            }
+        }
+        impl ActionIo for SetCreatorPolicy {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 64, 83, 101,
+                116, 67, 114, 101, 97, 116, 111, 114, 80, 111, 108, 105, 99, 121,
+            ];
+            type Params = super::CreatorPolicy;
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct SetFeeTo(());
+        impl SetFeeTo {
+            #[allow(dead_code)]
+            pub fn encode_call(new_fee_to: ActorId) -> Vec<u8> {
+                <SetFeeTo as ActionIo>::encode_call(&new_fee_to)
+            }
+        }
+        impl ActionIo for SetFeeTo {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 32, 83, 101,
+                116, 70, 101, 101, 84, 111,
+            ];
+            type Params = ActorId;
+            type Reply = Result<u64, super::FactoryError>;
+        }
+        pub struct SetPairCreationGas(());
+        impl SetPairCreationGas {
+            #[allow(dead_code)]
+            pub fn encode_call(gas_limit: u64, reply_gas: u64) -> Vec<u8> {
+                <SetPairCreationGas as ActionIo>::encode_call(&(gas_limit, reply_gas))
+            }
+        }
+        impl ActionIo for SetPairCreationGas {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 72, 83, 101,
+                116, 80, 97, 105, 114, 67, 114, 101, 97, 116, 105, 111, 110, 71, 97, 115,
+            ];
+            type Params = (u64, u64);
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct SetRouter(());
+        impl SetRouter {
+            #[allow(dead_code)]
+            pub fn encode_call(router: ActorId) -> Vec<u8> {
+                <SetRouter as ActionIo>::encode_call(&router)
+            }
+        }
+        impl ActionIo for SetRouter {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 36, 83, 101,
+                116, 82, 111, 117, 116, 101, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Result<u64, super::FactoryError>;
+        }
+        pub struct SetTimelockDelay(());
+        impl SetTimelockDelay {
+            #[allow(dead_code)]
+            pub fn encode_call(delay: u64) -> Vec<u8> {
+                <SetTimelockDelay as ActionIo>::encode_call(&delay)
+            }
+        }
+        impl ActionIo for SetTimelockDelay {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 64, 83, 101,
+                116, 84, 105, 109, 101, 108, 111, 99, 107, 68, 101, 108, 97, 121,
+            ];
+            type Params = u64;
+            type Reply = Result<u64, super::FactoryError>;
+        }
+        pub struct SetTokenMetadata(());
+        impl SetTokenMetadata {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                token: ActorId,
+                name: String,
+                symbol: String,
+                decimals: u8,
+                logo_uri: Option<String>,
+            ) -> Vec<u8> {
+                <SetTokenMetadata as ActionIo>::encode_call(&(
+                    token, name, symbol, decimals, logo_uri,
+                ))
+            }
+        }
+        impl ActionIo for SetTokenMetadata {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 64, 83, 101,
+                116, 84, 111, 107, 101, 110, 77, 101, 116, 97, 100, 97, 116, 97,
+            ];
+            type Params = (ActorId, String, String, u8, Option<String>);
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct SetTokenStatus(());
+        impl SetTokenStatus {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, status: super::TokenStatus) -> Vec<u8> {
+                <SetTokenStatus as ActionIo>::encode_call(&(token, status))
+            }
+        }
+        impl ActionIo for SetTokenStatus {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 83, 101,
+                116, 84, 111, 107, 101, 110, 83, 116, 97, 116, 117, 115,
+            ];
+            type Params = (ActorId, super::TokenStatus);
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct UnpauseAll(());
+        impl UnpauseAll {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <UnpauseAll as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for UnpauseAll {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 40, 85, 110,
+                112, 97, 117, 115, 101, 65, 108, 108,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::FactoryError>;
+        }
+        pub struct UnpausePair(());
+        impl UnpausePair {
+            #[allow(dead_code)]
+            pub fn encode_call(pair: ActorId) -> Vec<u8> {
+                <UnpausePair as ActionIo>::encode_call(&pair)
+            }
+        }
+        impl ActionIo for UnpausePair {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 44, 85, 110,
+                112, 97, 117, 115, 101, 80, 97, 105, 114,
             ];
             type Params = ActorId;
             type Reply = Result<(), super::FactoryError>;
@@ -354,7 +1041,68 @@ pub mod factory_service {
                 100, 97, 116, 101, 67, 111, 100, 101, 73, 100, 80, 97, 105, 114,
             ];
             type Params = CodeId;
-            type Reply = Result<(), super::FactoryError>;
+            type Reply = Result<u64, super::FactoryError>;
+        }
+        pub struct WithdrawCreationFees(());
+        impl WithdrawCreationFees {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <WithdrawCreationFees as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for WithdrawCreationFees {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 80, 87, 105,
+                116, 104, 100, 114, 97, 119, 67, 114, 101, 97, 116, 105, 111, 110, 70, 101, 101,
+                115,
+            ];
+            type Params = ();
+            type Reply = Result<u128, super::FactoryError>;
+        }
+        pub struct GrantRole(());
+        impl GrantRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <GrantRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for GrantRole {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 36, 71, 114,
+                97, 110, 116, 82, 111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct RenounceRole(());
+        impl RenounceRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role) -> Vec<u8> {
+                <RenounceRole as ActionIo>::encode_call(&role)
+            }
+        }
+        impl ActionIo for RenounceRole {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 48, 82, 101,
+                110, 111, 117, 110, 99, 101, 82, 111, 108, 101,
+            ];
+            type Params = super::Role;
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct RevokeRole(());
+        impl RevokeRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <RevokeRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for RevokeRole {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 40, 82, 101,
+                118, 111, 107, 101, 82, 111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = Result<(), super::AccessControlError>;
         }
         pub struct GetAdmin(());
         impl GetAdmin {
@@ -386,110 +1134,472 @@ pub mod factory_service {
             type Params = ();
             type Reply = Vec<(ActorId, ActorId)>;
         }
-        pub struct GetAllPairsAddress(());
-        impl GetAllPairsAddress {
+        pub struct GetAllPairsAddress(());
+        impl GetAllPairsAddress {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetAllPairsAddress as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetAllPairsAddress {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 72, 71, 101,
+                116, 65, 108, 108, 80, 97, 105, 114, 115, 65, 100, 100, 114, 101, 115, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
+        pub struct GetAllPairsLength(());
+        impl GetAllPairsLength {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetAllPairsLength as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetAllPairsLength {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 68, 71, 101,
+                116, 65, 108, 108, 80, 97, 105, 114, 115, 76, 101, 110, 103, 116, 104,
+            ];
+            type Params = ();
+            type Reply = u64;
+        }
+        pub struct GetCodeIdPair(());
+        impl GetCodeIdPair {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetCodeIdPair as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetCodeIdPair {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 52, 71, 101,
+                116, 67, 111, 100, 101, 73, 100, 80, 97, 105, 114,
+            ];
+            type Params = ();
+            type Reply = CodeId;
+        }
+        pub struct GetFeeTo(());
+        impl GetFeeTo {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetFeeTo as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetFeeTo {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 32, 71, 101,
+                116, 70, 101, 101, 84, 111,
+            ];
+            type Params = ();
+            type Reply = ActorId;
+        }
+        pub struct GetFeeToSetter(());
+        impl GetFeeToSetter {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetFeeToSetter as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetFeeToSetter {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101,
+                116, 70, 101, 101, 84, 111, 83, 101, 116, 116, 101, 114,
+            ];
+            type Params = ();
+            type Reply = ActorId;
+        }
+        pub struct GetPair(());
+        impl GetPair {
+            #[allow(dead_code)]
+            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
+                <GetPair as ActionIo>::encode_call(&(token_a, token_b))
+            }
+        }
+        impl ActionIo for GetPair {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 28, 71, 101,
+                116, 80, 97, 105, 114,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = ActorId;
+        }
+        pub struct GetPairByIndex(());
+        impl GetPairByIndex {
+            #[allow(dead_code)]
+            pub fn encode_call(index: u64) -> Vec<u8> {
+                <GetPairByIndex as ActionIo>::encode_call(&index)
+            }
+        }
+        impl ActionIo for GetPairByIndex {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101,
+                116, 80, 97, 105, 114, 66, 121, 73, 110, 100, 101, 120,
+            ];
+            type Params = u64;
+            type Reply = Option<(ActorId, ActorId, ActorId)>;
+        }
+        pub struct GetPairCreationGas(());
+        impl GetPairCreationGas {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPairCreationGas as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPairCreationGas {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 72, 71, 101,
+                116, 80, 97, 105, 114, 67, 114, 101, 97, 116, 105, 111, 110, 71, 97, 115,
+            ];
+            type Params = ();
+            type Reply = (u64, u64);
+        }
+        pub struct GetPairCreationPolicy(());
+        impl GetPairCreationPolicy {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPairCreationPolicy as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPairCreationPolicy {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 84, 71, 101,
+                116, 80, 97, 105, 114, 67, 114, 101, 97, 116, 105, 111, 110, 80, 111, 108, 105, 99,
+                121,
+            ];
+            type Params = ();
+            type Reply = super::PairCreationPolicy;
+        }
+        pub struct GetPairHistory(());
+        impl GetPairHistory {
+            #[allow(dead_code)]
+            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
+                <GetPairHistory as ActionIo>::encode_call(&(token_a, token_b))
+            }
+        }
+        impl ActionIo for GetPairHistory {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101,
+                116, 80, 97, 105, 114, 72, 105, 115, 116, 111, 114, 121,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = Vec<super::PairMigration>;
+        }
+        pub struct GetPairLength(());
+        impl GetPairLength {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPairLength as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPairLength {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 52, 71, 101,
+                116, 80, 97, 105, 114, 76, 101, 110, 103, 116, 104,
+            ];
+            type Params = ();
+            type Reply = u64;
+        }
+        pub struct GetPairTradingStatus(());
+        impl GetPairTradingStatus {
+            #[allow(dead_code)]
+            pub fn encode_call(pair: ActorId, token_a: ActorId, token_b: ActorId) -> Vec<u8> {
+                <GetPairTradingStatus as ActionIo>::encode_call(&(pair, token_a, token_b))
+            }
+        }
+        impl ActionIo for GetPairTradingStatus {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 80, 71, 101,
+                116, 80, 97, 105, 114, 84, 114, 97, 100, 105, 110, 103, 83, 116, 97, 116, 117, 115,
+            ];
+            type Params = (ActorId, ActorId, ActorId);
+            type Reply = super::PairTradingStatus;
+        }
+        pub struct GetPairs(());
+        impl GetPairs {
+            #[allow(dead_code)]
+            pub fn encode_call(offset: u64, limit: u32) -> Vec<u8> {
+                <GetPairs as ActionIo>::encode_call(&(offset, limit))
+            }
+        }
+        impl ActionIo for GetPairs {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 32, 71, 101,
+                116, 80, 97, 105, 114, 115,
+            ];
+            type Params = (u64, u32);
+            type Reply = Vec<(ActorId, ActorId, ActorId)>;
+        }
+        pub struct GetPairsForToken(());
+        impl GetPairsForToken {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, offset: u64, limit: u32) -> Vec<u8> {
+                <GetPairsForToken as ActionIo>::encode_call(&(token, offset, limit))
+            }
+        }
+        impl ActionIo for GetPairsForToken {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 64, 71, 101,
+                116, 80, 97, 105, 114, 115, 70, 111, 114, 84, 111, 107, 101, 110,
+            ];
+            type Params = (ActorId, u64, u32);
+            type Reply = Vec<(ActorId, ActorId, ActorId)>;
+        }
+        pub struct GetPausedPairs(());
+        impl GetPausedPairs {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPausedPairs as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPausedPairs {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101,
+                116, 80, 97, 117, 115, 101, 100, 80, 97, 105, 114, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
+        pub struct GetPendingAdmin(());
+        impl GetPendingAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPendingAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPendingAdmin {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101,
+                116, 80, 101, 110, 100, 105, 110, 103, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Option<ActorId>;
+        }
+        pub struct GetPendingFeeToSetter(());
+        impl GetPendingFeeToSetter {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPendingFeeToSetter as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPendingFeeToSetter {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 84, 71, 101,
+                116, 80, 101, 110, 100, 105, 110, 103, 70, 101, 101, 84, 111, 83, 101, 116, 116,
+                101, 114,
+            ];
+            type Params = ();
+            type Reply = Option<ActorId>;
+        }
+        pub struct GetQueuedOperations(());
+        impl GetQueuedOperations {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetQueuedOperations as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetQueuedOperations {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 76, 71, 101,
+                116, 81, 117, 101, 117, 101, 100, 79, 112, 101, 114, 97, 116, 105, 111, 110, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<super::QueuedOperation>;
+        }
+        pub struct GetRouter(());
+        impl GetRouter {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetRouter as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetRouter {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 36, 71, 101,
+                116, 82, 111, 117, 116, 101, 114,
+            ];
+            type Params = ();
+            type Reply = ActorId;
+        }
+        pub struct GetSuccessorPair(());
+        impl GetSuccessorPair {
+            #[allow(dead_code)]
+            pub fn encode_call(pair: ActorId) -> Vec<u8> {
+                <GetSuccessorPair as ActionIo>::encode_call(&pair)
+            }
+        }
+        impl ActionIo for GetSuccessorPair {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 64, 71, 101,
+                116, 83, 117, 99, 99, 101, 115, 115, 111, 114, 80, 97, 105, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Option<ActorId>;
+        }
+        pub struct GetTimelockDelay(());
+        impl GetTimelockDelay {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetTimelockDelay as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetTimelockDelay {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 64, 71, 101,
+                116, 84, 105, 109, 101, 108, 111, 99, 107, 68, 101, 108, 97, 121,
+            ];
+            type Params = ();
+            type Reply = u64;
+        }
+        pub struct GetTokenMetadata(());
+        impl GetTokenMetadata {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId) -> Vec<u8> {
+                <GetTokenMetadata as ActionIo>::encode_call(&token)
+            }
+        }
+        impl ActionIo for GetTokenMetadata {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 64, 71, 101,
+                116, 84, 111, 107, 101, 110, 77, 101, 116, 97, 100, 97, 116, 97,
+            ];
+            type Params = ActorId;
+            type Reply = Option<super::TokenMetadata>;
+        }
+        pub struct GetTokenStatus(());
+        impl GetTokenStatus {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId) -> Vec<u8> {
+                <GetTokenStatus as ActionIo>::encode_call(&token)
+            }
+        }
+        impl ActionIo for GetTokenStatus {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101,
+                116, 84, 111, 107, 101, 110, 83, 116, 97, 116, 117, 115,
+            ];
+            type Params = ActorId;
+            type Reply = super::TokenStatus;
+        }
+        pub struct GetTokensByStatus(());
+        impl GetTokensByStatus {
+            #[allow(dead_code)]
+            pub fn encode_call(status: super::TokenStatus, offset: u32, limit: u32) -> Vec<u8> {
+                <GetTokensByStatus as ActionIo>::encode_call(&(status, offset, limit))
+            }
+        }
+        impl ActionIo for GetTokensByStatus {
+            const ROUTE: &'static [u8] = &[
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 68, 71, 101,
+                116, 84, 111, 107, 101, 110, 115, 66, 121, 83, 116, 97, 116, 117, 115,
+            ];
+            type Params = (super::TokenStatus, u32, u32);
+            type Reply = Vec<ActorId>;
+        }
+        pub struct GetTokensMetadata(());
+        impl GetTokensMetadata {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <GetAllPairsAddress as ActionIo>::encode_call(&())
+            pub fn encode_call(tokens: Vec<ActorId>) -> Vec<u8> {
+                <GetTokensMetadata as ActionIo>::encode_call(&tokens)
             }
         }
-        impl ActionIo for GetAllPairsAddress {
+        impl ActionIo for GetTokensMetadata {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 72, 71, 101,
-                116, 65, 108, 108, 80, 97, 105, 114, 115, 65, 100, 100, 114, 101, 115, 115,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 68, 71, 101,
+                116, 84, 111, 107, 101, 110, 115, 77, 101, 116, 97, 100, 97, 116, 97,
             ];
-            type Params = ();
-            type Reply = Vec<ActorId>;
+            type Params = Vec<ActorId>;
+            type Reply = Vec<Option<super::TokenMetadata>>;
         }
-        pub struct GetCodeIdPair(());
-        impl GetCodeIdPair {
+        pub struct IsAllPaused(());
+        impl IsAllPaused {
             #[allow(dead_code)]
             pub fn encode_call() -> Vec<u8> {
-                <GetCodeIdPair as ActionIo>::encode_call(&())
+                <IsAllPaused as ActionIo>::encode_call(&())
             }
         }
-        impl ActionIo for GetCodeIdPair {
+        impl ActionIo for IsAllPaused {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 52, 71, 101,
-                116, 67, 111, 100, 101, 73, 100, 80, 97, 105, 114,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 44, 73, 115,
+                65, 108, 108, 80, 97, 117, 115, 101, 100,
             ];
             type Params = ();
-            type Reply = CodeId;
+            type Reply = bool;
         }
-        pub struct GetFeeTo(());
-        impl GetFeeTo {
+        pub struct IsPairBlocked(());
+        impl IsPairBlocked {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <GetFeeTo as ActionIo>::encode_call(&())
+            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
+                <IsPairBlocked as ActionIo>::encode_call(&(token_a, token_b))
             }
         }
-        impl ActionIo for GetFeeTo {
+        impl ActionIo for IsPairBlocked {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 32, 71, 101,
-                116, 70, 101, 101, 84, 111,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 52, 73, 115,
+                80, 97, 105, 114, 66, 108, 111, 99, 107, 101, 100,
             ];
-            type Params = ();
-            type Reply = ActorId;
+            type Params = (ActorId, ActorId);
+            type Reply = bool;
         }
-        pub struct GetFeeToSetter(());
-        impl GetFeeToSetter {
+        pub struct IsPairPaused(());
+        impl IsPairPaused {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <GetFeeToSetter as ActionIo>::encode_call(&())
+            pub fn encode_call(pair: ActorId) -> Vec<u8> {
+                <IsPairPaused as ActionIo>::encode_call(&pair)
             }
         }
-        impl ActionIo for GetFeeToSetter {
+        impl ActionIo for IsPairPaused {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101,
-                116, 70, 101, 101, 84, 111, 83, 101, 116, 116, 101, 114,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 48, 73, 115,
+                80, 97, 105, 114, 80, 97, 117, 115, 101, 100,
             ];
-            type Params = ();
-            type Reply = ActorId;
+            type Params = ActorId;
+            type Reply = bool;
         }
-        pub struct GetPair(());
-        impl GetPair {
+        pub struct GetAccountRoles(());
+        impl GetAccountRoles {
             #[allow(dead_code)]
-            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
-                <GetPair as ActionIo>::encode_call(&(token_a, token_b))
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetAccountRoles as ActionIo>::encode_call(&account)
             }
         }
-        impl ActionIo for GetPair {
+        impl ActionIo for GetAccountRoles {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 28, 71, 101,
-                116, 80, 97, 105, 114,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101,
+                116, 65, 99, 99, 111, 117, 110, 116, 82, 111, 108, 101, 115,
             ];
-            type Params = (ActorId, ActorId);
-            type Reply = ActorId;
+            type Params = ActorId;
+            type Reply = Vec<super::Role>;
         }
-        pub struct GetPairLength(());
-        impl GetPairLength {
+        pub struct GetRoleMembers(());
+        impl GetRoleMembers {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <GetPairLength as ActionIo>::encode_call(&())
+            pub fn encode_call(role: super::Role) -> Vec<u8> {
+                <GetRoleMembers as ActionIo>::encode_call(&role)
             }
         }
-        impl ActionIo for GetPairLength {
+        impl ActionIo for GetRoleMembers {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 52, 71, 101,
-                116, 80, 97, 105, 114, 76, 101, 110, 103, 116, 104,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101,
+                116, 82, 111, 108, 101, 77, 101, 109, 98, 101, 114, 115,
             ];
-            type Params = ();
-            type Reply = u64;
+            type Params = super::Role;
+            type Reply = Vec<ActorId>;
         }
-        pub struct GetRouter(());
-        impl GetRouter {
+        pub struct HasRole(());
+        impl HasRole {
             #[allow(dead_code)]
-            pub fn encode_call() -> Vec<u8> {
-                <GetRouter as ActionIo>::encode_call(&())
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <HasRole as ActionIo>::encode_call(&(role, account))
             }
         }
-        impl ActionIo for GetRouter {
+        impl ActionIo for HasRole {
             const ROUTE: &'static [u8] = &[
-                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 36, 71, 101,
-                116, 82, 111, 117, 116, 101, 114,
+                56, 70, 97, 99, 116, 111, 114, 121, 83, 101, 114, 118, 105, 99, 101, 28, 72, 97,
+                115, 82, 111, 108, 101,
             ];
-            type Params = ();
-            type Reply = ActorId;
+            type Params = (super::Role, ActorId);
+            type Reply = bool;
         }
     }
 
@@ -507,21 +1617,44 @@ pub mod factory_service {
                 token_pair: (ActorId, ActorId),
                 /// [`ActorId`] of a created Pair contract.
                 pair_address: ActorId,
-                /// A number of Pair contracts (including a created one) inside the
-                /// Factory contract.
+                /// Position of the created pair in the `all_pairs` registry, counting
+                /// from 1; `get_pair_by_index(pair_number - 1)` returns it.
                 pair_number: u64,
+                /// Metadata of `token_pair.0` and `token_pair.1`.
+                tokens_metadata: (TokenMetadata, TokenMetadata),
+                lp_decimals: u8,
             },
-            /// Should be returned from [`Action::FeeToSetter`].
+            /// Emitted by `accept_fee_to_setter`.
             FeeToSetterSet(ActorId),
             /// Should be returned from [`Action::FeeTo`].
             FeeToSet(ActorId),
             Pair(ActorId),
             RouterSet(ActorId),
+            /// Emitted by `accept_admin`.
             AdminSet(ActorId),
+            AdminTransferProposed(ActorId),
+            AdminTransferCancelled(ActorId),
+            FeeToSetterTransferProposed(ActorId),
+            FeeToSetterTransferCancelled(ActorId),
             CodeIdPairUpdated(CodeId),
+            PairCreationGasSet {
+                gas_limit: u64,
+                reply_gas: u64,
+            },
+            PairAdded {
+                token_pair: (ActorId, ActorId),
+                pair_address: ActorId,
+                /// Position of the pair in the `all_pairs` registry, counting from 1.
+                pair_number: u64,
+            },
             PairRemoved {
                 token_pair: (ActorId, ActorId),
             },
+            PairMigrated {
+                token_pair: (ActorId, ActorId),
+                old_pair: ActorId,
+                new_pair: ActorId,
+            },
             BridgedAssetAdded {
                 token_address: ActorId,
                 name: String,
@@ -531,6 +1664,46 @@ pub mod factory_service {
             BridgedAssetRemoved {
                 token_address: ActorId,
             },
+            CreatorPolicySet(CreatorPolicy),
+            CreatorAllowed(ActorId),
+            CreatorDisallowed(ActorId),
+            CreationFeeSet(u128),
+            CreationFeesWithdrawn {
+                to: ActorId,
+                amount: u128,
+            },
+            TokenStatusSet {
+                token: ActorId,
+                status: TokenStatus,
+            },
+            TokenMetadataSet {
+                token: ActorId,
+            },
+            TokenMetadataRemoved {
+                token: ActorId,
+            },
+            PairPaused(ActorId),
+            PairUnpaused(ActorId),
+            AllPairsPaused,
+            AllPairsUnpaused,
+            OperationQueued {
+                id: u64,
+                change: ConfigChange,
+                eta: u64,
+            },
+            OperationExecuted(u64),
+            OperationCancelled(u64),
+            TimelockDelaySet(u64),
+            RoleGranted {
+                role: Role,
+                account: ActorId,
+                sender: ActorId,
+            },
+            RoleRevoked {
+                role: Role,
+                account: ActorId,
+                sender: ActorId,
+            },
         }
         impl EventIo for FactoryServiceEvents {
             const ROUTE: &'static [u8] = &[
@@ -545,11 +1718,33 @@ pub mod factory_service {
                 &[16, 80, 97, 105, 114],
                 &[36, 82, 111, 117, 116, 101, 114, 83, 101, 116],
                 &[32, 65, 100, 109, 105, 110, 83, 101, 116],
+                &[
+                    84, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 80, 114, 111,
+                    112, 111, 115, 101, 100,
+                ],
+                &[
+                    88, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 67, 97, 110,
+                    99, 101, 108, 108, 101, 100,
+                ],
+                &[
+                    108, 70, 101, 101, 84, 111, 83, 101, 116, 116, 101, 114, 84, 114, 97, 110, 115,
+                    102, 101, 114, 80, 114, 111, 112, 111, 115, 101, 100,
+                ],
+                &[
+                    112, 70, 101, 101, 84, 111, 83, 101, 116, 116, 101, 114, 84, 114, 97, 110, 115,
+                    102, 101, 114, 67, 97, 110, 99, 101, 108, 108, 101, 100,
+                ],
                 &[
                     68, 67, 111, 100, 101, 73, 100, 80, 97, 105, 114, 85, 112, 100, 97, 116, 101,
                     100,
                 ],
+                &[
+                    72, 80, 97, 105, 114, 67, 114, 101, 97, 116, 105, 111, 110, 71, 97, 115, 83,
+                    101, 116,
+                ],
+                &[36, 80, 97, 105, 114, 65, 100, 100, 101, 100],
                 &[44, 80, 97, 105, 114, 82, 101, 109, 111, 118, 101, 100],
+                &[48, 80, 97, 105, 114, 77, 105, 103, 114, 97, 116, 101, 100],
                 &[
                     68, 66, 114, 105, 100, 103, 101, 100, 65, 115, 115, 101, 116, 65, 100, 100,
                     101, 100,
@@ -558,6 +1753,57 @@ pub mod factory_service {
                     76, 66, 114, 105, 100, 103, 101, 100, 65, 115, 115, 101, 116, 82, 101, 109,
                     111, 118, 101, 100,
                 ],
+                &[
+                    64, 67, 114, 101, 97, 116, 111, 114, 80, 111, 108, 105, 99, 121, 83, 101, 116,
+                ],
+                &[
+                    56, 67, 114, 101, 97, 116, 111, 114, 65, 108, 108, 111, 119, 101, 100,
+                ],
+                &[
+                    68, 67, 114, 101, 97, 116, 111, 114, 68, 105, 115, 97, 108, 108, 111, 119, 101,
+                    100,
+                ],
+                &[
+                    56, 67, 114, 101, 97, 116, 105, 111, 110, 70, 101, 101, 83, 101, 116,
+                ],
+                &[
+                    84, 67, 114, 101, 97, 116, 105, 111, 110, 70, 101, 101, 115, 87, 105, 116, 104,
+                    100, 114, 97, 119, 110,
+                ],
+                &[
+                    56, 84, 111, 107, 101, 110, 83, 116, 97, 116, 117, 115, 83, 101, 116,
+                ],
+                &[
+                    64, 84, 111, 107, 101, 110, 77, 101, 116, 97, 100, 97, 116, 97, 83, 101, 116,
+                ],
+                &[
+                    80, 84, 111, 107, 101, 110, 77, 101, 116, 97, 100, 97, 116, 97, 82, 101, 109,
+                    111, 118, 101, 100,
+                ],
+                &[40, 80, 97, 105, 114, 80, 97, 117, 115, 101, 100],
+                &[48, 80, 97, 105, 114, 85, 110, 112, 97, 117, 115, 101, 100],
+                &[
+                    56, 65, 108, 108, 80, 97, 105, 114, 115, 80, 97, 117, 115, 101, 100,
+                ],
+                &[
+                    64, 65, 108, 108, 80, 97, 105, 114, 115, 85, 110, 112, 97, 117, 115, 101, 100,
+                ],
+                &[
+                    60, 79, 112, 101, 114, 97, 116, 105, 111, 110, 81, 117, 101, 117, 101, 100,
+                ],
+                &[
+                    68, 79, 112, 101, 114, 97, 116, 105, 111, 110, 69, 120, 101, 99, 117, 116, 101,
+                    100,
+                ],
+                &[
+                    72, 79, 112, 101, 114, 97, 116, 105, 111, 110, 67, 97, 110, 99, 101, 108, 108,
+                    101, 100,
+                ],
+                &[
+                    64, 84, 105, 109, 101, 108, 111, 99, 107, 68, 101, 108, 97, 121, 83, 101, 116,
+                ],
+                &[44, 82, 111, 108, 101, 71, 114, 97, 110, 116, 101, 100],
+                &[44, 82, 111, 108, 101, 82, 101, 118, 111, 107, 101, 100],
             ];
             type Event = Self;
         }
@@ -569,14 +1815,6 @@ pub mod factory_service {
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
-pub struct BridgedAsset {
-    pub name: String,
-    pub symbol: String,
-    pub decimals: u8,
-}
-#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
-#[codec(crate = sails_rs::scale_codec)]
-#[scale_info(crate = sails_rs::scale_info)]
 pub enum FactoryError {
     Unauthorized,
     UnexpectedFTEvent,
@@ -587,6 +1825,154 @@ pub enum FactoryError {
     PairNotExist,
     VFTError,
     BridgedAssetExist,
+    /// The candidate of `add_pair` did not answer the pair queries.
+    PairQueryFailed,
+    PairTokensMismatch,
+    PairFactoryMismatch,
+    PairRouterMismatch,
+    CreatorNotAllowed,
+    InsufficientCreationFee,
+    NoFeesToWithdraw,
+    TokenBlocked,
+    AlreadyPaused,
+    NotPaused,
+    ZeroAddress,
+    /// There is no proposed admin or `fee_to_setter` to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin or `fee_to_setter`.
+    NotPendingAccount,
+    OperationNotFound,
+    /// The operation's `eta` has not been reached yet.
+    OperationNotReady,
+    InvalidTimelockDelay,
+    /// `create_pair` was given the same token twice.
+    IdenticalAddresses,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct BridgedAsset {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+/// Who may call `create_pair`. `Role::DefaultAdmin` holders may create pairs
+/// in every mode.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum CreatorPolicy {
+    Permissionless,
+    /// Only creators added with `allow_creator`.
+    Allowlisted,
+    /// Only the router, so every pair goes through its checks.
+    RouterOnly,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TokenStatus {
+    /// No flag set; tokens without a flag are not listed by status.
+    Unknown,
+    Verified,
+    /// No new pairs, and existing pairs refuse `swap`/`mint`; `burn` stays open.
+    Blocked,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Role {
+    /// Grants and revokes roles and changes the program's configuration.
+    DefaultAdmin,
+    /// Pauses and unpauses trading.
+    Pauser,
+    /// Sets fees and where they go.
+    FeeManager,
+    /// Lists tokens and edits their metadata and status.
+    AssetManager,
+    /// Moves funds held by the program.
+    Treasurer,
+    /// Sets staking rewards.
+    RewardManager,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum AccessControlError {
+    /// The caller does not hold `Role::DefaultAdmin`.
+    MissingAdminRole,
+    /// The account does not hold the role.
+    RoleNotHeld,
+    /// `DefaultAdmin` only changes hands through the program's
+    /// `propose_admin`/`accept_admin`.
+    AdminRoleNotGrantable,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PairCreationPolicy {
+    pub policy: CreatorPolicy,
+    pub allowed_creators: Vec<ActorId>,
+    /// Native VARA `create_pair` must be sent with.
+    pub creation_fee: u128,
+    /// Creation fees held for `fee_to` until withdrawn.
+    pub accrued_fees: u128,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PairMigration {
+    pub old_pair: ActorId,
+    pub new_pair: ActorId,
+    /// Code the new pair was deployed from.
+    pub code_id: CodeId,
+    /// Block timestamp of the migration.
+    pub migrated_at: u64,
+}
+/// What a pair allows, as answered to the pair itself. `burn` is open in
+/// every state so LPs can always exit.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PairTradingStatus {
+    Active,
+    /// Paused on its own or with all pairs.
+    Paused,
+    /// One of the tokens is `TokenStatus::Blocked`.
+    Blocked,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct QueuedOperation {
+    pub id: u64,
+    pub change: ConfigChange,
+    /// Block timestamp from which anyone can execute the change.
+    pub eta: u64,
+}
+/// A configuration change held back by the timelock.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ConfigChange {
+    FeeTo(ActorId),
+    Router(ActorId),
+    CodeIdPair(CodeId),
+    /// Applies to operations queued after it executes.
+    TimelockDelay(u64),
+}
+/// Metadata of a pair token, from `bridged_assets`, the admin, or fetched
+/// from the token on first use.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub logo_uri: Option<String>,
+    /// Mirrors the token's `TokenStatus::Verified` flag.
+    pub verified: bool,
 }
 
 pub mod traits {
@@ -608,6 +1994,12 @@ pub mod traits {
     #[allow(clippy::type_complexity)]
     pub trait FactoryService {
         type Args;
+        fn accept_admin(
+            &mut self,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn accept_fee_to_setter(
+            &mut self,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
         fn add_bridged_asset(
             &mut self,
             token_address: ActorId,
@@ -621,11 +2013,51 @@ pub mod traits {
             token_b: ActorId,
             pair_address: ActorId,
         ) -> impl Call<Output = Result<ActorId, FactoryError>, Args = Self::Args>;
+        fn allow_creator(
+            &mut self,
+            creator: ActorId,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn cancel_admin_transfer(
+            &mut self,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn cancel_fee_to_setter_transfer(
+            &mut self,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn cancel_operation(
+            &mut self,
+            id: u64,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
         fn create_pair(
             &mut self,
             token_a: ActorId,
             token_b: ActorId,
         ) -> impl Call<Output = Result<ActorId, FactoryError>, Args = Self::Args>;
+        fn disallow_creator(
+            &mut self,
+            creator: ActorId,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn execute_operation(
+            &mut self,
+            id: u64,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn migrate_pair(
+            &mut self,
+            token_a: ActorId,
+            token_b: ActorId,
+        ) -> impl Call<Output = Result<ActorId, FactoryError>, Args = Self::Args>;
+        fn pause_all(&mut self) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn pause_pair(
+            &mut self,
+            pair: ActorId,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn propose_admin(
+            &mut self,
+            new_admin: ActorId,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn propose_fee_to_setter(
+            &mut self,
+            new_fee_setter: ActorId,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
         fn remove_bridged_asset(
             &mut self,
             token_address: ActorId,
@@ -635,29 +2067,80 @@ pub mod traits {
             token_a: ActorId,
             token_b: ActorId,
         ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
-        fn set_admin(
+        fn remove_token_metadata(
             &mut self,
-            new_admin: ActorId,
+            token: ActorId,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn set_creation_fee(
+            &mut self,
+            creation_fee: u128,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn set_creator_policy(
+            &mut self,
+            policy: CreatorPolicy,
         ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
         fn set_fee_to(
             &mut self,
             new_fee_to: ActorId,
-        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
-        fn set_fee_to_setter(
+        ) -> impl Call<Output = Result<u64, FactoryError>, Args = Self::Args>;
+        fn set_pair_creation_gas(
             &mut self,
-            new_fee_setter: ActorId,
+            gas_limit: u64,
+            reply_gas: u64,
         ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
         fn set_router(
             &mut self,
             router: ActorId,
+        ) -> impl Call<Output = Result<u64, FactoryError>, Args = Self::Args>;
+        fn set_timelock_delay(
+            &mut self,
+            delay: u64,
+        ) -> impl Call<Output = Result<u64, FactoryError>, Args = Self::Args>;
+        fn set_token_metadata(
+            &mut self,
+            token: ActorId,
+            name: String,
+            symbol: String,
+            decimals: u8,
+            logo_uri: Option<String>,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn set_token_status(
+            &mut self,
+            token: ActorId,
+            status: TokenStatus,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn unpause_all(
+            &mut self,
+        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        fn unpause_pair(
+            &mut self,
+            pair: ActorId,
         ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
         fn update_code_id_pair(
             &mut self,
             new_code_id_pair: CodeId,
-        ) -> impl Call<Output = Result<(), FactoryError>, Args = Self::Args>;
+        ) -> impl Call<Output = Result<u64, FactoryError>, Args = Self::Args>;
+        fn withdraw_creation_fees(
+            &mut self,
+        ) -> impl Call<Output = Result<u128, FactoryError>, Args = Self::Args>;
+        fn grant_role(
+            &mut self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn renounce_role(
+            &mut self,
+            role: Role,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn revoke_role(
+            &mut self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
         fn get_admin(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
         fn get_all_pairs(&self) -> impl Query<Output = Vec<(ActorId, ActorId)>, Args = Self::Args>;
         fn get_all_pairs_address(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn get_all_pairs_length(&self) -> impl Query<Output = u64, Args = Self::Args>;
         fn get_code_id_pair(&self) -> impl Query<Output = CodeId, Args = Self::Args>;
         fn get_fee_to(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
         fn get_fee_to_setter(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
@@ -666,8 +2149,89 @@ pub mod traits {
             token_a: ActorId,
             token_b: ActorId,
         ) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_pair_by_index(
+            &self,
+            index: u64,
+        ) -> impl Query<Output = Option<(ActorId, ActorId, ActorId)>, Args = Self::Args>;
+        fn get_pair_creation_gas(&self) -> impl Query<Output = (u64, u64), Args = Self::Args>;
+        fn get_pair_creation_policy(
+            &self,
+        ) -> impl Query<Output = PairCreationPolicy, Args = Self::Args>;
+        fn get_pair_history(
+            &self,
+            token_a: ActorId,
+            token_b: ActorId,
+        ) -> impl Query<Output = Vec<PairMigration>, Args = Self::Args>;
         fn get_pair_length(&self) -> impl Query<Output = u64, Args = Self::Args>;
+        fn get_pair_trading_status(
+            &self,
+            pair: ActorId,
+            token_a: ActorId,
+            token_b: ActorId,
+        ) -> impl Query<Output = PairTradingStatus, Args = Self::Args>;
+        fn get_pairs(
+            &self,
+            offset: u64,
+            limit: u32,
+        ) -> impl Query<Output = Vec<(ActorId, ActorId, ActorId)>, Args = Self::Args>;
+        fn get_pairs_for_token(
+            &self,
+            token: ActorId,
+            offset: u64,
+            limit: u32,
+        ) -> impl Query<Output = Vec<(ActorId, ActorId, ActorId)>, Args = Self::Args>;
+        fn get_paused_pairs(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn get_pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn get_pending_fee_to_setter(
+            &self,
+        ) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn get_queued_operations(
+            &self,
+        ) -> impl Query<Output = Vec<QueuedOperation>, Args = Self::Args>;
         fn get_router(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_successor_pair(
+            &self,
+            pair: ActorId,
+        ) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn get_timelock_delay(&self) -> impl Query<Output = u64, Args = Self::Args>;
+        fn get_token_metadata(
+            &self,
+            token: ActorId,
+        ) -> impl Query<Output = Option<TokenMetadata>, Args = Self::Args>;
+        fn get_token_status(
+            &self,
+            token: ActorId,
+        ) -> impl Query<Output = TokenStatus, Args = Self::Args>;
+        fn get_tokens_by_status(
+            &self,
+            status: TokenStatus,
+            offset: u32,
+            limit: u32,
+        ) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn get_tokens_metadata(
+            &self,
+            tokens: Vec<ActorId>,
+        ) -> impl Query<Output = Vec<Option<TokenMetadata>>, Args = Self::Args>;
+        fn is_all_paused(&self) -> impl Query<Output = bool, Args = Self::Args>;
+        fn is_pair_blocked(
+            &self,
+            token_a: ActorId,
+            token_b: ActorId,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
+        fn is_pair_paused(&self, pair: ActorId) -> impl Query<Output = bool, Args = Self::Args>;
+        fn get_account_roles(
+            &self,
+            account: ActorId,
+        ) -> impl Query<Output = Vec<Role>, Args = Self::Args>;
+        fn get_role_members(
+            &self,
+            role: Role,
+        ) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn has_role(
+            &self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
     }
 }
 
@@ -680,5 +2244,5 @@ extern crate std;
 pub mod mockall {
     use super::*;
     use sails_rs::mockall::*;
-    mock! { pub FactoryService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::FactoryService for FactoryService<A> { type Args = A; fn add_bridged_asset (&mut self, token_address: ActorId,name: String,symbol: String,decimals: u8,) -> MockCall<A, Result<BridgedAsset, FactoryError>>;fn add_pair (&mut self, token_a: ActorId,token_b: ActorId,pair_address: ActorId,) -> MockCall<A, Result<ActorId, FactoryError>>;fn create_pair (&mut self, token_a: ActorId,token_b: ActorId,) -> MockCall<A, Result<ActorId, FactoryError>>;fn remove_bridged_asset (&mut self, token_address: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn remove_pair (&mut self, token_a: ActorId,token_b: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn set_admin (&mut self, new_admin: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn set_fee_to (&mut self, new_fee_to: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn set_fee_to_setter (&mut self, new_fee_setter: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn set_router (&mut self, router: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn update_code_id_pair (&mut self, new_code_id_pair: CodeId,) -> MockCall<A, Result<(), FactoryError>>;fn get_admin (& self, ) -> MockQuery<A, ActorId>;fn get_all_pairs (& self, ) -> MockQuery<A, Vec<(ActorId,ActorId,)>>;fn get_all_pairs_address (& self, ) -> MockQuery<A, Vec<ActorId>>;fn get_code_id_pair (& self, ) -> MockQuery<A, CodeId>;fn get_fee_to (& self, ) -> MockQuery<A, ActorId>;fn get_fee_to_setter (& self, ) -> MockQuery<A, ActorId>;fn get_pair (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, ActorId>;fn get_pair_length (& self, ) -> MockQuery<A, u64>;fn get_router (& self, ) -> MockQuery<A, ActorId>; } }
+    mock! { pub FactoryService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::FactoryService for FactoryService<A> { type Args = A; fn accept_admin (&mut self, ) -> MockCall<A, Result<(), FactoryError>>;fn accept_fee_to_setter (&mut self, ) -> MockCall<A, Result<(), FactoryError>>;fn add_bridged_asset (&mut self, token_address: ActorId,name: String,symbol: String,decimals: u8,) -> MockCall<A, Result<BridgedAsset, FactoryError>>;fn add_pair (&mut self, token_a: ActorId,token_b: ActorId,pair_address: ActorId,) -> MockCall<A, Result<ActorId, FactoryError>>;fn allow_creator (&mut self, creator: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn cancel_admin_transfer (&mut self, ) -> MockCall<A, Result<(), FactoryError>>;fn cancel_fee_to_setter_transfer (&mut self, ) -> MockCall<A, Result<(), FactoryError>>;fn cancel_operation (&mut self, id: u64,) -> MockCall<A, Result<(), FactoryError>>;fn create_pair (&mut self, token_a: ActorId,token_b: ActorId,) -> MockCall<A, Result<ActorId, FactoryError>>;fn disallow_creator (&mut self, creator: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn execute_operation (&mut self, id: u64,) -> MockCall<A, Result<(), FactoryError>>;fn migrate_pair (&mut self, token_a: ActorId,token_b: ActorId,) -> MockCall<A, Result<ActorId, FactoryError>>;fn pause_all (&mut self, ) -> MockCall<A, Result<(), FactoryError>>;fn pause_pair (&mut self, pair: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn propose_admin (&mut self, new_admin: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn propose_fee_to_setter (&mut self, new_fee_setter: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn remove_bridged_asset (&mut self, token_address: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn remove_pair (&mut self, token_a: ActorId,token_b: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn remove_token_metadata (&mut self, token: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn set_creation_fee (&mut self, creation_fee: u128,) -> MockCall<A, Result<(), FactoryError>>;fn set_creator_policy (&mut self, policy: CreatorPolicy,) -> MockCall<A, Result<(), FactoryError>>;fn set_fee_to (&mut self, new_fee_to: ActorId,) -> MockCall<A, Result<u64, FactoryError>>;fn set_pair_creation_gas (&mut self, gas_limit: u64,reply_gas: u64,) -> MockCall<A, Result<(), FactoryError>>;fn set_router (&mut self, router: ActorId,) -> MockCall<A, Result<u64, FactoryError>>;fn set_timelock_delay (&mut self, delay: u64,) -> MockCall<A, Result<u64, FactoryError>>;fn set_token_metadata (&mut self, token: ActorId,name: String,symbol: String,decimals: u8,logo_uri: Option<String>,) -> MockCall<A, Result<(), FactoryError>>;fn set_token_status (&mut self, token: ActorId,status: TokenStatus,) -> MockCall<A, Result<(), FactoryError>>;fn unpause_all (&mut self, ) -> MockCall<A, Result<(), FactoryError>>;fn unpause_pair (&mut self, pair: ActorId,) -> MockCall<A, Result<(), FactoryError>>;fn update_code_id_pair (&mut self, new_code_id_pair: CodeId,) -> MockCall<A, Result<u64, FactoryError>>;fn withdraw_creation_fees (&mut self, ) -> MockCall<A, Result<u128, FactoryError>>;fn grant_role (&mut self, role: Role,account: ActorId,) -> MockCall<A, Result<(), AccessControlError>>;fn renounce_role (&mut self, role: Role,) -> MockCall<A, Result<(), AccessControlError>>;fn revoke_role (&mut self, role: Role,account: ActorId,) -> MockCall<A, Result<(), AccessControlError>>;fn get_admin (& self, ) -> MockQuery<A, ActorId>;fn get_all_pairs (& self, ) -> MockQuery<A, Vec<(ActorId,ActorId,)>>;fn get_all_pairs_address (& self, ) -> MockQuery<A, Vec<ActorId>>;fn get_all_pairs_length (& self, ) -> MockQuery<A, u64>;fn get_code_id_pair (& self, ) -> MockQuery<A, CodeId>;fn get_fee_to (& self, ) -> MockQuery<A, ActorId>;fn get_fee_to_setter (& self, ) -> MockQuery<A, ActorId>;fn get_pair (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, ActorId>;fn get_pair_by_index (& self, index: u64,) -> MockQuery<A, Option<(ActorId,ActorId,ActorId,)>>;fn get_pair_creation_gas (& self, ) -> MockQuery<A, (u64,u64,)>;fn get_pair_creation_policy (& self, ) -> MockQuery<A, PairCreationPolicy>;fn get_pair_history (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, Vec<PairMigration>>;fn get_pair_length (& self, ) -> MockQuery<A, u64>;fn get_pair_trading_status (& self, pair: ActorId,token_a: ActorId,token_b: ActorId,) -> MockQuery<A, PairTradingStatus>;fn get_pairs (& self, offset: u64,limit: u32,) -> MockQuery<A, Vec<(ActorId,ActorId,ActorId,)>>;fn get_pairs_for_token (& self, token: ActorId,offset: u64,limit: u32,) -> MockQuery<A, Vec<(ActorId,ActorId,ActorId,)>>;fn get_paused_pairs (& self, ) -> MockQuery<A, Vec<ActorId>>;fn get_pending_admin (& self, ) -> MockQuery<A, Option<ActorId>>;fn get_pending_fee_to_setter (& self, ) -> MockQuery<A, Option<ActorId>>;fn get_queued_operations (& self, ) -> MockQuery<A, Vec<QueuedOperation>>;fn get_router (& self, ) -> MockQuery<A, ActorId>;fn get_successor_pair (& self, pair: ActorId,) -> MockQuery<A, Option<ActorId>>;fn get_timelock_delay (& self, ) -> MockQuery<A, u64>;fn get_token_metadata (& self, token: ActorId,) -> MockQuery<A, Option<TokenMetadata>>;fn get_token_status (& self, token: ActorId,) -> MockQuery<A, TokenStatus>;fn get_tokens_by_status (& self, status: TokenStatus,offset: u32,limit: u32,) -> MockQuery<A, Vec<ActorId>>;fn get_tokens_metadata (& self, tokens: Vec<ActorId>,) -> MockQuery<A, Vec<Option<TokenMetadata>>>;fn is_all_paused (& self, ) -> MockQuery<A, bool>;fn is_pair_blocked (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, bool>;fn is_pair_paused (& self, pair: ActorId,) -> MockQuery<A, bool>;fn get_account_roles (& self, account: ActorId,) -> MockQuery<A, Vec<Role>>;fn get_role_members (& self, role: Role,) -> MockQuery<A, Vec<ActorId>>;fn has_role (& self, role: Role,account: ActorId,) -> MockQuery<A, bool>; } }
 }
@@ -1,5 +1,7 @@
 #![no_std]
-// include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+// exposes `WASM_BINARY_OPT` to the tests of programs deploying a factory
+#[cfg(not(target_arch = "wasm32"))]
+include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 #[cfg(target_arch = "wasm32")]
 pub use factory_vara_dex::wasm::*;
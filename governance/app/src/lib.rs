@@ -0,0 +1,26 @@
+#![no_std]
+pub mod clients;
+pub mod services;
+pub mod states;
+
+use sails_rs::{gstd::calls::GStdRemoting, prelude::*};
+
+use clients::extended_new_vft::Vft as VftClient;
+use services::governance_service::GovernanceService;
+use states::governance_state::GovernanceConfig;
+
+#[derive(Default)]
+pub struct GovernanceProgram;
+
+#[program]
+impl GovernanceProgram {
+    /// Panics if `config` has a zero voting period or a quorum above 100%.
+    pub fn new(voting_tokens: Vec<ActorId>, config: GovernanceConfig) -> Self {
+        GovernanceService::seed(voting_tokens, config);
+        Self
+    }
+    #[route("GovernanceService")]
+    pub fn governance_service(&self) -> GovernanceService {
+        GovernanceService::new(VftClient::new(GStdRemoting))
+    }
+}
@@ -0,0 +1,353 @@
+use gstd::{exec, msg};
+use sails_rs::{calls::Call, collections::HashMap, gstd::calls::GStdRemoting, prelude::*};
+
+use crate::clients::extended_new_vft::traits::Vft;
+use crate::clients::extended_new_vft::Vft as VftClient;
+use crate::states::governance_state::{
+    GovernanceConfig, GovernanceError, GovernanceEvent, Proposal, ProposalState, Receipt,
+    StateGovernance, GOVERNANCE, MAX_BPS,
+};
+
+pub struct GovernanceService {
+    pub vft_client: VftClient<GStdRemoting>,
+}
+
+impl GovernanceService {
+    pub fn seed(voting_tokens: Vec<ActorId>, config: GovernanceConfig) {
+        assert!(config.is_valid(), "Invalid config");
+        let mut state = StateGovernance {
+            voting_tokens: Vec::new(),
+            config,
+            deposits: HashMap::new(),
+            checkpoints: HashMap::new(),
+            total_checkpoints: Vec::new(),
+            proposals: HashMap::new(),
+            receipts: HashMap::new(),
+            next_proposal_id: 0,
+        };
+        for token in voting_tokens {
+            if !state.is_voting_token(&token) {
+                state.voting_tokens.push(token);
+            }
+        }
+        unsafe {
+            GOVERNANCE = Some(state);
+        }
+    }
+
+    fn check_self() -> Result<(), GovernanceError> {
+        if msg::source() != exec::program_id() {
+            return Err(GovernanceError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Whether `reply`, answering the Sails call `payload`, carries an `Err`:
+    /// it repeats the call's service and method names, then the `Result`
+    /// variant.
+    fn is_err_reply(payload: &[u8], reply: &[u8]) -> bool {
+        let mut route = payload;
+        if String::decode(&mut route).is_err() || String::decode(&mut route).is_err() {
+            return false;
+        }
+        let prefix = &payload[..payload.len() - route.len()];
+        reply.starts_with(prefix) && reply.get(prefix.len()) == Some(&1)
+    }
+
+    fn proposal(id: u64) -> Result<&'static mut Proposal, GovernanceError> {
+        StateGovernance::get_mut()
+            .proposals
+            .get_mut(&id)
+            .ok_or(GovernanceError::ProposalNotFound)
+    }
+}
+
+#[service(events = GovernanceEvent)]
+impl GovernanceService {
+    pub fn new(vft_client: VftClient<GStdRemoting>) -> Self {
+        Self { vft_client }
+    }
+
+    /// Moves `amount` of a voting token from the caller, who must have
+    /// approved it, into the governance. It counts for proposals created
+    /// from the next block on.
+    /// Tokens staked in `lp_staking` do not count: it keeps no
+    /// per-block history to take a proposal's snapshot from.
+    pub async fn deposit(&mut self, token: ActorId, amount: U256) -> Result<(), GovernanceError> {
+        let state = StateGovernance::get();
+        if !state.is_voting_token(&token) {
+            return Err(GovernanceError::NotVotingToken);
+        }
+        if amount.is_zero() {
+            return Err(GovernanceError::ZeroAmount);
+        }
+        let account = msg::source();
+        let res = self
+            .vft_client
+            .transfer_from(account, exec::program_id(), amount)
+            .send_recv(token)
+            .await;
+        if !matches!(res, Ok(true)) {
+            return Err(GovernanceError::TokenTransferFromFailed);
+        }
+
+        let state = StateGovernance::get_mut();
+        let deposit = state.deposits.entry((account, token)).or_default();
+        *deposit = deposit.saturating_add(amount);
+        state.move_votes(account, amount, true, exec::block_height());
+        self.emit_event(GovernanceEvent::Deposited {
+            account,
+            token,
+            amount,
+        })
+        .ok();
+        Ok(())
+    }
+
+    /// Returns deposited tokens at any time; votes already cast keep their
+    /// weight since they come from the proposal's snapshot.
+    pub async fn withdraw(&mut self, token: ActorId, amount: U256) -> Result<(), GovernanceError> {
+        if amount.is_zero() {
+            return Err(GovernanceError::ZeroAmount);
+        }
+        let account = msg::source();
+        let state = StateGovernance::get_mut();
+        let deposit = state.deposit_of(account, token);
+        if deposit < amount {
+            return Err(GovernanceError::InsufficientDeposit);
+        }
+        // taken out before the transfer so it cannot be withdrawn twice
+        state.deposits.insert((account, token), deposit - amount);
+        state.move_votes(account, amount, false, exec::block_height());
+
+        let res = self.vft_client.transfer(account, amount).send_recv(token).await;
+        if !matches!(res, Ok(true)) {
+            let state = StateGovernance::get_mut();
+            let deposit = state.deposits.entry((account, token)).or_default();
+            *deposit = deposit.saturating_add(amount);
+            state.move_votes(account, amount, true, exec::block_height());
+            return Err(GovernanceError::TokenTransferFailed);
+        }
+        self.emit_event(GovernanceEvent::Withdrawn {
+            account,
+            token,
+            amount,
+        })
+        .ok();
+        Ok(())
+    }
+
+    /// Opens a vote on calling `target` with the encoded Sails message
+    /// `payload` and returns the proposal id. Voting power is read at the
+    /// block before this one, for the proposer as well as for voters.
+    pub fn propose(
+        &mut self,
+        target: ActorId,
+        payload: Vec<u8>,
+        description: String,
+    ) -> Result<u64, GovernanceError> {
+        let state = StateGovernance::get_mut();
+        let proposer = msg::source();
+        let block = exec::block_height();
+        let snapshot_block = block.saturating_sub(1);
+        let votes = state.votes_at(&proposer, snapshot_block);
+        if votes.is_zero() || votes < state.config.proposal_threshold {
+            return Err(GovernanceError::BelowProposalThreshold);
+        }
+        let quorum = state.total_votes_at(snapshot_block) * U256::from(state.config.quorum_bps)
+            / U256::from(MAX_BPS);
+        let end_block = block.saturating_add(state.config.voting_period);
+
+        let id = state.next_proposal_id;
+        state.next_proposal_id += 1;
+        state.proposals.insert(
+            id,
+            Proposal {
+                id,
+                proposer,
+                description,
+                target,
+                payload,
+                snapshot_block,
+                end_block,
+                quorum,
+                for_votes: U256::zero(),
+                against_votes: U256::zero(),
+                canceled: false,
+                executed: false,
+            },
+        );
+        self.emit_event(GovernanceEvent::ProposalCreated {
+            id,
+            proposer,
+            target,
+            snapshot_block,
+            end_block,
+        })
+        .ok();
+        Ok(id)
+    }
+
+    /// Votes with the caller's full voting power at the proposal's
+    /// snapshot and returns it.
+    pub fn cast_vote(&mut self, id: u64, support: bool) -> Result<U256, GovernanceError> {
+        let proposal = Self::proposal(id)?;
+        if proposal.state(exec::block_height()) != ProposalState::Active {
+            return Err(GovernanceError::NotActive);
+        }
+        let voter = msg::source();
+        let state = StateGovernance::get_mut();
+        if state.receipts.contains_key(&(id, voter)) {
+            return Err(GovernanceError::AlreadyVoted);
+        }
+        let votes = state.votes_at(&voter, proposal.snapshot_block);
+        if votes.is_zero() {
+            return Err(GovernanceError::NoVotingPower);
+        }
+        if support {
+            proposal.for_votes = proposal.for_votes.saturating_add(votes);
+        } else {
+            proposal.against_votes = proposal.against_votes.saturating_add(votes);
+        }
+        state.receipts.insert((id, voter), Receipt { support, votes });
+        self.emit_event(GovernanceEvent::VoteCast {
+            id,
+            voter,
+            support,
+            votes,
+        })
+        .ok();
+        Ok(votes)
+    }
+
+    /// The proposer can withdraw a proposal while voting is open.
+    pub fn cancel_proposal(&mut self, id: u64) -> Result<(), GovernanceError> {
+        let proposal = Self::proposal(id)?;
+        if proposal.proposer != msg::source() {
+            return Err(GovernanceError::NotProposer);
+        }
+        if proposal.state(exec::block_height()) != ProposalState::Active {
+            return Err(GovernanceError::NotActive);
+        }
+        proposal.canceled = true;
+        self.emit_event(GovernanceEvent::ProposalCanceled(id)).ok();
+        Ok(())
+    }
+
+    /// Sends a succeeded proposal's call and returns the target's raw
+    /// reply. Anyone can execute. Factory and router setters only queue a
+    /// timelocked operation, which is then executed on the target itself.
+    /// The target's method must return a `Result`: an error reply or an
+    /// `Err` leaves the proposal executable and fails with
+    /// `ExecutionFailed`.
+    pub async fn execute_proposal(&mut self, id: u64) -> Result<Vec<u8>, GovernanceError> {
+        let proposal = Self::proposal(id)?;
+        if proposal.state(exec::block_height()) != ProposalState::Succeeded {
+            return Err(GovernanceError::NotSucceeded);
+        }
+        // marked before the call so it cannot be executed twice meanwhile
+        proposal.executed = true;
+        let target = proposal.target;
+        let payload = proposal.payload.clone();
+
+        // `await` fails on an error reply, e.g. a trap in the target
+        let reply = match msg::send_bytes_for_reply(target, payload.clone(), 0, 0) {
+            Ok(future) => future.await.ok(),
+            Err(_) => None,
+        };
+        let Some(reply) = reply.filter(|reply| !Self::is_err_reply(&payload, reply)) else {
+            if let Ok(proposal) = Self::proposal(id) {
+                proposal.executed = false;
+            }
+            return Err(GovernanceError::ExecutionFailed);
+        };
+        self.emit_event(GovernanceEvent::ProposalExecuted {
+            id,
+            reply: reply.clone(),
+        })
+        .ok();
+        Ok(reply)
+    }
+
+    /// Only callable by the governance itself, through a proposal.
+    pub fn add_voting_token(&mut self, token: ActorId) -> Result<(), GovernanceError> {
+        Self::check_self()?;
+        let state = StateGovernance::get_mut();
+        if state.is_voting_token(&token) {
+            return Err(GovernanceError::AlreadyVotingToken);
+        }
+        state.voting_tokens.push(token);
+        self.emit_event(GovernanceEvent::VotingTokenAdded(token)).ok();
+        Ok(())
+    }
+
+    /// Only callable by the governance itself. Applies to proposals created
+    /// afterwards.
+    pub fn update_config(&mut self, config: GovernanceConfig) -> Result<(), GovernanceError> {
+        Self::check_self()?;
+        if !config.is_valid() {
+            return Err(GovernanceError::InvalidConfig);
+        }
+        StateGovernance::get_mut().config = config;
+        self.emit_event(GovernanceEvent::ConfigUpdated(config)).ok();
+        Ok(())
+    }
+
+    //view function
+
+    pub fn get_voting_tokens(&self) -> Vec<ActorId> {
+        let state = StateGovernance::get();
+        state.voting_tokens.clone()
+    }
+
+    pub fn get_config(&self) -> GovernanceConfig {
+        let state = StateGovernance::get();
+        state.config
+    }
+
+    pub fn get_deposit(&self, account: ActorId, token: ActorId) -> U256 {
+        let state = StateGovernance::get();
+        state.deposit_of(account, token)
+    }
+
+    /// Current voting power of `account`.
+    pub fn get_votes(&self, account: ActorId) -> U256 {
+        let state = StateGovernance::get();
+        state.votes(&account)
+    }
+
+    /// Voting power of `account` at the end of `block`.
+    pub fn get_votes_at(&self, account: ActorId, block: u32) -> U256 {
+        let state = StateGovernance::get();
+        state.votes_at(&account, block)
+    }
+
+    pub fn get_total_votes_at(&self, block: u32) -> U256 {
+        let state = StateGovernance::get();
+        state.total_votes_at(block)
+    }
+
+    pub fn get_proposal(&self, id: u64) -> Option<Proposal> {
+        let state = StateGovernance::get();
+        state.proposals.get(&id).cloned()
+    }
+
+    pub fn get_proposal_state(&self, id: u64) -> Option<ProposalState> {
+        let state = StateGovernance::get();
+        state
+            .proposals
+            .get(&id)
+            .map(|proposal| proposal.state(exec::block_height()))
+    }
+
+    pub fn get_receipt(&self, id: u64, account: ActorId) -> Option<Receipt> {
+        let state = StateGovernance::get();
+        state.receipts.get(&(id, account)).copied()
+    }
+
+    /// Id the next proposal gets.
+    pub fn get_next_proposal_id(&self) -> u64 {
+        let state = StateGovernance::get();
+        state.next_proposal_id
+    }
+}
@@ -0,0 +1,254 @@
+use sails_rs::{collections::HashMap, prelude::*};
+
+pub static mut GOVERNANCE: Option<StateGovernance> = None;
+
+pub const MAX_BPS: u16 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct GovernanceConfig {
+    /// Blocks a proposal stays open for voting after it is created.
+    pub voting_period: u32,
+    /// Share of the snapshot's total voting power that must vote for a
+    /// proposal, in basis points.
+    pub quorum_bps: u16,
+    /// Voting power the proposer needs at the snapshot block.
+    pub proposal_threshold: U256,
+}
+
+impl GovernanceConfig {
+    pub fn is_valid(&self) -> bool {
+        self.voting_period > 0 && self.quorum_bps <= MAX_BPS
+    }
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            voting_period: 1,
+            quorum_bps: 0,
+            proposal_threshold: U256::zero(),
+        }
+    }
+}
+
+/// Voting power of an account, or of all accounts, from `block` on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct Checkpoint {
+    pub block: u32,
+    pub votes: U256,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum ProposalState {
+    Active,
+    Canceled,
+    /// Voting ended without a majority or without quorum.
+    Defeated,
+    Succeeded,
+    Executed,
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: ActorId,
+    pub description: String,
+    pub target: ActorId,
+    /// Full Sails message: encoded service route, method route and arguments.
+    pub payload: Vec<u8>,
+    /// Votes are weighed by deposits at the end of this block, the one
+    /// before the proposal was created.
+    pub snapshot_block: u32,
+    /// Last block in which votes are accepted.
+    pub end_block: u32,
+    /// Votes for needed to pass, fixed from the snapshot's total.
+    pub quorum: U256,
+    pub for_votes: U256,
+    pub against_votes: U256,
+    pub canceled: bool,
+    pub executed: bool,
+}
+
+impl Proposal {
+    pub fn state(&self, block: u32) -> ProposalState {
+        if self.canceled {
+            ProposalState::Canceled
+        } else if self.executed {
+            ProposalState::Executed
+        } else if block <= self.end_block {
+            ProposalState::Active
+        } else if self.for_votes > self.against_votes && self.for_votes >= self.quorum {
+            ProposalState::Succeeded
+        } else {
+            ProposalState::Defeated
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct Receipt {
+    pub support: bool,
+    pub votes: U256,
+}
+
+#[derive(Debug, Default)]
+pub struct StateGovernance {
+    /// LP tokens whose deposits carry voting power, one vote per unit.
+    pub voting_tokens: Vec<ActorId>,
+    pub config: GovernanceConfig,
+    /// Deposited amount by (account, token).
+    pub deposits: HashMap<(ActorId, ActorId), U256>,
+    /// Voting power history of each account, by increasing block.
+    pub checkpoints: HashMap<ActorId, Vec<Checkpoint>>,
+    pub total_checkpoints: Vec<Checkpoint>,
+    pub proposals: HashMap<u64, Proposal>,
+    pub receipts: HashMap<(u64, ActorId), Receipt>,
+    pub next_proposal_id: u64,
+}
+
+impl StateGovernance {
+    pub fn get_mut() -> &'static mut Self {
+        unsafe { GOVERNANCE.as_mut().expect("State Governance Error") }
+    }
+    pub fn get() -> &'static Self {
+        unsafe { GOVERNANCE.as_ref().expect("State Governance Error") }
+    }
+
+    pub fn is_voting_token(&self, token: &ActorId) -> bool {
+        self.voting_tokens.contains(token)
+    }
+
+    pub fn deposit_of(&self, account: ActorId, token: ActorId) -> U256 {
+        self.deposits
+            .get(&(account, token))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn votes(&self, account: &ActorId) -> U256 {
+        self.checkpoints
+            .get(account)
+            .and_then(|checkpoints| checkpoints.last())
+            .map(|checkpoint| checkpoint.votes)
+            .unwrap_or_default()
+    }
+
+    pub fn votes_at(&self, account: &ActorId, block: u32) -> U256 {
+        self.checkpoints
+            .get(account)
+            .map(|checkpoints| Self::lookup(checkpoints, block))
+            .unwrap_or_default()
+    }
+
+    pub fn total_votes_at(&self, block: u32) -> U256 {
+        Self::lookup(&self.total_checkpoints, block)
+    }
+
+    /// Adds `amount` to, or with `increase` unset removes it from, the
+    /// current voting power of `account` and of the total.
+    pub fn move_votes(&mut self, account: ActorId, amount: U256, increase: bool, block: u32) {
+        let checkpoints = self.checkpoints.entry(account).or_default();
+        Self::write(checkpoints, amount, increase, block);
+        Self::write(&mut self.total_checkpoints, amount, increase, block);
+    }
+
+    fn write(checkpoints: &mut Vec<Checkpoint>, amount: U256, increase: bool, block: u32) {
+        let current = checkpoints
+            .last()
+            .map(|checkpoint| checkpoint.votes)
+            .unwrap_or_default();
+        let votes = if increase {
+            current.saturating_add(amount)
+        } else {
+            current.saturating_sub(amount)
+        };
+        match checkpoints.last_mut() {
+            Some(last) if last.block == block => last.votes = votes,
+            _ => checkpoints.push(Checkpoint { block, votes }),
+        }
+    }
+
+    /// Votes of the last checkpoint at or before `block`.
+    fn lookup(checkpoints: &[Checkpoint], block: u32) -> U256 {
+        let index = checkpoints.partition_point(|checkpoint| checkpoint.block <= block);
+        if index == 0 {
+            U256::zero()
+        } else {
+            checkpoints[index - 1].votes
+        }
+    }
+}
+
+#[derive(Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum GovernanceEvent {
+    Deposited {
+        account: ActorId,
+        token: ActorId,
+        amount: U256,
+    },
+    Withdrawn {
+        account: ActorId,
+        token: ActorId,
+        amount: U256,
+    },
+    ProposalCreated {
+        id: u64,
+        proposer: ActorId,
+        target: ActorId,
+        snapshot_block: u32,
+        end_block: u32,
+    },
+    VoteCast {
+        id: u64,
+        voter: ActorId,
+        support: bool,
+        votes: U256,
+    },
+    ProposalCanceled(u64),
+    ProposalExecuted {
+        id: u64,
+        /// Raw reply of the target, including its Sails routes.
+        reply: Vec<u8>,
+    },
+    VotingTokenAdded(ActorId),
+    ConfigUpdated(GovernanceConfig),
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum GovernanceError {
+    /// Configuration is only changed through an executed proposal of the
+    /// governance itself.
+    Unauthorized,
+    NotVotingToken,
+    AlreadyVotingToken,
+    ZeroAmount,
+    InsufficientDeposit,
+    TokenTransferFromFailed,
+    TokenTransferFailed,
+    InvalidConfig,
+    BelowProposalThreshold,
+    ProposalNotFound,
+    /// The proposal is not open for voting.
+    NotActive,
+    AlreadyVoted,
+    NoVotingPower,
+    NotProposer,
+    NotSucceeded,
+    /// The target could not be reached, failed to handle the call or
+    /// returned an `Err`.
+    ExecutionFailed,
+}
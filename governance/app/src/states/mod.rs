@@ -0,0 +1 @@
+pub mod governance_state;
@@ -0,0 +1,324 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf};
+    use sails_rs::{
+        calls::{Action, ActionIo, Activation, Call, Query},
+        gtest::{calls::*, System},
+        prelude::*,
+    };
+
+    use client::{
+        governance_service::io as governance_io,
+        traits::{GovernanceFactory, GovernanceService},
+        GovernanceConfig,
+        GovernanceError,
+        GovernanceFactory as Factory,
+        GovernanceService as GovernanceClient,
+        ProposalState,
+    };
+    use governance::clients::extended_new_vft::{
+        traits::{Vft as _, WvaraVftFactory as _},
+        Vft as VftClient,
+        WvaraVftFactory,
+    };
+    use factory_vara_dex_client::{
+        factory_service::io as factory_io,
+        traits::{FactoryService, FactoryVaraDexFactory},
+        FactoryService as FactoryClient,
+        FactoryVaraDexFactory as DexFactory,
+    };
+
+    const ADMIN_ID: u64 = 10;
+    const VOTER_A: u64 = 11;
+    const VOTER_B: u64 = 12;
+    const USER_ID: u64 = 13;
+    const FEE_TO_ID: u64 = 20;
+
+    const VOTING_PERIOD: u32 = 50;
+
+    fn governance_wasm() -> PathBuf {
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+            .parent()
+            .unwrap()
+            .join("target")
+            .join("wasm32-gear")
+            .join("release")
+            .join("application_builder.opt.wasm")
+    }
+
+    /// Deploys a VFT standing in for an LP token, a governance voting with
+    /// it and a factory whose admin and `fee_to_setter` is the governance.
+    async fn init(quorum_bps: u16) -> (GTestRemoting, ActorId, ActorId, ActorId) {
+        let system = System::new();
+        system.init_logger();
+        for id in [ADMIN_ID, VOTER_A, VOTER_B, USER_ID] {
+            system.mint_to(id, 1_000_000_000_000_000);
+        }
+        let remoting = GTestRemoting::new(system, ADMIN_ID.into());
+        let governance_code_id = remoting.system().submit_code_file(governance_wasm());
+        let token_code_id = remoting
+            .system()
+            .submit_code_file("./wvara_vft_wasm.opt.wasm");
+        let factory_code_id = remoting
+            .system()
+            .submit_code(factory_vara_dex_wasm::WASM_BINARY_OPT);
+
+        let token_id = WvaraVftFactory::new(remoting.clone())
+            .new("LP token".to_string(), "LP".to_string(), 18)
+            .send_recv(token_code_id, "token")
+            .await
+            .unwrap();
+        let config = GovernanceConfig {
+            voting_period: VOTING_PERIOD,
+            quorum_bps,
+            proposal_threshold: 100.into(),
+        };
+        let governance_id = Factory::new(remoting.clone())
+            .new(vec![token_id], config)
+            .send_recv(governance_code_id, "governance")
+            .await
+            .unwrap();
+        let factory_id = DexFactory::new(remoting.clone())
+            .new(CodeId::zero(), ADMIN_ID.into(), governance_id, governance_id)
+            .send_recv(factory_code_id, "factory")
+            .await
+            .unwrap();
+
+        (remoting, governance_id, factory_id, token_id)
+    }
+
+    fn governance(remoting: &GTestRemoting, id: u64) -> GovernanceClient<GTestRemoting> {
+        GovernanceClient::new(remoting.clone().with_actor_id(id.into()))
+    }
+
+    /// Mints `amount` of the token to `id` and deposits it into the
+    /// governance.
+    async fn deposit(
+        remoting: &GTestRemoting,
+        id: u64,
+        governance_id: ActorId,
+        token_id: ActorId,
+        amount: u128,
+    ) {
+        let mut token = VftClient::new(remoting.clone().with_actor_id(id.into()));
+        token
+            .deposit()
+            .with_value(amount)
+            .send_recv(token_id)
+            .await
+            .unwrap();
+        token
+            .approve(governance_id, amount.into())
+            .send_recv(token_id)
+            .await
+            .unwrap();
+        governance(remoting, id)
+            .deposit(token_id, amount.into())
+            .send_recv(governance_id)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    fn end_voting(remoting: &GTestRemoting) {
+        let system = remoting.system();
+        system.run_to_block(system.block_height() + VOTING_PERIOD + 1);
+    }
+
+    #[tokio::test]
+    async fn test_fee_change_through_governance() {
+        let (remoting, governance_id, factory_id, token_id) = init(2_000).await;
+        let mut voter_a = governance(&remoting, VOTER_A);
+        let mut voter_b = governance(&remoting, VOTER_B);
+        let mut user = governance(&remoting, USER_ID);
+        let mut factory = FactoryClient::new(remoting.clone());
+
+        deposit(&remoting, VOTER_A, governance_id, token_id, 600).await;
+        deposit(&remoting, VOTER_B, governance_id, token_id, 400).await;
+        assert_eq!(
+            voter_a.get_deposit(VOTER_A.into(), token_id).recv(governance_id).await.unwrap(),
+            600.into()
+        );
+
+        let payload = factory_io::SetFeeTo::encode_call(FEE_TO_ID.into());
+        let res = user
+            .propose(factory_id, payload.clone(), "fee_to".to_string())
+            .send_recv(governance_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(GovernanceError::BelowProposalThreshold)));
+        let id = voter_a
+            .propose(factory_id, payload, "fee_to".to_string())
+            .send_recv(governance_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let proposal = voter_a.get_proposal(id).recv(governance_id).await.unwrap().unwrap();
+        assert_eq!(proposal.quorum, 200.into());
+
+        // deposits after the snapshot carry no weight on this proposal
+        deposit(&remoting, USER_ID, governance_id, token_id, 5_000).await;
+        let res = user.cast_vote(id, false).send_recv(governance_id).await.unwrap();
+        assert!(matches!(res, Err(GovernanceError::NoVotingPower)));
+        assert_eq!(user.get_votes(USER_ID.into()).recv(governance_id).await.unwrap(), 5_000.into());
+
+        let votes = voter_a.cast_vote(id, true).send_recv(governance_id).await.unwrap().unwrap();
+        assert_eq!(votes, 600.into());
+        let res = voter_a.cast_vote(id, true).send_recv(governance_id).await.unwrap();
+        assert!(matches!(res, Err(GovernanceError::AlreadyVoted)));
+        // the snapshot still counts after the deposit is withdrawn
+        voter_b
+            .withdraw(token_id, 400.into())
+            .send_recv(governance_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let votes = voter_b.cast_vote(id, false).send_recv(governance_id).await.unwrap().unwrap();
+        assert_eq!(votes, 400.into());
+
+        let res = voter_a.execute_proposal(id).send_recv(governance_id).await.unwrap();
+        assert!(matches!(res, Err(GovernanceError::NotSucceeded)));
+        end_voting(&remoting);
+        let state = voter_a.get_proposal_state(id).recv(governance_id).await.unwrap();
+        assert_eq!(state, Some(ProposalState::Succeeded));
+
+        let reply = user.execute_proposal(id).send_recv(governance_id).await.unwrap().unwrap();
+        // `set_fee_to` queued a timelocked operation
+        let operation_id = factory_io::SetFeeTo::decode_reply(reply).unwrap().unwrap();
+        factory
+            .execute_operation(operation_id)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let fee_to = factory.get_fee_to().recv(factory_id).await.unwrap();
+        assert_eq!(fee_to, FEE_TO_ID.into());
+
+        let state = voter_a.get_proposal_state(id).recv(governance_id).await.unwrap();
+        assert_eq!(state, Some(ProposalState::Executed));
+        let res = user.execute_proposal(id).send_recv(governance_id).await.unwrap();
+        assert!(matches!(res, Err(GovernanceError::NotSucceeded)));
+
+        let token = VftClient::new(remoting.clone());
+        let balance = token.balance_of(VOTER_B.into()).recv(token_id).await.unwrap();
+        assert_eq!(balance, 400.into());
+    }
+
+    #[tokio::test]
+    async fn test_quorum_and_cancel() {
+        let (remoting, governance_id, factory_id, token_id) = init(2_000).await;
+        let mut voter_a = governance(&remoting, VOTER_A);
+        let mut voter_b = governance(&remoting, VOTER_B);
+
+        deposit(&remoting, VOTER_A, governance_id, token_id, 150).await;
+        deposit(&remoting, VOTER_B, governance_id, token_id, 850).await;
+
+        // 150 for, none against, but short of the 200 quorum
+        let payload = factory_io::SetFeeTo::encode_call(FEE_TO_ID.into());
+        let id = voter_a
+            .propose(factory_id, payload.clone(), "fee_to".to_string())
+            .send_recv(governance_id)
+            .await
+            .unwrap()
+            .unwrap();
+        voter_a.cast_vote(id, true).send_recv(governance_id).await.unwrap().unwrap();
+        end_voting(&remoting);
+        let state = voter_a.get_proposal_state(id).recv(governance_id).await.unwrap();
+        assert_eq!(state, Some(ProposalState::Defeated));
+        let res = voter_a.cast_vote(id, true).send_recv(governance_id).await.unwrap();
+        assert!(matches!(res, Err(GovernanceError::NotActive)));
+
+        let id = voter_a
+            .propose(factory_id, payload, "fee_to".to_string())
+            .send_recv(governance_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = voter_b.cancel_proposal(id).send_recv(governance_id).await.unwrap();
+        assert!(matches!(res, Err(GovernanceError::NotProposer)));
+        voter_a.cancel_proposal(id).send_recv(governance_id).await.unwrap().unwrap();
+        let res = voter_b.cast_vote(id, true).send_recv(governance_id).await.unwrap();
+        assert!(matches!(res, Err(GovernanceError::NotActive)));
+        let state = voter_a.get_proposal_state(id).recv(governance_id).await.unwrap();
+        assert_eq!(state, Some(ProposalState::Canceled));
+    }
+
+    #[tokio::test]
+    async fn test_config_through_proposal() {
+        let (remoting, governance_id, _, token_id) = init(0).await;
+        let mut voter_a = governance(&remoting, VOTER_A);
+
+        let config = GovernanceConfig {
+            voting_period: 20,
+            quorum_bps: 5_000,
+            proposal_threshold: 1_000.into(),
+        };
+        // only reachable through the governance itself
+        let res = voter_a
+            .update_config(config.clone())
+            .send_recv(governance_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(GovernanceError::Unauthorized)));
+
+        deposit(&remoting, VOTER_A, governance_id, token_id, 100).await;
+        let res = voter_a
+            .deposit(FEE_TO_ID.into(), 100.into())
+            .send_recv(governance_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(GovernanceError::NotVotingToken)));
+
+        let payload = governance_io::UpdateConfig::encode_call(config.clone());
+        let id = voter_a
+            .propose(governance_id, payload, "config".to_string())
+            .send_recv(governance_id)
+            .await
+            .unwrap()
+            .unwrap();
+        voter_a.cast_vote(id, true).send_recv(governance_id).await.unwrap().unwrap();
+        end_voting(&remoting);
+        let reply = voter_a
+            .execute_proposal(id)
+            .send_recv(governance_id)
+            .await
+            .unwrap()
+            .unwrap();
+        governance_io::UpdateConfig::decode_reply(reply).unwrap().unwrap();
+        assert_eq!(voter_a.get_config().recv(governance_id).await.unwrap(), config);
+
+        // 100 deposited is now below the threshold
+        let res = voter_a
+            .propose(governance_id, vec![], "empty".to_string())
+            .send_recv(governance_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(GovernanceError::BelowProposalThreshold)));
+    }
+
+    #[tokio::test]
+    async fn test_failed_execution() {
+        let (remoting, governance_id, _, token_id) = init(2_000).await;
+        let mut voter_a = governance(&remoting, VOTER_A);
+        deposit(&remoting, VOTER_A, governance_id, token_id, 600).await;
+
+        // the token already votes, so the call replies with an `Err`
+        let payload = governance_io::AddVotingToken::encode_call(token_id);
+        let id = voter_a
+            .propose(governance_id, payload, "token".to_string())
+            .send_recv(governance_id)
+            .await
+            .unwrap()
+            .unwrap();
+        voter_a.cast_vote(id, true).send_recv(governance_id).await.unwrap().unwrap();
+        end_voting(&remoting);
+        let res = voter_a.execute_proposal(id).send_recv(governance_id).await.unwrap();
+        assert!(matches!(res, Err(GovernanceError::ExecutionFailed)));
+
+        // nothing happened, so it can be executed again
+        let state = voter_a.get_proposal_state(id).recv(governance_id).await.unwrap();
+        assert_eq!(state, Some(ProposalState::Succeeded));
+        let proposal = voter_a.get_proposal(id).recv(governance_id).await.unwrap().unwrap();
+        assert!(!proposal.executed);
+    }
+}
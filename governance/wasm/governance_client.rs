@@ -0,0 +1,751 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct GovernanceFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> GovernanceFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::GovernanceFactory for GovernanceFactory<R> {
+    type Args = R::Args;
+    /// Panics if `config` has a zero voting period or a quorum above 100%.
+    fn new(
+        &self,
+        voting_tokens: Vec<ActorId>,
+        config: GovernanceConfig,
+    ) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, governance_factory::io::New>::new(
+            self.remoting.clone(),
+            (voting_tokens, config),
+        )
+    }
+}
+
+pub mod governance_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                voting_tokens: Vec<ActorId>,
+                config: super::GovernanceConfig,
+            ) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&(voting_tokens, config))
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = (Vec<ActorId>, super::GovernanceConfig);
+            type Reply = ();
+        }
+    }
+}
+pub struct GovernanceService<R> {
+    remoting: R,
+}
+impl<R> GovernanceService<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::GovernanceService for GovernanceService<R> {
+    type Args = R::Args;
+    /// Only callable by the governance itself, through a proposal.
+    fn add_voting_token(
+        &mut self,
+        token: ActorId,
+    ) -> impl Call<Output = Result<(), GovernanceError>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::AddVotingToken>::new(
+            self.remoting.clone(),
+            token,
+        )
+    }
+    /// The proposer can withdraw a proposal while voting is open.
+    fn cancel_proposal(
+        &mut self,
+        id: u64,
+    ) -> impl Call<Output = Result<(), GovernanceError>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::CancelProposal>::new(self.remoting.clone(), id)
+    }
+    /// Votes with the caller's full voting power at the proposal's
+    /// snapshot and returns it.
+    fn cast_vote(
+        &mut self,
+        id: u64,
+        support: bool,
+    ) -> impl Call<Output = Result<U256, GovernanceError>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::CastVote>::new(
+            self.remoting.clone(),
+            (id, support),
+        )
+    }
+    /// Moves `amount` of a voting token from the caller, who must have
+    /// approved it, into the governance. It counts for proposals created
+    /// from the next block on.
+    /// Tokens staked in `lp_staking` do not count: it keeps no
+    /// per-block history to take a proposal's snapshot from.
+    fn deposit(
+        &mut self,
+        token: ActorId,
+        amount: U256,
+    ) -> impl Call<Output = Result<(), GovernanceError>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::Deposit>::new(
+            self.remoting.clone(),
+            (token, amount),
+        )
+    }
+    /// Sends a succeeded proposal's call and returns the target's raw
+    /// reply. Anyone can execute. Factory and router setters only queue a
+    /// timelocked operation, which is then executed on the target itself.
+    /// The target's method must return a `Result`: an error reply or an
+    /// `Err` leaves the proposal executable and fails with
+    /// `ExecutionFailed`.
+    fn execute_proposal(
+        &mut self,
+        id: u64,
+    ) -> impl Call<Output = Result<Vec<u8>, GovernanceError>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::ExecuteProposal>::new(self.remoting.clone(), id)
+    }
+    /// Opens a vote on calling `target` with the encoded Sails message
+    /// `payload` and returns the proposal id. Voting power is read at the
+    /// block before this one, for the proposer as well as for voters.
+    fn propose(
+        &mut self,
+        target: ActorId,
+        payload: Vec<u8>,
+        description: String,
+    ) -> impl Call<Output = Result<u64, GovernanceError>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::Propose>::new(
+            self.remoting.clone(),
+            (target, payload, description),
+        )
+    }
+    /// Only callable by the governance itself. Applies to proposals created
+    /// afterwards.
+    fn update_config(
+        &mut self,
+        config: GovernanceConfig,
+    ) -> impl Call<Output = Result<(), GovernanceError>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::UpdateConfig>::new(
+            self.remoting.clone(),
+            config,
+        )
+    }
+    /// Returns deposited tokens at any time; votes already cast keep their
+    /// weight since they come from the proposal's snapshot.
+    fn withdraw(
+        &mut self,
+        token: ActorId,
+        amount: U256,
+    ) -> impl Call<Output = Result<(), GovernanceError>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::Withdraw>::new(
+            self.remoting.clone(),
+            (token, amount),
+        )
+    }
+    fn get_config(&self) -> impl Query<Output = GovernanceConfig, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetConfig>::new(self.remoting.clone(), ())
+    }
+    fn get_deposit(
+        &self,
+        account: ActorId,
+        token: ActorId,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetDeposit>::new(
+            self.remoting.clone(),
+            (account, token),
+        )
+    }
+    /// Id the next proposal gets.
+    fn get_next_proposal_id(&self) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetNextProposalId>::new(
+            self.remoting.clone(),
+            (),
+        )
+    }
+    fn get_proposal(&self, id: u64) -> impl Query<Output = Option<Proposal>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetProposal>::new(self.remoting.clone(), id)
+    }
+    fn get_proposal_state(
+        &self,
+        id: u64,
+    ) -> impl Query<Output = Option<ProposalState>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetProposalState>::new(
+            self.remoting.clone(),
+            id,
+        )
+    }
+    fn get_receipt(
+        &self,
+        id: u64,
+        account: ActorId,
+    ) -> impl Query<Output = Option<Receipt>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetReceipt>::new(
+            self.remoting.clone(),
+            (id, account),
+        )
+    }
+    fn get_total_votes_at(&self, block: u32) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetTotalVotesAt>::new(
+            self.remoting.clone(),
+            block,
+        )
+    }
+    /// Current voting power of `account`.
+    fn get_votes(&self, account: ActorId) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetVotes>::new(self.remoting.clone(), account)
+    }
+    /// Voting power of `account` at the end of `block`.
+    fn get_votes_at(
+        &self,
+        account: ActorId,
+        block: u32,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetVotesAt>::new(
+            self.remoting.clone(),
+            (account, block),
+        )
+    }
+    fn get_voting_tokens(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, governance_service::io::GetVotingTokens>::new(self.remoting.clone(), ())
+    }
+}
+
+pub mod governance_service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct AddVotingToken(());
+        impl AddVotingToken {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId) -> Vec<u8> {
+                <AddVotingToken as ActionIo>::encode_call(&token)
+            }
+        }
+        impl ActionIo for AddVotingToken {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                56, 65, 100, 100, 86, 111, 116, 105, 110, 103, 84, 111, 107, 101, 110,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::GovernanceError>;
+        }
+        pub struct CancelProposal(());
+        impl CancelProposal {
+            #[allow(dead_code)]
+            pub fn encode_call(id: u64) -> Vec<u8> {
+                <CancelProposal as ActionIo>::encode_call(&id)
+            }
+        }
+        impl ActionIo for CancelProposal {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                56, 67, 97, 110, 99, 101, 108, 80, 114, 111, 112, 111, 115, 97, 108,
+            ];
+            type Params = u64;
+            type Reply = Result<(), super::GovernanceError>;
+        }
+        pub struct CastVote(());
+        impl CastVote {
+            #[allow(dead_code)]
+            pub fn encode_call(id: u64, support: bool) -> Vec<u8> {
+                <CastVote as ActionIo>::encode_call(&(id, support))
+            }
+        }
+        impl ActionIo for CastVote {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                32, 67, 97, 115, 116, 86, 111, 116, 101,
+            ];
+            type Params = (u64, bool);
+            type Reply = Result<U256, super::GovernanceError>;
+        }
+        pub struct Deposit(());
+        impl Deposit {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, amount: U256) -> Vec<u8> {
+                <Deposit as ActionIo>::encode_call(&(token, amount))
+            }
+        }
+        impl ActionIo for Deposit {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                28, 68, 101, 112, 111, 115, 105, 116,
+            ];
+            type Params = (ActorId, U256);
+            type Reply = Result<(), super::GovernanceError>;
+        }
+        pub struct ExecuteProposal(());
+        impl ExecuteProposal {
+            #[allow(dead_code)]
+            pub fn encode_call(id: u64) -> Vec<u8> {
+                <ExecuteProposal as ActionIo>::encode_call(&id)
+            }
+        }
+        impl ActionIo for ExecuteProposal {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                60, 69, 120, 101, 99, 117, 116, 101, 80, 114, 111, 112, 111, 115, 97, 108,
+            ];
+            type Params = u64;
+            type Reply = Result<Vec<u8>, super::GovernanceError>;
+        }
+        pub struct Propose(());
+        impl Propose {
+            #[allow(dead_code)]
+            pub fn encode_call(target: ActorId, payload: Vec<u8>, description: String) -> Vec<u8> {
+                <Propose as ActionIo>::encode_call(&(target, payload, description))
+            }
+        }
+        impl ActionIo for Propose {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                28, 80, 114, 111, 112, 111, 115, 101,
+            ];
+            type Params = (ActorId, Vec<u8>, String);
+            type Reply = Result<u64, super::GovernanceError>;
+        }
+        pub struct UpdateConfig(());
+        impl UpdateConfig {
+            #[allow(dead_code)]
+            pub fn encode_call(config: super::GovernanceConfig) -> Vec<u8> {
+                <UpdateConfig as ActionIo>::encode_call(&config)
+            }
+        }
+        impl ActionIo for UpdateConfig {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                48, 85, 112, 100, 97, 116, 101, 67, 111, 110, 102, 105, 103,
+            ];
+            type Params = super::GovernanceConfig;
+            type Reply = Result<(), super::GovernanceError>;
+        }
+        pub struct Withdraw(());
+        impl Withdraw {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, amount: U256) -> Vec<u8> {
+                <Withdraw as ActionIo>::encode_call(&(token, amount))
+            }
+        }
+        impl ActionIo for Withdraw {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                32, 87, 105, 116, 104, 100, 114, 97, 119,
+            ];
+            type Params = (ActorId, U256);
+            type Reply = Result<(), super::GovernanceError>;
+        }
+        pub struct GetConfig(());
+        impl GetConfig {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetConfig as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetConfig {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                36, 71, 101, 116, 67, 111, 110, 102, 105, 103,
+            ];
+            type Params = ();
+            type Reply = super::GovernanceConfig;
+        }
+        pub struct GetDeposit(());
+        impl GetDeposit {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId, token: ActorId) -> Vec<u8> {
+                <GetDeposit as ActionIo>::encode_call(&(account, token))
+            }
+        }
+        impl ActionIo for GetDeposit {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                40, 71, 101, 116, 68, 101, 112, 111, 115, 105, 116,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = U256;
+        }
+        pub struct GetNextProposalId(());
+        impl GetNextProposalId {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetNextProposalId as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetNextProposalId {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                68, 71, 101, 116, 78, 101, 120, 116, 80, 114, 111, 112, 111, 115, 97, 108, 73, 100,
+            ];
+            type Params = ();
+            type Reply = u64;
+        }
+        pub struct GetProposal(());
+        impl GetProposal {
+            #[allow(dead_code)]
+            pub fn encode_call(id: u64) -> Vec<u8> {
+                <GetProposal as ActionIo>::encode_call(&id)
+            }
+        }
+        impl ActionIo for GetProposal {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                44, 71, 101, 116, 80, 114, 111, 112, 111, 115, 97, 108,
+            ];
+            type Params = u64;
+            type Reply = Option<super::Proposal>;
+        }
+        pub struct GetProposalState(());
+        impl GetProposalState {
+            #[allow(dead_code)]
+            pub fn encode_call(id: u64) -> Vec<u8> {
+                <GetProposalState as ActionIo>::encode_call(&id)
+            }
+        }
+        impl ActionIo for GetProposalState {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                64, 71, 101, 116, 80, 114, 111, 112, 111, 115, 97, 108, 83, 116, 97, 116, 101,
+            ];
+            type Params = u64;
+            type Reply = Option<super::ProposalState>;
+        }
+        pub struct GetReceipt(());
+        impl GetReceipt {
+            #[allow(dead_code)]
+            pub fn encode_call(id: u64, account: ActorId) -> Vec<u8> {
+                <GetReceipt as ActionIo>::encode_call(&(id, account))
+            }
+        }
+        impl ActionIo for GetReceipt {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                40, 71, 101, 116, 82, 101, 99, 101, 105, 112, 116,
+            ];
+            type Params = (u64, ActorId);
+            type Reply = Option<super::Receipt>;
+        }
+        pub struct GetTotalVotesAt(());
+        impl GetTotalVotesAt {
+            #[allow(dead_code)]
+            pub fn encode_call(block: u32) -> Vec<u8> {
+                <GetTotalVotesAt as ActionIo>::encode_call(&block)
+            }
+        }
+        impl ActionIo for GetTotalVotesAt {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                60, 71, 101, 116, 84, 111, 116, 97, 108, 86, 111, 116, 101, 115, 65, 116,
+            ];
+            type Params = u32;
+            type Reply = U256;
+        }
+        pub struct GetVotes(());
+        impl GetVotes {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetVotes as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for GetVotes {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                32, 71, 101, 116, 86, 111, 116, 101, 115,
+            ];
+            type Params = ActorId;
+            type Reply = U256;
+        }
+        pub struct GetVotesAt(());
+        impl GetVotesAt {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId, block: u32) -> Vec<u8> {
+                <GetVotesAt as ActionIo>::encode_call(&(account, block))
+            }
+        }
+        impl ActionIo for GetVotesAt {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                40, 71, 101, 116, 86, 111, 116, 101, 115, 65, 116,
+            ];
+            type Params = (ActorId, u32);
+            type Reply = U256;
+        }
+        pub struct GetVotingTokens(());
+        impl GetVotingTokens {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetVotingTokens as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetVotingTokens {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+                60, 71, 101, 116, 86, 111, 116, 105, 110, 103, 84, 111, 107, 101, 110, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
+    }
+
+    #[allow(dead_code)]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        use sails_rs::events::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum GovernanceServiceEvents {
+            Deposited {
+                account: ActorId,
+                token: ActorId,
+                amount: U256,
+            },
+            Withdrawn {
+                account: ActorId,
+                token: ActorId,
+                amount: U256,
+            },
+            ProposalCreated {
+                id: u64,
+                proposer: ActorId,
+                target: ActorId,
+                snapshot_block: u32,
+                end_block: u32,
+            },
+            VoteCast {
+                id: u64,
+                voter: ActorId,
+                support: bool,
+                votes: U256,
+            },
+            ProposalCanceled(u64),
+            ProposalExecuted {
+                id: u64,
+                /// Raw reply of the target, including its Sails routes.
+                reply: Vec<u8>,
+            },
+            VotingTokenAdded(ActorId),
+            ConfigUpdated(GovernanceConfig),
+        }
+        impl EventIo for GovernanceServiceEvents {
+            const ROUTE: &'static [u8] = &[
+                68, 71, 111, 118, 101, 114, 110, 97, 110, 99, 101, 83, 101, 114, 118, 105, 99, 101,
+            ];
+            const EVENT_NAMES: &'static [&'static [u8]] = &[
+                &[36, 68, 101, 112, 111, 115, 105, 116, 101, 100],
+                &[36, 87, 105, 116, 104, 100, 114, 97, 119, 110],
+                &[
+                    60, 80, 114, 111, 112, 111, 115, 97, 108, 67, 114, 101, 97, 116, 101, 100,
+                ],
+                &[32, 86, 111, 116, 101, 67, 97, 115, 116],
+                &[
+                    64, 80, 114, 111, 112, 111, 115, 97, 108, 67, 97, 110, 99, 101, 108, 101, 100,
+                ],
+                &[
+                    64, 80, 114, 111, 112, 111, 115, 97, 108, 69, 120, 101, 99, 117, 116, 101, 100,
+                ],
+                &[
+                    64, 86, 111, 116, 105, 110, 103, 84, 111, 107, 101, 110, 65, 100, 100, 101, 100,
+                ],
+                &[
+                    52, 67, 111, 110, 102, 105, 103, 85, 112, 100, 97, 116, 101, 100,
+                ],
+            ];
+            type Event = Self;
+        }
+        pub fn listener<R: Listener<Vec<u8>>>(
+            remoting: R,
+        ) -> impl Listener<GovernanceServiceEvents> {
+            RemotingListener::<_, GovernanceServiceEvents>::new(remoting)
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GovernanceConfig {
+    /// Blocks a proposal stays open for voting after it is created.
+    pub voting_period: u32,
+    /// Share of the snapshot's total voting power that must vote for a
+    /// proposal, in basis points.
+    pub quorum_bps: u16,
+    /// Voting power the proposer needs at the snapshot block.
+    pub proposal_threshold: U256,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum GovernanceError {
+    /// Configuration is only changed through an executed proposal of the
+    /// governance itself.
+    Unauthorized,
+    NotVotingToken,
+    AlreadyVotingToken,
+    ZeroAmount,
+    InsufficientDeposit,
+    TokenTransferFromFailed,
+    TokenTransferFailed,
+    InvalidConfig,
+    BelowProposalThreshold,
+    ProposalNotFound,
+    /// The proposal is not open for voting.
+    NotActive,
+    AlreadyVoted,
+    NoVotingPower,
+    NotProposer,
+    NotSucceeded,
+    /// The target could not be reached, failed to handle the call or
+    /// returned an `Err`.
+    ExecutionFailed,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: ActorId,
+    pub description: String,
+    pub target: ActorId,
+    /// Full Sails message: encoded service route, method route and arguments.
+    pub payload: Vec<u8>,
+    /// Votes are weighed by deposits at the end of this block, the one
+    /// before the proposal was created.
+    pub snapshot_block: u32,
+    /// Last block in which votes are accepted.
+    pub end_block: u32,
+    /// Votes for needed to pass, fixed from the snapshot's total.
+    pub quorum: U256,
+    pub for_votes: U256,
+    pub against_votes: U256,
+    pub canceled: bool,
+    pub executed: bool,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProposalState {
+    Active,
+    Canceled,
+    /// Voting ended without a majority or without quorum.
+    Defeated,
+    Succeeded,
+    Executed,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Receipt {
+    pub support: bool,
+    pub votes: U256,
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait GovernanceFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(
+            &self,
+            voting_tokens: Vec<ActorId>,
+            config: GovernanceConfig,
+        ) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait GovernanceService {
+        type Args;
+        fn add_voting_token(
+            &mut self,
+            token: ActorId,
+        ) -> impl Call<Output = Result<(), GovernanceError>, Args = Self::Args>;
+        fn cancel_proposal(
+            &mut self,
+            id: u64,
+        ) -> impl Call<Output = Result<(), GovernanceError>, Args = Self::Args>;
+        fn cast_vote(
+            &mut self,
+            id: u64,
+            support: bool,
+        ) -> impl Call<Output = Result<U256, GovernanceError>, Args = Self::Args>;
+        fn deposit(
+            &mut self,
+            token: ActorId,
+            amount: U256,
+        ) -> impl Call<Output = Result<(), GovernanceError>, Args = Self::Args>;
+        fn execute_proposal(
+            &mut self,
+            id: u64,
+        ) -> impl Call<Output = Result<Vec<u8>, GovernanceError>, Args = Self::Args>;
+        fn propose(
+            &mut self,
+            target: ActorId,
+            payload: Vec<u8>,
+            description: String,
+        ) -> impl Call<Output = Result<u64, GovernanceError>, Args = Self::Args>;
+        fn update_config(
+            &mut self,
+            config: GovernanceConfig,
+        ) -> impl Call<Output = Result<(), GovernanceError>, Args = Self::Args>;
+        fn withdraw(
+            &mut self,
+            token: ActorId,
+            amount: U256,
+        ) -> impl Call<Output = Result<(), GovernanceError>, Args = Self::Args>;
+        fn get_config(&self) -> impl Query<Output = GovernanceConfig, Args = Self::Args>;
+        fn get_deposit(
+            &self,
+            account: ActorId,
+            token: ActorId,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_next_proposal_id(&self) -> impl Query<Output = u64, Args = Self::Args>;
+        fn get_proposal(&self, id: u64)
+            -> impl Query<Output = Option<Proposal>, Args = Self::Args>;
+        fn get_proposal_state(
+            &self,
+            id: u64,
+        ) -> impl Query<Output = Option<ProposalState>, Args = Self::Args>;
+        fn get_receipt(
+            &self,
+            id: u64,
+            account: ActorId,
+        ) -> impl Query<Output = Option<Receipt>, Args = Self::Args>;
+        fn get_total_votes_at(&self, block: u32) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_votes(&self, account: ActorId) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_votes_at(
+            &self,
+            account: ActorId,
+            block: u32,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_voting_tokens(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+    }
+}
+
+#[cfg(feature = "with_mocks")]
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+#[cfg(feature = "with_mocks")]
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mockall {
+    use super::*;
+    use sails_rs::mockall::*;
+    mock! { pub GovernanceService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::GovernanceService for GovernanceService<A> { type Args = A; fn add_voting_token (&mut self, token: ActorId,) -> MockCall<A, Result<(), GovernanceError>>;fn cancel_proposal (&mut self, id: u64,) -> MockCall<A, Result<(), GovernanceError>>;fn cast_vote (&mut self, id: u64,support: bool,) -> MockCall<A, Result<U256, GovernanceError>>;fn deposit (&mut self, token: ActorId,amount: U256,) -> MockCall<A, Result<(), GovernanceError>>;fn execute_proposal (&mut self, id: u64,) -> MockCall<A, Result<Vec<u8>, GovernanceError>>;fn propose (&mut self, target: ActorId,payload: Vec<u8>,description: String,) -> MockCall<A, Result<u64, GovernanceError>>;fn update_config (&mut self, config: GovernanceConfig,) -> MockCall<A, Result<(), GovernanceError>>;fn withdraw (&mut self, token: ActorId,amount: U256,) -> MockCall<A, Result<(), GovernanceError>>;fn get_config (& self, ) -> MockQuery<A, GovernanceConfig>;fn get_deposit (& self, account: ActorId,token: ActorId,) -> MockQuery<A, U256>;fn get_next_proposal_id (& self, ) -> MockQuery<A, u64>;fn get_proposal (& self, id: u64,) -> MockQuery<A, Option<Proposal>>;fn get_proposal_state (& self, id: u64,) -> MockQuery<A, Option<ProposalState>>;fn get_receipt (& self, id: u64,account: ActorId,) -> MockQuery<A, Option<Receipt>>;fn get_total_votes_at (& self, block: u32,) -> MockQuery<A, U256>;fn get_votes (& self, account: ActorId,) -> MockQuery<A, U256>;fn get_votes_at (& self, account: ActorId,block: u32,) -> MockQuery<A, U256>;fn get_voting_tokens (& self, ) -> MockQuery<A, Vec<ActorId>>; } }
+}
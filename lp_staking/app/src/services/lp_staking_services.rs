@@ -3,6 +3,7 @@ use sails_rs::gstd::calls::GStdRemoting;
 use sails_rs::gstd::exec::{self, block_timestamp};
 use sails_rs::{collections::*, gstd::msg, prelude::*};
 
+use access_control::{has_role, AccessControl, AccessControlService, Role};
 use crate::clients::extended_new_vft::traits::Vft;
 use crate::clients::extended_new_vft::Vft as VftClient;
 use crate::clients::lp_vara_dex_client::traits::LpService;
@@ -14,6 +15,7 @@ use crate::states::lp_staking_state::{
 pub struct LpStakingService {
     pub vft_client: VftClient<GStdRemoting>,
     pub lp_client: LpServiceClient<GStdRemoting>,
+    pub access_control: AccessControlService,
 }
 
 impl LpStakingService {
@@ -37,14 +39,16 @@ impl LpStakingService {
                 staked_token,
                 reward_token,
                 admin,
+                pending_admin: None,
                 user_info: HashMap::new(),
                 precision_factor: U256::exp10(4),
                 lock: false,
             })
         }
+        AccessControlService::seed(admin);
     }
 }
-#[sails_rs::service(events = LpStakingEvent)]
+#[sails_rs::service(extends = AccessControlService, events = LpStakingEvent)]
 impl LpStakingService {
     pub fn new(
         vft_client: VftClient<GStdRemoting>,
@@ -53,24 +57,69 @@ impl LpStakingService {
         Self {
             vft_client,
             lp_client,
+            access_control: AccessControlService::new(),
         }
     }
 
     // admin function
-    pub fn set_admin(&mut self, new_admin: ActorId) -> Result<bool, LpStakingError> {
+    /// `new_admin` takes over once it calls `accept_admin`.
+    pub fn propose_admin(&mut self, new_admin: ActorId) -> Result<bool, LpStakingError> {
         let state = StateLpStaking::get_mut();
         let sender = msg::source();
-        if sender != state.admin {
+        if !has_role(Role::DefaultAdmin, sender) {
             return Err(LpStakingError::ErrorNotAdmin);
         }
-        state.admin = new_admin;
+        if new_admin.is_zero() {
+            return Err(LpStakingError::ErrorZeroAccount);
+        }
+        state.pending_admin = Some(new_admin);
+        self.emit_event(LpStakingEvent::AdminTransferProposed { new_admin })
+            .ok();
+        Ok(true)
+    }
+
+    pub fn accept_admin(&mut self) -> Result<bool, LpStakingError> {
+        let state = StateLpStaking::get_mut();
+        let sender = msg::source();
+        match state.pending_admin {
+            None => return Err(LpStakingError::ErrorNoPendingTransfer),
+            Some(pending) if pending != sender => {
+                return Err(LpStakingError::ErrorNotPendingAccount)
+            }
+            Some(_) => {}
+        }
+        state.pending_admin = None;
+        let old_admin = state.admin;
+        // the new admin takes over every role of the old one
+        AccessControl::get_mut().transfer_roles(old_admin, sender);
+        state.admin = sender;
+        self.emit_event(LpStakingEvent::AdminTransferred {
+            old_admin,
+            new_admin: sender,
+        })
+        .ok();
+        Ok(true)
+    }
+
+    pub fn cancel_admin_transfer(&mut self) -> Result<bool, LpStakingError> {
+        let state = StateLpStaking::get_mut();
+        let sender = msg::source();
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(LpStakingError::ErrorNotAdmin);
+        }
+        let pending_admin = state
+            .pending_admin
+            .take()
+            .ok_or(LpStakingError::ErrorNoPendingTransfer)?;
+        self.emit_event(LpStakingEvent::AdminTransferCancelled { pending_admin })
+            .ok();
         Ok(true)
     }
 
     pub async fn recover_token(&mut self, token: ActorId) -> Result<bool, LpStakingError> {
         let state = StateLpStaking::get_mut();
         let sender = msg::source();
-        if sender != state.admin {
+        if !has_role(Role::Treasurer, sender) {
             return Err(LpStakingError::ErrorNotAdmin);
         }
 
@@ -103,7 +152,7 @@ impl LpStakingService {
     pub fn update_end_pool(&mut self, new_time_end: u64) -> Result<bool, LpStakingError> {
         let state = StateLpStaking::get_mut();
         let sender = msg::source();
-        if sender != state.admin {
+        if !has_role(Role::RewardManager, sender) {
             return Err(LpStakingError::ErrorNotAdmin);
         }
 
@@ -118,7 +167,7 @@ impl LpStakingService {
     ) -> Result<bool, LpStakingError> {
         let state = StateLpStaking::get_mut();
         let sender = msg::source();
-        if sender != state.admin {
+        if !has_role(Role::RewardManager, sender) {
             return Err(LpStakingError::ErrorNotAdmin);
         }
         state.minimum_deposit_amount = new_minimum_amount;
@@ -132,7 +181,7 @@ impl LpStakingService {
     ) -> Result<bool, LpStakingError> {
         let state = StateLpStaking::get_mut();
         let sender = msg::source();
-        if sender != state.admin {
+        if !has_role(Role::RewardManager, sender) {
             return Err(LpStakingError::ErrorNotAdmin);
         }
 
@@ -147,7 +196,7 @@ impl LpStakingService {
     ) -> Result<bool, LpStakingError> {
         let state = StateLpStaking::get_mut();
         let sender = msg::source();
-        if sender != state.admin {
+        if !has_role(Role::RewardManager, sender) {
             return Err(LpStakingError::ErrorNotAdmin);
         }
 
@@ -529,6 +578,11 @@ impl LpStakingService {
         }
     }
 
+    pub fn pending_admin(&self) -> Option<ActorId> {
+        let state = StateLpStaking::get();
+        state.pending_admin
+    }
+
     pub fn pool_info(&self) -> PoolStakingInfo {
         let state = StateLpStaking::get();
         PoolStakingInfo {
@@ -582,3 +636,9 @@ impl LpStakingService {
         }
     }
 }
+
+impl AsRef<AccessControlService> for LpStakingService {
+    fn as_ref(&self) -> &AccessControlService {
+        &self.access_control
+    }
+}
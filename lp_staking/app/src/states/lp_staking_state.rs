@@ -16,6 +16,8 @@ pub struct StateLpStaking {
     pub staked_token:ActorId,
     pub reward_token:ActorId,
     pub admin:ActorId,
+    /// Proposed `admin`, set until it accepts or the proposal is cancelled.
+    pub pending_admin:Option<ActorId>,
     pub user_info:HashMap<ActorId,UserInfo>,
     pub precision_factor : U256,
     pub lock : bool
@@ -77,6 +79,16 @@ pub enum LpStakingEvent {
     TokenRecovery {
         token:ActorId,
         amount:U256
+    },
+    AdminTransferProposed {
+        new_admin:ActorId
+    },
+    AdminTransferCancelled {
+        pending_admin:ActorId
+    },
+    AdminTransferred {
+        old_admin:ActorId,
+        new_admin:ActorId
     }
 
 }
@@ -109,5 +121,9 @@ pub enum LpStakingError {
     TransferLiquidityFailed,
     EPoolEnd,
     LPStakingStatusIncorrect,
-    ErrorInsufficientBalance
+    ErrorInsufficientBalance,
+    /// There is no proposed admin to accept or cancel.
+    ErrorNoPendingTransfer,
+    /// The caller is not the proposed admin.
+    ErrorNotPendingAccount
 }
\ No newline at end of file
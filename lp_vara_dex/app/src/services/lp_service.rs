@@ -1,11 +1,13 @@
+use access_control::{has_role, AccessControl, AccessControlService, Role};
 use crate::clients::extended_new_vft::traits::Vft;
 use crate::clients::extended_new_vft::Vft as VftClient;
 use crate::clients::factory_vara_dex_client::traits::FactoryService;
-use crate::clients::factory_vara_dex_client::FactoryService as FactoryServiceClient;
-use crate::states::lp_state::{LPError, LPEvent, StateLp, LP, MINIMUM_LIQUIDITY};
+use crate::clients::factory_vara_dex_client::{FactoryService as FactoryServiceClient, PairTradingStatus};
+use crate::states::lp_state::{LPError, LPEvent, StateLp, LP, MINIMUM_LIQUIDITY, NATIVE_VARA};
 use gstd::exec;
+use permit::{PermitError, PermitSignature};
 use sails_rs::calls::{Call, Query};
-use sails_rs::gstd::calls::GStdRemoting;
+use sails_rs::gstd::{calls::GStdRemoting, CommandReply};
 use sails_rs::{gstd::msg, prelude::*};
 use vft_service::{Service as VftService, Storage};
 
@@ -13,6 +15,7 @@ pub struct LPService {
     pub vft_client: VftClient<GStdRemoting>,
     pub vft_service: VftService,
     pub factory_client: FactoryServiceClient<GStdRemoting>,
+    pub access_control: AccessControlService,
 }
 
 impl LPService {
@@ -44,15 +47,17 @@ impl LPService {
                 ..Default::default()
             });
         }
+        AccessControlService::seed(admin);
         LPService {
             vft_service: <VftService>::seed(name, symbol, decimals),
             vft_client: VftClient::new(GStdRemoting),
             factory_client: FactoryServiceClient::new(GStdRemoting),
+            access_control: AccessControlService::new(),
         }
     }
 }
 
-#[service(extends = VftService,events = LPEvent)]
+#[service(extends = [VftService, AccessControlService], events = LPEvent)]
 impl LPService {
     pub fn new(
         vft_client: VftClient<GStdRemoting>,
@@ -62,6 +67,7 @@ impl LPService {
             vft_client,
             vft_service: VftService::new(),
             factory_client,
+            access_control: AccessControlService::new(),
         }
     }
 
@@ -89,22 +95,190 @@ impl LPService {
         state_lp.factory
     }
 
-    pub fn set_admin(&mut self, new_admin: ActorId) -> Result<(), LPError> {
+    /// The pair's tokens, larger id first.
+    pub fn get_tokens(&self) -> (ActorId, ActorId) {
+        let state_lp = StateLp::get();
+        state_lp.token
+    }
+
+    pub fn get_pending_admin(&self) -> Option<ActorId> {
+        let state_lp = StateLp::get();
+        state_lp.pending_admin
+    }
+
+    /// Balance of `account` at the end of `block`.
+    pub fn balance_of_at(&self, account: ActorId, block: u32) -> U256 {
+        let state_lp = StateLp::get();
+        state_lp
+            .balance_checkpoints
+            .get(&account)
+            .map(|checkpoints| StateLp::value_at(checkpoints, block))
+            .unwrap_or_default()
+    }
+
+    /// Total supply at the end of `block`.
+    pub fn total_supply_at(&self, block: u32) -> U256 {
+        let state_lp = StateLp::get();
+        StateLp::value_at(&state_lp.supply_checkpoints, block)
+    }
+
+    /// Account holding the voting weight of `account`'s balance, itself
+    /// unless it delegated.
+    pub fn get_delegate(&self, account: ActorId) -> ActorId {
+        let state_lp = StateLp::get();
+        state_lp.delegate_of(account)
+    }
+
+    /// Current voting weight of `account`: its own balance unless
+    /// delegated, plus balances delegated to it.
+    pub fn get_votes(&self, account: ActorId) -> U256 {
+        let state_lp = StateLp::get();
+        state_lp
+            .vote_checkpoints
+            .get(&account)
+            .map(|checkpoints| StateLp::latest(checkpoints))
+            .unwrap_or_default()
+    }
+
+    /// Voting weight of `account` at the end of `block`.
+    pub fn get_votes_at(&self, account: ActorId, block: u32) -> U256 {
+        let state_lp = StateLp::get();
+        state_lp
+            .vote_checkpoints
+            .get(&account)
+            .map(|checkpoints| StateLp::value_at(checkpoints, block))
+            .unwrap_or_default()
+    }
+
+    /// Current nonce of `owner`, which its next permit signature must cover.
+    pub fn nonces(&self, owner: ActorId) -> u64 {
+        permit::nonce_of(owner)
+    }
+
+    /// Sets `spender`'s allowance over `owner`'s LP tokens to `value` from
+    /// `owner`'s signature of the permit payload, so no `approve` message is
+    /// needed. Anyone can submit it.
+    pub fn permit(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> Result<(), LPError> {
+        permit::use_permit(owner, spender, value, deadline, &signature).map_err(|error| {
+            match error {
+                PermitError::Expired => LPError::DeadlineExceeded,
+                PermitError::InvalidSignature => LPError::InvalidSignature,
+            }
+        })?;
+        vft_service::funcs::approve(Storage::allowances(), owner, spender, value);
+        self.emit_event(LPEvent::Permit {
+            owner,
+            spender,
+            value,
+        })
+        .ok();
+        Ok(())
+    }
+
+    /// Same as the VFT `transfer`, also recording balance and voting weight
+    /// checkpoints.
+    pub fn transfer(&mut self, to: ActorId, value: U256) -> bool {
+        let from = msg::source();
+        let transferred = self.vft_service.transfer(to, value);
+        if transferred {
+            self._write_checkpoints(Some(from), Some(to), value);
+        }
+        transferred
+    }
+
+    /// Same as the VFT `transfer_from`, also recording balance and voting
+    /// weight checkpoints.
+    pub fn transfer_from(&mut self, from: ActorId, to: ActorId, value: U256) -> bool {
+        let transferred = self.vft_service.transfer_from(from, to, value);
+        if transferred {
+            self._write_checkpoints(Some(from), Some(to), value);
+        }
+        transferred
+    }
+
+    /// Hands the caller's voting weight, current and future balance, to
+    /// `delegatee`. Delegating to oneself takes it back.
+    pub fn delegate(&mut self, delegatee: ActorId) -> Result<(), LPError> {
+        if delegatee == ActorId::zero() {
+            return Err(LPError::ZeroActorId);
+        }
+        let state_lp = StateLp::get_mut();
+        let delegator = msg::source();
+        let from_delegate = state_lp.delegate_of(delegator);
+        if delegatee == delegator {
+            state_lp.delegates.remove(&delegator);
+        } else {
+            state_lp.delegates.insert(delegator, delegatee);
+        }
+        let balance = self.vft_service.balance_of(delegator);
+        state_lp.move_votes(
+            Some(from_delegate),
+            Some(delegatee),
+            balance,
+            exec::block_height(),
+        );
+        self.emit_event(LPEvent::DelegateChanged {
+            delegator,
+            from_delegate,
+            to_delegate: delegatee,
+        })
+        .ok();
+        Ok(())
+    }
+
+    /// Starts an admin handover that `accept_admin` completes.
+    pub fn propose_admin(&mut self, new_admin: ActorId) -> Result<(), LPError> {
         let state_lp = StateLp::get_mut();
-        if msg::source() != state_lp.admin {
+        if !has_role(Role::DefaultAdmin, msg::source()) {
             return Err(LPError::Unauthorized);
         }
         if new_admin == ActorId::zero() {
             return Err(LPError::InvalidAdmin);
         }
-        state_lp.admin = new_admin;
-        self.emit_event(LPEvent::AdminSet(new_admin)).ok();
+        state_lp.pending_admin = Some(new_admin);
+        self.emit_event(LPEvent::AdminTransferProposed(new_admin)).ok();
+        Ok(())
+    }
+
+    pub fn accept_admin(&mut self) -> Result<(), LPError> {
+        let state_lp = StateLp::get_mut();
+        let caller = msg::source();
+        match state_lp.pending_admin {
+            None => return Err(LPError::NoPendingTransfer),
+            Some(pending) if pending != caller => return Err(LPError::NotPendingAccount),
+            Some(_) => {}
+        }
+        state_lp.pending_admin = None;
+        // the new admin takes over every role of the old one
+        AccessControl::get_mut().transfer_roles(state_lp.admin, caller);
+        state_lp.admin = caller;
+        self.emit_event(LPEvent::AdminSet(caller)).ok();
+        Ok(())
+    }
+
+    pub fn cancel_admin_transfer(&mut self) -> Result<(), LPError> {
+        let state_lp = StateLp::get_mut();
+        if !has_role(Role::DefaultAdmin, msg::source()) {
+            return Err(LPError::Unauthorized);
+        }
+        let pending = state_lp
+            .pending_admin
+            .take()
+            .ok_or(LPError::NoPendingTransfer)?;
+        self.emit_event(LPEvent::AdminTransferCancelled(pending)).ok();
         Ok(())
     }
 
     pub fn set_router(&mut self, new_router: ActorId) -> Result<(), LPError> {
         let state_lp = StateLp::get_mut();
-        if msg::source() != state_lp.admin {
+        if !has_role(Role::DefaultAdmin, msg::source()) {
             return Err(LPError::Unauthorized);
         }
         if new_router == ActorId::zero() {
@@ -115,27 +289,23 @@ impl LPService {
         Ok(())
     }
 
-    pub async fn mint(&mut self, to: ActorId) -> Result<U256, LPError> {
+    /// Mints LP tokens for what was added to the pair since its last
+    /// update. VARA attached to the message counts for a native pair and
+    /// comes back with the reply if minting fails.
+    pub async fn mint(&mut self, to: ActorId) -> CommandReply<Result<U256, LPError>> {
+        Self::_credit_vara(msg::value());
+        let res = self._mint_liquidity(to).await;
+        Self::_refund_on_error(res)
+    }
+
+    async fn _mint_liquidity(&mut self, to: ActorId) -> Result<U256, LPError> {
+        self._check_active().await?;
         let (reserve0, reserve1, _) = self.get_reserves();
         let state_lp = StateLp::get_mut();
 
         let token_pair = state_lp.token.clone();
-        let balance_0_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.0.clone())
-            .await;
-        let Ok(balance_0) = balance_0_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
-        let balance_1_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.1.clone())
-            .await;
-        let Ok(balance_1) = balance_1_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
+        let balance_0 = self._balance_of(token_pair.0).await?;
+        let balance_1 = self._balance_of(token_pair.1).await?;
         let amount_0 = balance_0.checked_sub(reserve0).ok_or(LPError::Overflow)?;
         let amount_1 = balance_1.checked_sub(reserve1).ok_or(LPError::Overflow)?;
 
@@ -183,27 +353,27 @@ impl LPService {
         Ok(liquidity)
     }
 
-    pub async fn burn(&mut self, to: ActorId) -> Result<(U256, U256), LPError> {
+    /// Burns the LP tokens sent to the pair and pays out their share of
+    /// both tokens to `to`. When `to` is the caller, native VARA comes with
+    /// the reply, since a program cannot take it as a plain message.
+    pub async fn burn(&mut self, to: ActorId) -> CommandReply<Result<(U256, U256), LPError>> {
+        let reply_vara = StateLp::get().token.1 == NATIVE_VARA && to == msg::source();
+        let res = self._burn_liquidity(to, reply_vara).await;
+        let value = match res {
+            Ok((_, amount1)) if reply_vara => amount1.as_u128(),
+            _ => 0,
+        };
+        Self::_debit_vara(value);
+        CommandReply::new(res).with_value(value)
+    }
+
+    async fn _burn_liquidity(&mut self, to: ActorId, reply_vara: bool) -> Result<(U256, U256), LPError> {
         let (reserve0, reserve1, _) = self.get_reserves();
         let state_lp = StateLp::get_mut();
 
         let token_pair = state_lp.token.clone();
-        let balance0_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.0.clone())
-            .await;
-        let Ok(balance0) = balance0_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
-        let balance1_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.1.clone())
-            .await;
-        let Ok(balance1) = balance1_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
+        let balance0 = self._balance_of(token_pair.0).await?;
+        let balance1 = self._balance_of(token_pair.1).await?;
         let liquidity = self.vft_service.balance_of(exec::program_id());
 
         let fee_on = self._mint_fee(reserve0, reserve1).await?;
@@ -228,26 +398,18 @@ impl LPService {
         if transfer_a_res.is_err() {
             return Err(LPError::TransferFailed);
         }
-        let transfer_b_res = self._safe_transfer(token_pair.1.clone(), to, amount1).await;
-        if transfer_b_res.is_err() {
-            return Err(LPError::TransferFailed);
+        if !reply_vara {
+            let transfer_b_res = self._safe_transfer(token_pair.1.clone(), to, amount1).await;
+            if transfer_b_res.is_err() {
+                return Err(LPError::TransferFailed);
+            }
+        }
+        let balance0_after = self._balance_of(token_pair.0).await?;
+        let mut balance1_after = self._balance_of(token_pair.1).await?;
+        if reply_vara {
+            // still held until the reply is sent
+            balance1_after = balance1_after.saturating_sub(amount1);
         }
-        let balance0_after_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.0.clone())
-            .await;
-        let Ok(balance0_after) = balance0_after_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
-        let balance1_after_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.1.clone())
-            .await;
-        let Ok(balance1_after) = balance1_after_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
         self._update((balance0_after, balance1_after), (reserve0, reserve1))?;
         if fee_on {
             state_lp.k_last = state_lp
@@ -267,15 +429,30 @@ impl LPService {
         Ok((amount0, amount1))
     }
 
+    /// Sends out `amount0_out`/`amount1_out` against the input added since
+    /// the last update. For a native pair the VARA input can come attached
+    /// to this message, and comes back with the reply if the swap fails.
     pub async fn swap(
         &mut self,
         amount0_out: U256,
         amount1_out: U256,
         to: ActorId,
+    ) -> CommandReply<Result<(), LPError>> {
+        Self::_credit_vara(msg::value());
+        let res = self._swap(amount0_out, amount1_out, to).await;
+        Self::_refund_on_error(res)
+    }
+
+    async fn _swap(
+        &mut self,
+        amount0_out: U256,
+        amount1_out: U256,
+        to: ActorId,
     ) -> Result<(), LPError> {
         if amount0_out == U256::zero() && amount1_out == U256::zero() {
             return Err(LPError::InsufficientOutputAmount);
         }
+        self._check_active().await?;
 
         let (reserve0, reserve1, _) = self.get_reserves();
         if amount0_out >= reserve0 || amount1_out >= reserve1 {
@@ -300,22 +477,8 @@ impl LPService {
                 return Err(LPError::TransferFailed);
             }
         }
-        let balance0_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.0.clone())
-            .await;
-        let Ok(balance0) = balance0_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
-        let balance1_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.1.clone())
-            .await;
-        let Ok(balance1) = balance1_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
+        let balance0 = self._balance_of(token_pair.0).await?;
+        let balance1 = self._balance_of(token_pair.1).await?;
         let remain_0 = reserve0.checked_sub(amount0_out).ok_or(LPError::Overflow)?;
         let amount0_in = if balance0 > remain_0 {
             balance0.checked_sub(remain_0).ok_or(LPError::Overflow)?
@@ -376,83 +539,87 @@ impl LPService {
         let (reserve0, reserve1, _) = self.get_reserves();
         let state_lp = StateLp::get_mut();
 
-        if msg::source() != state_lp.admin && msg::source() != state_lp.router {
+        if !has_role(Role::Treasurer, msg::source()) && msg::source() != state_lp.router {
             return Err(LPError::Unauthorized);
         }
+        self._check_active().await?;
 
         let token_pair = state_lp.token.clone();
-        let balance0_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.0.clone())
-            .await;
-        let Ok(balance0) = balance0_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
-        let balance1_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.1.clone())
-            .await;
-        let Ok(balance1) = balance1_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
-        let transfer_a_res = self
-            ._safe_transfer(
-                token_pair.0.clone(),
-                to,
-                balance0.checked_sub(reserve0).ok_or(LPError::Overflow)?,
-            )
-            .await;
-        if transfer_a_res.is_err() {
+        let balance0 = self._balance_of(token_pair.0).await?;
+        let balance1 = self._balance_of(token_pair.1).await?;
+        let excess0 = balance0.checked_sub(reserve0).ok_or(LPError::Overflow)?;
+        let excess1 = balance1.checked_sub(reserve1).ok_or(LPError::Overflow)?;
+        // a side without excess has nothing to send
+        if !excess0.is_zero() && self._safe_transfer(token_pair.0, to, excess0).await.is_err() {
             return Err(LPError::TransferFailed);
         }
-        let transfer_b_res = self
-            ._safe_transfer(
-                token_pair.1.clone(),
-                to,
-                balance1.checked_sub(reserve1).ok_or(LPError::Overflow)?,
-            )
-            .await;
-        if transfer_b_res.is_err() {
+        if !excess1.is_zero() && self._safe_transfer(token_pair.1, to, excess1).await.is_err() {
             return Err(LPError::TransferFailed);
         }
 
         self.emit_event(LPEvent::Skim {
-            amount_a: (balance0 - reserve0),
-            amount_b: (balance1 - reserve1),
+            amount_a: excess0,
+            amount_b: excess1,
             to,
         })
         .ok();
         Ok(())
     }
 
+    /// Sends `to` what the pair holds of `token` beyond its reserve and
+    /// returns the amount. Only the router may call it, to give back the
+    /// input of a swap it could not finish; unlike `skim` it works while
+    /// the pair is paused or blocked.
+    pub async fn return_excess(&mut self, token: ActorId, to: ActorId) -> Result<U256, LPError> {
+        let (reserve0, reserve1, _) = self.get_reserves();
+        let state_lp = StateLp::get_mut();
+
+        if msg::source() != state_lp.router {
+            return Err(LPError::Unauthorized);
+        }
+        let reserve = if token == state_lp.token.0 {
+            reserve0
+        } else if token == state_lp.token.1 {
+            reserve1
+        } else {
+            return Err(LPError::InvalidTokens);
+        };
+        let excess = self
+            ._balance_of(token)
+            .await?
+            .checked_sub(reserve)
+            .ok_or(LPError::Overflow)?;
+        if excess.is_zero() {
+            return Ok(excess);
+        }
+        self._safe_transfer(token, to, excess).await?;
+
+        let (amount_a, amount_b) = if token == state_lp.token.0 {
+            (excess, U256::zero())
+        } else {
+            (U256::zero(), excess)
+        };
+        self.emit_event(LPEvent::Skim {
+            amount_a,
+            amount_b,
+            to,
+        })
+        .ok();
+        Ok(excess)
+    }
+
     pub async fn sync(&mut self) -> Result<(), LPError> {
         let (reserve0, reserve1, _) = self.get_reserves();
         let state_lp = StateLp::get_mut();
 
-        if msg::source() != state_lp.admin && msg::source() != state_lp.router {
+        if !has_role(Role::DefaultAdmin, msg::source()) && msg::source() != state_lp.router {
             return Err(LPError::Unauthorized);
         }
+        self._check_active().await?;
 
         let token_pair = state_lp.token.clone();
-        let balance0_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.0.clone())
-            .await;
-        let Ok(balance0) = balance0_res else {
-            return Err(LPError::CanNotConnectToVft);
-        };
-        let balance1_res = self
-            .vft_client
-            .balance_of(exec::program_id())
-            .recv(token_pair.1.clone())
-            .await;
-        let Ok(balance1) = balance1_res else {
-            // state_lp.lock = false;
-            return Err(LPError::CanNotConnectToVft);
-        };
+        let balance0 = self._balance_of(token_pair.0).await?;
+        let balance1 = self._balance_of(token_pair.1).await?;
         self._update((balance0, balance1), (reserve0, reserve1))?;
         // state_lp.lock = false
         self.emit_event(LPEvent::Sync {
@@ -463,6 +630,33 @@ impl LPService {
         Ok(())
     }
 
+    /// Asks the factory whether the pair is paused or blocked, at most once
+    /// per block while it is active. `burn` skips this so LPs can always
+    /// withdraw.
+    async fn _check_active(&self) -> Result<(), LPError> {
+        let state_lp = StateLp::get_mut();
+        let block = exec::block_height();
+        if state_lp.active_checked_at == Some(block) {
+            return Ok(());
+        }
+        let status_res = self
+            .factory_client
+            .get_pair_trading_status(exec::program_id(), state_lp.token.0, state_lp.token.1)
+            .recv(state_lp.factory)
+            .await;
+        let Ok(status) = status_res else {
+            return Err(LPError::CanNotConnectToFactory);
+        };
+        match status {
+            PairTradingStatus::Active => {
+                state_lp.active_checked_at = Some(block);
+                Ok(())
+            }
+            PairTradingStatus::Paused => Err(LPError::Paused),
+            PairTradingStatus::Blocked => Err(LPError::TokenBlocked),
+        }
+    }
+
     async fn _mint_fee(&mut self, reserve_0: U256, reserve_1: U256) -> Result<bool, LPError> {
         let state_lp = StateLp::get_mut();
 
@@ -514,6 +708,13 @@ impl LPService {
         if value.is_zero() {
             return Err(LPError::InvalidAmount);
         }
+        if token == NATIVE_VARA {
+            let value = u128::try_from(value).map_err(|_| LPError::Overflow)?;
+            msg::send_bytes(to, "Transfer Vara".encode(), value)
+                .map_err(|_| LPError::TransferFailed)?;
+            Self::_debit_vara(value);
+            return Ok(());
+        }
 
         let transfer_res = self
             .vft_client
@@ -531,6 +732,43 @@ impl LPService {
         }
     }
 
+    /// Balance of the pair in `token`; for native VARA, the value it has
+    /// taken in through `mint` and `swap` and not yet paid out.
+    async fn _balance_of(&self, token: ActorId) -> Result<U256, LPError> {
+        if token == NATIVE_VARA {
+            return Ok(StateLp::get().vara_balance);
+        }
+        self.vft_client
+            .balance_of(exec::program_id())
+            .recv(token)
+            .await
+            .map_err(|_| LPError::CanNotConnectToVft)
+    }
+
+    /// Replies with `res`, returning the VARA attached to the message when
+    /// it is an error.
+    fn _refund_on_error<T>(res: Result<T, LPError>) -> CommandReply<Result<T, LPError>> {
+        let value = if res.is_err() { msg::value() } else { 0 };
+        Self::_debit_vara(value);
+        CommandReply::new(res).with_value(value)
+    }
+
+    /// Counts `value` received by a native pair towards its VARA side.
+    fn _credit_vara(value: u128) {
+        let state_lp = StateLp::get_mut();
+        if state_lp.token.1 == NATIVE_VARA {
+            state_lp.vara_balance = state_lp.vara_balance.saturating_add(value.into());
+        }
+    }
+
+    /// Takes `value` leaving a native pair off its VARA side.
+    fn _debit_vara(value: u128) {
+        let state_lp = StateLp::get_mut();
+        if state_lp.token.1 == NATIVE_VARA {
+            state_lp.vara_balance = state_lp.vara_balance.saturating_sub(value.into());
+        }
+    }
+
     fn _mint(&mut self, to: ActorId, liquidity: U256) -> Result<(), LPError> {
         if liquidity <= U256::zero() {
             return Err(LPError::InsufficientLiquidityMinted);
@@ -547,6 +785,7 @@ impl LPService {
         *total_supply = total_supply
             .checked_add(liquidity)
             .ok_or(LPError::Overflow)?;
+        self._write_checkpoints(None, Some(to), liquidity);
 
         self.emit_event(LPEvent::LPMint {
             to,
@@ -585,6 +824,7 @@ impl LPService {
         *total_supply = total_supply
             .checked_sub(liquidity)
             .ok_or(LPError::Overflow)?;
+        self._write_checkpoints(Some(from), None, liquidity);
         self.emit_event(LPEvent::LPBurn {
             from: from,
             amount: liquidity,
@@ -593,6 +833,25 @@ impl LPService {
         Ok(())
     }
 
+    /// Records balances, total supply and voting weight after `amount`
+    /// moved from `from` to `to`; `None` stands for a mint or a burn.
+    fn _write_checkpoints(&mut self, from: Option<ActorId>, to: Option<ActorId>, amount: U256) {
+        let state_lp = StateLp::get_mut();
+        let block = exec::block_height();
+        for account in [from, to].into_iter().flatten() {
+            let balance = self.vft_service.balance_of(account);
+            let checkpoints = state_lp.balance_checkpoints.entry(account).or_default();
+            StateLp::write_checkpoint(checkpoints, balance, block);
+        }
+        if from.is_none() || to.is_none() {
+            let total_supply = self.vft_service.total_supply();
+            StateLp::write_checkpoint(&mut state_lp.supply_checkpoints, total_supply, block);
+        }
+        let from_delegate = from.map(|account| state_lp.delegate_of(account));
+        let to_delegate = to.map(|account| state_lp.delegate_of(account));
+        state_lp.move_votes(from_delegate, to_delegate, amount, block);
+    }
+
     fn _update(&mut self, balance: (U256, U256), reverse: (U256, U256)) -> Result<(), LPError> {
         let state_lp = StateLp::get_mut();
         let block_timestamp = exec::block_timestamp() % 2u64.pow(32);
@@ -625,3 +884,9 @@ impl AsRef<VftService> for LPService {
         &self.vft_service
     }
 }
+
+impl AsRef<AccessControlService> for LPService {
+    fn as_ref(&self) -> &AccessControlService {
+        &self.access_control
+    }
+}
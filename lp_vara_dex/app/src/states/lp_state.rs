@@ -1,12 +1,19 @@
-use sails_rs::prelude::*;
+use sails_rs::{collections::HashMap, prelude::*};
 
 pub static mut LP: Option<StateLp> = None;
 
 pub const MINIMUM_LIQUIDITY: u128 = 10u128.pow(3);
 
+/// Token id standing for native VARA. A pair created with it holds that
+/// side as program value instead of a wVARA balance; being zero, it is
+/// always the pair's second token.
+pub const NATIVE_VARA: ActorId = ActorId::zero();
+
 #[derive(Debug, Default)]
 pub struct StateLp {
     pub admin: ActorId,
+    /// Proposed `admin`, set until it accepts or the proposal is cancelled.
+    pub pending_admin: Option<ActorId>,
     pub router: ActorId,
     pub name:String,
     pub symbol:String,
@@ -17,10 +24,34 @@ pub struct StateLp {
     pub cumulative_price: (U256, U256),
     pub last_block_ts: u64,
     pub k_last: U256,
+    /// VARA the pair holds on its native side. Kept apart from the program
+    /// balance so the existential deposit and stray value never reach the
+    /// reserves.
+    pub vara_balance: U256,
+    /// Block in which the factory last reported the pair active; later
+    /// calls in the same block skip the factory query.
+    pub active_checked_at: Option<u32>,
+    /// Balance history of each account, by increasing block.
+    pub balance_checkpoints: HashMap<ActorId, Vec<Checkpoint>>,
+    pub supply_checkpoints: Vec<Checkpoint>,
+    /// Accounts that handed their voting weight to another; the rest vote
+    /// with their own balance.
+    pub delegates: HashMap<ActorId, ActorId>,
+    /// Voting weight history of each account, by increasing block.
+    pub vote_checkpoints: HashMap<ActorId, Vec<Checkpoint>>,
     // pub lock: bool
     
 }
 
+/// Value of a balance, the total supply or a voting weight from `block` on.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct Checkpoint {
+    pub block: u32,
+    pub value: U256,
+}
+
 impl StateLp {
     pub fn get_mut() -> &'static mut Self {
         unsafe { LP.as_mut().expect("State Lp Error") }
@@ -28,6 +59,60 @@ impl StateLp {
     pub fn get() -> &'static Self {
         unsafe { LP.as_ref().expect("State Lp Error") }
     }
+
+    pub fn delegate_of(&self, account: ActorId) -> ActorId {
+        self.delegates.get(&account).copied().unwrap_or(account)
+    }
+
+    /// Moves `amount` of voting weight between delegates; `None` stands
+    /// for a mint or a burn.
+    pub fn move_votes(
+        &mut self,
+        from: Option<ActorId>,
+        to: Option<ActorId>,
+        amount: U256,
+        block: u32,
+    ) {
+        if from == to || amount.is_zero() {
+            return;
+        }
+        if let Some(from) = from {
+            let checkpoints = self.vote_checkpoints.entry(from).or_default();
+            let votes = Self::latest(checkpoints).saturating_sub(amount);
+            Self::write_checkpoint(checkpoints, votes, block);
+        }
+        if let Some(to) = to {
+            let checkpoints = self.vote_checkpoints.entry(to).or_default();
+            let votes = Self::latest(checkpoints).saturating_add(amount);
+            Self::write_checkpoint(checkpoints, votes, block);
+        }
+    }
+
+    pub fn latest(checkpoints: &[Checkpoint]) -> U256 {
+        checkpoints
+            .last()
+            .map(|checkpoint| checkpoint.value)
+            .unwrap_or_default()
+    }
+
+    /// Value at the end of `block`, from the last checkpoint at or before it.
+    pub fn value_at(checkpoints: &[Checkpoint], block: u32) -> U256 {
+        let index = checkpoints.partition_point(|checkpoint| checkpoint.block <= block);
+        if index == 0 {
+            U256::zero()
+        } else {
+            checkpoints[index - 1].value
+        }
+    }
+
+    /// Records `value` for `block`, replacing an earlier write in the same
+    /// block.
+    pub fn write_checkpoint(checkpoints: &mut Vec<Checkpoint>, value: U256, block: u32) {
+        match checkpoints.last_mut() {
+            Some(last) if last.block == block => last.value = value,
+            _ => checkpoints.push(Checkpoint { block, value }),
+        }
+    }
 }
 #[derive(Encode, Decode, TypeInfo, Debug)]
 #[codec(crate = gstd::codec)]
@@ -83,8 +168,22 @@ pub enum LPEvent {
         /// A recipient of skimmed tokens.
         to: ActorId,
     },
+    /// Emitted by `accept_admin`.
     AdminSet(ActorId),
+    AdminTransferProposed(ActorId),
+    AdminTransferCancelled(ActorId),
     RouterSet(ActorId),
+    DelegateChanged {
+        delegator: ActorId,
+        from_delegate: ActorId,
+        to_delegate: ActorId,
+    },
+    /// Emitted by `permit` instead of the VFT `Approval`.
+    Permit {
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+    },
    
 }
 #[derive(Encode, Decode, TypeInfo, Debug)]
@@ -133,5 +232,16 @@ pub enum LPError {
     InvalidReserves,
     InvalidAmount,
     InvalidAdmin,
+    /// The factory has blocked one of the pair's tokens.
+    TokenBlocked,
+    /// The factory has paused the pair, or all pairs.
+    Paused,
+    /// There is no proposed admin to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin.
+    NotPendingAccount,
+    /// The permit signature is not the owner's over the permit payload
+    /// with its current nonce.
+    InvalidSignature,
 }
 
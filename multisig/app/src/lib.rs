@@ -0,0 +1,22 @@
+#![no_std]
+pub mod services;
+pub mod states;
+
+use sails_rs::prelude::*;
+use services::multisig_service::MultisigService;
+
+#[derive(Default)]
+pub struct MultisigProgram;
+
+#[program]
+impl MultisigProgram {
+    /// Panics if `threshold` is zero or above the number of distinct signers.
+    pub fn new(signers: Vec<ActorId>, threshold: u32) -> Self {
+        MultisigService::seed(signers, threshold);
+        Self
+    }
+    #[route("MultisigService")]
+    pub fn multisig_service(&self) -> MultisigService {
+        MultisigService::new()
+    }
+}
@@ -0,0 +1,243 @@
+use gstd::{exec, msg};
+use sails_rs::{collections::HashMap, prelude::*};
+
+use crate::states::multisig_state::{
+    MultisigError, MultisigEvent, StateMultisig, Transaction, TransactionStatus, MULTISIG,
+};
+
+#[derive(Default)]
+pub struct MultisigService(());
+
+impl MultisigService {
+    pub fn seed(signers: Vec<ActorId>, threshold: u32) {
+        let mut state = StateMultisig {
+            signers: Vec::new(),
+            threshold,
+            transactions: HashMap::new(),
+            next_nonce: 0,
+        };
+        for signer in signers {
+            if !state.is_signer(&signer) {
+                state.signers.push(signer);
+            }
+        }
+        assert!(state.is_valid_threshold(threshold), "Invalid threshold");
+        unsafe {
+            MULTISIG = Some(state);
+        }
+    }
+
+    /// Pending, unexpired transaction `nonce`, for a signer to act on.
+    fn pending_transaction(nonce: u64) -> Result<&'static mut Transaction, MultisigError> {
+        let state = StateMultisig::get_mut();
+        if !state.is_signer(&msg::source()) {
+            return Err(MultisigError::NotSigner);
+        }
+        let transaction = state
+            .transactions
+            .get_mut(&nonce)
+            .ok_or(MultisigError::TransactionNotFound)?;
+        if transaction.status != TransactionStatus::Pending {
+            return Err(MultisigError::AlreadyExecuted);
+        }
+        if transaction.is_expired(exec::block_timestamp()) {
+            return Err(MultisigError::Expired);
+        }
+        Ok(transaction)
+    }
+
+    fn check_self() -> Result<(), MultisigError> {
+        if msg::source() != exec::program_id() {
+            return Err(MultisigError::Unauthorized);
+        }
+        Ok(())
+    }
+}
+
+#[service(events = MultisigEvent)]
+impl MultisigService {
+    pub fn new() -> Self {
+        Self(())
+    }
+
+    /// Submits a call of `target` with the encoded Sails message `payload`
+    /// and returns its nonce. The submitter's confirmation is included.
+    pub fn submit_transaction(
+        &mut self,
+        target: ActorId,
+        payload: Vec<u8>,
+        value: u128,
+        expires_at: Option<u64>,
+    ) -> Result<u64, MultisigError> {
+        let state = StateMultisig::get_mut();
+        let submitter = msg::source();
+        if !state.is_signer(&submitter) {
+            return Err(MultisigError::NotSigner);
+        }
+        if expires_at.is_some_and(|expires_at| expires_at < exec::block_timestamp()) {
+            return Err(MultisigError::Expired);
+        }
+        let nonce = state.next_nonce;
+        state.next_nonce += 1;
+        state.transactions.insert(
+            nonce,
+            Transaction {
+                nonce,
+                target,
+                payload,
+                value,
+                submitter,
+                confirmations: vec![submitter],
+                expires_at,
+                status: TransactionStatus::Pending,
+            },
+        );
+        self.emit_event(MultisigEvent::Submitted {
+            nonce,
+            submitter,
+            target,
+            value,
+        })
+        .ok();
+        self.emit_event(MultisigEvent::Confirmed {
+            nonce,
+            signer: submitter,
+        })
+        .ok();
+        Ok(nonce)
+    }
+
+    pub fn confirm_transaction(&mut self, nonce: u64) -> Result<(), MultisigError> {
+        let transaction = Self::pending_transaction(nonce)?;
+        let signer = msg::source();
+        if transaction.confirmations.contains(&signer) {
+            return Err(MultisigError::AlreadyConfirmed);
+        }
+        transaction.confirmations.push(signer);
+        self.emit_event(MultisigEvent::Confirmed { nonce, signer }).ok();
+        Ok(())
+    }
+
+    pub fn revoke_confirmation(&mut self, nonce: u64) -> Result<(), MultisigError> {
+        let transaction = Self::pending_transaction(nonce)?;
+        let signer = msg::source();
+        let Some(index) = transaction.confirmations.iter().position(|s| *s == signer) else {
+            return Err(MultisigError::NotConfirmed);
+        };
+        transaction.confirmations.remove(index);
+        self.emit_event(MultisigEvent::Revoked { nonce, signer }).ok();
+        Ok(())
+    }
+
+    /// Sends the call once `threshold` current signers have confirmed and
+    /// returns the target's raw reply. Any signer can execute.
+    pub async fn execute_transaction(&mut self, nonce: u64) -> Result<Vec<u8>, MultisigError> {
+        let transaction = Self::pending_transaction(nonce)?;
+        let state = StateMultisig::get();
+        if state.confirmation_count(transaction) < state.threshold {
+            return Err(MultisigError::NotEnoughConfirmations);
+        }
+        if exec::value_available() < transaction.value {
+            return Err(MultisigError::InsufficientBalance);
+        }
+        // marked before the call so it cannot be executed twice meanwhile
+        transaction.status = TransactionStatus::Executed;
+        let (target, value) = (transaction.target, transaction.value);
+        let payload = transaction.payload.clone();
+
+        let reply = match msg::send_bytes_for_reply(target, payload, value, 0) {
+            Ok(future) => future.await.ok(),
+            Err(_) => None,
+        };
+        let Some(reply) = reply else {
+            if let Some(transaction) = StateMultisig::get_mut().transactions.get_mut(&nonce) {
+                transaction.status = TransactionStatus::Pending;
+            }
+            return Err(MultisigError::ExecutionFailed);
+        };
+        self.emit_event(MultisigEvent::Executed {
+            nonce,
+            reply: reply.clone(),
+        })
+        .ok();
+        Ok(reply)
+    }
+
+    /// Only callable by the multisig itself, through a transaction.
+    pub fn add_signer(&mut self, signer: ActorId) -> Result<(), MultisigError> {
+        Self::check_self()?;
+        let state = StateMultisig::get_mut();
+        if state.is_signer(&signer) {
+            return Err(MultisigError::AlreadySigner);
+        }
+        state.signers.push(signer);
+        self.emit_event(MultisigEvent::SignerAdded(signer)).ok();
+        Ok(())
+    }
+
+    /// Only callable by the multisig itself. Confirmations of a removed
+    /// signer stop counting.
+    pub fn remove_signer(&mut self, signer: ActorId) -> Result<(), MultisigError> {
+        Self::check_self()?;
+        let state = StateMultisig::get_mut();
+        let Some(index) = state.signers.iter().position(|s| *s == signer) else {
+            return Err(MultisigError::NotSigner);
+        };
+        if state.signers.len() <= state.threshold as usize {
+            return Err(MultisigError::InvalidThreshold);
+        }
+        state.signers.remove(index);
+        self.emit_event(MultisigEvent::SignerRemoved(signer)).ok();
+        Ok(())
+    }
+
+    /// Only callable by the multisig itself.
+    pub fn change_threshold(&mut self, threshold: u32) -> Result<(), MultisigError> {
+        Self::check_self()?;
+        let state = StateMultisig::get_mut();
+        if !state.is_valid_threshold(threshold) {
+            return Err(MultisigError::InvalidThreshold);
+        }
+        state.threshold = threshold;
+        self.emit_event(MultisigEvent::ThresholdChanged(threshold)).ok();
+        Ok(())
+    }
+
+    //view function
+
+    pub fn get_signers(&self) -> Vec<ActorId> {
+        let state = StateMultisig::get();
+        state.signers.clone()
+    }
+
+    pub fn get_threshold(&self) -> u32 {
+        let state = StateMultisig::get();
+        state.threshold
+    }
+
+    /// Nonce the next submitted transaction gets.
+    pub fn get_next_nonce(&self) -> u64 {
+        let state = StateMultisig::get();
+        state.next_nonce
+    }
+
+    pub fn get_transaction(&self, nonce: u64) -> Option<Transaction> {
+        let state = StateMultisig::get();
+        state.transactions.get(&nonce).cloned()
+    }
+
+    /// Unexpired transactions waiting for confirmations or execution, by
+    /// nonce.
+    pub fn get_pending_transactions(&self) -> Vec<Transaction> {
+        let state = StateMultisig::get();
+        let now = exec::block_timestamp();
+        let mut pending: Vec<Transaction> = state
+            .transactions
+            .values()
+            .filter(|tx| tx.status == TransactionStatus::Pending && !tx.is_expired(now))
+            .cloned()
+            .collect();
+        pending.sort_by_key(|tx| tx.nonce);
+        pending
+    }
+}
@@ -0,0 +1,124 @@
+use sails_rs::{collections::HashMap, prelude::*};
+
+pub static mut MULTISIG: Option<StateMultisig> = None;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum TransactionStatus {
+    Pending,
+    /// Set before the call is sent and kept once it succeeds; a failed call
+    /// puts the transaction back to `Pending`.
+    Executed,
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct Transaction {
+    /// Nonce of the transaction, also its id.
+    pub nonce: u64,
+    pub target: ActorId,
+    /// Full Sails message: encoded service route, method route and arguments.
+    pub payload: Vec<u8>,
+    /// Native VARA sent from the multisig's balance with the call.
+    pub value: u128,
+    pub submitter: ActorId,
+    /// Signers that confirmed, in confirmation order.
+    pub confirmations: Vec<ActorId>,
+    /// Block timestamp after which the transaction can no longer be
+    /// confirmed or executed.
+    pub expires_at: Option<u64>,
+    pub status: TransactionStatus,
+}
+
+impl Transaction {
+    pub fn is_expired(&self, timestamp: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| timestamp > expires_at)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StateMultisig {
+    pub signers: Vec<ActorId>,
+    /// Confirmations a transaction needs before it can be executed.
+    pub threshold: u32,
+    pub transactions: HashMap<u64, Transaction>,
+    pub next_nonce: u64,
+}
+
+impl StateMultisig {
+    pub fn get_mut() -> &'static mut Self {
+        unsafe { MULTISIG.as_mut().expect("State Multisig Error") }
+    }
+    pub fn get() -> &'static Self {
+        unsafe { MULTISIG.as_ref().expect("State Multisig Error") }
+    }
+
+    pub fn is_signer(&self, account: &ActorId) -> bool {
+        self.signers.contains(account)
+    }
+
+    /// Confirmations of `transaction` by accounts that are still signers.
+    pub fn confirmation_count(&self, transaction: &Transaction) -> u32 {
+        transaction
+            .confirmations
+            .iter()
+            .filter(|signer| self.is_signer(signer))
+            .count() as u32
+    }
+
+    pub fn is_valid_threshold(&self, threshold: u32) -> bool {
+        threshold > 0 && threshold as usize <= self.signers.len()
+    }
+}
+
+#[derive(Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum MultisigEvent {
+    Submitted {
+        nonce: u64,
+        submitter: ActorId,
+        target: ActorId,
+        value: u128,
+    },
+    Confirmed {
+        nonce: u64,
+        signer: ActorId,
+    },
+    Revoked {
+        nonce: u64,
+        signer: ActorId,
+    },
+    Executed {
+        nonce: u64,
+        /// Raw reply of the target, including its Sails routes.
+        reply: Vec<u8>,
+    },
+    SignerAdded(ActorId),
+    SignerRemoved(ActorId),
+    ThresholdChanged(u32),
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum MultisigError {
+    NotSigner,
+    /// Signer management is only reachable through an executed transaction
+    /// of the multisig itself.
+    Unauthorized,
+    TransactionNotFound,
+    AlreadyExecuted,
+    Expired,
+    AlreadyConfirmed,
+    NotConfirmed,
+    NotEnoughConfirmations,
+    /// The multisig holds less than the transaction's `value`.
+    InsufficientBalance,
+    /// The target could not be reached or failed to handle the call.
+    ExecutionFailed,
+    AlreadySigner,
+    InvalidThreshold,
+}
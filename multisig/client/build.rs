@@ -0,0 +1,16 @@
+use sails_client_gen::ClientGenerator;
+use std::{env, path::PathBuf};
+
+fn main() {
+    let out_dir_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let idl_path = out_dir_path.join("multisig.idl");
+    let client_path = out_dir_path.join("multisig_client.rs");
+
+    sails_idl_gen::generate_idl_to_file::<multisig::MultisigProgram>(&idl_path)
+        .unwrap();
+
+    ClientGenerator::from_idl_path(&idl_path)
+        .with_mocks("mocks")
+        .generate_to(&client_path)
+        .unwrap();
+}
@@ -0,0 +1,4 @@
+#![no_std]
+
+// Incorporate the generated code based on the idl file
+include!(concat!(env!("OUT_DIR"), "/multisig_client.rs"));
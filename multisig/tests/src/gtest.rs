@@ -0,0 +1,267 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf};
+    use sails_rs::{
+        calls::{Activation, ActionIo, Call, Query},
+        gtest::{calls::*, System},
+        prelude::*,
+    };
+
+    use client::{
+        traits::{MultisigFactory, MultisigService},
+        MultisigError,
+        MultisigFactory as Factory,
+        MultisigService as MultisigClient,
+        TransactionStatus,
+    };
+    use factory_vara_dex_client::{
+        factory_service::io as factory_io,
+        traits::{FactoryService, FactoryVaraDexFactory},
+        FactoryService as FactoryClient,
+        FactoryVaraDexFactory as DexFactory,
+    };
+
+    const ADMIN_ID: u64 = 10;
+    const SIGNER_A: u64 = 11;
+    const SIGNER_B: u64 = 12;
+    const SIGNER_C: u64 = 13;
+    const USER_ID: u64 = 14;
+    const FEE_TO_ID: u64 = 20;
+
+    fn multisig_wasm() -> PathBuf {
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+            .parent()
+            .unwrap()
+            .join("target")
+            .join("wasm32-gear")
+            .join("release")
+            .join("application_builder.opt.wasm")
+    }
+
+    /// Deploys a 2-of-3 multisig and a factory whose admin and
+    /// `fee_to_setter` is the multisig.
+    async fn init() -> (GTestRemoting, ActorId, ActorId) {
+        let system = System::new();
+        system.init_logger();
+        for id in [ADMIN_ID, SIGNER_A, SIGNER_B, SIGNER_C, USER_ID] {
+            system.mint_to(id, 1_000_000_000_000_000);
+        }
+        let remoting = GTestRemoting::new(system, ADMIN_ID.into());
+        let multisig_code_id = remoting.system().submit_code_file(multisig_wasm());
+        let factory_code_id = remoting
+            .system()
+            .submit_code(factory_vara_dex_wasm::WASM_BINARY_OPT);
+
+        let multisig_id = Factory::new(remoting.clone())
+            .new(vec![SIGNER_A.into(), SIGNER_B.into(), SIGNER_C.into()], 2)
+            .send_recv(multisig_code_id, "multisig")
+            .await
+            .unwrap();
+        let factory_id = DexFactory::new(remoting.clone())
+            .new(CodeId::zero(), ADMIN_ID.into(), multisig_id, multisig_id)
+            .send_recv(factory_code_id, "factory")
+            .await
+            .unwrap();
+
+        (remoting, multisig_id, factory_id)
+    }
+
+    fn signer(remoting: &GTestRemoting, id: u64) -> MultisigClient<GTestRemoting> {
+        MultisigClient::new(remoting.clone().with_actor_id(id.into()))
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_to_through_multisig() {
+        let (remoting, multisig_id, factory_id) = init().await;
+        let mut signer_a = signer(&remoting, SIGNER_A);
+        let mut signer_b = signer(&remoting, SIGNER_B);
+        let mut user = signer(&remoting, USER_ID);
+        let mut factory = FactoryClient::new(remoting.clone());
+
+        // the factory only takes `set_fee_to` from the multisig
+        let res = factory
+            .set_fee_to(FEE_TO_ID.into())
+            .send_recv(factory_id)
+            .await
+            .unwrap();
+        assert!(res.is_err());
+
+        let payload = factory_io::SetFeeTo::encode_call(FEE_TO_ID.into());
+        let res = user
+            .submit_transaction(factory_id, payload.clone(), 0, None)
+            .send_recv(multisig_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(MultisigError::NotSigner)));
+        let nonce = signer_a
+            .submit_transaction(factory_id, payload, 0, None)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(nonce, 0);
+        assert_eq!(signer_a.get_next_nonce().recv(multisig_id).await.unwrap(), 1);
+
+        // one confirmation of two
+        let res = signer_a
+            .execute_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(MultisigError::NotEnoughConfirmations)));
+        let res = signer_a
+            .confirm_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(MultisigError::AlreadyConfirmed)));
+
+        // a revoked confirmation no longer counts
+        signer_b
+            .confirm_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        signer_b
+            .revoke_confirmation(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = signer_b
+            .execute_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(MultisigError::NotEnoughConfirmations)));
+        signer_b
+            .confirm_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let pending = signer_a.get_pending_transactions().recv(multisig_id).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].confirmations, vec![SIGNER_A.into(), SIGNER_B.into()]);
+
+        let reply = signer_b
+            .execute_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        // `set_fee_to` queued a timelocked operation
+        let operation_id = factory_io::SetFeeTo::decode_reply(reply).unwrap().unwrap();
+        factory
+            .execute_operation(operation_id)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let fee_to = factory.get_fee_to().recv(factory_id).await.unwrap();
+        assert_eq!(fee_to, FEE_TO_ID.into());
+
+        let transaction = signer_a.get_transaction(nonce).recv(multisig_id).await.unwrap().unwrap();
+        assert_eq!(transaction.status, TransactionStatus::Executed);
+        assert!(signer_a.get_pending_transactions().recv(multisig_id).await.unwrap().is_empty());
+        let res = signer_a
+            .execute_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(MultisigError::AlreadyExecuted)));
+    }
+
+    #[tokio::test]
+    async fn test_expired_transaction() {
+        let (remoting, multisig_id, factory_id) = init().await;
+        let mut signer_a = signer(&remoting, SIGNER_A);
+        let mut signer_b = signer(&remoting, SIGNER_B);
+
+        let expires_at = remoting.system().block_timestamp() + 5 * 3000;
+        let payload = factory_io::SetFeeTo::encode_call(FEE_TO_ID.into());
+        let nonce = signer_a
+            .submit_transaction(factory_id, payload, 0, Some(expires_at))
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let system = remoting.system();
+        system.run_to_block(system.block_height() + 10);
+        let res = signer_b
+            .confirm_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(MultisigError::Expired)));
+        assert!(signer_a.get_pending_transactions().recv(multisig_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_signer_management() {
+        let (remoting, multisig_id, _) = init().await;
+        let mut signer_a = signer(&remoting, SIGNER_A);
+        let mut signer_c = signer(&remoting, SIGNER_C);
+
+        // only reachable through the multisig itself
+        let res = signer_a
+            .change_threshold(3)
+            .send_recv(multisig_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(MultisigError::Unauthorized)));
+
+        let payload = client::multisig_service::io::AddSigner::encode_call(USER_ID.into());
+        let nonce = signer_a
+            .submit_transaction(multisig_id, payload, 0, None)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        signer_c
+            .confirm_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        signer_c
+            .execute_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let signers = signer_a.get_signers().recv(multisig_id).await.unwrap();
+        assert_eq!(
+            signers,
+            vec![SIGNER_A.into(), SIGNER_B.into(), SIGNER_C.into(), USER_ID.into()]
+        );
+
+        let payload = client::multisig_service::io::ChangeThreshold::encode_call(5);
+        let nonce = signer_a
+            .submit_transaction(multisig_id, payload, 0, None)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        signer_c
+            .confirm_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        // an error returned by the target still executes the transaction;
+        // the reply carries it
+        let reply = signer_c
+            .execute_transaction(nonce)
+            .send_recv(multisig_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = client::multisig_service::io::ChangeThreshold::decode_reply(reply).unwrap();
+        assert!(matches!(res, Err(MultisigError::InvalidThreshold)));
+        assert_eq!(signer_a.get_threshold().recv(multisig_id).await.unwrap(), 2);
+    }
+}
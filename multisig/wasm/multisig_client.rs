@@ -0,0 +1,520 @@
+// Code generated by sails-client-gen. DO NOT EDIT.
+#[allow(unused_imports)]
+use sails_rs::collections::BTreeMap;
+#[allow(unused_imports)]
+use sails_rs::{
+    calls::{Activation, Call, Query, Remoting, RemotingAction},
+    prelude::*,
+    String,
+};
+pub struct MultisigFactory<R> {
+    #[allow(dead_code)]
+    remoting: R,
+}
+impl<R> MultisigFactory<R> {
+    #[allow(unused)]
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::MultisigFactory for MultisigFactory<R> {
+    type Args = R::Args;
+    /// Panics if `threshold` is zero or above the number of distinct signers.
+    fn new(&self, signers: Vec<ActorId>, threshold: u32) -> impl Activation<Args = R::Args> {
+        RemotingAction::<_, multisig_factory::io::New>::new(
+            self.remoting.clone(),
+            (signers, threshold),
+        )
+    }
+}
+
+pub mod multisig_factory {
+    use super::*;
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct New(());
+        impl New {
+            #[allow(dead_code)]
+            pub fn encode_call(signers: Vec<ActorId>, threshold: u32) -> Vec<u8> {
+                <New as ActionIo>::encode_call(&(signers, threshold))
+            }
+        }
+        impl ActionIo for New {
+            const ROUTE: &'static [u8] = &[12, 78, 101, 119];
+            type Params = (Vec<ActorId>, u32);
+            type Reply = ();
+        }
+    }
+}
+pub struct MultisigService<R> {
+    remoting: R,
+}
+impl<R> MultisigService<R> {
+    pub fn new(remoting: R) -> Self {
+        Self { remoting }
+    }
+}
+impl<R: Remoting + Clone> traits::MultisigService for MultisigService<R> {
+    type Args = R::Args;
+    /// Only callable by the multisig itself, through a transaction.
+    fn add_signer(
+        &mut self,
+        signer: ActorId,
+    ) -> impl Call<Output = Result<(), MultisigError>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::AddSigner>::new(self.remoting.clone(), signer)
+    }
+    /// Only callable by the multisig itself.
+    fn change_threshold(
+        &mut self,
+        threshold: u32,
+    ) -> impl Call<Output = Result<(), MultisigError>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::ChangeThreshold>::new(
+            self.remoting.clone(),
+            threshold,
+        )
+    }
+    fn confirm_transaction(
+        &mut self,
+        nonce: u64,
+    ) -> impl Call<Output = Result<(), MultisigError>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::ConfirmTransaction>::new(
+            self.remoting.clone(),
+            nonce,
+        )
+    }
+    /// Sends the call once `threshold` current signers have confirmed and
+    /// returns the target's raw reply. Any signer can execute.
+    fn execute_transaction(
+        &mut self,
+        nonce: u64,
+    ) -> impl Call<Output = Result<Vec<u8>, MultisigError>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::ExecuteTransaction>::new(
+            self.remoting.clone(),
+            nonce,
+        )
+    }
+    /// Only callable by the multisig itself. Confirmations of a removed
+    /// signer stop counting.
+    fn remove_signer(
+        &mut self,
+        signer: ActorId,
+    ) -> impl Call<Output = Result<(), MultisigError>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::RemoveSigner>::new(self.remoting.clone(), signer)
+    }
+    fn revoke_confirmation(
+        &mut self,
+        nonce: u64,
+    ) -> impl Call<Output = Result<(), MultisigError>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::RevokeConfirmation>::new(
+            self.remoting.clone(),
+            nonce,
+        )
+    }
+    /// Submits a call of `target` with the encoded Sails message `payload`
+    /// and returns its nonce. The submitter's confirmation is included.
+    fn submit_transaction(
+        &mut self,
+        target: ActorId,
+        payload: Vec<u8>,
+        value: u128,
+        expires_at: Option<u64>,
+    ) -> impl Call<Output = Result<u64, MultisigError>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::SubmitTransaction>::new(
+            self.remoting.clone(),
+            (target, payload, value, expires_at),
+        )
+    }
+    /// Nonce the next submitted transaction gets.
+    fn get_next_nonce(&self) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::GetNextNonce>::new(self.remoting.clone(), ())
+    }
+    /// Unexpired transactions waiting for confirmations or execution, by
+    /// nonce.
+    fn get_pending_transactions(&self) -> impl Query<Output = Vec<Transaction>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::GetPendingTransactions>::new(
+            self.remoting.clone(),
+            (),
+        )
+    }
+    fn get_signers(&self) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::GetSigners>::new(self.remoting.clone(), ())
+    }
+    fn get_threshold(&self) -> impl Query<Output = u32, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::GetThreshold>::new(self.remoting.clone(), ())
+    }
+    fn get_transaction(
+        &self,
+        nonce: u64,
+    ) -> impl Query<Output = Option<Transaction>, Args = R::Args> {
+        RemotingAction::<_, multisig_service::io::GetTransaction>::new(self.remoting.clone(), nonce)
+    }
+}
+
+pub mod multisig_service {
+    use super::*;
+
+    pub mod io {
+        use super::*;
+        use sails_rs::calls::ActionIo;
+        pub struct AddSigner(());
+        impl AddSigner {
+            #[allow(dead_code)]
+            pub fn encode_call(signer: ActorId) -> Vec<u8> {
+                <AddSigner as ActionIo>::encode_call(&signer)
+            }
+        }
+        impl ActionIo for AddSigner {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 36, 65,
+                100, 100, 83, 105, 103, 110, 101, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::MultisigError>;
+        }
+        pub struct ChangeThreshold(());
+        impl ChangeThreshold {
+            #[allow(dead_code)]
+            pub fn encode_call(threshold: u32) -> Vec<u8> {
+                <ChangeThreshold as ActionIo>::encode_call(&threshold)
+            }
+        }
+        impl ActionIo for ChangeThreshold {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 60, 67,
+                104, 97, 110, 103, 101, 84, 104, 114, 101, 115, 104, 111, 108, 100,
+            ];
+            type Params = u32;
+            type Reply = Result<(), super::MultisigError>;
+        }
+        pub struct ConfirmTransaction(());
+        impl ConfirmTransaction {
+            #[allow(dead_code)]
+            pub fn encode_call(nonce: u64) -> Vec<u8> {
+                <ConfirmTransaction as ActionIo>::encode_call(&nonce)
+            }
+        }
+        impl ActionIo for ConfirmTransaction {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 72, 67,
+                111, 110, 102, 105, 114, 109, 84, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110,
+            ];
+            type Params = u64;
+            type Reply = Result<(), super::MultisigError>;
+        }
+        pub struct ExecuteTransaction(());
+        impl ExecuteTransaction {
+            #[allow(dead_code)]
+            pub fn encode_call(nonce: u64) -> Vec<u8> {
+                <ExecuteTransaction as ActionIo>::encode_call(&nonce)
+            }
+        }
+        impl ActionIo for ExecuteTransaction {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 72, 69,
+                120, 101, 99, 117, 116, 101, 84, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110,
+            ];
+            type Params = u64;
+            type Reply = Result<Vec<u8>, super::MultisigError>;
+        }
+        pub struct RemoveSigner(());
+        impl RemoveSigner {
+            #[allow(dead_code)]
+            pub fn encode_call(signer: ActorId) -> Vec<u8> {
+                <RemoveSigner as ActionIo>::encode_call(&signer)
+            }
+        }
+        impl ActionIo for RemoveSigner {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 48, 82,
+                101, 109, 111, 118, 101, 83, 105, 103, 110, 101, 114,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::MultisigError>;
+        }
+        pub struct RevokeConfirmation(());
+        impl RevokeConfirmation {
+            #[allow(dead_code)]
+            pub fn encode_call(nonce: u64) -> Vec<u8> {
+                <RevokeConfirmation as ActionIo>::encode_call(&nonce)
+            }
+        }
+        impl ActionIo for RevokeConfirmation {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 72, 82,
+                101, 118, 111, 107, 101, 67, 111, 110, 102, 105, 114, 109, 97, 116, 105, 111, 110,
+            ];
+            type Params = u64;
+            type Reply = Result<(), super::MultisigError>;
+        }
+        pub struct SubmitTransaction(());
+        impl SubmitTransaction {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                target: ActorId,
+                payload: Vec<u8>,
+                value: u128,
+                expires_at: Option<u64>,
+            ) -> Vec<u8> {
+                <SubmitTransaction as ActionIo>::encode_call(&(target, payload, value, expires_at))
+            }
+        }
+        impl ActionIo for SubmitTransaction {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 68, 83,
+                117, 98, 109, 105, 116, 84, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110,
+            ];
+            type Params = (ActorId, Vec<u8>, u128, Option<u64>);
+            type Reply = Result<u64, super::MultisigError>;
+        }
+        pub struct GetNextNonce(());
+        impl GetNextNonce {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetNextNonce as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetNextNonce {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 48, 71,
+                101, 116, 78, 101, 120, 116, 78, 111, 110, 99, 101,
+            ];
+            type Params = ();
+            type Reply = u64;
+        }
+        pub struct GetPendingTransactions(());
+        impl GetPendingTransactions {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPendingTransactions as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPendingTransactions {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 88, 71,
+                101, 116, 80, 101, 110, 100, 105, 110, 103, 84, 114, 97, 110, 115, 97, 99, 116,
+                105, 111, 110, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<super::Transaction>;
+        }
+        pub struct GetSigners(());
+        impl GetSigners {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetSigners as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetSigners {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 40, 71,
+                101, 116, 83, 105, 103, 110, 101, 114, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<ActorId>;
+        }
+        pub struct GetThreshold(());
+        impl GetThreshold {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetThreshold as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetThreshold {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 48, 71,
+                101, 116, 84, 104, 114, 101, 115, 104, 111, 108, 100,
+            ];
+            type Params = ();
+            type Reply = u32;
+        }
+        pub struct GetTransaction(());
+        impl GetTransaction {
+            #[allow(dead_code)]
+            pub fn encode_call(nonce: u64) -> Vec<u8> {
+                <GetTransaction as ActionIo>::encode_call(&nonce)
+            }
+        }
+        impl ActionIo for GetTransaction {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101, 56, 71,
+                101, 116, 84, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110,
+            ];
+            type Params = u64;
+            type Reply = Option<super::Transaction>;
+        }
+    }
+
+    #[allow(dead_code)]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod events {
+        use super::*;
+        use sails_rs::events::*;
+        #[derive(PartialEq, Debug, Encode, Decode)]
+        #[codec(crate = sails_rs::scale_codec)]
+        pub enum MultisigServiceEvents {
+            Submitted {
+                nonce: u64,
+                submitter: ActorId,
+                target: ActorId,
+                value: u128,
+            },
+            Confirmed {
+                nonce: u64,
+                signer: ActorId,
+            },
+            Revoked {
+                nonce: u64,
+                signer: ActorId,
+            },
+            Executed {
+                nonce: u64,
+                /// Raw reply of the target, including its Sails routes.
+                reply: Vec<u8>,
+            },
+            SignerAdded(ActorId),
+            SignerRemoved(ActorId),
+            ThresholdChanged(u32),
+        }
+        impl EventIo for MultisigServiceEvents {
+            const ROUTE: &'static [u8] = &[
+                60, 77, 117, 108, 116, 105, 115, 105, 103, 83, 101, 114, 118, 105, 99, 101,
+            ];
+            const EVENT_NAMES: &'static [&'static [u8]] = &[
+                &[36, 83, 117, 98, 109, 105, 116, 116, 101, 100],
+                &[36, 67, 111, 110, 102, 105, 114, 109, 101, 100],
+                &[28, 82, 101, 118, 111, 107, 101, 100],
+                &[32, 69, 120, 101, 99, 117, 116, 101, 100],
+                &[44, 83, 105, 103, 110, 101, 114, 65, 100, 100, 101, 100],
+                &[
+                    52, 83, 105, 103, 110, 101, 114, 82, 101, 109, 111, 118, 101, 100,
+                ],
+                &[
+                    64, 84, 104, 114, 101, 115, 104, 111, 108, 100, 67, 104, 97, 110, 103, 101, 100,
+                ],
+            ];
+            type Event = Self;
+        }
+        pub fn listener<R: Listener<Vec<u8>>>(remoting: R) -> impl Listener<MultisigServiceEvents> {
+            RemotingListener::<_, MultisigServiceEvents>::new(remoting)
+        }
+    }
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum MultisigError {
+    NotSigner,
+    /// Signer management is only reachable through an executed transaction
+    /// of the multisig itself.
+    Unauthorized,
+    TransactionNotFound,
+    AlreadyExecuted,
+    Expired,
+    AlreadyConfirmed,
+    NotConfirmed,
+    NotEnoughConfirmations,
+    /// The multisig holds less than the transaction's `value`.
+    InsufficientBalance,
+    /// The target could not be reached or failed to handle the call.
+    ExecutionFailed,
+    AlreadySigner,
+    InvalidThreshold,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Transaction {
+    /// Nonce of the transaction, also its id.
+    pub nonce: u64,
+    pub target: ActorId,
+    /// Full Sails message: encoded service route, method route and arguments.
+    pub payload: Vec<u8>,
+    /// Native VARA sent from the multisig's balance with the call.
+    pub value: u128,
+    pub submitter: ActorId,
+    /// Signers that confirmed, in confirmation order.
+    pub confirmations: Vec<ActorId>,
+    /// Block timestamp after which the transaction can no longer be
+    /// confirmed or executed.
+    pub expires_at: Option<u64>,
+    pub status: TransactionStatus,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TransactionStatus {
+    Pending,
+    /// Set before the call is sent and kept once it succeeds; a failed call
+    /// puts the transaction back to `Pending`.
+    Executed,
+}
+
+pub mod traits {
+    use super::*;
+    #[allow(dead_code)]
+    pub trait MultisigFactory {
+        type Args;
+        #[allow(clippy::new_ret_no_self)]
+        #[allow(clippy::wrong_self_convention)]
+        fn new(&self, signers: Vec<ActorId>, threshold: u32) -> impl Activation<Args = Self::Args>;
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub trait MultisigService {
+        type Args;
+        fn add_signer(
+            &mut self,
+            signer: ActorId,
+        ) -> impl Call<Output = Result<(), MultisigError>, Args = Self::Args>;
+        fn change_threshold(
+            &mut self,
+            threshold: u32,
+        ) -> impl Call<Output = Result<(), MultisigError>, Args = Self::Args>;
+        fn confirm_transaction(
+            &mut self,
+            nonce: u64,
+        ) -> impl Call<Output = Result<(), MultisigError>, Args = Self::Args>;
+        fn execute_transaction(
+            &mut self,
+            nonce: u64,
+        ) -> impl Call<Output = Result<Vec<u8>, MultisigError>, Args = Self::Args>;
+        fn remove_signer(
+            &mut self,
+            signer: ActorId,
+        ) -> impl Call<Output = Result<(), MultisigError>, Args = Self::Args>;
+        fn revoke_confirmation(
+            &mut self,
+            nonce: u64,
+        ) -> impl Call<Output = Result<(), MultisigError>, Args = Self::Args>;
+        fn submit_transaction(
+            &mut self,
+            target: ActorId,
+            payload: Vec<u8>,
+            value: u128,
+            expires_at: Option<u64>,
+        ) -> impl Call<Output = Result<u64, MultisigError>, Args = Self::Args>;
+        fn get_next_nonce(&self) -> impl Query<Output = u64, Args = Self::Args>;
+        fn get_pending_transactions(
+            &self,
+        ) -> impl Query<Output = Vec<Transaction>, Args = Self::Args>;
+        fn get_signers(&self) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn get_threshold(&self) -> impl Query<Output = u32, Args = Self::Args>;
+        fn get_transaction(
+            &self,
+            nonce: u64,
+        ) -> impl Query<Output = Option<Transaction>, Args = Self::Args>;
+    }
+}
+
+#[cfg(feature = "with_mocks")]
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+#[cfg(feature = "with_mocks")]
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mockall {
+    use super::*;
+    use sails_rs::mockall::*;
+    mock! { pub MultisigService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::MultisigService for MultisigService<A> { type Args = A; fn add_signer (&mut self, signer: ActorId,) -> MockCall<A, Result<(), MultisigError>>;fn change_threshold (&mut self, threshold: u32,) -> MockCall<A, Result<(), MultisigError>>;fn confirm_transaction (&mut self, nonce: u64,) -> MockCall<A, Result<(), MultisigError>>;fn execute_transaction (&mut self, nonce: u64,) -> MockCall<A, Result<Vec<u8>, MultisigError>>;fn remove_signer (&mut self, signer: ActorId,) -> MockCall<A, Result<(), MultisigError>>;fn revoke_confirmation (&mut self, nonce: u64,) -> MockCall<A, Result<(), MultisigError>>;fn submit_transaction (&mut self, target: ActorId,payload: Vec<u8>,value: u128,expires_at: Option<u64>,) -> MockCall<A, Result<u64, MultisigError>>;fn get_next_nonce (& self, ) -> MockQuery<A, u64>;fn get_pending_transactions (& self, ) -> MockQuery<A, Vec<Transaction>>;fn get_signers (& self, ) -> MockQuery<A, Vec<ActorId>>;fn get_threshold (& self, ) -> MockQuery<A, u32>;fn get_transaction (& self, nonce: u64,) -> MockQuery<A, Option<Transaction>>; } }
+}
@@ -0,0 +1,153 @@
+#![no_std]
+
+//! Signed approvals shared by the LP and wVARA token programs. An owner
+//! signs [`permit_payload`] off-chain and anyone submits it with the
+//! token's `permit`, which checks it with [`use_permit`] and sets the
+//! allowance without a separate `approve` message.
+
+use sails_rs::{
+    collections::HashMap,
+    gstd::exec,
+    prelude::*,
+};
+
+pub static mut PERMIT_NONCES: Option<HashMap<ActorId, u64>> = None;
+
+/// Prefix of every signed payload, so a permit signature cannot pass for
+/// a signature over anything else.
+pub const PERMIT_DOMAIN: &[u8] = b"VarSwap Permit";
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PermitSignature {
+    /// Made with the `substrate` signing context, as wallets sign raw data.
+    Sr25519([u8; 64]),
+    Ed25519([u8; 64]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermitError {
+    Expired,
+    InvalidSignature,
+}
+
+/// Bytes the owner signs: the domain, the token program, the approval and
+/// the owner's current nonce.
+pub fn permit_payload(
+    token: ActorId,
+    owner: ActorId,
+    spender: ActorId,
+    value: U256,
+    nonce: u64,
+    deadline: u64,
+) -> Vec<u8> {
+    (PERMIT_DOMAIN, token, owner, spender, value, nonce, deadline).encode()
+}
+
+/// Whether `signature` is `signer`'s over `payload`. Wallets wrap raw data
+/// in `<Bytes>` tags before signing it, so the check does the same.
+pub fn verify(signer: ActorId, payload: &[u8], signature: &PermitSignature) -> bool {
+    let message = [b"<Bytes>".as_slice(), payload, b"</Bytes>".as_slice()].concat();
+    let public_key: [u8; 32] = signer.into();
+    match signature {
+        PermitSignature::Sr25519(signature) => {
+            let (Ok(public_key), Ok(signature)) = (
+                schnorrkel::PublicKey::from_bytes(&public_key),
+                schnorrkel::Signature::from_bytes(signature),
+            ) else {
+                return false;
+            };
+            public_key
+                .verify_simple(b"substrate", &message, &signature)
+                .is_ok()
+        }
+        PermitSignature::Ed25519(signature) => {
+            let Ok(public_key) = ed25519_dalek::VerifyingKey::from_bytes(&public_key) else {
+                return false;
+            };
+            let signature = ed25519_dalek::Signature::from_bytes(signature);
+            public_key.verify_strict(&message, &signature).is_ok()
+        }
+    }
+}
+
+pub fn nonce_of(owner: ActorId) -> u64 {
+    unsafe { PERMIT_NONCES.as_ref() }
+        .and_then(|nonces| nonces.get(&owner).copied())
+        .unwrap_or_default()
+}
+
+/// Checks a permit for the running token program against `owner`'s current
+/// nonce and, if valid, moves the nonce on so it cannot be replayed.
+pub fn use_permit(
+    owner: ActorId,
+    spender: ActorId,
+    value: U256,
+    deadline: u64,
+    signature: &PermitSignature,
+) -> Result<(), PermitError> {
+    if deadline < exec::block_timestamp() {
+        return Err(PermitError::Expired);
+    }
+    let nonce = nonce_of(owner);
+    let payload = permit_payload(exec::program_id(), owner, spender, value, nonce, deadline);
+    if !verify(owner, &payload, signature) {
+        return Err(PermitError::InvalidSignature);
+    }
+    let nonces = unsafe { PERMIT_NONCES.get_or_insert_with(HashMap::new) };
+    nonces.insert(owner, nonce + 1);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Signer;
+
+    fn payload(owner: ActorId) -> Vec<u8> {
+        permit_payload(1.into(), owner, 2.into(), 100.into(), 0, 1_000)
+    }
+
+    fn wrapped(payload: &[u8]) -> Vec<u8> {
+        [b"<Bytes>".as_slice(), payload, b"</Bytes>".as_slice()].concat()
+    }
+
+    #[test]
+    fn sr25519_permit() {
+        let keypair = schnorrkel::MiniSecretKey::from_bytes(&[7; 32])
+            .unwrap()
+            .expand_to_keypair(schnorrkel::ExpansionMode::Ed25519);
+        let owner = ActorId::from(keypair.public.to_bytes());
+        let payload = payload(owner);
+        let signature = PermitSignature::Sr25519(
+            keypair
+                .sign_simple(b"substrate", &wrapped(&payload))
+                .to_bytes(),
+        );
+        assert!(verify(owner, &payload, &signature));
+        assert!(!verify(3.into(), &payload, &signature));
+        // another value, or the next nonce, is a different payload
+        let other = permit_payload(1.into(), owner, 2.into(), 101.into(), 0, 1_000);
+        assert!(!verify(owner, &other, &signature));
+        let other = permit_payload(1.into(), owner, 2.into(), 100.into(), 1, 1_000);
+        assert!(!verify(owner, &other, &signature));
+    }
+
+    #[test]
+    fn ed25519_permit() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[9; 32]);
+        let owner = ActorId::from(key.verifying_key().to_bytes());
+        let payload = payload(owner);
+        let signature = PermitSignature::Ed25519(key.sign(&wrapped(&payload)).to_bytes());
+        assert!(verify(owner, &payload, &signature));
+        // a permit for one token is not valid on another
+        let other = permit_payload(4.into(), owner, 2.into(), 100.into(), 0, 1_000);
+        assert!(!verify(owner, &other, &signature));
+        // nor is it as a signature of the other scheme
+        let PermitSignature::Ed25519(bytes) = signature else {
+            unreachable!()
+        };
+        assert!(!verify(owner, &payload, &PermitSignature::Sr25519(bytes)));
+    }
+}
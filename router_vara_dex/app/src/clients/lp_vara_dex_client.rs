@@ -88,20 +88,68 @@ impl<R> LpService<R> {
 }
 impl<R: Remoting + Clone> traits::LpService for LpService<R> {
     type Args = R::Args;
+    fn accept_admin(&mut self) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::AcceptAdmin>::new(self.remoting.clone(), ())
+    }
+    /// Burns the LP tokens sent to the pair and pays out their share of
+    /// both tokens to `to`. When `to` is the caller, native VARA comes with
+    /// the reply, since a program cannot take it as a plain message.
     fn burn(
         &mut self,
         to: ActorId,
     ) -> impl Call<Output = Result<(U256, U256), LpError>, Args = R::Args> {
         RemotingAction::<_, lp_service::io::Burn>::new(self.remoting.clone(), to)
     }
+    fn cancel_admin_transfer(&mut self) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::CancelAdminTransfer>::new(self.remoting.clone(), ())
+    }
+    /// Hands the caller's voting weight, current and future balance, to
+    /// `delegatee`. Delegating to oneself takes it back.
+    fn delegate(
+        &mut self,
+        delegatee: ActorId,
+    ) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Delegate>::new(self.remoting.clone(), delegatee)
+    }
+    /// Mints LP tokens for what was added to the pair since its last
+    /// update. VARA attached to the message counts for a native pair and
+    /// comes back with the reply if minting fails.
     fn mint(&mut self, to: ActorId) -> impl Call<Output = Result<U256, LpError>, Args = R::Args> {
         RemotingAction::<_, lp_service::io::Mint>::new(self.remoting.clone(), to)
     }
-    fn set_admin(
+    /// Sets `spender`'s allowance over `owner`'s LP tokens to `value` from
+    /// `owner`'s signature of the permit payload, so no `approve` message is
+    /// needed. Anyone can submit it.
+    fn permit(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Permit>::new(
+            self.remoting.clone(),
+            (owner, spender, value, deadline, signature),
+        )
+    }
+    /// Starts an admin handover that `accept_admin` completes.
+    fn propose_admin(
         &mut self,
         new_admin: ActorId,
     ) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
-        RemotingAction::<_, lp_service::io::SetAdmin>::new(self.remoting.clone(), new_admin)
+        RemotingAction::<_, lp_service::io::ProposeAdmin>::new(self.remoting.clone(), new_admin)
+    }
+    /// Sends `to` what the pair holds of `token` beyond its reserve and
+    /// returns the amount. Only the router may call it, to give back the
+    /// input of a swap it could not finish; unlike `skim` it works while
+    /// the pair is paused or blocked.
+    fn return_excess(
+        &mut self,
+        token: ActorId,
+        to: ActorId,
+    ) -> impl Call<Output = Result<U256, LpError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::ReturnExcess>::new(self.remoting.clone(), (token, to))
     }
     fn set_router(
         &mut self,
@@ -112,6 +160,9 @@ impl<R: Remoting + Clone> traits::LpService for LpService<R> {
     fn skim(&mut self, to: ActorId) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
         RemotingAction::<_, lp_service::io::Skim>::new(self.remoting.clone(), to)
     }
+    /// Sends out `amount0_out`/`amount1_out` against the input added since
+    /// the last update. For a native pair the VARA input can come attached
+    /// to this message, and comes back with the reply if the swap fails.
     fn swap(
         &mut self,
         amount0_out: U256,
@@ -126,16 +177,13 @@ impl<R: Remoting + Clone> traits::LpService for LpService<R> {
     fn sync(&mut self) -> impl Call<Output = Result<(), LpError>, Args = R::Args> {
         RemotingAction::<_, lp_service::io::Sync>::new(self.remoting.clone(), ())
     }
-    fn approve(
-        &mut self,
-        spender: ActorId,
-        value: U256,
-    ) -> impl Call<Output = bool, Args = R::Args> {
-        RemotingAction::<_, lp_service::io::Approve>::new(self.remoting.clone(), (spender, value))
-    }
+    /// Same as the VFT `transfer`, also recording balance and voting weight
+    /// checkpoints.
     fn transfer(&mut self, to: ActorId, value: U256) -> impl Call<Output = bool, Args = R::Args> {
         RemotingAction::<_, lp_service::io::Transfer>::new(self.remoting.clone(), (to, value))
     }
+    /// Same as the VFT `transfer_from`, also recording balance and voting
+    /// weight checkpoints.
     fn transfer_from(
         &mut self,
         from: ActorId,
@@ -147,18 +195,96 @@ impl<R: Remoting + Clone> traits::LpService for LpService<R> {
             (from, to, value),
         )
     }
+    fn approve(
+        &mut self,
+        spender: ActorId,
+        value: U256,
+    ) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Approve>::new(self.remoting.clone(), (spender, value))
+    }
+    /// Grants any role but `DefaultAdmin`, which the new holder has to
+    /// accept through the program's `propose_admin`/`accept_admin`.
+    fn grant_role(
+        &mut self,
+        role: Role,
+        account: ActorId,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GrantRole>::new(self.remoting.clone(), (role, account))
+    }
+    /// Drops a role of the caller. Renouncing the last `DefaultAdmin` leaves
+    /// the roles fixed for good.
+    fn renounce_role(
+        &mut self,
+        role: Role,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::RenounceRole>::new(self.remoting.clone(), role)
+    }
+    fn revoke_role(
+        &mut self,
+        role: Role,
+        account: ActorId,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::RevokeRole>::new(self.remoting.clone(), (role, account))
+    }
+    /// Balance of `account` at the end of `block`.
+    fn balance_of_at(
+        &self,
+        account: ActorId,
+        block: u32,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::BalanceOfAt>::new(
+            self.remoting.clone(),
+            (account, block),
+        )
+    }
     fn get_admin(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, lp_service::io::GetAdmin>::new(self.remoting.clone(), ())
     }
+    /// Account holding the voting weight of `account`'s balance, itself
+    /// unless it delegated.
+    fn get_delegate(&self, account: ActorId) -> impl Query<Output = ActorId, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetDelegate>::new(self.remoting.clone(), account)
+    }
     fn get_factory(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, lp_service::io::GetFactory>::new(self.remoting.clone(), ())
     }
+    fn get_pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetPendingAdmin>::new(self.remoting.clone(), ())
+    }
     fn get_reserves(&self) -> impl Query<Output = (U256, U256, u64), Args = R::Args> {
         RemotingAction::<_, lp_service::io::GetReserves>::new(self.remoting.clone(), ())
     }
     fn get_router(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, lp_service::io::GetRouter>::new(self.remoting.clone(), ())
     }
+    /// The pair's tokens, larger id first.
+    fn get_tokens(&self) -> impl Query<Output = (ActorId, ActorId), Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetTokens>::new(self.remoting.clone(), ())
+    }
+    /// Current voting weight of `account`: its own balance unless
+    /// delegated, plus balances delegated to it.
+    fn get_votes(&self, account: ActorId) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetVotes>::new(self.remoting.clone(), account)
+    }
+    /// Voting weight of `account` at the end of `block`.
+    fn get_votes_at(
+        &self,
+        account: ActorId,
+        block: u32,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetVotesAt>::new(
+            self.remoting.clone(),
+            (account, block),
+        )
+    }
+    /// Current nonce of `owner`, which its next permit signature must cover.
+    fn nonces(&self, owner: ActorId) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::Nonces>::new(self.remoting.clone(), owner)
+    }
+    /// Total supply at the end of `block`.
+    fn total_supply_at(&self, block: u32) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::TotalSupplyAt>::new(self.remoting.clone(), block)
+    }
     fn allowance(
         &self,
         owner: ActorId,
@@ -181,6 +307,18 @@ impl<R: Remoting + Clone> traits::LpService for LpService<R> {
     fn total_supply(&self) -> impl Query<Output = U256, Args = R::Args> {
         RemotingAction::<_, lp_service::io::TotalSupply>::new(self.remoting.clone(), ())
     }
+    fn get_account_roles(
+        &self,
+        account: ActorId,
+    ) -> impl Query<Output = Vec<Role>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetAccountRoles>::new(self.remoting.clone(), account)
+    }
+    fn get_role_members(&self, role: Role) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::GetRoleMembers>::new(self.remoting.clone(), role)
+    }
+    fn has_role(&self, role: Role, account: ActorId) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, lp_service::io::HasRole>::new(self.remoting.clone(), (role, account))
+    }
 }
 
 pub mod lp_service {
@@ -189,6 +327,21 @@ pub mod lp_service {
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
+        pub struct AcceptAdmin(());
+        impl AcceptAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AcceptAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AcceptAdmin {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 44, 65, 99, 99, 101, 112, 116, 65,
+                100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::LpError>;
+        }
         pub struct Burn(());
         impl Burn {
             #[allow(dead_code)]
@@ -203,6 +356,36 @@ pub mod lp_service {
             type Params = ActorId;
             type Reply = Result<(U256, U256), super::LpError>;
         }
+        pub struct CancelAdminTransfer(());
+        impl CancelAdminTransfer {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <CancelAdminTransfer as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CancelAdminTransfer {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 76, 67, 97, 110, 99, 101, 108, 65,
+                100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct Delegate(());
+        impl Delegate {
+            #[allow(dead_code)]
+            pub fn encode_call(delegatee: ActorId) -> Vec<u8> {
+                <Delegate as ActionIo>::encode_call(&delegatee)
+            }
+        }
+        impl ActionIo for Delegate {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 32, 68, 101, 108, 101, 103, 97, 116,
+                101,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::LpError>;
+        }
         pub struct Mint(());
         impl Mint {
             #[allow(dead_code)]
@@ -217,21 +400,56 @@ pub mod lp_service {
             type Params = ActorId;
             type Reply = Result<U256, super::LpError>;
         }
-        pub struct SetAdmin(());
-        impl SetAdmin {
+        pub struct Permit(());
+        impl Permit {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+                deadline: u64,
+                signature: super::PermitSignature,
+            ) -> Vec<u8> {
+                <Permit as ActionIo>::encode_call(&(owner, spender, value, deadline, signature))
+            }
+        }
+        impl ActionIo for Permit {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 24, 80, 101, 114, 109, 105, 116,
+            ];
+            type Params = (ActorId, ActorId, U256, u64, super::PermitSignature);
+            type Reply = Result<(), super::LpError>;
+        }
+        pub struct ProposeAdmin(());
+        impl ProposeAdmin {
             #[allow(dead_code)]
             pub fn encode_call(new_admin: ActorId) -> Vec<u8> {
-                <SetAdmin as ActionIo>::encode_call(&new_admin)
+                <ProposeAdmin as ActionIo>::encode_call(&new_admin)
             }
         }
-        impl ActionIo for SetAdmin {
+        impl ActionIo for ProposeAdmin {
             const ROUTE: &'static [u8] = &[
-                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 32, 83, 101, 116, 65, 100, 109, 105,
-                110,
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 48, 80, 114, 111, 112, 111, 115, 101,
+                65, 100, 109, 105, 110,
             ];
             type Params = ActorId;
             type Reply = Result<(), super::LpError>;
         }
+        pub struct ReturnExcess(());
+        impl ReturnExcess {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, to: ActorId) -> Vec<u8> {
+                <ReturnExcess as ActionIo>::encode_call(&(token, to))
+            }
+        }
+        impl ActionIo for ReturnExcess {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 48, 82, 101, 116, 117, 114, 110, 69,
+                120, 99, 101, 115, 115,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = Result<U256, super::LpError>;
+        }
         pub struct SetRouter(());
         impl SetRouter {
             #[allow(dead_code)]
@@ -289,20 +507,6 @@ pub mod lp_service {
             type Params = ();
             type Reply = Result<(), super::LpError>;
         }
-        pub struct Approve(());
-        impl Approve {
-            #[allow(dead_code)]
-            pub fn encode_call(spender: ActorId, value: U256) -> Vec<u8> {
-                <Approve as ActionIo>::encode_call(&(spender, value))
-            }
-        }
-        impl ActionIo for Approve {
-            const ROUTE: &'static [u8] = &[
-                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 28, 65, 112, 112, 114, 111, 118, 101,
-            ];
-            type Params = (ActorId, U256);
-            type Reply = bool;
-        }
         pub struct Transfer(());
         impl Transfer {
             #[allow(dead_code)]
@@ -333,6 +537,80 @@ pub mod lp_service {
             type Params = (ActorId, ActorId, U256);
             type Reply = bool;
         }
+        pub struct Approve(());
+        impl Approve {
+            #[allow(dead_code)]
+            pub fn encode_call(spender: ActorId, value: U256) -> Vec<u8> {
+                <Approve as ActionIo>::encode_call(&(spender, value))
+            }
+        }
+        impl ActionIo for Approve {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 28, 65, 112, 112, 114, 111, 118, 101,
+            ];
+            type Params = (ActorId, U256);
+            type Reply = bool;
+        }
+        pub struct GrantRole(());
+        impl GrantRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <GrantRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for GrantRole {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 36, 71, 114, 97, 110, 116, 82, 111,
+                108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct RenounceRole(());
+        impl RenounceRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role) -> Vec<u8> {
+                <RenounceRole as ActionIo>::encode_call(&role)
+            }
+        }
+        impl ActionIo for RenounceRole {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 48, 82, 101, 110, 111, 117, 110, 99,
+                101, 82, 111, 108, 101,
+            ];
+            type Params = super::Role;
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct RevokeRole(());
+        impl RevokeRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <RevokeRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for RevokeRole {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 40, 82, 101, 118, 111, 107, 101, 82,
+                111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct BalanceOfAt(());
+        impl BalanceOfAt {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId, block: u32) -> Vec<u8> {
+                <BalanceOfAt as ActionIo>::encode_call(&(account, block))
+            }
+        }
+        impl ActionIo for BalanceOfAt {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 44, 66, 97, 108, 97, 110, 99, 101,
+                79, 102, 65, 116,
+            ];
+            type Params = (ActorId, u32);
+            type Reply = U256;
+        }
         pub struct GetAdmin(());
         impl GetAdmin {
             #[allow(dead_code)]
@@ -348,6 +626,21 @@ pub mod lp_service {
             type Params = ();
             type Reply = ActorId;
         }
+        pub struct GetDelegate(());
+        impl GetDelegate {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetDelegate as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for GetDelegate {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 44, 71, 101, 116, 68, 101, 108, 101,
+                103, 97, 116, 101,
+            ];
+            type Params = ActorId;
+            type Reply = ActorId;
+        }
         pub struct GetFactory(());
         impl GetFactory {
             #[allow(dead_code)]
@@ -363,6 +656,21 @@ pub mod lp_service {
             type Params = ();
             type Reply = ActorId;
         }
+        pub struct GetPendingAdmin(());
+        impl GetPendingAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPendingAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPendingAdmin {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101, 116, 80, 101, 110, 100,
+                105, 110, 103, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Option<ActorId>;
+        }
         pub struct GetReserves(());
         impl GetReserves {
             #[allow(dead_code)]
@@ -393,6 +701,80 @@ pub mod lp_service {
             type Params = ();
             type Reply = ActorId;
         }
+        pub struct GetTokens(());
+        impl GetTokens {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetTokens as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetTokens {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 36, 71, 101, 116, 84, 111, 107, 101,
+                110, 115,
+            ];
+            type Params = ();
+            type Reply = (ActorId, ActorId);
+        }
+        pub struct GetVotes(());
+        impl GetVotes {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetVotes as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for GetVotes {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 32, 71, 101, 116, 86, 111, 116, 101,
+                115,
+            ];
+            type Params = ActorId;
+            type Reply = U256;
+        }
+        pub struct GetVotesAt(());
+        impl GetVotesAt {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId, block: u32) -> Vec<u8> {
+                <GetVotesAt as ActionIo>::encode_call(&(account, block))
+            }
+        }
+        impl ActionIo for GetVotesAt {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 40, 71, 101, 116, 86, 111, 116, 101,
+                115, 65, 116,
+            ];
+            type Params = (ActorId, u32);
+            type Reply = U256;
+        }
+        pub struct Nonces(());
+        impl Nonces {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <Nonces as ActionIo>::encode_call(&owner)
+            }
+        }
+        impl ActionIo for Nonces {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 24, 78, 111, 110, 99, 101, 115,
+            ];
+            type Params = ActorId;
+            type Reply = u64;
+        }
+        pub struct TotalSupplyAt(());
+        impl TotalSupplyAt {
+            #[allow(dead_code)]
+            pub fn encode_call(block: u32) -> Vec<u8> {
+                <TotalSupplyAt as ActionIo>::encode_call(&block)
+            }
+        }
+        impl ActionIo for TotalSupplyAt {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 52, 84, 111, 116, 97, 108, 83, 117,
+                112, 112, 108, 121, 65, 116,
+            ];
+            type Params = u32;
+            type Reply = U256;
+        }
         pub struct Allowance(());
         impl Allowance {
             #[allow(dead_code)]
@@ -481,6 +863,50 @@ pub mod lp_service {
             type Params = ();
             type Reply = U256;
         }
+        pub struct GetAccountRoles(());
+        impl GetAccountRoles {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetAccountRoles as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for GetAccountRoles {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101, 116, 65, 99, 99, 111,
+                117, 110, 116, 82, 111, 108, 101, 115,
+            ];
+            type Params = ActorId;
+            type Reply = Vec<super::Role>;
+        }
+        pub struct GetRoleMembers(());
+        impl GetRoleMembers {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role) -> Vec<u8> {
+                <GetRoleMembers as ActionIo>::encode_call(&role)
+            }
+        }
+        impl ActionIo for GetRoleMembers {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101, 116, 82, 111, 108, 101,
+                77, 101, 109, 98, 101, 114, 115,
+            ];
+            type Params = super::Role;
+            type Reply = Vec<ActorId>;
+        }
+        pub struct HasRole(());
+        impl HasRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <HasRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for HasRole {
+            const ROUTE: &'static [u8] = &[
+                36, 76, 112, 83, 101, 114, 118, 105, 99, 101, 28, 72, 97, 115, 82, 111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = bool;
+        }
     }
 
     #[allow(dead_code)]
@@ -537,8 +963,22 @@ pub mod lp_service {
                 /// A recipient of skimmed tokens.
                 to: ActorId,
             },
+            /// Emitted by `accept_admin`.
             AdminSet(ActorId),
+            AdminTransferProposed(ActorId),
+            AdminTransferCancelled(ActorId),
             RouterSet(ActorId),
+            DelegateChanged {
+                delegator: ActorId,
+                from_delegate: ActorId,
+                to_delegate: ActorId,
+            },
+            /// Emitted by `permit` instead of the VFT `Approval`.
+            Permit {
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+            },
             Approval {
                 owner: ActorId,
                 spender: ActorId,
@@ -549,6 +989,16 @@ pub mod lp_service {
                 to: ActorId,
                 value: U256,
             },
+            RoleGranted {
+                role: Role,
+                account: ActorId,
+                sender: ActorId,
+            },
+            RoleRevoked {
+                role: Role,
+                account: ActorId,
+                sender: ActorId,
+            },
         }
         impl EventIo for LpServiceEvents {
             const ROUTE: &'static [u8] = &[36, 76, 112, 83, 101, 114, 118, 105, 99, 101];
@@ -562,9 +1012,23 @@ pub mod lp_service {
                 &[16, 83, 121, 110, 99],
                 &[16, 83, 107, 105, 109],
                 &[32, 65, 100, 109, 105, 110, 83, 101, 116],
+                &[
+                    84, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 80, 114, 111,
+                    112, 111, 115, 101, 100,
+                ],
+                &[
+                    88, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 67, 97, 110,
+                    99, 101, 108, 108, 101, 100,
+                ],
                 &[36, 82, 111, 117, 116, 101, 114, 83, 101, 116],
+                &[
+                    60, 68, 101, 108, 101, 103, 97, 116, 101, 67, 104, 97, 110, 103, 101, 100,
+                ],
+                &[24, 80, 101, 114, 109, 105, 116],
                 &[32, 65, 112, 112, 114, 111, 118, 97, 108],
                 &[32, 84, 114, 97, 110, 115, 102, 101, 114],
+                &[44, 82, 111, 108, 101, 71, 114, 97, 110, 116, 101, 100],
+                &[44, 82, 111, 108, 101, 82, 101, 118, 111, 107, 101, 100],
             ];
             type Event = Self;
         }
@@ -617,6 +1081,55 @@ pub enum LpError {
     EmitEventFailed,
     InvalidReserves,
     InvalidAmount,
+    InvalidAdmin,
+    /// The factory has blocked one of the pair's tokens.
+    TokenBlocked,
+    /// The factory has paused the pair, or all pairs.
+    Paused,
+    /// There is no proposed admin to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin.
+    NotPendingAccount,
+    /// The permit signature is not the owner's over the permit payload
+    /// with its current nonce.
+    InvalidSignature,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PermitSignature {
+    /// Made with the `substrate` signing context, as wallets sign raw data.
+    Sr25519([u8; 64]),
+    Ed25519([u8; 64]),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Role {
+    /// Grants and revokes roles and changes the program's configuration.
+    DefaultAdmin,
+    /// Pauses and unpauses trading.
+    Pauser,
+    /// Sets fees and where they go.
+    FeeManager,
+    /// Lists tokens and edits their metadata and status.
+    AssetManager,
+    /// Moves funds held by the program.
+    Treasurer,
+    /// Sets staking rewards.
+    RewardManager,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum AccessControlError {
+    /// The caller does not hold `Role::DefaultAdmin`.
+    MissingAdminRole,
+    /// The account does not hold the role.
+    RoleNotHeld,
+    /// `DefaultAdmin` only changes hands through the program's
+    /// `propose_admin`/`accept_admin`.
+    AdminRoleNotGrantable,
 }
 
 pub mod traits {
@@ -642,18 +1155,39 @@ pub mod traits {
     #[allow(clippy::type_complexity)]
     pub trait LpService {
         type Args;
+        fn accept_admin(&mut self) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
         fn burn(
             &mut self,
             to: ActorId,
         ) -> impl Call<Output = Result<(U256, U256), LpError>, Args = Self::Args>;
+        fn cancel_admin_transfer(
+            &mut self,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn delegate(
+            &mut self,
+            delegatee: ActorId,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
         fn mint(
             &mut self,
             to: ActorId,
         ) -> impl Call<Output = Result<U256, LpError>, Args = Self::Args>;
-        fn set_admin(
+        fn permit(
+            &mut self,
+            owner: ActorId,
+            spender: ActorId,
+            value: U256,
+            deadline: u64,
+            signature: PermitSignature,
+        ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn propose_admin(
             &mut self,
             new_admin: ActorId,
         ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
+        fn return_excess(
+            &mut self,
+            token: ActorId,
+            to: ActorId,
+        ) -> impl Call<Output = Result<U256, LpError>, Args = Self::Args>;
         fn set_router(
             &mut self,
             new_router: ActorId,
@@ -669,11 +1203,6 @@ pub mod traits {
             to: ActorId,
         ) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
         fn sync(&mut self) -> impl Call<Output = Result<(), LpError>, Args = Self::Args>;
-        fn approve(
-            &mut self,
-            spender: ActorId,
-            value: U256,
-        ) -> impl Call<Output = bool, Args = Self::Args>;
         fn transfer(
             &mut self,
             to: ActorId,
@@ -685,10 +1214,46 @@ pub mod traits {
             to: ActorId,
             value: U256,
         ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn approve(
+            &mut self,
+            spender: ActorId,
+            value: U256,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn grant_role(
+            &mut self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn renounce_role(
+            &mut self,
+            role: Role,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn revoke_role(
+            &mut self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn balance_of_at(
+            &self,
+            account: ActorId,
+            block: u32,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
         fn get_admin(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_delegate(&self, account: ActorId)
+            -> impl Query<Output = ActorId, Args = Self::Args>;
         fn get_factory(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
         fn get_reserves(&self) -> impl Query<Output = (U256, U256, u64), Args = Self::Args>;
         fn get_router(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_tokens(&self) -> impl Query<Output = (ActorId, ActorId), Args = Self::Args>;
+        fn get_votes(&self, account: ActorId) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_votes_at(
+            &self,
+            account: ActorId,
+            block: u32,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
+        fn nonces(&self, owner: ActorId) -> impl Query<Output = u64, Args = Self::Args>;
+        fn total_supply_at(&self, block: u32) -> impl Query<Output = U256, Args = Self::Args>;
         fn allowance(
             &self,
             owner: ActorId,
@@ -699,6 +1264,19 @@ pub mod traits {
         fn name(&self) -> impl Query<Output = String, Args = Self::Args>;
         fn symbol(&self) -> impl Query<Output = String, Args = Self::Args>;
         fn total_supply(&self) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_account_roles(
+            &self,
+            account: ActorId,
+        ) -> impl Query<Output = Vec<Role>, Args = Self::Args>;
+        fn get_role_members(
+            &self,
+            role: Role,
+        ) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn has_role(
+            &self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
     }
 }
 
@@ -711,5 +1289,5 @@ extern crate std;
 pub mod mockall {
     use super::*;
     use sails_rs::mockall::*;
-    mock! { pub LpService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::LpService for LpService<A> { type Args = A; fn burn (&mut self, to: ActorId,) -> MockCall<A, Result<(U256,U256,), LpError>>;fn mint (&mut self, to: ActorId,) -> MockCall<A, Result<U256, LpError>>;fn set_admin (&mut self, new_admin: ActorId,) -> MockCall<A, Result<(), LpError>>;fn set_router (&mut self, new_router: ActorId,) -> MockCall<A, Result<(), LpError>>;fn skim (&mut self, to: ActorId,) -> MockCall<A, Result<(), LpError>>;fn swap (&mut self, amount0_out: U256,amount1_out: U256,to: ActorId,) -> MockCall<A, Result<(), LpError>>;fn sync (&mut self, ) -> MockCall<A, Result<(), LpError>>;fn approve (&mut self, spender: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer_from (&mut self, from: ActorId,to: ActorId,value: U256,) -> MockCall<A, bool>;fn get_admin (& self, ) -> MockQuery<A, ActorId>;fn get_factory (& self, ) -> MockQuery<A, ActorId>;fn get_reserves (& self, ) -> MockQuery<A, (U256,U256,u64,)>;fn get_router (& self, ) -> MockQuery<A, ActorId>;fn allowance (& self, owner: ActorId,spender: ActorId,) -> MockQuery<A, U256>;fn balance_of (& self, account: ActorId,) -> MockQuery<A, U256>;fn decimals (& self, ) -> MockQuery<A, u8>;fn name (& self, ) -> MockQuery<A, String>;fn symbol (& self, ) -> MockQuery<A, String>;fn total_supply (& self, ) -> MockQuery<A, U256>; } }
+    mock! { pub LpService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::LpService for LpService<A> { type Args = A; fn accept_admin (&mut self, ) -> MockCall<A, Result<(), LpError>>;fn burn (&mut self, to: ActorId,) -> MockCall<A, Result<(U256,U256,), LpError>>;fn cancel_admin_transfer (&mut self, ) -> MockCall<A, Result<(), LpError>>;fn delegate (&mut self, delegatee: ActorId,) -> MockCall<A, Result<(), LpError>>;fn mint (&mut self, to: ActorId,) -> MockCall<A, Result<U256, LpError>>;fn permit (&mut self, owner: ActorId,spender: ActorId,value: U256,deadline: u64,signature: PermitSignature,) -> MockCall<A, Result<(), LpError>>;fn propose_admin (&mut self, new_admin: ActorId,) -> MockCall<A, Result<(), LpError>>;fn return_excess (&mut self, token: ActorId,to: ActorId,) -> MockCall<A, Result<U256, LpError>>;fn set_router (&mut self, new_router: ActorId,) -> MockCall<A, Result<(), LpError>>;fn skim (&mut self, to: ActorId,) -> MockCall<A, Result<(), LpError>>;fn swap (&mut self, amount0_out: U256,amount1_out: U256,to: ActorId,) -> MockCall<A, Result<(), LpError>>;fn sync (&mut self, ) -> MockCall<A, Result<(), LpError>>;fn transfer (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer_from (&mut self, from: ActorId,to: ActorId,value: U256,) -> MockCall<A, bool>;fn approve (&mut self, spender: ActorId,value: U256,) -> MockCall<A, bool>;fn grant_role (&mut self, role: Role,account: ActorId,) -> MockCall<A, Result<(), AccessControlError>>;fn renounce_role (&mut self, role: Role,) -> MockCall<A, Result<(), AccessControlError>>;fn revoke_role (&mut self, role: Role,account: ActorId,) -> MockCall<A, Result<(), AccessControlError>>;fn balance_of_at (& self, account: ActorId,block: u32,) -> MockQuery<A, U256>;fn get_admin (& self, ) -> MockQuery<A, ActorId>;fn get_delegate (& self, account: ActorId,) -> MockQuery<A, ActorId>;fn get_factory (& self, ) -> MockQuery<A, ActorId>;fn get_pending_admin (& self, ) -> MockQuery<A, Option<ActorId>>;fn get_reserves (& self, ) -> MockQuery<A, (U256,U256,u64,)>;fn get_router (& self, ) -> MockQuery<A, ActorId>;fn get_tokens (& self, ) -> MockQuery<A, (ActorId,ActorId,)>;fn get_votes (& self, account: ActorId,) -> MockQuery<A, U256>;fn get_votes_at (& self, account: ActorId,block: u32,) -> MockQuery<A, U256>;fn nonces (& self, owner: ActorId,) -> MockQuery<A, u64>;fn total_supply_at (& self, block: u32,) -> MockQuery<A, U256>;fn allowance (& self, owner: ActorId,spender: ActorId,) -> MockQuery<A, U256>;fn balance_of (& self, account: ActorId,) -> MockQuery<A, U256>;fn decimals (& self, ) -> MockQuery<A, u8>;fn name (& self, ) -> MockQuery<A, String>;fn symbol (& self, ) -> MockQuery<A, String>;fn total_supply (& self, ) -> MockQuery<A, U256>;fn get_account_roles (& self, account: ActorId,) -> MockQuery<A, Vec<Role>>;fn get_role_members (& self, role: Role,) -> MockQuery<A, Vec<ActorId>>;fn has_role (& self, role: Role,account: ActorId,) -> MockQuery<A, bool>; } }
 }
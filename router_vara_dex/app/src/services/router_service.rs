@@ -1,3 +1,4 @@
+use access_control::{has_role, AccessControl, AccessControlService, Role};
 use gstd::{exec, msg, ActorId};
 use parity_scale_codec::{Decode, Encode};
 use sails_rs::calls::{Action, Call, Query};
@@ -10,14 +11,20 @@ use crate::clients::factory_vara_dex_client::traits::FactoryService;
 use crate::clients::factory_vara_dex_client::FactoryService as FactoryServiceClient;
 use crate::clients::lp_vara_dex_client::traits::LpService;
 use crate::clients::lp_vara_dex_client::LpService as LpServiceClient;
+use crate::clients::lp_vara_dex_client::{LpError, PermitSignature};
 use crate::states::router_state::{
-   LiquidityJoin, PendingRefund, RouterError, RouterEvent, RouterState, ROUTER,
+   CachedPair, ConfigChange, FactoryInfo, HopSimulation, LiquidityJoin, PathQuote, PendingRefund,
+   QueuedOperation, RouterError, RouterEvent, RouterState, SimulationError, SwapIntent,
+   SwapSimulation, DEFAULT_PAIR_FEE_PPM, MAX_TIMELOCK_DELAY, NATIVE_VARA,
+   RECEIVER_CALLBACK_TIMEOUT, ROUTER,
 };
+use crate::states::router_state::{receiver_callback_accepted, receiver_callback_payload};
 
 pub struct RouterService {
     pub factory_client: FactoryServiceClient<GStdRemoting>,
     pub vft_client: VftClient<GStdRemoting>,
     pub lp_client: LpServiceClient<GStdRemoting>,
+    pub access_control: AccessControlService,
 }
 
 impl RouterService {
@@ -30,19 +37,44 @@ impl RouterService {
     ) {
         unsafe {
             ROUTER = Some(RouterState {
-                factory_address,
+                factories: vec![FactoryInfo {
+                    address: factory_address,
+                    fee_ppm: DEFAULT_PAIR_FEE_PPM,
+                }],
                 wvara_address,
                 admin: admin_addr,
+                pending_admin: None,
                 fund_addr,
                 swap_fee_bps,
                 lock: false,
+                in_callback: false,
                 pending_liquidity: Default::default(),
                 liquidity_join: Default::default(),
+                pair_cache: Default::default(),
+                timelock_delay: 0,
+                queued_operations: Vec::new(),
+                next_operation_id: 0,
+                intent_nonces: Default::default(),
+                vault_balances: Default::default(),
+                vault_totals: Default::default(),
+                refunds: Default::default(),
             });
         }
+        AccessControlService::seed(admin_addr);
+    }
+
+    fn queue_operation(&mut self, change: ConfigChange) -> u64 {
+        let operation = RouterState::get_mut().queue_operation(change, exec::block_timestamp());
+        self.emit_event(RouterEvent::OperationQueued {
+            id: operation.id,
+            change: operation.change,
+            eta: operation.eta,
+        })
+        .ok();
+        operation.id
     }
 }
-#[service(events = RouterEvent)]
+#[service(extends = AccessControlService, events = RouterEvent)]
 impl RouterService {
     pub fn new(
         factory_client: FactoryServiceClient<GStdRemoting>,
@@ -53,80 +85,334 @@ impl RouterService {
             factory_client,
             vft_client,
             lp_client,
+            access_control: AccessControlService::new(),
         }
     }
 
     //admin functions
 
-    pub fn update_new_admin(&mut self, new_addr: ActorId) -> Result<bool, RouterError> {
+    /// The proposed account becomes admin, with the old admin's roles, once
+    /// it calls `accept_admin`.
+    pub fn propose_admin(&mut self, new_addr: ActorId) -> Result<bool, RouterError> {
         let router_state = RouterState::get_mut();
         let sender = msg::source();
-        if sender == ActorId::zero() || sender != router_state.admin {
-            return Err(RouterError::IdenticalAddresses);
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        if new_addr.is_zero() {
+            return Err(RouterError::ZeroAddress);
         }
-        router_state.admin = new_addr;
+        router_state.pending_admin = Some(new_addr);
+        self.emit_event(RouterEvent::AdminTransferProposed { new_admin: new_addr })
+            .ok();
         Ok(true)
     }
 
-    pub fn update_new_factorty(&mut self, new_factory_addr: ActorId) -> Result<bool, RouterError> {
+    pub fn accept_admin(&mut self) -> Result<bool, RouterError> {
         let router_state = RouterState::get_mut();
         let sender = msg::source();
-        if sender == ActorId::zero() || sender != router_state.admin {
-            return Err(RouterError::IdenticalAddresses);
+        match router_state.pending_admin {
+            None => return Err(RouterError::NoPendingTransfer),
+            Some(pending) if pending != sender => return Err(RouterError::NotPendingAccount),
+            Some(_) => {}
         }
-        router_state.factory_address = new_factory_addr;
+        router_state.pending_admin = None;
+        let old_admin = router_state.admin;
+        // the new admin takes over every role of the old one
+        AccessControl::get_mut().transfer_roles(old_admin, sender);
+        router_state.admin = sender;
+        self.emit_event(RouterEvent::AdminTransferred {
+            old_admin,
+            new_admin: sender,
+        })
+        .ok();
         Ok(true)
     }
 
-    pub fn update_new_wrapvara(&mut self, new_wvara_addr: ActorId) -> Result<bool, RouterError> {
+    pub fn cancel_admin_transfer(&mut self) -> Result<bool, RouterError> {
         let router_state = RouterState::get_mut();
         let sender = msg::source();
-        if sender == ActorId::zero() || sender != router_state.admin {
-            return Err(RouterError::IdenticalAddresses);
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
         }
-        router_state.wvara_address = new_wvara_addr;
+        let pending_admin = router_state
+            .pending_admin
+            .take()
+            .ok_or(RouterError::NoPendingTransfer)?;
+        self.emit_event(RouterEvent::AdminTransferCancelled { pending_admin })
+            .ok();
         Ok(true)
     }
 
+    /// Queues the change and returns its operation id; see `execute_operation`.
+    pub fn update_new_factorty(&mut self, new_factory_addr: ActorId) -> Result<u64, RouterError> {
+        let sender = msg::source();
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        Ok(self.queue_operation(ConfigChange::Factory(new_factory_addr)))
+    }
+
+    /// Queues the change and returns its operation id.
+    pub fn update_new_wrapvara(&mut self, new_wvara_addr: ActorId) -> Result<u64, RouterError> {
+        let sender = msg::source();
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        Ok(self.queue_operation(ConfigChange::Wvara(new_wvara_addr)))
+    }
+
     pub fn update_fund_addr(&mut self, new_fund_addr: ActorId) -> Result<bool, RouterError> {
         let router_state = RouterState::get_mut();
         let sender = msg::source();
-        if sender == ActorId::zero() || sender != router_state.admin {
-            return Err(RouterError::IdenticalAddresses);
+        if !has_role(Role::FeeManager, sender) {
+            return Err(RouterError::NotAdmin);
         }
         router_state.fund_addr = new_fund_addr;
         Ok(true)
     }
 
-    pub fn update_swap_fee_bps(&mut self, new_swap_fee_bps: u128) -> Result<bool, RouterError> {
+    /// Queues the change and returns its operation id.
+    pub fn update_swap_fee_bps(&mut self, new_swap_fee_bps: u128) -> Result<u64, RouterError> {
+        let sender = msg::source();
+        if !has_role(Role::FeeManager, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        Ok(self.queue_operation(ConfigChange::SwapFeeBps(new_swap_fee_bps)))
+    }
+
+    /// Lowering the delay waits out the current one like any other change.
+    pub fn update_timelock_delay(&mut self, delay: u64) -> Result<u64, RouterError> {
+        let sender = msg::source();
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        if delay > MAX_TIMELOCK_DELAY {
+            return Err(RouterError::InvalidTimelockDelay);
+        }
+        Ok(self.queue_operation(ConfigChange::TimelockDelay(delay)))
+    }
+
+    /// Applies a queued change once its `eta` has passed; callable by anyone.
+    pub fn execute_operation(&mut self, id: u64) -> Result<bool, RouterError> {
+        let router_state = RouterState::get_mut();
+        let Some(operation) = router_state.queued_operations.iter().find(|op| op.id == id) else {
+            return Err(RouterError::OperationNotFound);
+        };
+        if exec::block_timestamp() < operation.eta {
+            return Err(RouterError::OperationNotReady);
+        }
+        let operation = router_state.take_operation(id).expect("operation was found");
+        match operation.change {
+            ConfigChange::Factory(factory) => {
+                // a promoted secondary factory leaves the secondary list and
+                // keeps its fee
+                let promoted = router_state
+                    .factories
+                    .iter()
+                    .skip(1)
+                    .position(|info| info.address == factory)
+                    .map(|index| router_state.factories.remove(index + 1));
+                if let Some(primary) = router_state.factories.first_mut() {
+                    *primary = promoted.unwrap_or(FactoryInfo {
+                        address: factory,
+                        fee_ppm: primary.fee_ppm,
+                    });
+                }
+                // pairs of the old factory must not be served from the cache
+                router_state.pair_cache.clear();
+            }
+            ConfigChange::Wvara(wvara) => router_state.wvara_address = wvara,
+            ConfigChange::SwapFeeBps(swap_fee_bps) => router_state.swap_fee_bps = swap_fee_bps,
+            ConfigChange::TimelockDelay(delay) => router_state.timelock_delay = delay,
+        }
+        self.emit_event(RouterEvent::OperationExecuted {
+            id,
+            change: operation.change,
+        })
+        .ok();
+        Ok(true)
+    }
+
+    pub fn cancel_operation(&mut self, id: u64) -> Result<bool, RouterError> {
         let router_state = RouterState::get_mut();
         let sender = msg::source();
-        if sender == ActorId::zero() || sender != router_state.admin {
-            return Err(RouterError::IdenticalAddresses);
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
         }
-        router_state.swap_fee_bps = new_swap_fee_bps;
+        router_state
+            .take_operation(id)
+            .ok_or(RouterError::OperationNotFound)?;
+        self.emit_event(RouterEvent::OperationCancelled { id }).ok();
         Ok(true)
     }
 
+    /// Called by a registered factory when a pair is added, replaced or
+    /// removed, or by the admin to repair the cache; admin syncs apply to the
+    /// primary factory. A zero `pair_address` evicts the entry.
+    pub fn sync_pair(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        pair_address: ActorId,
+    ) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        let sender = msg::source();
+        let factory = if router_state.factory_info(sender).is_some() {
+            sender
+        } else if has_role(Role::DefaultAdmin, sender) {
+            router_state.primary_factory()
+        } else {
+            return Err(RouterError::NotAdmin);
+        };
+        let key = Self::_pair_key(token_a, token_b);
+        if pair_address.is_zero() || factory != router_state.primary_factory() {
+            // a pair of a secondary factory may be shadowed by an uncached
+            // pair of a higher priority one, so let the next lookup resolve it
+            router_state.pair_cache.remove(&key);
+        } else {
+            router_state.pair_cache.insert(
+                key,
+                CachedPair {
+                    factory,
+                    pair: pair_address,
+                },
+            );
+        }
+        self.emit_event(RouterEvent::PairCacheSynced {
+            token_a,
+            token_b,
+            pair_address,
+        })
+        .ok();
+        Ok(())
+    }
+
+    /// Registers a factory with the lowest lookup priority.
+    pub fn add_factory(&mut self, factory: ActorId, fee_ppm: u128) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        let sender = msg::source();
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        if factory.is_zero() {
+            return Err(RouterError::ZeroAddress);
+        }
+        if fee_ppm >= 1_000_000 {
+            return Err(RouterError::InvalidFee);
+        }
+        if router_state.factory_info(factory).is_some() {
+            return Err(RouterError::FactoryAlreadyRegistered);
+        }
+        router_state.factories.push(FactoryInfo {
+            address: factory,
+            fee_ppm,
+        });
+        // the new factory may have pairs cached as missing
+        router_state.pair_cache.retain(|_, cached| !cached.pair.is_zero());
+        self.emit_event(RouterEvent::FactoryAdded { factory, fee_ppm })
+            .ok();
+        Ok(())
+    }
+
+    pub fn remove_factory(&mut self, factory: ActorId) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        let sender = msg::source();
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        let Some(index) = router_state
+            .factories
+            .iter()
+            .position(|info| info.address == factory)
+        else {
+            return Err(RouterError::FactoryNotRegistered);
+        };
+        if index == 0 {
+            return Err(RouterError::CannotRemovePrimaryFactory);
+        }
+        router_state.factories.remove(index);
+        router_state
+            .pair_cache
+            .retain(|_, cached| cached.factory != factory);
+        self.emit_event(RouterEvent::FactoryRemoved { factory }).ok();
+        Ok(())
+    }
+
+    pub fn update_factory_fee(
+        &mut self,
+        factory: ActorId,
+        fee_ppm: u128,
+    ) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        let sender = msg::source();
+        if !has_role(Role::FeeManager, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        if fee_ppm >= 1_000_000 {
+            return Err(RouterError::InvalidFee);
+        }
+        let Some(info) = router_state
+            .factories
+            .iter_mut()
+            .find(|info| info.address == factory)
+        else {
+            return Err(RouterError::FactoryNotRegistered);
+        };
+        info.fee_ppm = fee_ppm;
+        self.emit_event(RouterEvent::FactoryFeeUpdated { factory, fee_ppm })
+            .ok();
+        Ok(())
+    }
+
+    pub fn clear_pair_cache(&mut self) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        let sender = msg::source();
+        if !has_role(Role::DefaultAdmin, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        router_state.pair_cache.clear();
+        self.emit_event(RouterEvent::PairCacheCleared).ok();
+        Ok(())
+    }
+
     pub async fn refund_token(
         &mut self,
         token_addr: ActorId,
         amount: U256,
     ) -> Result<bool, RouterError> {
-        let router_state = RouterState::get_mut();
         let sender = msg::source();
-        if sender == ActorId::zero() || sender != router_state.admin {
-            return Err(RouterError::IdenticalAddresses);
+        if !has_role(Role::Treasurer, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        // vault balances and owed refunds are not the router's to refund
+        let state = RouterState::get();
+        let owed = state
+            .vault_total(token_addr)
+            .saturating_add(state.refund_total(token_addr));
+        if !owed.is_zero() {
+            let balance = self
+                .vft_client
+                .balance_of(exec::program_id())
+                .recv(token_addr)
+                .await
+                .unwrap_or_default();
+            if balance.saturating_sub(owed) < amount {
+                return Err(RouterError::InsufficientVaultBalance);
+            }
         }
         let _ = self._transfer(token_addr, sender, amount).await?;
         Ok(true)
     }
 
     pub async fn refund_vara(&mut self, amount: u128) -> Result<bool, RouterError> {
-        let router_state = RouterState::get_mut();
         let sender = msg::source();
-        if sender == ActorId::zero() || sender != router_state.admin {
-            return Err(RouterError::IdenticalAddresses);
+        if !has_role(Role::Treasurer, sender) {
+            return Err(RouterError::NotAdmin);
+        }
+        let owed = RouterState::get().refund_total(NATIVE_VARA);
+        if U256::from(exec::value_available()).saturating_sub(owed) < U256::from(amount) {
+            return Err(RouterError::InsufficientVaultBalance);
         }
         let _ = msg::send_bytes(sender, "Refund Vara".encode(), amount);
         Ok(true)
@@ -152,21 +438,14 @@ impl RouterService {
         Ok((token0, token1))
     }
 
+    /// Resolves the pair from the first registered factory that has one.
     pub async fn pair_for(
         &self,
         token_a: ActorId,
         token_b: ActorId,
     ) -> Result<ActorId, RouterError> {
-        let router_state = RouterState::get();
-        let pair_res = self
-            .factory_client
-            .get_pair(token_a, token_b)
-            .recv(router_state.factory_address)
-            .await;
-        match pair_res {
-            Ok(pair) => Ok(pair),
-            Err(_) => Err(RouterError::PairNotFound),
-        }
+        let (pair, _) = self._resolve_pair(token_a, token_b).await?;
+        Ok(pair)
     }
 
     pub async fn get_reserves(
@@ -185,7 +464,7 @@ impl RouterService {
                     Ok((reserve1, reserve0, pair))
                 }
             }
-            Err(_) => Err(RouterError::PairNotFound),
+            Err(_) => Err(RouterError::PairQueryFailed { pair }),
         }
     }
 
@@ -214,28 +493,7 @@ impl RouterService {
         reserve_in: U256,
         reserve_out: U256,
     ) -> Result<U256, RouterError> {
-        if amount_in == U256::zero() {
-            return Err(RouterError::InsufficientInputAmount);
-        }
-        if reserve_in == U256::zero() || reserve_out == U256::zero() {
-            return Err(RouterError::InsufficientLiquidity);
-        }
-
-        let precision = U256::from(1_000_000);
-        let fee = U256::from(997_000);
-
-        let amount_in_with_fee = amount_in.checked_mul(fee).ok_or(RouterError::Overflow)?;
-        let numerator = amount_in_with_fee
-            .checked_mul(reserve_out)
-            .ok_or(RouterError::Overflow)?;
-        let denominator = reserve_in
-            .checked_mul(precision)
-            .and_then(|x| x.checked_add(amount_in_with_fee))
-            .ok_or(RouterError::Overflow)?;
-        let result = numerator
-            .checked_div(denominator)
-            .ok_or(RouterError::DivisionError)?;
-        Ok(result)
+        Self::_amount_out(amount_in, reserve_in, reserve_out, DEFAULT_PAIR_FEE_PPM)
     }
 
     pub fn get_amount_in(
@@ -244,30 +502,7 @@ impl RouterService {
         reserve_in: U256,
         reserve_out: U256,
     ) -> Result<U256, RouterError> {
-        if amount_out == U256::zero() {
-            return Err(RouterError::InsufficientOutputAmount);
-        }
-        if reserve_in == U256::zero() || reserve_out == U256::zero() {
-            return Err(RouterError::InsufficientLiquidity);
-        }
-
-        let precision = U256::from(1_000_000);
-        let fee = U256::from(997_000);
-        let one = U256::one();
-
-        let numerator = reserve_in
-            .checked_mul(amount_out)
-            .and_then(|x| x.checked_mul(precision))
-            .ok_or(RouterError::Overflow)?;
-        let denominator = reserve_out
-            .checked_sub(amount_out)
-            .and_then(|x| x.checked_mul(fee))
-            .ok_or(RouterError::Overflow)?;
-        let result = numerator
-            .checked_div(denominator)
-            .and_then(|x| x.checked_add(one))
-            .ok_or(RouterError::DivisionError)?;
-        Ok(result)
+        Self::_amount_in(amount_out, reserve_in, reserve_out, DEFAULT_PAIR_FEE_PPM)
     }
 
     pub async fn get_amounts_out(
@@ -280,8 +515,9 @@ impl RouterService {
         }
         let mut amounts = vec![amount_in];
         for i in 0..path.len() - 1 {
-            let (reserve_in, reserve_out, _) = self.get_reserves(path[i], path[i + 1]).await?;
-            amounts.push(self.get_amount_out(amounts[i], reserve_in, reserve_out)?);
+            let (_, reserve_in, reserve_out, fee_ppm) =
+                self._pair_reserves(path[i], path[i + 1], None).await?;
+            amounts.push(Self::_amount_out(amounts[i], reserve_in, reserve_out, fee_ppm)?);
         }
         Ok(amounts)
     }
@@ -298,19 +534,162 @@ impl RouterService {
         let len = amounts.len();
         amounts[len - 1] = amount_out;
         for i in (1..path.len()).rev() {
-            let (reserve_in, reserve_out, _) = self.get_reserves(path[i - 1], path[i]).await?;
-            amounts[i - 1] = self.get_amount_in(amounts[i], reserve_in, reserve_out)?;
+            let (_, reserve_in, reserve_out, fee_ppm) =
+                self._pair_reserves(path[i - 1], path[i], None).await?;
+            amounts[i - 1] = Self::_amount_in(amounts[i], reserve_in, reserve_out, fee_ppm)?;
         }
         Ok(amounts)
     }
 
+    /// Quotes `path` hop by hop across all registered factories, picking for
+    /// each hop the pair with the best output.
+    pub async fn get_best_amounts_out(
+        &self,
+        amount_in: U256,
+        path: Vec<ActorId>,
+    ) -> Result<PathQuote, RouterError> {
+        if path.len() < 2 {
+            return Err(RouterError::InvalidPath);
+        }
+        let factories = RouterState::get().factories.clone();
+        let mut quote = PathQuote {
+            amounts: vec![amount_in],
+            factories: Vec::with_capacity(path.len() - 1),
+        };
+        for i in 0..path.len() - 1 {
+            let mut best: Option<(U256, ActorId)> = None;
+            for info in factories.iter() {
+                let res = self
+                    ._pair_reserves(path[i], path[i + 1], Some(info.address))
+                    .await;
+                let (_, reserve_in, reserve_out, fee_ppm) = match res {
+                    Ok(reserves) => reserves,
+                    Err(RouterError::PairNotFound) => continue,
+                    Err(error) => return Err(error),
+                };
+                let Ok(amount_out) =
+                    Self::_amount_out(quote.amounts[i], reserve_in, reserve_out, fee_ppm)
+                else {
+                    continue;
+                };
+                if best.is_none_or(|(best_out, _)| amount_out > best_out) {
+                    best = Some((amount_out, info.address));
+                }
+            }
+            let Some((amount_out, factory)) = best else {
+                return Err(RouterError::PairNotFound);
+            };
+            quote.amounts.push(amount_out);
+            quote.factories.push(factory);
+        }
+        Ok(quote)
+    }
+
+    /// Dry-runs a swap along `path` against the live reserves without moving
+    /// any funds. `amount` is the input amount when `exact_in` is set and the
+    /// desired output amount otherwise.
+    pub async fn simulate_swap(
+        &self,
+        path: Vec<ActorId>,
+        amount: U256,
+        exact_in: bool,
+        deadline: u64,
+    ) -> SwapSimulation {
+        let simulation = SwapSimulation::default();
+        if path.len() < 2 {
+            return simulation.fail(None, SimulationError::Router(RouterError::InvalidPath));
+        }
+        if deadline < exec::block_timestamp() {
+            return simulation.fail(None, SimulationError::Router(RouterError::Expired));
+        }
+
+        let hops = path.len() - 1;
+        let mut pools = Vec::with_capacity(hops);
+        for i in 0..hops {
+            match self._pair_reserves(path[i], path[i + 1], None).await {
+                Ok(pool) => pools.push(pool),
+                Err(error) => return simulation.fail(Some(i), SimulationError::Router(error)),
+            }
+        }
+
+        let mut amounts = vec![U256::zero(); path.len()];
+        if exact_in {
+            amounts[0] = amount;
+            for i in 0..hops {
+                let (_, reserve_in, reserve_out, fee_ppm) = pools[i];
+                match Self::_amount_out(amounts[i], reserve_in, reserve_out, fee_ppm) {
+                    Ok(amount_out) => amounts[i + 1] = amount_out,
+                    Err(error) => return simulation.fail(Some(i), SimulationError::Router(error)),
+                }
+            }
+        } else {
+            amounts[hops] = amount;
+            for i in (0..hops).rev() {
+                let (_, reserve_in, reserve_out, fee_ppm) = pools[i];
+                if amounts[i + 1] >= reserve_out {
+                    return simulation.fail(
+                        Some(i),
+                        SimulationError::Pair(LpError::InsufficientLiquidity),
+                    );
+                }
+                match Self::_amount_in(amounts[i + 1], reserve_in, reserve_out, fee_ppm) {
+                    Ok(amount_in) => amounts[i] = amount_in,
+                    Err(error) => return simulation.fail(Some(i), SimulationError::Router(error)),
+                }
+            }
+        }
+
+        let mut simulation = SwapSimulation {
+            amounts: amounts.clone(),
+            ..simulation
+        };
+        for i in 0..hops {
+            let (pair, reserve_in, reserve_out, fee_ppm) = pools[i];
+            let mut hop = HopSimulation {
+                pair,
+                token_in: path[i],
+                token_out: path[i + 1],
+                amount_in: amounts[i],
+                amount_out: amounts[i + 1],
+                reserve_in,
+                reserve_out,
+                reserve_in_after: reserve_in,
+                reserve_out_after: reserve_out,
+                k_before: U256::zero(),
+                k_after: U256::zero(),
+            };
+            let res = Self::_simulate_hop(&mut hop, fee_ppm);
+            simulation.hops.push(hop);
+            if let Err(error) = res {
+                return simulation.fail(Some(i), SimulationError::Pair(error));
+            }
+        }
+        simulation
+    }
+
     pub fn get_admin(&self) -> ActorId {
         let state = RouterState::get();
         state.admin
     }
+    pub fn get_pending_admin(&self) -> Option<ActorId> {
+        let state = RouterState::get();
+        state.pending_admin
+    }
+    pub fn get_timelock_delay(&self) -> u64 {
+        let state = RouterState::get();
+        state.timelock_delay
+    }
+    pub fn get_queued_operations(&self) -> Vec<QueuedOperation> {
+        let state = RouterState::get();
+        state.queued_operations.clone()
+    }
     pub fn get_factory(&self) -> ActorId {
         let state = RouterState::get();
-        state.factory_address
+        state.primary_factory()
+    }
+    pub fn get_factories(&self) -> Vec<FactoryInfo> {
+        let state = RouterState::get();
+        state.factories.clone()
     }
     pub fn get_wvara(&self) -> ActorId {
         let state = RouterState::get();
@@ -320,6 +699,35 @@ impl RouterService {
         let state = RouterState::get();
         state.fund_addr
     }
+    pub fn get_vault_balance(&self, owner: ActorId, token: ActorId) -> U256 {
+        let state = RouterState::get();
+        state.vault_balance(owner, token)
+    }
+    /// Every non-zero vault balance of `owner`, as (token, amount).
+    pub fn get_vault_balances(&self, owner: ActorId) -> Vec<(ActorId, U256)> {
+        let state = RouterState::get();
+        state
+            .vault_balances
+            .iter()
+            .filter(|((account, _), _)| *account == owner)
+            .map(|((_, token), amount)| (*token, *amount))
+            .collect()
+    }
+    /// Refunds `claim_refunds` would send `user`, as (token, amount).
+    pub fn get_refunds(&self, user: ActorId) -> Vec<(ActorId, U256)> {
+        let state = RouterState::get();
+        state
+            .refunds
+            .iter()
+            .filter(|((account, _), _)| *account == user)
+            .map(|((_, token), amount)| (*token, *amount))
+            .collect()
+    }
+    /// Nonce the next signed swap intent of `owner` must carry.
+    pub fn get_intent_nonce(&self, owner: ActorId) -> u64 {
+        let state = RouterState::get();
+        state.intent_nonce(&owner)
+    }
     pub fn get_swap_fee_bps(&self) -> u128 {
         let state = RouterState::get();
         state.swap_fee_bps
@@ -328,6 +736,21 @@ impl RouterService {
         let state = RouterState::get();
         state.lock
     }
+    pub fn get_pair_cache(&self) -> Vec<(ActorId, ActorId, ActorId)> {
+        let state = RouterState::get();
+        state
+            .pair_cache
+            .iter()
+            .map(|((token0, token1), cached)| (*token0, *token1, cached.pair))
+            .collect()
+    }
+    pub fn get_cached_pair(&self, token_a: ActorId, token_b: ActorId) -> Option<ActorId> {
+        let state = RouterState::get();
+        state
+            .pair_cache
+            .get(&Self::_pair_key(token_a, token_b))
+            .map(|cached| cached.pair)
+    }
     pub fn get_liquidity_join(&self, user: ActorId) -> Vec<LiquidityJoin> {
         let state = RouterState::get();
         if let Some(join) = state.liquidity_join.get(&user) {
@@ -338,18 +761,132 @@ impl RouterService {
     }
 
     // private functions
-    async fn _add_liquidity(
+    fn _pair_key(token_a: ActorId, token_b: ActorId) -> (ActorId, ActorId) {
+        if token_b > token_a {
+            (token_b, token_a)
+        } else {
+            (token_a, token_b)
+        }
+    }
+
+    fn _record_liquidity_join(user: ActorId, token_a: ActorId, token_b: ActorId, pair: ActorId) {
+        let router_state = RouterState::get_mut();
+        let join = router_state.liquidity_join.entry(user).or_default();
+        if !join.iter().any(|x| x.pair == pair) {
+            join.push(LiquidityJoin {
+                token_a,
+                token_b,
+                pair,
+            });
+        }
+    }
+
+    /// Creates the pair in the primary factory, forwarding its creation fee
+    /// out of the `budget` the caller attached. Whatever of `budget` is not
+    /// spent, including the change the factory replies with, is recorded as
+    /// a refund of `caller`. The caller holds the lock.
+    async fn _create_pair(
         &mut self,
+        caller: ActorId,
         token_a: ActorId,
         token_b: ActorId,
-        amount_a_desired: U256,
-        amount_b_desired: U256,
-        amount_a_min: U256,
-        amount_b_min: U256,
-    ) -> Result<(U256, U256), RouterError> {
-        let (reserve_a, reserve_b, _) = self.get_reserves(token_a, token_b).await?;
+        budget: u128,
+    ) -> Result<ActorId, RouterError> {
+        let before = exec::value_available();
+        let res = self._create_pair_for_fee(token_a, token_b, budget).await;
+        let spent = before.saturating_sub(exec::value_available());
+        self._record_refund(caller, NATIVE_VARA, U256::from(budget.saturating_sub(spent)));
+        res
+    }
 
-        if reserve_a.is_zero() && reserve_b.is_zero() {
+    async fn _create_pair_for_fee(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        budget: u128,
+    ) -> Result<ActorId, RouterError> {
+        let router_state = RouterState::get_mut();
+        // new pairs always go to the primary factory
+        let factory = router_state.primary_factory();
+        let pair_address = self._factory_pair(factory, token_a, token_b).await?;
+        if !pair_address.is_zero() {
+            return Err(RouterError::PairAlreadyExists);
+        }
+        let Ok(policy) = self
+            .factory_client
+            .get_pair_creation_policy()
+            .recv(factory)
+            .await
+        else {
+            return Err(RouterError::FactoryCallFailed {
+                factory,
+                error: None,
+            });
+        };
+        let create_pair_res = self
+            .factory_client
+            .create_pair(token_a, token_b)
+            .with_value(policy.creation_fee.min(budget))
+            .send_recv(factory)
+            .await;
+        let pair_address = match create_pair_res {
+            Ok(Ok(pair_address)) => pair_address,
+            Ok(Err(error)) => {
+                return Err(RouterError::FactoryCallFailed {
+                    factory,
+                    error: Some(error),
+                })
+            }
+            Err(_) => {
+                return Err(RouterError::FactoryCallFailed {
+                    factory,
+                    error: None,
+                })
+            }
+        };
+        router_state.pair_cache.insert(
+            Self::_pair_key(token_a, token_b),
+            CachedPair {
+                factory,
+                pair: pair_address,
+            },
+        );
+        self.emit_event(RouterEvent::CreatePair {
+            token_a,
+            token_b,
+            pair_address,
+        })
+        .ok();
+        Ok(pair_address)
+    }
+
+    /// Fails unless `pair` has no reserves yet, i.e. nobody minted or synced
+    /// into a pair the caller just created.
+    async fn _ensure_empty_pair(&self, pair: ActorId) -> Result<(), RouterError> {
+        let (reserve_0, reserve_1, _) = self
+            .lp_client
+            .get_reserves()
+            .recv(pair)
+            .await
+            .map_err(|_| RouterError::PairQueryFailed { pair })?;
+        if !reserve_0.is_zero() || !reserve_1.is_zero() {
+            return Err(RouterError::PairNotEmpty);
+        }
+        Ok(())
+    }
+
+    async fn _add_liquidity(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        amount_a_desired: U256,
+        amount_b_desired: U256,
+        amount_a_min: U256,
+        amount_b_min: U256,
+    ) -> Result<(U256, U256), RouterError> {
+        let (reserve_a, reserve_b, _) = self.get_reserves(token_a, token_b).await?;
+
+        if reserve_a.is_zero() && reserve_b.is_zero() {
             Ok((amount_a_desired, amount_b_desired))
         } else {
             let amount_b_optimal = self.quote(amount_a_desired, reserve_a, reserve_b)?;
@@ -369,11 +906,229 @@ impl RouterService {
         }
     }
 
+    fn _amount_out(
+        amount_in: U256,
+        reserve_in: U256,
+        reserve_out: U256,
+        fee_ppm: u128,
+    ) -> Result<U256, RouterError> {
+        if amount_in == U256::zero() {
+            return Err(RouterError::InsufficientInputAmount);
+        }
+        if reserve_in == U256::zero() || reserve_out == U256::zero() {
+            return Err(RouterError::InsufficientLiquidity);
+        }
+
+        let precision = U256::from(1_000_000);
+        let fee = precision - U256::from(fee_ppm);
+
+        let amount_in_with_fee = amount_in.checked_mul(fee).ok_or(RouterError::Overflow)?;
+        let numerator = amount_in_with_fee
+            .checked_mul(reserve_out)
+            .ok_or(RouterError::Overflow)?;
+        let denominator = reserve_in
+            .checked_mul(precision)
+            .and_then(|x| x.checked_add(amount_in_with_fee))
+            .ok_or(RouterError::Overflow)?;
+        let result = numerator
+            .checked_div(denominator)
+            .ok_or(RouterError::DivisionError)?;
+        Ok(result)
+    }
+
+    fn _amount_in(
+        amount_out: U256,
+        reserve_in: U256,
+        reserve_out: U256,
+        fee_ppm: u128,
+    ) -> Result<U256, RouterError> {
+        if amount_out == U256::zero() {
+            return Err(RouterError::InsufficientOutputAmount);
+        }
+        if reserve_in == U256::zero() || reserve_out == U256::zero() {
+            return Err(RouterError::InsufficientLiquidity);
+        }
+
+        let precision = U256::from(1_000_000);
+        let fee = precision - U256::from(fee_ppm);
+        let one = U256::one();
+
+        let numerator = reserve_in
+            .checked_mul(amount_out)
+            .and_then(|x| x.checked_mul(precision))
+            .ok_or(RouterError::Overflow)?;
+        let denominator = reserve_out
+            .checked_sub(amount_out)
+            .and_then(|x| x.checked_mul(fee))
+            .ok_or(RouterError::Overflow)?;
+        let result = numerator
+            .checked_div(denominator)
+            .and_then(|x| x.checked_add(one))
+            .ok_or(RouterError::DivisionError)?;
+        Ok(result)
+    }
+
+    async fn _factory_pair(
+        &self,
+        factory: ActorId,
+        token_a: ActorId,
+        token_b: ActorId,
+    ) -> Result<ActorId, RouterError> {
+        let pair_res = self.factory_client.get_pair(token_a, token_b).recv(factory).await;
+        match pair_res {
+            Ok(pair) => Ok(pair),
+            Err(_) => Err(RouterError::FactoryCallFailed {
+                factory,
+                error: None,
+            }),
+        }
+    }
+
+    /// Returns the pair and its fee from the cache, or from the first factory
+    /// that has one. The pair is zero when no factory has it.
+    async fn _resolve_pair(
+        &self,
+        token_a: ActorId,
+        token_b: ActorId,
+    ) -> Result<(ActorId, u128), RouterError> {
+        let router_state = RouterState::get_mut();
+        let key = Self::_pair_key(token_a, token_b);
+        if let Some(cached) = router_state.pair_cache.get(&key) {
+            if let Some(info) = router_state.factory_info(cached.factory) {
+                return Ok((cached.pair, info.fee_ppm));
+            }
+        }
+        for info in router_state.factories.clone() {
+            let pair = self._factory_pair(info.address, token_a, token_b).await?;
+            if !pair.is_zero() {
+                router_state.pair_cache.insert(
+                    key,
+                    CachedPair {
+                        factory: info.address,
+                        pair,
+                    },
+                );
+                return Ok((pair, info.fee_ppm));
+            }
+        }
+        Ok((ActorId::zero(), DEFAULT_PAIR_FEE_PPM))
+    }
+
+    /// Reserves of the hop's pair as `(pair, reserve_in, reserve_out, fee_ppm)`,
+    /// taken from `factory` when given, otherwise auto-resolved.
+    async fn _pair_reserves(
+        &self,
+        token_in: ActorId,
+        token_out: ActorId,
+        factory: Option<ActorId>,
+    ) -> Result<(ActorId, U256, U256, u128), RouterError> {
+        let (token0, _) = self.sort_tokens(token_in, token_out)?;
+        let (pair, fee_ppm) = match factory {
+            Some(factory) => {
+                let Some(info) = RouterState::get().factory_info(factory) else {
+                    return Err(RouterError::FactoryNotRegistered);
+                };
+                let fee_ppm = info.fee_ppm;
+                let pair = self._factory_pair(factory, token_in, token_out).await?;
+                (pair, fee_ppm)
+            }
+            None => self._resolve_pair(token_in, token_out).await?,
+        };
+        if pair.is_zero() {
+            return Err(RouterError::PairNotFound);
+        }
+        let res = self.lp_client.get_reserves().recv(pair).await;
+        match res {
+            Ok((reserve0, reserve1, _)) => {
+                if token_in == token0 {
+                    Ok((pair, reserve0, reserve1, fee_ppm))
+                } else {
+                    Ok((pair, reserve1, reserve0, fee_ppm))
+                }
+            }
+            Err(_) => Err(RouterError::PairQueryFailed { pair }),
+        }
+    }
+
+    // mirrors the checks done by the pair in `swap`
+    fn _simulate_hop(hop: &mut HopSimulation, fee_ppm: u128) -> Result<(), LpError> {
+        hop.k_before = hop
+            .reserve_in
+            .checked_mul(hop.reserve_out)
+            .ok_or(LpError::Overflow)?;
+        if hop.amount_out == U256::zero() {
+            return Err(LpError::InsufficientOutputAmount);
+        }
+        if hop.amount_out >= hop.reserve_out {
+            return Err(LpError::InsufficientLiquidity);
+        }
+        if hop.amount_in == U256::zero() {
+            return Err(LpError::InsufficientInputAmount);
+        }
+        hop.reserve_in_after = hop
+            .reserve_in
+            .checked_add(hop.amount_in)
+            .ok_or(LpError::Overflow)?;
+        hop.reserve_out_after = hop.reserve_out - hop.amount_out;
+        hop.k_after = hop
+            .reserve_in_after
+            .checked_mul(hop.reserve_out_after)
+            .ok_or(LpError::Overflow)?;
+
+        let balance_in_adjusted = hop
+            .reserve_in_after
+            .checked_mul(U256::from(1_000_000))
+            .and_then(|v| v.checked_sub(hop.amount_in.checked_mul(U256::from(fee_ppm))?))
+            .ok_or(LpError::Overflow)?;
+        let balance_out_adjusted = hop
+            .reserve_out_after
+            .checked_mul(U256::from(1_000_000))
+            .ok_or(LpError::Overflow)?;
+        let left = balance_in_adjusted
+            .checked_mul(balance_out_adjusted)
+            .ok_or(LpError::Overflow)?;
+        let right = hop
+            .k_before
+            .checked_mul(U256::from(1_000_000_000_000u128))
+            .ok_or(LpError::Overflow)?;
+        if left < right {
+            return Err(LpError::KConstant);
+        }
+        Ok(())
+    }
+
     async fn _swap(
         &mut self,
         amounts: Vec<U256>,
         path: Vec<ActorId>,
         to: ActorId,
+    ) -> Result<(), RouterError> {
+        self._swap_with_value(amounts, path, to, 0).await
+    }
+
+    async fn _swap_with_value(
+        &mut self,
+        amounts: Vec<U256>,
+        path: Vec<ActorId>,
+        to: ActorId,
+        value: u128,
+    ) -> Result<(), RouterError> {
+        let mut pairs = Vec::with_capacity(path.len() - 1);
+        for i in 0..path.len() - 1 {
+            pairs.push(self.pair_for(path[i], path[i + 1]).await?);
+        }
+        self._swap_through(amounts, path, pairs, to, value).await
+    }
+
+    /// `value` is attached to the first hop's swap, as the input of a native
+    /// VARA pair.
+    async fn _swap_through(
+        &mut self,
+        amounts: Vec<U256>,
+        path: Vec<ActorId>,
+        pairs: Vec<ActorId>,
+        to: ActorId,
+        value: u128,
     ) -> Result<(), RouterError> {
         for i in 0..path.len() - 1 {
             let (input, output) = (path[i], path[i + 1]);
@@ -384,19 +1139,30 @@ impl RouterService {
             } else {
                 (amount_out, U256::zero())
             };
-            let to = if i < path.len() - 2 {
-                self.pair_for(output, path[i + 2]).await?
-            } else {
-                to
-            };
-            let pair = self.pair_for(input, output).await?;
+            let to = if i < path.len() - 2 { pairs[i + 1] } else { to };
+            let pair = pairs[i];
             let swap_res = self
                 .lp_client
                 .swap(amount0_out, amount1_out, to)
+                .with_value(if i == 0 { value } else { 0 })
                 .send_recv(pair)
                 .await;
-            if swap_res.is_err() {
-                return Err(RouterError::SwapFailed);
+            match swap_res {
+                Ok(Ok(())) => {}
+                Ok(Err(error)) => {
+                    return Err(RouterError::PairSwapFailed {
+                        hop: i as u32,
+                        pair,
+                        error: Some(error),
+                    })
+                }
+                Err(_) => {
+                    return Err(RouterError::PairSwapFailed {
+                        hop: i as u32,
+                        pair,
+                        error: None,
+                    })
+                }
             }
         }
         Ok(())
@@ -414,13 +1180,14 @@ impl RouterService {
             .transfer_from(from, to, value)
             .send_recv(token)
             .await;
-        let Ok(transfer_token_status) = send_token_res else {
-            return Err(RouterError::TransferFromFailed);
-        };
-        if !transfer_token_status {
-            return Err(RouterError::TransferFromFailed);
-        } else {
+        if let Ok(true) = send_token_res {
             Ok(())
+        } else {
+            Err(RouterError::TokenTransferFromFailed {
+                token,
+                from,
+                to,
+            })
         }
     }
 
@@ -435,13 +1202,208 @@ impl RouterService {
             .transfer(to, value)
             .send_recv(token)
             .await;
-        let Ok(transfer_wvara_status) = transfer_wvara_res else {
-            return Err(RouterError::TransferFailed);
+        if let Ok(true) = transfer_wvara_res {
+            Ok(())
+        } else {
+            Err(RouterError::TokenTransferFailed {
+                token,
+                to,
+            })
+        }
+    }
+
+    /// Records `amount` of `token` held by the router as owed to `user`,
+    /// to be sent back by `claim_refunds`.
+    fn _record_refund(&mut self, user: ActorId, token: ActorId, amount: U256) {
+        if amount.is_zero() {
+            return;
+        }
+        RouterState::get_mut().record_refund(user, token, amount);
+        self.emit_event(RouterEvent::RefundRecorded {
+            user,
+            token,
+            amount,
+        })
+        .ok();
+    }
+
+    /// Takes the input of the hop `error` reports back from its pair and
+    /// records it as a refund of `owner`. Native pairs send VARA back with
+    /// their reply instead.
+    async fn _recover_failed_hop(&mut self, owner: ActorId, path: &[ActorId], error: &RouterError) {
+        let RouterError::PairSwapFailed { hop, pair, .. } = *error else {
+            return;
+        };
+        let mut held = [(path[hop as usize], U256::zero())];
+        self._return_excess(pair, &mut held).await;
+        self._record_refunds(owner, &held);
+    }
+
+    /// Takes back what `pair` holds of `held`'s tokens beyond its reserves
+    /// and adds it to `held`.
+    async fn _return_excess(&mut self, pair: ActorId, held: &mut [(ActorId, U256)]) {
+        for (token, amount) in held.iter_mut() {
+            if *token == NATIVE_VARA {
+                continue;
+            }
+            let returned = self
+                .lp_client
+                .return_excess(*token, exec::program_id())
+                .send_recv(pair)
+                .await;
+            if let Ok(Ok(returned)) = returned {
+                *amount += returned;
+            }
+        }
+    }
+
+    fn _record_refunds(&mut self, user: ActorId, refunds: &[(ActorId, U256)]) {
+        for (token, amount) in refunds {
+            self._record_refund(user, *token, *amount);
+        }
+    }
+
+    /// Sends `to` the `amount` of `token` the router holds for `sender`,
+    /// then calls `on_varswap_received(token, amount, sender, data)` on it.
+    /// A recipient that does not take the tokens sends them back to the
+    /// router before replying `false`; what comes back is recorded as a
+    /// refund of `sender`, as is the output if it cannot be sent.
+    async fn _notify_recipient(
+        &mut self,
+        sender: ActorId,
+        token: ActorId,
+        amount: U256,
+        to: ActorId,
+        data: Vec<u8>,
+    ) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        // the recipient must not reenter the router while it is notified
+        router_state.in_callback = true;
+
+        if let Err(error) = self._transfer(token, to, amount).await {
+            self._record_refund(sender, token, amount);
+            router_state.in_callback = false;
+            return Err(error);
+        }
+
+        let router = exec::program_id();
+        let balance_before = self.vft_client.balance_of(router).recv(token).await;
+        let payload = receiver_callback_payload(token, amount, sender, &data);
+        let reply = match msg::send_bytes_for_reply(to, payload, 0, 0)
+            .ok()
+            .and_then(|future| future.up_to(Some(RECEIVER_CALLBACK_TIMEOUT)).ok())
+        {
+            Some(future) => future.await.ok(),
+            None => None,
         };
-        if !transfer_wvara_status {
-            return Err(RouterError::TransferFailed);
+        if reply != Some(receiver_callback_accepted()) {
+            let balance_after = self.vft_client.balance_of(router).recv(token).await;
+            if let (Ok(before), Ok(after)) = (balance_before, balance_after) {
+                self._record_refund(sender, token, after.saturating_sub(before));
+            }
+            router_state.in_callback = false;
+            return Err(RouterError::RecipientCallbackFailed { to });
+        }
+        router_state.in_callback = false;
+
+        self.emit_event(RouterEvent::RecipientNotified {
+            to,
+            token,
+            amount,
+            sender,
+        })
+        .ok();
+        Ok(())
+    }
+
+    /// Pair of `token` with native VARA, zero when no factory has one.
+    /// Misses are cached too, with a zero factory, so that VARA entry points
+    /// of tokens without a native pair skip the factory queries; `sync_pair`
+    /// and `add_factory` drop them.
+    async fn _native_pair(&self, token: ActorId) -> Result<ActorId, RouterError> {
+        let key = Self::_pair_key(token, NATIVE_VARA);
+        if let Some(cached) = RouterState::get().pair_cache.get(&key) {
+            if cached.pair.is_zero() {
+                return Ok(ActorId::zero());
+            }
+        }
+        let pair = self.pair_for(token, NATIVE_VARA).await?;
+        if pair.is_zero() {
+            RouterState::get_mut().pair_cache.insert(
+                key,
+                CachedPair {
+                    factory: ActorId::zero(),
+                    pair,
+                },
+            );
+        }
+        Ok(pair)
+    }
+
+    /// For a VARA swap `path` with wVARA at its start (`vara_in`) or end,
+    /// the same path through the native pair of that hop, if there is one.
+    async fn _native_route(
+        &self,
+        path: &[ActorId],
+        vara_in: bool,
+    ) -> Result<Option<Vec<ActorId>>, RouterError> {
+        if path.len() < 2 {
+            return Err(RouterError::InvalidPath);
+        }
+        let (end, neighbour) = if vara_in {
+            (0, 1)
         } else {
-            Ok(())
+            (path.len() - 1, path.len() - 2)
+        };
+        if path[end] != RouterState::get().wvara_address {
+            return Ok(None);
+        }
+        if self._native_pair(path[neighbour]).await?.is_zero() {
+            return Ok(None);
+        }
+        let mut native_path = path.to_vec();
+        native_path[end] = NATIVE_VARA;
+        Ok(Some(native_path))
+    }
+
+    /// Swaps the attached VARA along `path`, which starts at a native pair,
+    /// sending `amounts[0]` with the first hop. Whatever the swap leaves of
+    /// the attached VARA goes back to `caller`: all of it if the first hop
+    /// fails, and the excess over `amounts[0]` otherwise. A VARA refund that
+    /// cannot be sent, and the input a later failed hop leaves in its pair,
+    /// are recorded as refunds of `caller`.
+    async fn _swap_native_vara_in(
+        &mut self,
+        caller: ActorId,
+        amounts: Vec<U256>,
+        path: Vec<ActorId>,
+        to: ActorId,
+    ) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        let vara_amount = msg::value();
+        router_state.lock = true;
+        let swap_res = self
+            ._swap_with_value(amounts.clone(), path.clone(), to, amounts[0].as_u128())
+            .await;
+        // a failed first hop returns its VARA with the reply
+        let spent = match &swap_res {
+            Ok(()) => amounts[0].as_u128(),
+            Err(error @ RouterError::PairSwapFailed { hop, .. }) if *hop > 0 => {
+                self._recover_failed_hop(caller, &path, error).await;
+                amounts[0].as_u128()
+            }
+            Err(_) => 0,
+        };
+        self._refund_vara(caller, vara_amount.saturating_sub(spent));
+        router_state.lock = false;
+        swap_res
+    }
+
+    /// Sends `user` back `amount` of the VARA it attached, recording it as a
+    /// refund if the message cannot be sent.
+    fn _refund_vara(&mut self, user: ActorId, amount: u128) {
+        if amount > 0 && msg::send_bytes(user, "Refund Vara".encode(), amount).is_err() {
+            self._record_refund(user, NATIVE_VARA, U256::from(amount));
         }
     }
 
@@ -487,7 +1449,7 @@ impl RouterService {
     pub async fn recover_pending_liquidity(&mut self, user: ActorId) -> Result<(), RouterError> {
         let router_state = RouterState::get_mut();
 
-        if msg::source() != router_state.admin {
+        if !has_role(Role::Treasurer, msg::source()) {
             return Err(RouterError::NotAdmin);
         }
 
@@ -515,8 +1477,7 @@ impl RouterService {
     }
 
     pub async fn skim_pair_liquidity(&mut self, pair: ActorId) -> Result<(), RouterError> {
-        let router_state = RouterState::get_mut();
-        if msg::source() != router_state.admin {
+        if !has_role(Role::Treasurer, msg::source()) {
             return Err(RouterError::NotAdmin);
         }
 
@@ -525,16 +1486,19 @@ impl RouterService {
             .skim(exec::program_id())
             .send_recv(pair)
             .await;
-        if skim_res.is_err() {
-            return Err(RouterError::SkimPairLiquidityFailed);
+        match skim_res {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(error)) => Err(RouterError::PairSkimFailed {
+                pair,
+                error: Some(error),
+            }),
+            Err(_) => Err(RouterError::PairSkimFailed { pair, error: None }),
         }
-
-        Ok(())
     }
 
     pub fn lock_router(&mut self) -> Result<(), RouterError> {
         let router_state = RouterState::get_mut();
-        if msg::source() != router_state.admin {
+        if !has_role(Role::Pauser, msg::source()) {
             return Err(RouterError::NotAdmin);
         }
         router_state.lock = true;
@@ -543,7 +1507,7 @@ impl RouterService {
 
     pub fn unlock_router(&mut self) -> Result<(), RouterError> {
         let router_state = RouterState::get_mut();
-        if msg::source() != router_state.admin {
+        if !has_role(Role::Pauser, msg::source()) {
             return Err(RouterError::NotAdmin);
         }
         router_state.lock = false;
@@ -552,98 +1516,272 @@ impl RouterService {
 
     // __________________________________________________________public functions__________________________________________________________
 
-    async fn transfer_from_liquidity(
+    /// Submits the caller's permit giving the router an allowance of
+    /// `liquidity` over its LP tokens of `pair`. A rejected permit is fine
+    /// if the allowance already covers `liquidity`, as when the same permit
+    /// was submitted to the pair first.
+    async fn _permit_liquidity(
         &mut self,
         pair: ActorId,
-        from: ActorId,
-        to: ActorId,
         liquidity: U256,
+        deadline: u64,
+        signature: PermitSignature,
     ) -> Result<(), RouterError> {
-        let transfer_liquidity_res = self
+        let owner = msg::source();
+        let router = exec::program_id();
+        let permit_res = self
             .lp_client
-            .transfer_from(from, to, liquidity)
-            .with_gas_limit(5_000_000_000)
+            .permit(owner, router, liquidity, deadline, signature)
             .send_recv(pair)
             .await;
-        let Ok(transfer_liquidity_status) = transfer_liquidity_res else {
-            return Err(RouterError::TransferFromLiquidityFailed);
+        let error = match permit_res {
+            Ok(Ok(())) => return Ok(()),
+            Ok(Err(error)) => Some(error),
+            Err(_) => None,
         };
-        if !transfer_liquidity_status {
-            return Err(RouterError::TransferFromLiquidityFailed);
-        } else {
-            Ok(())
+        // someone may have submitted the same permit first
+        let allowance = self.lp_client.allowance(owner, router).recv(pair).await;
+        if matches!(allowance, Ok(allowance) if allowance >= liquidity) {
+            return Ok(());
         }
+        Err(RouterError::PairPermitFailed { pair, error })
     }
 
-    pub async fn create_pair(
+    fn permit_signature(signature: PermitSignature) -> permit::PermitSignature {
+        match signature {
+            PermitSignature::Sr25519(signature) => permit::PermitSignature::Sr25519(signature),
+            PermitSignature::Ed25519(signature) => permit::PermitSignature::Ed25519(signature),
+        }
+    }
+
+    /// `add_liquidity_vara` into the native `pair` of `token`: the VARA
+    /// goes with the `mint` message instead of through wVARA.
+    async fn _add_liquidity_native(
         &mut self,
-        token_a: ActorId,
-        token_b: ActorId,
-    ) -> Result<(), RouterError> {
+        pair: ActorId,
+        token: ActorId,
+        amount_token_desired: U256,
+        amount_token_min: U256,
+        amount_vara_min: U256,
+        to: ActorId,
+    ) -> Result<(U256, U256, U256), RouterError> {
         let router_state = RouterState::get_mut();
-        if router_state.lock {
-            return Err(RouterError::IncorrectState);
-        }
+        let caller = msg::source();
+        let vara_amount = msg::value();
+        let amounts_res = self
+            ._add_liquidity(
+                token,
+                NATIVE_VARA,
+                amount_token_desired,
+                U256::from(vara_amount),
+                amount_token_min,
+                amount_vara_min,
+            )
+            .await;
+        let (amount_token, amount_vara) = match amounts_res {
+            Ok(amounts) => amounts,
+            Err(error) => {
+                self._refund_vara(caller, vara_amount);
+                return Err(error);
+            }
+        };
 
-        let pair_address = self.pair_for(token_a, token_b).await?;
-        if !pair_address.is_zero() {
-            return Err(RouterError::PairAlreadyExists);
+        router_state.lock = true;
+        Self::_record_liquidity_join(caller, token, NATIVE_VARA, pair);
+
+        // temporary pending liquidity
+        router_state.pending_liquidity.insert(
+            caller,
+            vec![PendingRefund {
+                token_addr: token,
+                amount: amount_token,
+                refunded: false,
+            }],
+        );
+
+        if let Err(error) = self._transfer_from(token, caller, pair, amount_token).await {
+            router_state.lock = false;
+            self._refund_vara(caller, vara_amount);
+            return Err(error);
         }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token) {
+                entry.refunded = true;
+            }
+        }
+
+        // a failed mint returns the VARA with its reply
+        let liquidity_res = self
+            .lp_client
+            .mint(to)
+            .with_value(amount_vara.as_u128())
+            .send_recv(pair)
+            .await;
+        let liquidity = match liquidity_res {
+            Ok(Ok(liquidity)) => liquidity,
+            Ok(Err(error)) => {
+                router_state.lock = false;
+                self._refund_vara(caller, vara_amount);
+                return Err(RouterError::PairMintFailed {
+                    pair,
+                    error: Some(error),
+                });
+            }
+            Err(_) => {
+                router_state.lock = false;
+                self._refund_vara(caller, vara_amount);
+                return Err(RouterError::PairMintFailed { pair, error: None });
+            }
+        };
+
+        self._refund_vara(caller, vara_amount - amount_vara.as_u128());
+        router_state.lock = false;
+        // clean the temporary pending liquidity
+        router_state.pending_liquidity.remove(&caller);
+
+        self.emit_event(RouterEvent::AddLiquidityVARA {
+            token_a: token,
+            amount_a: amount_token,
+            amount_vara,
+            to,
+            liquidity,
+        })
+        .ok();
+
+        Ok((amount_token, amount_vara, liquidity))
+    }
+
+    /// `remove_liquidity_vara` from the native `pair` of `token`. The pair
+    /// burns to the router, which checks the amounts it actually received
+    /// before forwarding them to `to`. Amounts the router cannot forward, or
+    /// that fall short of the minimums, are recorded as refunds of the
+    /// caller.
+    async fn _remove_liquidity_native(
+        &mut self,
+        pair: ActorId,
+        token: ActorId,
+        liquidity: U256,
+        amount_token_min: U256,
+        amount_vara_min: U256,
+        to: ActorId,
+    ) -> Result<(U256, U256), RouterError> {
+        let router_state = RouterState::get_mut();
+        let caller = msg::source();
+
         router_state.lock = true;
+        if let Err(error) = self
+            .transfer_from_liquidity(pair, caller, pair, liquidity)
+            .await
+        {
+            router_state.lock = false;
+            return Err(error);
+        }
 
-        if pair_address.is_zero() {
-            let create_fee = msg::value();
-            let create_pair_res = self
-                .factory_client
-                .create_pair(token_a, token_b)
-                .with_value(create_fee)
-                .send_recv(router_state.factory_address)
-                .await;
-            if create_pair_res.is_err() {
+        let burn_res = self.lp_client.burn(exec::program_id()).send_recv(pair).await;
+        let (amount_token, amount_vara) = match burn_res {
+            // the native side is always the pair's second token
+            Ok(Ok(amounts)) => amounts,
+            Ok(Err(error)) => {
                 router_state.lock = false;
-                return Err(RouterError::CreatePairFailed);
+                return Err(RouterError::PairBurnFailed {
+                    pair,
+                    error: Some(error),
+                });
             }
-            let mut pair_address = ActorId::zero();
-            if let Ok(pair_address_unwrap) = create_pair_res {
-                if let Ok(pair_address_res) = pair_address_unwrap {
-                    pair_address = pair_address_res;
-                } else {
-                    router_state.lock = false;
-                    return Err(RouterError::CreatePairFailed);
-                }
-            } else {
+            Err(_) => {
                 router_state.lock = false;
-                return Err(RouterError::CreatePairFailed);
+                return Err(RouterError::PairBurnFailed { pair, error: None });
             }
-            router_state.lock = false;  
-            self.emit_event(RouterEvent::CreatePair {
-                token_a,
-                token_b,
-                pair_address,
-            })
-            .ok();
+        };
+
+        let held = [(token, amount_token), (NATIVE_VARA, amount_vara)];
+        let checked = if amount_token < amount_token_min {
+            Err(RouterError::InsufficientTokenAmount)
+        } else if amount_vara < amount_vara_min {
+            Err(RouterError::InsufficientVaraAmount)
         } else {
+            self._transfer(token, to, amount_token).await
+        };
+        if let Err(error) = checked {
+            self._record_refunds(caller, &held);
             router_state.lock = false;
-            return Err(RouterError::PairAlreadyExists);
+            return Err(error);
         }
-        Ok(())
+        if msg::send_bytes(to, "Transfer Vara".encode(), amount_vara.as_u128()).is_err() {
+            self._record_refund(caller, NATIVE_VARA, amount_vara);
+        }
+        router_state.lock = false;
+
+        self.emit_event(RouterEvent::RemoveLiquidityVARA {
+            token_a: token,
+            amount_a_received: amount_token,
+            amount_vara_received: amount_vara,
+            to,
+            liquidity,
+        })
+        .ok();
+
+        Ok((amount_token, amount_vara))
     }
 
-    pub async fn add_liquidity(
+    async fn transfer_from_liquidity(
+        &mut self,
+        pair: ActorId,
+        from: ActorId,
+        to: ActorId,
+        liquidity: U256,
+    ) -> Result<(), RouterError> {
+        let transfer_liquidity_res = self
+            .lp_client
+            .transfer_from(from, to, liquidity)
+            .send_recv(pair)
+            .await;
+        if let Ok(true) = transfer_liquidity_res {
+            Ok(())
+        } else {
+            Err(RouterError::TokenTransferFromFailed {
+                token: pair,
+                from,
+                to,
+            })
+        }
+    }
+
+    /// `msg::value()` pays the factory's creation fee; the rest is recorded
+    /// as a refund.
+    pub async fn create_pair(
         &mut self,
         token_a: ActorId,
         token_b: ActorId,
-        amount_a_desired: U256,
-        amount_b_desired: U256,
-        amount_a_min: U256,
-        amount_b_min: U256,
+    ) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+        router_state.lock = true;
+        let res = self._create_pair(msg::source(), token_a, token_b, msg::value()).await;
+        router_state.lock = false;
+        res.map(|_| ())
+    }
+
+    /// Creates the pair and mints its first liquidity in one message, so the
+    /// initial price cannot be front-run: it fails with `PairNotEmpty`, and
+    /// skims the deposit back to the caller, if the pair got reserves before
+    /// the mint. `msg::value()` pays the creation fee; the rest is recorded
+    /// as a refund.
+    pub async fn create_pair_and_add_liquidity(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        amount_a: U256,
+        amount_b: U256,
         to: ActorId,
         deadline: u64,
-    ) -> Result<(U256, U256, U256), RouterError> {
+    ) -> Result<(ActorId, U256), RouterError> {
         let router_state = RouterState::get_mut();
         let caller = msg::source();
 
-        if router_state.lock {
+        if router_state.is_locked() {
             return Err(RouterError::IncorrectState);
         }
 
@@ -651,45 +1789,51 @@ impl RouterService {
             return Err(RouterError::Expired);
         }
 
+        if amount_a.is_zero() || amount_b.is_zero() {
+            return Err(RouterError::InvalidLiquidityAmount);
+        }
+
         let allowance_a_res = self
             .vft_client
             .allowance(caller, exec::program_id())
-            .recv(token_a.clone())
+            .recv(token_a)
             .await;
         let allowance_a = match allowance_a_res {
             Ok(val) => val,
             Err(_) => return Err(RouterError::InsufficientAllowance),
         };
-        if allowance_a < amount_a_desired {
+        if allowance_a < amount_a {
             return Err(RouterError::InsufficientAllowance);
         }
 
         let allowance_b_res = self
             .vft_client
             .allowance(caller, exec::program_id())
-            .recv(token_b.clone())
+            .recv(token_b)
             .await;
         let allowance_b = match allowance_b_res {
             Ok(val) => val,
             Err(_) => return Err(RouterError::InsufficientAllowance),
         };
-        if allowance_b < amount_b_desired {
+        if allowance_b < amount_b {
             return Err(RouterError::InsufficientAllowance);
         }
 
-        let (amount_a, amount_b) = self
-            ._add_liquidity(
-                token_a,
-                token_b,
-                amount_a_desired,
-                amount_b_desired,
-                amount_a_min,
-                amount_b_min,
-            )
-            .await?;
-
         router_state.lock = true;
 
+        let pair = match self._create_pair(caller, token_a, token_b, msg::value()).await {
+            Ok(pair) => pair,
+            Err(error) => {
+                router_state.lock = false;
+                return Err(error);
+            }
+        };
+        if let Err(error) = self._ensure_empty_pair(pair).await {
+            router_state.lock = false;
+            return Err(error);
+        }
+        Self::_record_liquidity_join(caller, token_a, token_b, pair);
+
         router_state.pending_liquidity.insert(
             caller,
             vec![
@@ -706,84 +1850,45 @@ impl RouterService {
             ],
         );
 
-        let pair = self.pair_for(token_a, token_b).await?;
-        if pair.is_zero() {
+        if let Err(error) = self._transfer_from(token_a, caller, pair, amount_a).await {
             router_state.lock = false;
-            return Err(RouterError::PairNotFound);
+            return Err(error);
         }
-
-        // check if the pair is already in the liquidity_join map
-        if let Some(join) = router_state.liquidity_join.get_mut(&caller) {
-            if !join.iter().any(|x| x.pair == pair) {
-                join.push(LiquidityJoin {
-                    token_a,
-                    token_b,
-                    pair,
-                });
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_a) {
+                entry.refunded = true;
             }
-        } else {
-            router_state.liquidity_join.insert(
-                caller,
-                vec![LiquidityJoin {
-                    token_a,
-                    token_b,
-                    pair,
-                }],
-            );
         }
-
-        let success_a = self
-            ._transfer_from(token_a, msg::source(), pair, amount_a)
-            .await
-            .is_ok();
-        if success_a {
-         
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_a) {
-                    entry.refunded = true;
-                }
-            }
-        } else {
+        if let Err(error) = self._transfer_from(token_b, caller, pair, amount_b).await {
             router_state.lock = false;
-            return Err(RouterError::TransferAFailed);
+            return Err(error);
         }
-        let success_b = self
-            ._transfer_from(token_b, msg::source(), pair, amount_b)
-            .await
-            .is_ok();
-        if success_b {
-           
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_b) {
-                    entry.refunded = true;
-                }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_b) {
+                entry.refunded = true;
             }
-        } else {
-            router_state.lock = false;  
-            return Err(RouterError::TransferBFailed);
         }
-        // Mint LP tokens
-        let mint_liquidity_res = self
-            .lp_client
-            .mint(to)
-            .send_recv(pair)
-            .await;
-        if mint_liquidity_res.is_err() {    
+
+        // someone may have minted or synced while the tokens were moving
+        if let Err(error) = self._ensure_empty_pair(pair).await {
+            let _ = self.lp_client.skim(caller).send_recv(pair).await;
             router_state.lock = false;
-            return Err(RouterError::MintLiquidityFailed);
+            return Err(error);
         }
-        let mut liquidity = U256::from(0);
-        if let Ok(liqui_res) = mint_liquidity_res {
-            if let Ok(liquidity_amount) = liqui_res {
-                liquidity = liquidity_amount;
-            } else {
+        let liquidity = match self.lp_client.mint(to).send_recv(pair).await {
+            Ok(Ok(liquidity)) => liquidity,
+            Ok(Err(error)) => {
                 router_state.lock = false;
-                return Err(RouterError::MintLiquidityFailed);
+                return Err(RouterError::PairMintFailed {
+                    pair,
+                    error: Some(error),
+                });
             }
-        } else {
-            router_state.lock = false;
-            return Err(RouterError::MintLiquidityFailed);
-        }
+            Err(_) => {
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed { pair, error: None });
+            }
+        };
         router_state.lock = false;
         // clean the temporary pending liquidity
         router_state.pending_liquidity.remove(&caller);
@@ -793,25 +1898,27 @@ impl RouterService {
             amount_a,
             amount_b,
             to,
-            liquidity: liquidity.clone(),
+            liquidity,
         })
         .ok();
-        Ok((amount_a, amount_b, liquidity))
+        Ok((pair, liquidity))
     }
 
-    pub async fn add_liquidity_vara(
+    /// VARA variant of `create_pair_and_add_liquidity`. `amount_vara` of the
+    /// attached value is wrapped and deposited, the rest pays the creation fee
+    /// with any change recorded as a refund.
+    pub async fn create_pair_and_add_liquidity_vara(
         &mut self,
         token: ActorId,
-        amount_token_desired: U256,
-        amount_token_min: U256,
-        amount_vara_min: U256,
+        amount_token: U256,
+        amount_vara: u128,
         to: ActorId,
         deadline: u64,
-    ) -> Result<(U256, U256, U256), RouterError> {
+    ) -> Result<(ActorId, U256), RouterError> {
         let router_state = RouterState::get_mut();
         let caller = msg::source();
 
-        if router_state.lock {
+        if router_state.is_locked() {
             return Err(RouterError::IncorrectState);
         }
 
@@ -819,65 +1926,48 @@ impl RouterService {
             return Err(RouterError::Expired);
         }
 
+        if amount_token.is_zero() || amount_vara == 0 {
+            return Err(RouterError::InvalidLiquidityAmount);
+        }
+        if msg::value() < amount_vara {
+            return Err(RouterError::InsufficientVaraAmount);
+        }
+
         let allowance_res = self
             .vft_client
             .allowance(caller, exec::program_id())
-            .recv(token.clone())
+            .recv(token)
             .await;
         let allowance = match allowance_res {
             Ok(val) => val,
             Err(_) => return Err(RouterError::InsufficientAllowance),
         };
-        if allowance < amount_token_desired {
+        if allowance < amount_token {
             return Err(RouterError::InsufficientAllowance);
         }
 
-        let wrapped_vara = router_state.wvara_address;
-        let amount_vara_desired = U256::from(msg::value());
-        let (amount_token, amount_vara) = self
-            ._add_liquidity(
-                token,
-                wrapped_vara,
-                amount_token_desired,
-                amount_vara_desired,
-                amount_token_min,
-                amount_vara_min,
-            )
-            .await?;
-
         router_state.lock = true;
 
-        let pair = self.pair_for(token, wrapped_vara).await?;
-        if pair.is_zero() {
+        let wrapped_vara = router_state.wvara_address;
+        let create_fee = msg::value() - amount_vara;
+        let pair = match self._create_pair(caller, token, wrapped_vara, create_fee).await {
+            Ok(pair) => pair,
+            Err(error) => {
+                router_state.lock = false;
+                let _ = msg::send_bytes(caller, "Refund Vara".encode(), amount_vara);
+                return Err(error);
+            }
+        };
+        if let Err(error) = self._ensure_empty_pair(pair).await {
             router_state.lock = false;
-            return Err(RouterError::PairNotFound);
+            let _ = msg::send_bytes(caller, "Refund Vara".encode(), amount_vara);
+            return Err(error);
         }
+        Self::_record_liquidity_join(caller, token, wrapped_vara, pair);
 
-        // check if the pair is already in the liquidity_join map
-
-        if let Some(join) = router_state.liquidity_join.get_mut(&caller) {
-            if !join.iter().any(|x| x.pair == pair) {
-                join.push(LiquidityJoin {
-                    token_a: token,
-                    token_b: wrapped_vara,
-                    pair,
-                });
-            }
-        } else {
-            router_state.liquidity_join.insert(
-                caller,
-                vec![LiquidityJoin {
-                    token_a: token,
-                    token_b: wrapped_vara,
-                    pair,
-                }],
-            );
-        }
-
-        let wrap_vara_res = self._wrap_vara(amount_vara.as_u128()).await;
-        if wrap_vara_res.is_err() {
+        if self._wrap_vara(amount_vara).await.is_err() {
             router_state.lock = false;
-            let _ = msg::send_bytes(caller, "Refund Vara".encode(), amount_vara.as_u128());
+            let _ = msg::send_bytes(caller, "Refund Vara".encode(), amount_vara);
             return Err(RouterError::DepositWVARAFailed);
         }
         // temporary pending liquidity
@@ -891,98 +1981,84 @@ impl RouterService {
                 },
                 PendingRefund {
                     token_addr: wrapped_vara,
-                    amount: amount_vara,
+                    amount: U256::from(amount_vara),
                     refunded: false,
                 },
             ],
         );
 
-        let success_a = self
-            ._transfer(wrapped_vara, pair, amount_vara)
+        if let Err(error) = self
+            ._transfer(wrapped_vara, pair, U256::from(amount_vara))
             .await
-            .is_ok();
-        if success_a {
-          
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
-                    entry.refunded = true;
-                }
-            }
-        } else {
+        {
             router_state.lock = false;
-            return Err(RouterError::DepositWVARAFailed);
+            return Err(error);
         }
-
-        let success_b = self
-            ._transfer_from(token, msg::source(), pair, amount_token)
-            .await
-            .is_ok();
-        if success_b {
-          
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token) {
-                    entry.refunded = true;
-                }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
+                entry.refunded = true;
             }
-        } else {
+        }
+        if let Err(error) = self._transfer_from(token, caller, pair, amount_token).await {
             router_state.lock = false;
-            return Err(RouterError::TransferBFailed);
+            return Err(error);
         }
-
-        // Mint LP tokens
-        let liquidity_res = self
-            .lp_client
-            .mint(to)
-            // .with_gas_limit(10_000_000_000)
-            .send_recv(pair)
-            .await;
-
-        let mut liquidity = U256::from(0);
-        if let Ok(liqui_res) = liquidity_res {
-            if let Ok(liquidity_amount) = liqui_res {
-                liquidity = liquidity_amount;
-            } else {
-                router_state.lock = false;
-                return Err(RouterError::MintLiquidityFailed);
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token) {
+                entry.refunded = true;
             }
-        } else {
-            router_state.lock = false;
-            return Err(RouterError::MintLiquidityFailed);
         }
 
-        if amount_vara.as_u128() < msg::value() {
-            let refund = msg::value() - amount_vara.as_u128();
-            let _ = msg::send_bytes(msg::source(), "Transfer Vara".encode(), refund);
+        // someone may have minted or synced while the tokens were moving; the
+        // skim returns the VARA side as wVARA
+        if let Err(error) = self._ensure_empty_pair(pair).await {
+            let _ = self.lp_client.skim(caller).send_recv(pair).await;
+            router_state.lock = false;
+            return Err(error);
         }
+        let liquidity = match self.lp_client.mint(to).send_recv(pair).await {
+            Ok(Ok(liquidity)) => liquidity,
+            Ok(Err(error)) => {
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed {
+                    pair,
+                    error: Some(error),
+                });
+            }
+            Err(_) => {
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed { pair, error: None });
+            }
+        };
         router_state.lock = false;
         // clean the temporary pending liquidity
         router_state.pending_liquidity.remove(&caller);
-
         self.emit_event(RouterEvent::AddLiquidityVARA {
             token_a: token,
             amount_a: amount_token,
-            amount_vara,
+            amount_vara: U256::from(amount_vara),
             to,
-            liquidity: liquidity.clone(),
+            liquidity,
         })
         .ok();
-
-        Ok((amount_token, amount_vara, liquidity))
+        Ok((pair, liquidity))
     }
 
-    pub async fn remove_liquidity_vara(
+    pub async fn add_liquidity(
         &mut self,
-        token: ActorId,
-        liquidity: U256,
-        amount_token_min: U256,
-        amount_vara_min: U256,
+        token_a: ActorId,
+        token_b: ActorId,
+        amount_a_desired: U256,
+        amount_b_desired: U256,
+        amount_a_min: U256,
+        amount_b_min: U256,
         to: ActorId,
         deadline: u64,
-    ) -> Result<(U256, U256), RouterError> {
+    ) -> Result<(U256, U256, U256), RouterError> {
         let router_state = RouterState::get_mut();
         let caller = msg::source();
 
-        if router_state.lock {
+        if router_state.is_locked() {
             return Err(RouterError::IncorrectState);
         }
 
@@ -990,243 +2066,1195 @@ impl RouterService {
             return Err(RouterError::Expired);
         }
 
-        let wrapped_vara = router_state.wvara_address;
-
-        router_state.lock = true;
-
-        let pair = self.pair_for(token, wrapped_vara).await?;
-        if pair.is_zero() {
-            router_state.lock = false;
-            return Err(RouterError::PairNotFound);
-        }
-        let transfer_liq_res = self
-            .transfer_from_liquidity(pair, msg::source(), pair, liquidity)
+        let allowance_a_res = self
+            .vft_client
+            .allowance(caller, exec::program_id())
+            .recv(token_a.clone())
             .await;
-
-        if transfer_liq_res.is_err() {
-            router_state.lock = false;
-            return Err(RouterError::TransferLiquidityFailed);
+        let allowance_a = match allowance_a_res {
+            Ok(val) => val,
+            Err(_) => return Err(RouterError::InsufficientAllowance),
+        };
+        if allowance_a < amount_a_desired {
+            return Err(RouterError::InsufficientAllowance);
         }
 
-        // Burn LP tokens
-        let burn_res = self
-            .lp_client
-            .burn(exec::program_id())
-            // .with_gas_limit(10_000_000_000)
-            .send_recv(pair)
+        let allowance_b_res = self
+            .vft_client
+            .allowance(caller, exec::program_id())
+            .recv(token_b.clone())
             .await;
-        let Ok((amount0, amount1)) = burn_res.unwrap() else {
-            router_state.lock = false;
-            return Err(RouterError::BurnLiquidityFailed);
+        let allowance_b = match allowance_b_res {
+            Ok(val) => val,
+            Err(_) => return Err(RouterError::InsufficientAllowance),
         };
+        if allowance_b < amount_b_desired {
+            return Err(RouterError::InsufficientAllowance);
+        }
 
-        let (token0, _) = self.sort_tokens(token, wrapped_vara)?;
-        let (amount_token, amount_vara) = if token == token0 {
-            (amount0, amount1)
-        } else {
-            (amount1, amount0)
-        };
+        let (amount_a, amount_b) = self
+            ._add_liquidity(
+                token_a,
+                token_b,
+                amount_a_desired,
+                amount_b_desired,
+                amount_a_min,
+                amount_b_min,
+            )
+            .await?;
 
-        if amount_token < amount_token_min {
-            router_state.lock = false;
-            return Err(RouterError::InsufficientTokenAmount);
-        }
-        if amount_vara < amount_vara_min {
-            router_state.lock = false;
-            return Err(RouterError::InsufficientVaraAmount);
-        }
+        let pair = self.pair_for(token_a, token_b).await?;
+
+        router_state.lock = true;
 
-        // temporary pending liquidity
         router_state.pending_liquidity.insert(
             caller,
             vec![
                 PendingRefund {
-                    token_addr: token,
-                    amount: amount_token,
+                    token_addr: token_a,
+                    amount: amount_a,
                     refunded: false,
                 },
                 PendingRefund {
-                    token_addr: wrapped_vara,
-                    amount: amount_vara,
+                    token_addr: token_b,
+                    amount: amount_b,
                     refunded: false,
                 },
             ],
         );
 
-        let success_a = self._transfer(token, to, amount_token).await.is_ok();
-        if success_a {
-           
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token) {
-                    entry.refunded = true;
-                }
+        if pair.is_zero() {
+            router_state.lock = false;
+            return Err(RouterError::PairNotFound);
+        }
+
+        // check if the pair is already in the liquidity_join map
+        if let Some(join) = router_state.liquidity_join.get_mut(&caller) {
+            if !join.iter().any(|x| x.pair == pair) {
+                join.push(LiquidityJoin {
+                    token_a,
+                    token_b,
+                    pair,
+                });
             }
         } else {
+            router_state.liquidity_join.insert(
+                caller,
+                vec![LiquidityJoin {
+                    token_a,
+                    token_b,
+                    pair,
+                }],
+            );
+        }
+
+        if let Err(error) = self
+            ._transfer_from(token_a, msg::source(), pair, amount_a)
+            .await
+        {
             router_state.lock = false;
-            return Err(RouterError::TransferAFailed);
-        }
-        let unwrap_success = self._unwrap_vara(amount_vara).await.is_ok();
-        if unwrap_success {
-           
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
-                    entry.refunded = true;
-                }
+            return Err(error);
+        }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_a) {
+                entry.refunded = true;
             }
-        } else {
+        }
+        if let Err(error) = self
+            ._transfer_from(token_b, msg::source(), pair, amount_b)
+            .await
+        {
             router_state.lock = false;
-            return Err(RouterError::WithdrawWvaraFailed);
+            return Err(error);
         }
-        // send vara to user
-        let _ = msg::send_bytes(to, "Transfer Vara".encode(), amount_vara.as_u128());
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_b) {
+                entry.refunded = true;
+            }
+        }
+        // Mint LP tokens
+        let mint_liquidity_res = self
+            .lp_client
+            .mint(to)
+            .send_recv(pair)
+            .await;
+        let liquidity = match mint_liquidity_res {
+            Ok(Ok(liquidity)) => liquidity,
+            Ok(Err(error)) => {
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed {
+                    pair,
+                    error: Some(error),
+                });
+            }
+            Err(_) => {
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed { pair, error: None });
+            }
+        };
         router_state.lock = false;
         // clean the temporary pending liquidity
         router_state.pending_liquidity.remove(&caller);
-
-        self.emit_event(RouterEvent::RemoveLiquidityVARA {
-            token_a: token,
-            amount_a_received: amount_token,
-            amount_vara_received: amount_vara,
+        self.emit_event(RouterEvent::AddLiquidity {
+            token_a,
+            token_b,
+            amount_a,
+            amount_b,
             to,
-            liquidity,
+            liquidity: liquidity.clone(),
         })
         .ok();
-
-        Ok((amount_token, amount_vara))
+        Ok((amount_a, amount_b, liquidity))
     }
 
-    pub async fn remove_liquidity(
+    pub async fn add_liquidity_vara(
         &mut self,
-        token_a: ActorId,
-        token_b: ActorId,
-        liquidity: U256,
-        amount_a_min: U256,
-        amount_b_min: U256,
+        token: ActorId,
+        amount_token_desired: U256,
+        amount_token_min: U256,
+        amount_vara_min: U256,
         to: ActorId,
         deadline: u64,
-    ) -> Result<(U256, U256), RouterError> {
+    ) -> Result<(U256, U256, U256), RouterError> {
+        let router_state = RouterState::get_mut();
         let caller = msg::source();
+
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+
         if deadline < exec::block_timestamp() {
             return Err(RouterError::Expired);
         }
 
-        let pair = self.pair_for(token_a, token_b).await?;
-
-        let router_state = RouterState::get_mut();
-
-        if router_state.lock {
-            return Err(RouterError::IncorrectState);
+        let allowance_res = self
+            .vft_client
+            .allowance(caller, exec::program_id())
+            .recv(token.clone())
+            .await;
+        let allowance = match allowance_res {
+            Ok(val) => val,
+            Err(_) => return Err(RouterError::InsufficientAllowance),
+        };
+        if allowance < amount_token_desired {
+            return Err(RouterError::InsufficientAllowance);
         }
 
-        // Transfer LP tokens to pair
-        router_state.lock = true;
-        let transfer_lp_res = self
-            .transfer_from_liquidity(pair, msg::source(), pair, liquidity)
-            .await;
-        if transfer_lp_res.is_err() {
-            router_state.lock = false;
-            return Err(RouterError::TransferLiquidityFailed);
+        let native_pair = self._native_pair(token).await?;
+        if !native_pair.is_zero() {
+            return self
+                ._add_liquidity_native(
+                    native_pair,
+                    token,
+                    amount_token_desired,
+                    amount_token_min,
+                    amount_vara_min,
+                    to,
+                )
+                .await;
         }
 
-        // Burn LP tokens
+        let wrapped_vara = router_state.wvara_address;
+        let amount_vara_desired = U256::from(msg::value());
+        let (amount_token, amount_vara) = self
+            ._add_liquidity(
+                token,
+                wrapped_vara,
+                amount_token_desired,
+                amount_vara_desired,
+                amount_token_min,
+                amount_vara_min,
+            )
+            .await?;
+
+        let pair = self.pair_for(token, wrapped_vara).await?;
+
+        router_state.lock = true;
+        if pair.is_zero() {
+            router_state.lock = false;
+            return Err(RouterError::PairNotFound);
+        }
+
+        // check if the pair is already in the liquidity_join map
+
+        if let Some(join) = router_state.liquidity_join.get_mut(&caller) {
+            if !join.iter().any(|x| x.pair == pair) {
+                join.push(LiquidityJoin {
+                    token_a: token,
+                    token_b: wrapped_vara,
+                    pair,
+                });
+            }
+        } else {
+            router_state.liquidity_join.insert(
+                caller,
+                vec![LiquidityJoin {
+                    token_a: token,
+                    token_b: wrapped_vara,
+                    pair,
+                }],
+            );
+        }
+
+        let wrap_vara_res = self._wrap_vara(amount_vara.as_u128()).await;
+        if wrap_vara_res.is_err() {
+            router_state.lock = false;
+            let _ = msg::send_bytes(caller, "Refund Vara".encode(), amount_vara.as_u128());
+            return Err(RouterError::DepositWVARAFailed);
+        }
+        // temporary pending liquidity
+        router_state.pending_liquidity.insert(
+            caller,
+            vec![
+                PendingRefund {
+                    token_addr: token,
+                    amount: amount_token,
+                    refunded: false,
+                },
+                PendingRefund {
+                    token_addr: wrapped_vara,
+                    amount: amount_vara,
+                    refunded: false,
+                },
+            ],
+        );
+
+        if let Err(error) = self._transfer(wrapped_vara, pair, amount_vara).await {
+            router_state.lock = false;
+            return Err(error);
+        }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
+                entry.refunded = true;
+            }
+        }
+
+        if let Err(error) = self
+            ._transfer_from(token, msg::source(), pair, amount_token)
+            .await
+        {
+            router_state.lock = false;
+            return Err(error);
+        }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token) {
+                entry.refunded = true;
+            }
+        }
+
+        // Mint LP tokens
+        let liquidity_res = self
+            .lp_client
+            .mint(to)
+            // .with_gas_limit(10_000_000_000)
+            .send_recv(pair)
+            .await;
+
+        let liquidity = match liquidity_res {
+            Ok(Ok(liquidity)) => liquidity,
+            Ok(Err(error)) => {
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed {
+                    pair,
+                    error: Some(error),
+                });
+            }
+            Err(_) => {
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed { pair, error: None });
+            }
+        };
+
+        if amount_vara.as_u128() < msg::value() {
+            let refund = msg::value() - amount_vara.as_u128();
+            let _ = msg::send_bytes(msg::source(), "Transfer Vara".encode(), refund);
+        }
+        router_state.lock = false;
+        // clean the temporary pending liquidity
+        router_state.pending_liquidity.remove(&caller);
+
+        self.emit_event(RouterEvent::AddLiquidityVARA {
+            token_a: token,
+            amount_a: amount_token,
+            amount_vara,
+            to,
+            liquidity: liquidity.clone(),
+        })
+        .ok();
+
+        Ok((amount_token, amount_vara, liquidity))
+    }
+
+    pub async fn remove_liquidity_vara(
+        &mut self,
+        token: ActorId,
+        liquidity: U256,
+        amount_token_min: U256,
+        amount_vara_min: U256,
+        to: ActorId,
+        deadline: u64,
+    ) -> Result<(U256, U256), RouterError> {
+        let router_state = RouterState::get_mut();
+        let caller = msg::source();
+
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+
+        if deadline < exec::block_timestamp() {
+            return Err(RouterError::Expired);
+        }
+
+        let native_pair = self._native_pair(token).await?;
+        if !native_pair.is_zero() {
+            return self
+                ._remove_liquidity_native(
+                    native_pair,
+                    token,
+                    liquidity,
+                    amount_token_min,
+                    amount_vara_min,
+                    to,
+                )
+                .await;
+        }
+
+        let wrapped_vara = router_state.wvara_address;
+
+        let pair = self.pair_for(token, wrapped_vara).await?;
+
+        router_state.lock = true;
+        if pair.is_zero() {
+            router_state.lock = false;
+            return Err(RouterError::PairNotFound);
+        }
+        if let Err(error) = self
+            .transfer_from_liquidity(pair, msg::source(), pair, liquidity)
+            .await
+        {
+            router_state.lock = false;
+            return Err(error);
+        }
+
+        // Burn LP tokens
         let burn_res = self
             .lp_client
             .burn(exec::program_id())
             // .with_gas_limit(10_000_000_000)
             .send_recv(pair)
             .await;
-        let Ok((amount0, amount1)) = burn_res.unwrap() else {
-            router_state.lock = false;
-            return Err(RouterError::MintLiquidityFailed);
+        let (amount0, amount1) = match burn_res {
+            Ok(Ok(amounts)) => amounts,
+            Ok(Err(error)) => {
+                router_state.lock = false;
+                return Err(RouterError::PairBurnFailed {
+                    pair,
+                    error: Some(error),
+                });
+            }
+            Err(_) => {
+                router_state.lock = false;
+                return Err(RouterError::PairBurnFailed { pair, error: None });
+            }
         };
 
-        let (token0, _) = self.sort_tokens(token_a, token_b)?;
-        let (amount_a, amount_b) = if token_a == token0 {
+        let (token0, _) = self.sort_tokens(token, wrapped_vara)?;
+        let (amount_token, amount_vara) = if token == token0 {
             (amount0, amount1)
         } else {
             (amount1, amount0)
         };
 
-        if amount_a < amount_a_min {
+        if amount_token < amount_token_min {
             router_state.lock = false;
-            return Err(RouterError::InsufficientAAmount);
+            return Err(RouterError::InsufficientTokenAmount);
         }
-        if amount_b < amount_b_min {
+        if amount_vara < amount_vara_min {
             router_state.lock = false;
-            return Err(RouterError::InsufficientBAmount);
+            return Err(RouterError::InsufficientVaraAmount);
         }
 
         // temporary pending liquidity
-
         router_state.pending_liquidity.insert(
             caller,
             vec![
                 PendingRefund {
-                    token_addr: token_a,
-                    amount: amount_a,
+                    token_addr: token,
+                    amount: amount_token,
                     refunded: false,
                 },
                 PendingRefund {
-                    token_addr: token_b,
-                    amount: amount_b,
+                    token_addr: wrapped_vara,
+                    amount: amount_vara,
                     refunded: false,
                 },
             ],
         );
 
-        let success_a = self._transfer(token_a, to, amount_a).await.is_ok();
-        if success_a {
-          
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_a) {
-                    entry.refunded = true;
-                }
-            }
-        } else {
+        if let Err(error) = self._transfer(token, to, amount_token).await {
             router_state.lock = false;
-            return Err(RouterError::TransferAFailed);
-        }
-        let success_b = self._transfer(token_b, to, amount_b).await.is_ok();
-        if success_b {
-            
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_b) {
-                    entry.refunded = true;
-                }
+            return Err(error);
+        }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token) {
+                entry.refunded = true;
             }
-        } else {
+        }
+        if let Err(error) = self._unwrap_vara(amount_vara).await {
             router_state.lock = false;
-            return Err(RouterError::TransferBFailed);
+            return Err(error);
         }
-
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
+                entry.refunded = true;
+            }
+        }
+        // send vara to user
+        let _ = msg::send_bytes(to, "Transfer Vara".encode(), amount_vara.as_u128());
         router_state.lock = false;
         // clean the temporary pending liquidity
         router_state.pending_liquidity.remove(&caller);
 
-        self.emit_event(RouterEvent::RemoveLiquidity {
-            token_a,
-            token_b,
-            amount_a_received: amount_a,
-            amount_b_received: amount_b,
+        self.emit_event(RouterEvent::RemoveLiquidityVARA {
+            token_a: token,
+            amount_a_received: amount_token,
+            amount_vara_received: amount_vara,
             to,
             liquidity,
         })
         .ok();
-        Ok((amount_a, amount_b))
+
+        Ok((amount_token, amount_vara))
+    }
+
+    pub async fn remove_liquidity(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        liquidity: U256,
+        amount_a_min: U256,
+        amount_b_min: U256,
+        to: ActorId,
+        deadline: u64,
+    ) -> Result<(U256, U256), RouterError> {
+        let caller = msg::source();
+        if deadline < exec::block_timestamp() {
+            return Err(RouterError::Expired);
+        }
+
+        let pair = self.pair_for(token_a, token_b).await?;
+
+        let router_state = RouterState::get_mut();
+
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+
+        // Transfer LP tokens to pair
+        router_state.lock = true;
+        if let Err(error) = self
+            .transfer_from_liquidity(pair, msg::source(), pair, liquidity)
+            .await
+        {
+            router_state.lock = false;
+            return Err(error);
+        }
+
+        // Burn LP tokens
+        let burn_res = self
+            .lp_client
+            .burn(exec::program_id())
+            // .with_gas_limit(10_000_000_000)
+            .send_recv(pair)
+            .await;
+        let (amount0, amount1) = match burn_res {
+            Ok(Ok(amounts)) => amounts,
+            Ok(Err(error)) => {
+                router_state.lock = false;
+                return Err(RouterError::PairBurnFailed {
+                    pair,
+                    error: Some(error),
+                });
+            }
+            Err(_) => {
+                router_state.lock = false;
+                return Err(RouterError::PairBurnFailed { pair, error: None });
+            }
+        };
+
+        let (token0, _) = self.sort_tokens(token_a, token_b)?;
+        let (amount_a, amount_b) = if token_a == token0 {
+            (amount0, amount1)
+        } else {
+            (amount1, amount0)
+        };
+
+        if amount_a < amount_a_min {
+            router_state.lock = false;
+            return Err(RouterError::InsufficientAAmount);
+        }
+        if amount_b < amount_b_min {
+            router_state.lock = false;
+            return Err(RouterError::InsufficientBAmount);
+        }
+
+        // temporary pending liquidity
+
+        router_state.pending_liquidity.insert(
+            caller,
+            vec![
+                PendingRefund {
+                    token_addr: token_a,
+                    amount: amount_a,
+                    refunded: false,
+                },
+                PendingRefund {
+                    token_addr: token_b,
+                    amount: amount_b,
+                    refunded: false,
+                },
+            ],
+        );
+
+        if let Err(error) = self._transfer(token_a, to, amount_a).await {
+            router_state.lock = false;
+            return Err(error);
+        }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_a) {
+                entry.refunded = true;
+            }
+        }
+        if let Err(error) = self._transfer(token_b, to, amount_b).await {
+            router_state.lock = false;
+            return Err(error);
+        }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == token_b) {
+                entry.refunded = true;
+            }
+        }
+
+        router_state.lock = false;
+        // clean the temporary pending liquidity
+        router_state.pending_liquidity.remove(&caller);
+
+        self.emit_event(RouterEvent::RemoveLiquidity {
+            token_a,
+            token_b,
+            amount_a_received: amount_a,
+            amount_b_received: amount_b,
+            to,
+            liquidity,
+        })
+        .ok();
+        Ok((amount_a, amount_b))
+    }
+
+    /// `remove_liquidity` with the router's LP allowance set by a permit the
+    /// caller signed for `liquidity` and `deadline`, instead of an `approve`.
+    pub async fn remove_liquidity_with_permit(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        liquidity: U256,
+        amount_a_min: U256,
+        amount_b_min: U256,
+        to: ActorId,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> Result<(U256, U256), RouterError> {
+        let pair = self.pair_for(token_a, token_b).await?;
+        if pair.is_zero() {
+            return Err(RouterError::PairNotFound);
+        }
+        self._permit_liquidity(pair, liquidity, deadline, signature).await?;
+        self.remove_liquidity(
+            token_a,
+            token_b,
+            liquidity,
+            amount_a_min,
+            amount_b_min,
+            to,
+            deadline,
+        )
+        .await
+    }
+
+    /// `remove_liquidity_vara` with a permit in place of the LP `approve`.
+    pub async fn remove_liquidity_vara_with_permit(
+        &mut self,
+        token: ActorId,
+        liquidity: U256,
+        amount_token_min: U256,
+        amount_vara_min: U256,
+        to: ActorId,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> Result<(U256, U256), RouterError> {
+        let wrapped_vara = RouterState::get().wvara_address;
+        let mut pair = self._native_pair(token).await?;
+        if pair.is_zero() {
+            pair = self.pair_for(token, wrapped_vara).await?;
+        }
+        if pair.is_zero() {
+            return Err(RouterError::PairNotFound);
+        }
+        self._permit_liquidity(pair, liquidity, deadline, signature).await?;
+        self.remove_liquidity_vara(
+            token,
+            liquidity,
+            amount_token_min,
+            amount_vara_min,
+            to,
+            deadline,
+        )
+        .await
+    }
+
+    /// Moves `liquidity` from the deprecated `old_pair` into the pair the
+    /// primary factory now registers for `token_a`/`token_b`, minting the new
+    /// LP to `to`. Token amounts the new pair's price does not take are sent
+    /// back to the caller; whatever the router holds when the migration stops
+    /// halfway is recorded for `claim_refunds`.
+    pub async fn migrate_liquidity(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        old_pair: ActorId,
+        liquidity: U256,
+        to: ActorId,
+        deadline: u64,
+    ) -> Result<(U256, U256, U256), RouterError> {
+        let caller = msg::source();
+        if deadline < exec::block_timestamp() {
+            return Err(RouterError::Expired);
+        }
+
+        let router_state = RouterState::get_mut();
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+
+        let factory = router_state.primary_factory();
+        let new_pair = self._factory_pair(factory, token_a, token_b).await?;
+        if new_pair.is_zero() {
+            return Err(RouterError::PairNotFound);
+        }
+        let history = match self
+            .factory_client
+            .get_pair_history(token_a, token_b)
+            .recv(factory)
+            .await
+        {
+            Ok(history) => history,
+            Err(_) => {
+                return Err(RouterError::FactoryCallFailed {
+                    factory,
+                    error: None,
+                })
+            }
+        };
+        if old_pair == new_pair || !history.iter().any(|migration| migration.old_pair == old_pair) {
+            return Err(RouterError::PairNotDeprecated);
+        }
+        // quotes below must use the new pair even if the factory push is late
+        router_state.pair_cache.insert(
+            Self::_pair_key(token_a, token_b),
+            CachedPair {
+                factory,
+                pair: new_pair,
+            },
+        );
+
+        router_state.lock = true;
+        if let Err(error) = self
+            .transfer_from_liquidity(old_pair, caller, old_pair, liquidity)
+            .await
+        {
+            router_state.lock = false;
+            return Err(error);
+        }
+
+        let burn_res = self
+            .lp_client
+            .burn(exec::program_id())
+            .send_recv(old_pair)
+            .await;
+        let (amount0, amount1) = match burn_res {
+            Ok(Ok(amounts)) => amounts,
+            Ok(Err(error)) => {
+                router_state.lock = false;
+                return Err(RouterError::PairBurnFailed {
+                    pair: old_pair,
+                    error: Some(error),
+                });
+            }
+            Err(_) => {
+                router_state.lock = false;
+                return Err(RouterError::PairBurnFailed {
+                    pair: old_pair,
+                    error: None,
+                });
+            }
+        };
+        let (token0, _) = self.sort_tokens(token_a, token_b)?;
+        let (burned_a, burned_b) = if token_a == token0 {
+            (amount0, amount1)
+        } else {
+            (amount1, amount0)
+        };
+
+        // what the router holds for the caller; wherever the migration
+        // stops, it is recorded as a refund
+        let mut held = [(token_a, burned_a), (token_b, burned_b)];
+
+        let (amount_a, amount_b) = match self
+            ._add_liquidity(token_a, token_b, burned_a, burned_b, U256::zero(), U256::zero())
+            .await
+        {
+            Ok(amounts) => amounts,
+            Err(error) => {
+                self._record_refunds(caller, &held);
+                router_state.lock = false;
+                return Err(error);
+            }
+        };
+
+        for (index, amount) in [amount_a, amount_b].into_iter().enumerate() {
+            if let Err(error) = self._transfer(held[index].0, new_pair, amount).await {
+                // a token already sent to the new pair is taken back
+                self._return_excess(new_pair, &mut held).await;
+                self._record_refunds(caller, &held);
+                router_state.lock = false;
+                return Err(error);
+            }
+            held[index].1 -= amount;
+        }
+
+        let mint_res = self
+            .lp_client
+            .mint(to)
+            .send_recv(new_pair)
+            .await;
+        let new_liquidity = match mint_res {
+            Ok(Ok(liquidity)) => liquidity,
+            Ok(Err(error)) => {
+                self._return_excess(new_pair, &mut held).await;
+                self._record_refunds(caller, &held);
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed {
+                    pair: new_pair,
+                    error: Some(error),
+                });
+            }
+            Err(_) => {
+                self._return_excess(new_pair, &mut held).await;
+                self._record_refunds(caller, &held);
+                router_state.lock = false;
+                return Err(RouterError::PairMintFailed {
+                    pair: new_pair,
+                    error: None,
+                });
+            }
+        };
+
+        // send back what the new pair's price did not take
+        for (token, amount) in held {
+            if !amount.is_zero() && self._transfer(token, caller, amount).await.is_err() {
+                self._record_refund(caller, token, amount);
+            }
+        }
+        router_state.lock = false;
+
+        Self::_record_liquidity_join(to, token_a, token_b, new_pair);
+        self.emit_event(RouterEvent::LiquidityMigrated {
+            token_a,
+            token_b,
+            old_pair,
+            new_pair,
+            liquidity_burned: liquidity,
+            liquidity_minted: new_liquidity,
+            to,
+        })
+        .ok();
+        Ok((amount_a, amount_b, new_liquidity))
+    }
+
+    pub async fn swap_exact_tokens_for_tokens(
+        &mut self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+    ) -> Result<Vec<U256>, RouterError> {
+        if RouterState::get().is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+
+        if deadline < exec::block_timestamp() {
+            return Err(RouterError::Expired);
+        }
+
+        let amounts = self
+            ._swap_exact_tokens_from(
+                msg::source(),
+                amount_in,
+                amount_out_min,
+                path.clone(),
+                to,
+                U256::zero(),
+            )
+            .await?;
+        let amount_out = amounts[amounts.len() - 1];
+
+        self.emit_event(RouterEvent::SwapExactTokensForTokens {
+            amount_in,
+            amount_out,
+            path: path.clone(),
+            to,
+        })
+        .ok();
+
+        Ok(amounts)
+    }
+
+    /// `swap_exact_tokens_for_tokens` for a program recipient. With `notify`
+    /// the output goes through the router, which sends it to `to` and then
+    /// calls `on_varswap_received(token, amount, sender, data)` on it; the
+    /// call fails with `RecipientCallbackFailed` unless that returns `true`.
+    /// VARA outputs have no callback: they reach `to` as message value.
+    pub async fn swap_exact_tokens_for_tokens_with_callback(
+        &mut self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+        notify: bool,
+        data: Vec<u8>,
+    ) -> Result<Vec<U256>, RouterError> {
+        if !notify {
+            return self
+                .swap_exact_tokens_for_tokens(amount_in, amount_out_min, path, to, deadline)
+                .await;
+        }
+        if RouterState::get().is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+
+        if deadline < exec::block_timestamp() {
+            return Err(RouterError::Expired);
+        }
+        if path.len() < 2 {
+            return Err(RouterError::InvalidPath);
+        }
+
+        let sender = msg::source();
+        let amounts = self
+            ._swap_exact_tokens_from(
+                sender,
+                amount_in,
+                amount_out_min,
+                path.clone(),
+                exec::program_id(),
+                U256::zero(),
+            )
+            .await?;
+        let amount_out = amounts[amounts.len() - 1];
+        self._notify_recipient(sender, path[path.len() - 1], amount_out, to, data)
+            .await?;
+
+        self.emit_event(RouterEvent::SwapExactTokensForTokens {
+            amount_in,
+            amount_out,
+            path,
+            to,
+        })
+        .ok();
+
+        Ok(amounts)
+    }
+
+    /// `swap_tokens_for_exact_tokens` with the recipient callback of
+    /// `swap_exact_tokens_for_tokens_with_callback`.
+    pub async fn swap_tokens_for_exact_tokens_with_callback(
+        &mut self,
+        amount_out: U256,
+        amount_in_max: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+        notify: bool,
+        data: Vec<u8>,
+    ) -> Result<Vec<U256>, RouterError> {
+        if !notify {
+            return self
+                .swap_tokens_for_exact_tokens(amount_out, amount_in_max, path, to, deadline)
+                .await;
+        }
+        let amounts = self
+            ._swap_tokens_for_exact_tokens(
+                amount_out,
+                amount_in_max,
+                path.clone(),
+                exec::program_id(),
+                to,
+                deadline,
+            )
+            .await?;
+        self._notify_recipient(msg::source(), path[path.len() - 1], amount_out, to, data)
+            .await?;
+        Ok(amounts)
+    }
+
+    /// `swap_exact_vara_for_tokens` with the recipient callback of
+    /// `swap_exact_tokens_for_tokens_with_callback`.
+    pub async fn swap_exact_vara_for_tokens_with_callback(
+        &mut self,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+        notify: bool,
+        data: Vec<u8>,
+    ) -> Result<Vec<U256>, RouterError> {
+        if !notify {
+            return self
+                .swap_exact_vara_for_tokens(amount_out_min, path, to, deadline)
+                .await;
+        }
+        let amounts = self
+            ._swap_exact_vara_for_tokens(amount_out_min, path.clone(), exec::program_id(), to, deadline)
+            .await?;
+        let amount_out = amounts[amounts.len() - 1];
+        self._notify_recipient(msg::source(), path[path.len() - 1], amount_out, to, data)
+            .await?;
+        Ok(amounts)
+    }
+
+    /// `swap_vara_for_exact_tokens` with the recipient callback of
+    /// `swap_exact_tokens_for_tokens_with_callback`.
+    pub async fn swap_vara_for_exact_tokens_with_callback(
+        &mut self,
+        amount_out: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+        notify: bool,
+        data: Vec<u8>,
+    ) -> Result<Vec<U256>, RouterError> {
+        if !notify {
+            return self
+                .swap_vara_for_exact_tokens(amount_out, path, to, deadline)
+                .await;
+        }
+        let amounts = self
+            ._swap_vara_for_exact_tokens(amount_out, path.clone(), exec::program_id(), to, deadline)
+            .await?;
+        self._notify_recipient(msg::source(), path[path.len() - 1], amount_out, to, data)
+            .await?;
+        Ok(amounts)
+    }
+
+    /// Body of `swap_exact_tokens_for_tokens` with `amount_in` paid by
+    /// `from`, whose allowance to the router must also cover `reserved`.
+    /// Only `PairSwapFailed` comes after `from`'s tokens left; what the
+    /// failed hop's pair held of them is then recorded as its refund.
+    async fn _swap_exact_tokens_from(
+        &mut self,
+        from: ActorId,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        reserved: U256,
+    ) -> Result<Vec<U256>, RouterError> {
+        let router_state = RouterState::get_mut();
+        let caller = from;
+
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+
+        let allowance_res = self
+            .vft_client
+            .allowance(caller, exec::program_id())
+            .recv(path[0].clone())
+            .await;
+        let allowance = match allowance_res {
+            Ok(val) => val,
+            Err(_) => return Err(RouterError::InsufficientAllowance),
+        };
+        if allowance < amount_in.saturating_add(reserved) {
+            return Err(RouterError::InsufficientAllowance);
+        }
+
+        let amounts = self.get_amounts_out(amount_in, path.clone()).await?;
+
+        router_state.lock = true;
+
+        let amount_out = amounts[amounts.len() - 1];
+
+        if amount_out < amount_out_min {
+            router_state.lock = false;
+            return Err(RouterError::InsufficientOutputAmount);
+        }
+
+        // every pair is looked up before the first transfer, so from then on
+        // only a hop can fail
+        let mut pairs = Vec::with_capacity(path.len() - 1);
+        for i in 0..path.len() - 1 {
+            match self.pair_for(path[i], path[i + 1]).await {
+                Ok(pair) if !pair.is_zero() => pairs.push(pair),
+                Ok(_) => {
+                    router_state.lock = false;
+                    return Err(RouterError::PairNotFound);
+                }
+                Err(error) => {
+                    router_state.lock = false;
+                    return Err(error);
+                }
+            }
+        }
+
+        if let Err(error) = self
+            ._transfer_from(path[0], caller, pairs[0], amounts[0])
+            .await
+        {
+            router_state.lock = false;
+            return Err(error);
+        }
+        if let Err(error) = self._swap_through(amounts.clone(), path.clone(), pairs, to, 0).await {
+            self._recover_failed_hop(caller, &path, &error).await;
+            router_state.lock = false;
+            return Err(error);
+        }
+
+        router_state.lock = false;
+
+        Ok(amounts)
+    }
+
+    /// Runs `intent` as its owner's `swap_exact_tokens_for_tokens`, for any
+    /// relayer that submits it with the owner's `signature` over
+    /// `SwapIntent::payload`. The owner's allowance must cover `amount_in`
+    /// plus the relayer's `fee`, at most `max_relayer_fee`, which goes to the
+    /// relayer once the swap is done. Each nonce runs at most once, and is
+    /// used up once the owner's tokens have left, even if a hop then fails.
+    pub async fn execute_signed_swap(
+        &mut self,
+        intent: SwapIntent,
+        signature: PermitSignature,
+        fee: U256,
+    ) -> Result<Vec<U256>, RouterError> {
+        let router_state = RouterState::get_mut();
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+        if intent.deadline < exec::block_timestamp() {
+            return Err(RouterError::Expired);
+        }
+        if intent.path.len() < 2 {
+            return Err(RouterError::InvalidPath);
+        }
+        if fee > intent.max_relayer_fee {
+            return Err(RouterError::ExcessiveRelayerFee);
+        }
+        let owner = intent.owner;
+        let nonce = router_state.intent_nonce(&owner);
+        if intent.nonce != nonce {
+            return Err(RouterError::InvalidNonce);
+        }
+        let payload = intent.payload(exec::program_id());
+        if !permit::verify(owner, &payload, &Self::permit_signature(signature)) {
+            return Err(RouterError::InvalidSignature);
+        }
+        // taken before the swap so the intent cannot run twice meanwhile
+        router_state.intent_nonces.insert(owner, nonce + 1);
+
+        let swap_res = self
+            ._swap_exact_tokens_from(
+                owner,
+                intent.amount_in,
+                intent.amount_out_min,
+                intent.path.clone(),
+                intent.to,
+                fee,
+            )
+            .await;
+        let amounts = match swap_res {
+            Ok(amounts) => amounts,
+            // the owner's tokens already left and were recorded as a refund
+            Err(error @ RouterError::PairSwapFailed { .. }) => return Err(error),
+            Err(error) => {
+                let router_state = RouterState::get_mut();
+                if router_state.intent_nonce(&owner) == nonce + 1 {
+                    router_state.intent_nonces.insert(owner, nonce);
+                }
+                return Err(error);
+            }
+        };
+
+        // the swap stands even if the fee cannot be paid; the event then
+        // reports no fee
+        let relayer = msg::source();
+        let mut relayer_fee = fee;
+        if !fee.is_zero()
+            && self
+                ._transfer_from(intent.path[0], owner, relayer, fee)
+                .await
+                .is_err()
+        {
+            relayer_fee = U256::zero();
+        }
+
+        self.emit_event(RouterEvent::SignedSwapExecuted {
+            owner,
+            relayer,
+            nonce,
+            amount_in: amounts[0],
+            amount_out: amounts[amounts.len() - 1],
+            path: intent.path,
+            to: intent.to,
+            relayer_fee,
+        })
+        .ok();
+
+        Ok(amounts)
     }
 
-    pub async fn swap_exact_tokens_for_tokens(
+    /// Invalidates the caller's signed swap intent with `nonce`, which must
+    /// be its current one, so no relayer can execute it.
+    pub fn cancel_nonce(&mut self, nonce: u64) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        let owner = msg::source();
+        if router_state.intent_nonce(&owner) != nonce {
+            return Err(RouterError::InvalidNonce);
+        }
+        router_state.intent_nonces.insert(owner, nonce + 1);
+        self.emit_event(RouterEvent::IntentNonceCancelled { owner, nonce })
+            .ok();
+        Ok(())
+    }
+
+    /// Like `swap_exact_tokens_for_tokens`, but hop `i` goes through the pair
+    /// of `factories[i]`, e.g. as returned by `get_best_amounts_out`. The
+    /// input a failed hop's pair held is recorded as the caller's refund.
+    pub async fn swap_exact_tokens_for_tokens_via(
         &mut self,
         amount_in: U256,
         amount_out_min: U256,
         path: Vec<ActorId>,
+        factories: Vec<ActorId>,
         to: ActorId,
         deadline: u64,
     ) -> Result<Vec<U256>, RouterError> {
         let router_state = RouterState::get_mut();
         let caller = msg::source();
 
-        if router_state.lock {
+        if router_state.is_locked() {
             return Err(RouterError::IncorrectState);
         }
 
@@ -1234,10 +3262,14 @@ impl RouterService {
             return Err(RouterError::Expired);
         }
 
+        if path.len() < 2 || factories.len() != path.len() - 1 {
+            return Err(RouterError::InvalidPath);
+        }
+
         let allowance_res = self
             .vft_client
             .allowance(caller, exec::program_id())
-            .recv(path[0].clone())
+            .recv(path[0])
             .await;
         let allowance = match allowance_res {
             Ok(val) => val,
@@ -1249,7 +3281,28 @@ impl RouterService {
 
         router_state.lock = true;
 
-        let amounts = self.get_amounts_out(amount_in, path.clone()).await?;
+        let mut amounts = vec![amount_in];
+        let mut pairs = Vec::with_capacity(factories.len());
+        for i in 0..path.len() - 1 {
+            let res = self
+                ._pair_reserves(path[i], path[i + 1], Some(factories[i]))
+                .await;
+            let (pair, reserve_in, reserve_out, fee_ppm) = match res {
+                Ok(reserves) => reserves,
+                Err(error) => {
+                    router_state.lock = false;
+                    return Err(error);
+                }
+            };
+            match Self::_amount_out(amounts[i], reserve_in, reserve_out, fee_ppm) {
+                Ok(amount_out) => amounts.push(amount_out),
+                Err(error) => {
+                    router_state.lock = false;
+                    return Err(error);
+                }
+            }
+            pairs.push(pair);
+        }
 
         let amount_out = amounts[amounts.len() - 1];
 
@@ -1258,53 +3311,28 @@ impl RouterService {
             return Err(RouterError::InsufficientOutputAmount);
         }
 
-        // Transfer tokens from sender to first pair
-        let first_pair = self.pair_for(path[0], path[1]).await?;
-        if first_pair.is_zero() {
-            router_state.lock = false;
-            return Err(RouterError::PairNotFound);
-        }
-
-        // temporary pending liquidity
-        router_state.pending_liquidity.insert(
-            caller,
-            vec![PendingRefund {
-                token_addr: path[0].clone(),
-                amount: amounts[0].clone(),
-                refunded: false,
-            }],
-        );
-
-        let transfer_success = self
-            ._transfer_from(path[0], msg::source(), first_pair, amounts[0])
+        if let Err(error) = self
+            ._transfer_from(path[0], caller, pairs[0], amounts[0])
             .await
-            .is_ok();
-        if !transfer_success {
+        {
             router_state.lock = false;
-            return Err(RouterError::TransferFailed);
+            return Err(error);
         }
-        // Perform the swap
-        let swap_success = self._swap(amounts.clone(), path.clone(), to).await.is_ok();
-        if !swap_success {
+        if let Err(error) = self
+            ._swap_through(amounts.clone(), path.clone(), pairs, to, 0)
+            .await
+        {
+            self._recover_failed_hop(caller, &path, &error).await;
             router_state.lock = false;
-            return Err(RouterError::SwapFailed);
-        }
-        if transfer_success && swap_success {
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == path[0].clone()) {
-                    entry.refunded = true;
-                }
-            }
+            return Err(error);
         }
 
         router_state.lock = false;
-        // clean the temporary pending liquidity
-        router_state.pending_liquidity.remove(&caller);
 
         self.emit_event(RouterEvent::SwapExactTokensForTokens {
             amount_in,
             amount_out,
-            path: path.clone(),
+            path,
             to,
         })
         .ok();
@@ -1319,6 +3347,21 @@ impl RouterService {
         path: Vec<ActorId>,
         to: ActorId,
         deadline: u64,
+    ) -> Result<Vec<U256>, RouterError> {
+        self._swap_tokens_for_exact_tokens(amount_out, amount_in_max, path, to, to, deadline)
+            .await
+    }
+
+    /// Body of `swap_tokens_for_exact_tokens`, paying `to` while the swap event names
+    /// `recipient`.
+    async fn _swap_tokens_for_exact_tokens(
+        &mut self,
+        amount_out: U256,
+        amount_in_max: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        recipient: ActorId,
+        deadline: u64,
     ) -> Result<Vec<U256>, RouterError> {
         if deadline < exec::block_timestamp() {
             return Err(RouterError::Expired);
@@ -1327,7 +3370,7 @@ impl RouterService {
         let router_state = RouterState::get_mut();
         let caller = msg::source();
 
-        if router_state.lock {
+        if router_state.is_locked() {
             return Err(RouterError::IncorrectState);
         }
 
@@ -1365,27 +3408,22 @@ impl RouterService {
             }],
         );
 
-        let transfer_success = self
+        if let Err(error) = self
             ._transfer_from(path[0], msg::source(), first_pair, amounts[0])
             .await
-            .is_ok();
-        if !transfer_success {
+        {
             router_state.lock = false;
-            return Err(RouterError::TransferFailed);
+            return Err(error);
         }
 
-        let swap_success = self._swap(amounts.clone(), path.clone(), to).await.is_ok();
-        if !swap_success {
+        if let Err(error) = self._swap(amounts.clone(), path.clone(), to).await {
             router_state.lock = false;
-            return Err(RouterError::SwapFailed);
+            return Err(error);
         }
 
-        if transfer_success && swap_success {
-           
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == path[0].clone()) {
-                    entry.refunded = true;
-                }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == path[0].clone()) {
+                entry.refunded = true;
             }
         }
 
@@ -1397,7 +3435,7 @@ impl RouterService {
             amount_out,
             amount_in: amounts[0],
             path: path.clone(),
-            to,
+            to: recipient,
         })
         .ok();
 
@@ -1410,6 +3448,20 @@ impl RouterService {
         path: Vec<ActorId>,
         to: ActorId,
         deadline: u64,
+    ) -> Result<Vec<U256>, RouterError> {
+        self._swap_exact_vara_for_tokens(amount_out_min, path, to, to, deadline)
+            .await
+    }
+
+    /// Body of `swap_exact_vara_for_tokens`, paying `to` while the swap event names
+    /// `recipient`.
+    async fn _swap_exact_vara_for_tokens(
+        &mut self,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        recipient: ActorId,
+        deadline: u64,
     ) -> Result<Vec<U256>, RouterError> {
         if deadline < exec::block_timestamp() {
             return Err(RouterError::Expired);
@@ -1417,12 +3469,31 @@ impl RouterService {
         let router_state = RouterState::get_mut();
         let caller = msg::source();
 
-        if router_state.lock {
+        if router_state.is_locked() {
             return Err(RouterError::IncorrectState);
         }
 
         let wrapped_vara = router_state.wvara_address;
         let vara_amount = msg::value();
+        if let Some(native_path) = self._native_route(&path, true).await? {
+            let amounts = self
+                .get_amounts_out(U256::from(vara_amount), native_path.clone())
+                .await?;
+            let amount_out = amounts[amounts.len() - 1];
+            if amount_out < amount_out_min {
+                return Err(RouterError::InsufficientOutputAmount);
+            }
+            self._swap_native_vara_in(caller, amounts.clone(), native_path, to)
+                .await?;
+            self.emit_event(RouterEvent::SwapExactVARAForTokens {
+                amount_in: U256::from(vara_amount),
+                amount_out,
+                path,
+                to: recipient,
+            })
+            .ok();
+            return Ok(amounts);
+        }
         let amounts = self
             .get_amounts_out(U256::from(vara_amount), path.clone())
             .await?;
@@ -1456,26 +3527,21 @@ impl RouterService {
             }],
         );
 
-        let transfer_success = self
+        if let Err(error) = self
             ._transfer(wrapped_vara, first_pair, U256::from(vara_amount))
             .await
-            .is_ok();
-        if !transfer_success {
+        {
             router_state.lock = false;
-            return Err(RouterError::DepositWVARAFailed);
+            return Err(error);
         }
 
-        let swap_success = self._swap(amounts.clone(), path.clone(), to).await.is_ok();
-        if !swap_success {
+        if let Err(error) = self._swap(amounts.clone(), path.clone(), to).await {
             router_state.lock = false;
-            return Err(RouterError::SwapFailed);
+            return Err(error);
         }
-        if transfer_success && swap_success {
-          
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
-                    entry.refunded = true;
-                }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
+                entry.refunded = true;
             }
         }
 
@@ -1487,7 +3553,7 @@ impl RouterService {
             amount_in: U256::from(vara_amount),
             amount_out,
             path: path.clone(),
-            to,
+            to: recipient,
         })
         .ok();
         Ok(amounts)
@@ -1506,6 +3572,31 @@ impl RouterService {
         }
         let router_state = RouterState::get_mut();
         let caller = msg::source();
+        if let Some(native_path) = self._native_route(&path, false).await? {
+            let amounts = self.get_amounts_in(amount_out, native_path.clone()).await?;
+            if amounts[0] > amount_in_max {
+                return Err(RouterError::ExcessiveInputAmount);
+            }
+            // at least `amount_out` for exactly `amounts[0]`
+            let amounts = self
+                ._swap_exact_tokens_from(
+                    caller,
+                    amounts[0],
+                    amount_out,
+                    native_path,
+                    to,
+                    U256::zero(),
+                )
+                .await?;
+            self.emit_event(RouterEvent::SwapTokensForExactVARA {
+                amount_out: amounts[amounts.len() - 1],
+                amount_in: amounts[0],
+                path,
+                to,
+            })
+            .ok();
+            return Ok(amounts);
+        }
         let amounts = self.get_amounts_in(amount_out, path.clone()).await?;
         if amounts[0] > amount_in_max {
             return Err(RouterError::ExcessiveInputAmount);
@@ -1528,7 +3619,7 @@ impl RouterService {
             return Err(RouterError::InsufficientAllowance);
         }
 
-        if router_state.lock {
+        if router_state.is_locked() {
             return Err(RouterError::IncorrectState);
         }
 
@@ -1544,34 +3635,28 @@ impl RouterService {
             }],
         );
 
-        let transfer_success = self
+        if let Err(error) = self
             ._transfer_from(path[0], msg::source(), first_pair, amounts[0])
             .await
-            .is_ok();
-        if !transfer_success {
+        {
             router_state.lock = false;
-            return Err(RouterError::TransferFailed);
+            return Err(error);
         }
-        let swap_success = self
+        if let Err(error) = self
             ._swap(amounts.clone(), path.clone(), exec::program_id())
             .await
-            .is_ok();
-        if !swap_success {
+        {
             router_state.lock = false;
-            return Err(RouterError::SwapFailed);
+            return Err(error);
         }
         let amount_vara_out = amounts[amounts.len() - 1];
-        let unwrap_success = self._unwrap_vara(amount_vara_out).await.is_ok();
-        if !unwrap_success {
+        if let Err(error) = self._unwrap_vara(amount_vara_out).await {
             router_state.lock = false;
-            return Err(RouterError::WithdrawWvaraFailed);
+            return Err(error);
         }
-        if transfer_success && swap_success && unwrap_success {
-           
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == path[0].clone()) {
-                    entry.refunded = true;
-                }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == path[0].clone()) {
+                entry.refunded = true;
             }
         }
 
@@ -1605,6 +3690,28 @@ impl RouterService {
         let router_state = RouterState::get_mut();
         let caller = msg::source();
 
+        // the native pair sends the VARA to `to` itself
+        if let Some(native_path) = self._native_route(&path, false).await? {
+            let amounts = self
+                ._swap_exact_tokens_from(
+                    caller,
+                    amount_in,
+                    amount_out_min,
+                    native_path,
+                    to,
+                    U256::zero(),
+                )
+                .await?;
+            self.emit_event(RouterEvent::SwapExactTokensForVARA {
+                amount_in,
+                amount_out: amounts[amounts.len() - 1],
+                path,
+                to,
+            })
+            .ok();
+            return Ok(amounts);
+        }
+
         let amounts = self.get_amounts_out(amount_in, path.clone()).await?;
         let amount_vara_out = amounts[amounts.len() - 1];
         if amount_vara_out < amount_out_min {
@@ -1640,35 +3747,29 @@ impl RouterService {
             }],
         );
 
-        let transfer_success = self
+        if let Err(error) = self
             ._transfer_from(path[0], msg::source(), first_pair, amount_in)
             .await
-            .is_ok();
-        if !transfer_success {
+        {
             router_state.lock = false;
-            return Err(RouterError::TransferFailed);
+            return Err(error);
         }
-        let swap_success = self
+        if let Err(error) = self
             ._swap(amounts.clone(), path.clone(), exec::program_id())
             .await
-            .is_ok();
-        if !swap_success {
+        {
             router_state.lock = false;
-            return Err(RouterError::SwapFailed);
+            return Err(error);
         }
 
-        let unwrap_success = self._unwrap_vara(amount_vara_out).await.is_ok();
-        if !unwrap_success {
+        if let Err(error) = self._unwrap_vara(amount_vara_out).await {
             router_state.lock = false;
-            return Err(RouterError::WithdrawWvaraFailed);
+            return Err(error);
         }
 
-        if transfer_success && swap_success && unwrap_success {
-           
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == path[0].clone()) {
-                    entry.refunded = true;
-                }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == path[0].clone()) {
+                entry.refunded = true;
             }
         }
 
@@ -1695,6 +3796,20 @@ impl RouterService {
         path: Vec<ActorId>,
         to: ActorId,
         deadline: u64,
+    ) -> Result<Vec<U256>, RouterError> {
+        self._swap_vara_for_exact_tokens(amount_out, path, to, to, deadline)
+            .await
+    }
+
+    /// Body of `swap_vara_for_exact_tokens`, paying `to` while the swap event names
+    /// `recipient`.
+    async fn _swap_vara_for_exact_tokens(
+        &mut self,
+        amount_out: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        recipient: ActorId,
+        deadline: u64,
     ) -> Result<Vec<U256>, RouterError> {
         if deadline < exec::block_timestamp() {
             return Err(RouterError::Expired);
@@ -1705,6 +3820,26 @@ impl RouterService {
         let wrapped_vara = router_state.wvara_address;
         let vara_amount = msg::value();
 
+        if let Some(native_path) = self._native_route(&path, true).await? {
+            let amounts = self.get_amounts_in(amount_out, native_path.clone()).await?;
+            if amounts[0].as_u128() > vara_amount {
+                return Err(RouterError::ExcessiveInputAmount);
+            }
+            if router_state.is_locked() {
+                return Err(RouterError::IncorrectState);
+            }
+            self._swap_native_vara_in(caller, amounts.clone(), native_path, to)
+                .await?;
+            self.emit_event(RouterEvent::SwapVARAForExactTokens {
+                amount_out,
+                amount_in: amounts[0],
+                path,
+                to: recipient,
+            })
+            .ok();
+            return Ok(amounts);
+        }
+
         let amounts = self.get_amounts_in(amount_out, path.clone()).await?;
         if amounts[0].as_u128() > vara_amount {
             return Err(RouterError::ExcessiveInputAmount);
@@ -1717,7 +3852,7 @@ impl RouterService {
 
         let router_state = RouterState::get_mut();
 
-        if router_state.lock {
+        if router_state.is_locked() {
             return Err(RouterError::IncorrectState);
         }
 
@@ -1740,26 +3875,18 @@ impl RouterService {
             }],
         );
 
-        let dep_success = self
-            ._transfer(wrapped_vara, first_pair, amounts[0])
-            .await
-            .is_ok();
-        if !dep_success {
+        if let Err(error) = self._transfer(wrapped_vara, first_pair, amounts[0]).await {
             router_state.lock = false;
-            return Err(RouterError::DepositWVARAFailed);
+            return Err(error);
         }
 
-        let swap_success = self._swap(amounts.clone(), path.clone(), to).await.is_ok();
-        if !swap_success {
+        if let Err(error) = self._swap(amounts.clone(), path.clone(), to).await {
             router_state.lock = false;
-            return Err(RouterError::SwapFailed);
+            return Err(error);
         }
-        if dep_success && swap_success {
-          
-            if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
-                if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
-                    entry.refunded = true;
-                }
+        if let Some(refs) = router_state.pending_liquidity.get_mut(&caller) {
+            if let Some(entry) = refs.iter_mut().find(|x| x.token_addr == wrapped_vara) {
+                entry.refunded = true;
             }
         }
 
@@ -1778,10 +3905,281 @@ impl RouterService {
             amount_out,
             amount_in: amounts[0],
             path: path.clone(),
+            to: recipient,
+        })
+        .ok();
+
+        Ok(amounts)
+    }
+
+    // __________________________________________________________vault functions__________________________________________________________
+
+    /// Moves `amount` of `token` from the caller, who must have approved
+    /// the router, into its vault and returns the new vault balance.
+    pub async fn deposit_to_vault(
+        &mut self,
+        token: ActorId,
+        amount: U256,
+    ) -> Result<U256, RouterError> {
+        if RouterState::get().is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+        if amount.is_zero() {
+            return Err(RouterError::InsufficientInputAmount);
+        }
+        let owner = msg::source();
+        self._transfer_from(token, owner, exec::program_id(), amount)
+            .await?;
+
+        let router_state = RouterState::get_mut();
+        router_state.credit_vault(owner, token, amount);
+        self.emit_event(RouterEvent::VaultDeposited {
+            owner,
+            token,
+            amount,
+        })
+        .ok();
+        Ok(router_state.vault_balance(owner, token))
+    }
+
+    /// Wraps the attached VARA once and credits it to the caller's wVARA
+    /// vault balance, which it returns.
+    pub async fn deposit_vara_to_vault(&mut self) -> Result<U256, RouterError> {
+        let owner = msg::source();
+        let vara_amount = msg::value();
+        if RouterState::get().is_locked() {
+            let _ = msg::send_bytes(owner, "Refund Vara".encode(), vara_amount);
+            return Err(RouterError::IncorrectState);
+        }
+        if vara_amount == 0 {
+            return Err(RouterError::InsufficientVaraAmount);
+        }
+        if self._wrap_vara(vara_amount).await.is_err() {
+            let _ = msg::send_bytes(owner, "Refund Vara".encode(), vara_amount);
+            return Err(RouterError::DepositWVARAFailed);
+        }
+
+        let router_state = RouterState::get_mut();
+        let wrapped_vara = router_state.wvara_address;
+        let amount = U256::from(vara_amount);
+        router_state.credit_vault(owner, wrapped_vara, amount);
+        self.emit_event(RouterEvent::VaultDeposited {
+            owner,
+            token: wrapped_vara,
+            amount,
+        })
+        .ok();
+        Ok(router_state.vault_balance(owner, wrapped_vara))
+    }
+
+    /// Sends `amount` of `token` from the caller's vault to `to` and returns
+    /// what is left in the vault.
+    pub async fn withdraw_from_vault(
+        &mut self,
+        token: ActorId,
+        amount: U256,
+        to: ActorId,
+    ) -> Result<U256, RouterError> {
+        let router_state = RouterState::get_mut();
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+        let owner = msg::source();
+        // taken out before the transfer so it cannot be withdrawn twice
+        router_state.debit_vault(owner, token, amount)?;
+        if let Err(error) = self._transfer(token, to, amount).await {
+            RouterState::get_mut().credit_vault(owner, token, amount);
+            return Err(error);
+        }
+
+        self.emit_event(RouterEvent::VaultWithdrawn {
+            owner,
+            token,
+            amount,
+            to,
+        })
+        .ok();
+        Ok(RouterState::get().vault_balance(owner, token))
+    }
+
+    /// Unwraps `amount` of the caller's wVARA vault balance and sends it to
+    /// `to` as VARA.
+    pub async fn withdraw_vara_from_vault(
+        &mut self,
+        amount: U256,
+        to: ActorId,
+    ) -> Result<U256, RouterError> {
+        let router_state = RouterState::get_mut();
+        if router_state.is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+        let owner = msg::source();
+        let wrapped_vara = router_state.wvara_address;
+        router_state.debit_vault(owner, wrapped_vara, amount)?;
+        if let Err(error) = self._unwrap_vara(amount).await {
+            RouterState::get_mut().credit_vault(owner, wrapped_vara, amount);
+            return Err(error);
+        }
+        let _ = msg::send_bytes(to, "Transfer Vara".encode(), amount.as_u128());
+
+        self.emit_event(RouterEvent::VaultWithdrawn {
+            owner,
+            token: wrapped_vara,
+            amount,
             to,
         })
         .ok();
+        Ok(RouterState::get().vault_balance(owner, wrapped_vara))
+    }
+
+    /// Sends the caller every refund recorded for it and returns what was
+    /// sent, as (token, amount). Refunds whose transfer fails stay recorded.
+    pub async fn claim_refunds(&mut self) -> Result<Vec<(ActorId, U256)>, RouterError> {
+        let user = msg::source();
+        let router_state = RouterState::get_mut();
+        let refunds = self.get_refunds(user);
+        if refunds.is_empty() {
+            return Err(RouterError::NoRefunds);
+        }
+        // taken out before the transfers so they cannot be claimed twice
+        for (token, _) in refunds.iter() {
+            router_state.refunds.remove(&(user, *token));
+        }
+
+        let mut claimed = Vec::new();
+        for (token, amount) in refunds {
+            if token == NATIVE_VARA {
+                if msg::send_bytes(user, "Refund Vara".encode(), amount.as_u128()).is_err() {
+                    RouterState::get_mut().record_refund(user, token, amount);
+                    continue;
+                }
+            } else if self._transfer(token, user, amount).await.is_err() {
+                RouterState::get_mut().record_refund(user, token, amount);
+                continue;
+            }
+            self.emit_event(RouterEvent::RefundClaimed {
+                user,
+                token,
+                amount,
+            })
+            .ok();
+            claimed.push((token, amount));
+        }
+        Ok(claimed)
+    }
+
+    /// `swap_exact_tokens_for_tokens` paid from the caller's vault balance
+    /// of `path[0]`, with the output credited to its vault balance of the
+    /// last token. Skips the allowance query and the `transfer_from`.
+    pub async fn swap_exact_tokens_for_tokens_internal(
+        &mut self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        deadline: u64,
+    ) -> Result<Vec<U256>, RouterError> {
+        if deadline < exec::block_timestamp() {
+            return Err(RouterError::Expired);
+        }
+        if RouterState::get().is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+        let owner = msg::source();
+        let amounts = self.get_amounts_out(amount_in, path.clone()).await?;
+        let amount_out = amounts[amounts.len() - 1];
+        if amount_out < amount_out_min {
+            return Err(RouterError::InsufficientOutputAmount);
+        }
+
+        self._swap_internal(owner, amounts.clone(), path.clone())
+            .await?;
+
+        self.emit_event(RouterEvent::SwapExactTokensForTokensInternal {
+            owner,
+            amount_in,
+            amount_out,
+            path,
+        })
+        .ok();
+        Ok(amounts)
+    }
+
+    /// `swap_tokens_for_exact_tokens` between the caller's vault balances.
+    pub async fn swap_tokens_for_exact_tokens_internal(
+        &mut self,
+        amount_out: U256,
+        amount_in_max: U256,
+        path: Vec<ActorId>,
+        deadline: u64,
+    ) -> Result<Vec<U256>, RouterError> {
+        if deadline < exec::block_timestamp() {
+            return Err(RouterError::Expired);
+        }
+        if RouterState::get().is_locked() {
+            return Err(RouterError::IncorrectState);
+        }
+        let owner = msg::source();
+        let amounts = self.get_amounts_in(amount_out, path.clone()).await?;
+        if amounts[0] > amount_in_max {
+            return Err(RouterError::ExcessiveInputAmount);
+        }
+
+        self._swap_internal(owner, amounts.clone(), path.clone())
+            .await?;
 
+        self.emit_event(RouterEvent::SwapTokensForExactTokensInternal {
+            owner,
+            amount_out,
+            amount_in: amounts[0],
+            path,
+        })
+        .ok();
         Ok(amounts)
     }
+
+    /// Debits `amounts[0]` of `path[0]` from `owner`'s vault, sends it from
+    /// the router to the first pair and credits the output back to the
+    /// vault. If a hop fails after the input left the router, what its pair
+    /// held of it is recorded as `owner`'s refund, as with external swaps.
+    async fn _swap_internal(
+        &mut self,
+        owner: ActorId,
+        amounts: Vec<U256>,
+        path: Vec<ActorId>,
+    ) -> Result<(), RouterError> {
+        let router_state = RouterState::get_mut();
+        let (token_in, token_out) = (path[0], path[path.len() - 1]);
+        let first_pair = self.pair_for(path[0], path[1]).await?;
+        if first_pair.is_zero() {
+            return Err(RouterError::PairNotFound);
+        }
+        router_state.debit_vault(owner, token_in, amounts[0])?;
+
+        router_state.lock = true;
+
+        if let Err(error) = self._transfer(token_in, first_pair, amounts[0]).await {
+            router_state.credit_vault(owner, token_in, amounts[0]);
+            router_state.lock = false;
+            return Err(error);
+        }
+
+        if let Err(error) = self
+            ._swap(amounts.clone(), path.clone(), exec::program_id())
+            .await
+        {
+            self._recover_failed_hop(owner, &path, &error).await;
+            router_state.lock = false;
+            return Err(error);
+        }
+
+        router_state.credit_vault(owner, token_out, amounts[amounts.len() - 1]);
+        router_state.lock = false;
+        Ok(())
+    }
+}
+
+impl AsRef<AccessControlService> for RouterService {
+    fn as_ref(&self) -> &AccessControlService {
+        &self.access_control
+    }
 }
@@ -1,8 +1,46 @@
 use gstd::collections::HashMap;
 use sails_rs::prelude::*;
 
+use crate::clients::factory_vara_dex_client::FactoryError;
+use crate::clients::lp_vara_dex_client::LpError;
+
 pub static mut ROUTER: Option<RouterState> = None;
 
+/// Swap fee of the pairs deployed by the factory the router is initialized
+/// with, in parts per million.
+pub const DEFAULT_PAIR_FEE_PPM: u128 = 3_000;
+
+/// Token id of native VARA in a pair holding it as program value; such
+/// pairs are looked up as `(token, NATIVE_VARA)`.
+pub const NATIVE_VARA: ActorId = ActorId::zero();
+
+/// Longest timelock delay, in milliseconds.
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60 * 1000;
+
+/// A configuration change held back by the timelock.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq, Eq)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum ConfigChange {
+    /// Replaces the primary factory and clears the pair cache. A registered
+    /// secondary factory is moved up instead of being listed twice.
+    Factory(ActorId),
+    Wvara(ActorId),
+    SwapFeeBps(u128),
+    /// Applies to operations queued after it executes.
+    TimelockDelay(u64),
+}
+
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct QueuedOperation {
+    pub id: u64,
+    pub change: ConfigChange,
+    /// Block timestamp from which anyone can execute the change.
+    pub eta: u64,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct PendingRefund {
     pub token_addr: ActorId,
@@ -17,19 +55,180 @@ pub struct LiquidityJoin {
     pub pair: ActorId,
 }
 
+/// One hop of a simulated swap, computed against the pair's current reserves.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct HopSimulation {
+    pub pair: ActorId,
+    pub token_in: ActorId,
+    pub token_out: ActorId,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub reserve_in: U256,
+    pub reserve_out: U256,
+    pub reserve_in_after: U256,
+    pub reserve_out_after: U256,
+    pub k_before: U256,
+    pub k_after: U256,
+}
+
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub enum SimulationError {
+    Router(RouterError),
+    Pair(LpError),
+}
+
+/// Result of `simulate_swap`. `hops` stops at the first failing hop, whose
+/// index is `failed_hop`; it is `None` for path-level errors such as `Expired`.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Default)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct SwapSimulation {
+    pub amounts: Vec<U256>,
+    pub hops: Vec<HopSimulation>,
+    pub failed_hop: Option<u32>,
+    pub error: Option<SimulationError>,
+}
+
+impl SwapSimulation {
+    pub fn fail(mut self, hop: Option<usize>, error: SimulationError) -> Self {
+        self.failed_hop = hop.map(|hop| hop as u32);
+        self.error = Some(error);
+        self
+    }
+}
+
+/// Prefix of every signed swap intent, so its signature cannot pass for a
+/// permit or any other signed payload.
+pub const SWAP_INTENT_DOMAIN: &[u8] = b"VarSwap SwapIntent";
+
+/// An `owner`'s signed order to swap exactly `amount_in` along `path`,
+/// submitted on its behalf by a relayer through `execute_signed_swap`.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct SwapIntent {
+    pub owner: ActorId,
+    pub path: Vec<ActorId>,
+    pub amount_in: U256,
+    pub amount_out_min: U256,
+    pub to: ActorId,
+    pub deadline: u64,
+    /// Must equal the owner's current intent nonce.
+    pub nonce: u64,
+    /// Most the relayer may ask, paid in `path[0]` on top of `amount_in`.
+    pub max_relayer_fee: U256,
+}
+
+impl SwapIntent {
+    /// Bytes the owner signs: the domain, the router program and the intent.
+    pub fn payload(&self, router: ActorId) -> Vec<u8> {
+        (SWAP_INTENT_DOMAIN, router, self).encode()
+    }
+}
+
+/// Sails route of the service a recipient exposes to be notified of swap
+/// outputs, and of its `on_varswap_received` method.
+pub const RECEIVER_SERVICE_ROUTE: &str = "VarswapReceiver";
+pub const RECEIVER_METHOD_ROUTE: &str = "OnVarswapReceived";
+
+/// Blocks the router waits for a recipient to answer its callback.
+pub const RECEIVER_CALLBACK_TIMEOUT: u32 = 20;
+
+/// Message calling `on_varswap_received(token, amount, sender, data)` on a
+/// swap recipient.
+pub fn receiver_callback_payload(
+    token: ActorId,
+    amount: U256,
+    sender: ActorId,
+    data: &[u8],
+) -> Vec<u8> {
+    (
+        RECEIVER_SERVICE_ROUTE,
+        RECEIVER_METHOD_ROUTE,
+        token,
+        amount,
+        sender,
+        data,
+    )
+        .encode()
+}
+
+/// Reply of a recipient accepting the callback, i.e. returning `true`.
+pub fn receiver_callback_accepted() -> Vec<u8> {
+    (RECEIVER_SERVICE_ROUTE, RECEIVER_METHOD_ROUTE, true).encode()
+}
+
 pub type PendingLiquidityMap = HashMap<ActorId, Vec<PendingRefund>>;
 pub type LiquidityJoinMap = HashMap<ActorId, Vec<LiquidityJoin>>;
 
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct FactoryInfo {
+    pub address: ActorId,
+    /// Swap fee charged by the factory's pairs, in parts per million.
+    pub fee_ppm: u128,
+}
+
+#[derive(Debug, Clone)]
+pub struct CachedPair {
+    pub factory: ActorId,
+    pub pair: ActorId,
+}
+
+/// Pair addresses keyed by the token pair in factory order (larger id first).
+/// A zero pair is a cached miss of a native VARA pair.
+pub type PairCacheMap = HashMap<(ActorId, ActorId), CachedPair>;
+
+/// Result of `get_best_amounts_out`: `factories[i]` is the factory whose pair
+/// gives the best output for hop `i`.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[codec(crate = gstd::codec)]
+#[scale_info(crate = gstd::scale_info)]
+pub struct PathQuote {
+    pub amounts: Vec<U256>,
+    pub factories: Vec<ActorId>,
+}
+
 #[derive(Debug, Default)]
 pub struct RouterState {
-    pub factory_address: ActorId,
+    /// Registered factories in lookup priority order. The first one is the
+    /// primary factory, where the router creates new pairs.
+    pub factories: Vec<FactoryInfo>,
     pub wvara_address: ActorId,
     pub admin: ActorId,
+    /// Proposed `admin`, set until it accepts or the proposal is cancelled.
+    pub pending_admin: Option<ActorId>,
     pub fund_addr: ActorId,
     pub swap_fee_bps: u128,
     pub lock: bool,
+    /// Set while a swap recipient is notified, so it cannot reenter the
+    /// router. Kept apart from `lock`, which the Pauser also sets.
+    pub in_callback: bool,
     pub pending_liquidity: PendingLiquidityMap,
     pub liquidity_join: LiquidityJoinMap,
+    pub pair_cache: PairCacheMap,
+    /// Minimum time, in milliseconds, between queuing a configuration change
+    /// and executing it.
+    pub timelock_delay: u64,
+    /// Pending configuration changes, oldest first.
+    pub queued_operations: Vec<QueuedOperation>,
+    pub next_operation_id: u64,
+    /// Next nonce a signed swap intent of each owner must carry.
+    pub intent_nonces: HashMap<ActorId, u64>,
+    /// Vault balances by (owner, token), held by the router on the owners'
+    /// behalf.
+    pub vault_balances: HashMap<(ActorId, ActorId), U256>,
+    /// Sum of the vault balances of each token.
+    pub vault_totals: HashMap<ActorId, U256>,
+    /// Amounts by (user, token) the router still owes users after an
+    /// operation stopped halfway. Only `claim_refunds` drains it; native VARA
+    /// is keyed as `NATIVE_VARA`.
+    pub refunds: HashMap<(ActorId, ActorId), U256>,
 }
 
 impl RouterState {
@@ -39,6 +238,85 @@ impl RouterState {
     pub fn get() -> &'static Self {
         unsafe { ROUTER.as_ref().expect("State Factory Error") }
     }
+    /// Whether entry points must refuse to run: paused, in the middle of
+    /// another operation or notifying a swap recipient.
+    pub fn is_locked(&self) -> bool {
+        self.lock || self.in_callback
+    }
+    pub fn primary_factory(&self) -> ActorId {
+        self.factories
+            .first()
+            .map(|info| info.address)
+            .unwrap_or_default()
+    }
+    pub fn factory_info(&self, factory: ActorId) -> Option<&FactoryInfo> {
+        self.factories.iter().find(|info| info.address == factory)
+    }
+    pub fn queue_operation(&mut self, change: ConfigChange, timestamp: u64) -> QueuedOperation {
+        let operation = QueuedOperation {
+            id: self.next_operation_id,
+            change,
+            eta: timestamp.saturating_add(self.timelock_delay),
+        };
+        self.next_operation_id += 1;
+        self.queued_operations.push(operation.clone());
+        operation
+    }
+    pub fn intent_nonce(&self, owner: &ActorId) -> u64 {
+        self.intent_nonces.get(owner).copied().unwrap_or_default()
+    }
+    pub fn vault_balance(&self, owner: ActorId, token: ActorId) -> U256 {
+        self.vault_balances
+            .get(&(owner, token))
+            .copied()
+            .unwrap_or_default()
+    }
+    pub fn vault_total(&self, token: ActorId) -> U256 {
+        self.vault_totals.get(&token).copied().unwrap_or_default()
+    }
+    pub fn credit_vault(&mut self, owner: ActorId, token: ActorId, amount: U256) {
+        let balance = self.vault_balances.entry((owner, token)).or_default();
+        *balance = balance.saturating_add(amount);
+        let total = self.vault_totals.entry(token).or_default();
+        *total = total.saturating_add(amount);
+    }
+    pub fn debit_vault(
+        &mut self,
+        owner: ActorId,
+        token: ActorId,
+        amount: U256,
+    ) -> Result<(), RouterError> {
+        let balance = self.vault_balance(owner, token);
+        if balance < amount {
+            return Err(RouterError::InsufficientVaultBalance);
+        }
+        if balance == amount {
+            self.vault_balances.remove(&(owner, token));
+        } else {
+            self.vault_balances.insert((owner, token), balance - amount);
+        }
+        let total = self.vault_totals.entry(token).or_default();
+        *total = total.saturating_sub(amount);
+        Ok(())
+    }
+    pub fn record_refund(&mut self, user: ActorId, token: ActorId, amount: U256) {
+        if amount.is_zero() {
+            return;
+        }
+        let owed = self.refunds.entry((user, token)).or_default();
+        *owed = owed.saturating_add(amount);
+    }
+    /// Sum of the refunds owed in `token`.
+    pub fn refund_total(&self, token: ActorId) -> U256 {
+        self.refunds
+            .iter()
+            .filter(|((_, refund_token), _)| *refund_token == token)
+            .fold(U256::zero(), |total, (_, amount)| total.saturating_add(*amount))
+    }
+    pub fn take_operation(&mut self, id: u64) -> Option<QueuedOperation> {
+        let index = self.queued_operations.iter().position(|op| op.id == id)?;
+        Some(self.queued_operations.remove(index))
+    }
 }
 
 #[derive(Encode, Decode, TypeInfo, Debug)]
@@ -80,6 +358,15 @@ pub enum RouterEvent {
         to: ActorId,
         liquidity: U256,
     },
+    LiquidityMigrated {
+        token_a: ActorId,
+        token_b: ActorId,
+        old_pair: ActorId,
+        new_pair: ActorId,
+        liquidity_burned: U256,
+        liquidity_minted: U256,
+        to: ActorId,
+    },
     SwapExactTokensForTokens {
         amount_in: U256,
         amount_out: U256,
@@ -116,6 +403,99 @@ pub enum RouterEvent {
         path: Vec<ActorId>,
         to: ActorId,
     },
+    PairCacheSynced {
+        token_a: ActorId,
+        token_b: ActorId,
+        pair_address: ActorId,
+    },
+    PairCacheCleared,
+    FactoryAdded {
+        factory: ActorId,
+        fee_ppm: u128,
+    },
+    FactoryRemoved {
+        factory: ActorId,
+    },
+    FactoryFeeUpdated {
+        factory: ActorId,
+        fee_ppm: u128,
+    },
+    AdminTransferProposed {
+        new_admin: ActorId,
+    },
+    AdminTransferCancelled {
+        pending_admin: ActorId,
+    },
+    AdminTransferred {
+        old_admin: ActorId,
+        new_admin: ActorId,
+    },
+    OperationQueued {
+        id: u64,
+        change: ConfigChange,
+        eta: u64,
+    },
+    OperationExecuted {
+        id: u64,
+        change: ConfigChange,
+    },
+    OperationCancelled {
+        id: u64,
+    },
+    SignedSwapExecuted {
+        owner: ActorId,
+        relayer: ActorId,
+        nonce: u64,
+        amount_in: U256,
+        amount_out: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        /// Fee the relayer received, zero if its transfer failed.
+        relayer_fee: U256,
+    },
+    IntentNonceCancelled {
+        owner: ActorId,
+        nonce: u64,
+    },
+    VaultDeposited {
+        owner: ActorId,
+        token: ActorId,
+        amount: U256,
+    },
+    VaultWithdrawn {
+        owner: ActorId,
+        token: ActorId,
+        amount: U256,
+        to: ActorId,
+    },
+    SwapExactTokensForTokensInternal {
+        owner: ActorId,
+        amount_in: U256,
+        amount_out: U256,
+        path: Vec<ActorId>,
+    },
+    SwapTokensForExactTokensInternal {
+        owner: ActorId,
+        amount_out: U256,
+        amount_in: U256,
+        path: Vec<ActorId>,
+    },
+    RecipientNotified {
+        to: ActorId,
+        token: ActorId,
+        amount: U256,
+        sender: ActorId,
+    },
+    RefundRecorded {
+        user: ActorId,
+        token: ActorId,
+        amount: U256,
+    },
+    RefundClaimed {
+        user: ActorId,
+        token: ActorId,
+        amount: U256,
+    },
 }
 
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
@@ -156,5 +536,82 @@ pub enum RouterError {
     InsufficientAllowance,
     NotAdmin,
     NoPendingFunds,
-    SkimPairLiquidityFailed
+    SkimPairLiquidityFailed,
+    /// The pair of hop `hop` (index into the swap path) rejected the swap.
+    /// `error` is `None` when the message itself failed, e.g. ran out of gas.
+    PairSwapFailed {
+        hop: u32,
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    PairMintFailed {
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    PairBurnFailed {
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    PairSkimFailed {
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    /// The pair did not answer a reserves query.
+    PairQueryFailed {
+        pair: ActorId,
+    },
+    /// The factory rejected the call, or did not answer when `error` is `None`.
+    FactoryCallFailed {
+        factory: ActorId,
+        error: Option<FactoryError>,
+    },
+    TokenTransferFailed {
+        token: ActorId,
+        to: ActorId,
+    },
+    TokenTransferFromFailed {
+        token: ActorId,
+        from: ActorId,
+        to: ActorId,
+    },
+    FactoryAlreadyRegistered,
+    FactoryNotRegistered,
+    CannotRemovePrimaryFactory,
+    InvalidFee,
+    /// The pair is not a deprecated predecessor of the current pair.
+    PairNotDeprecated,
+    /// The intent's nonce is not the owner's current one: it already ran,
+    /// was cancelled, or is ahead of it.
+    InvalidNonce,
+    InvalidSignature,
+    /// The owner's vault balance is too low, or for `refund_token` and
+    /// `refund_vara`, the router holds no more than its vaults and refunds
+    /// owe.
+    InsufficientVaultBalance,
+    /// `to` rejected, trapped on or did not answer `on_varswap_received`.
+    /// It keeps the swap output unless it sent it back to the router,
+    /// which then records it as a refund of the sender.
+    RecipientCallbackFailed {
+        to: ActorId,
+    },
+    /// A pair the caller just created already has reserves.
+    PairNotEmpty,
+    /// The caller has no refund to claim.
+    NoRefunds,
+    /// There is no proposed admin to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin.
+    NotPendingAccount,
+    OperationNotFound,
+    /// The operation's `eta` has not been reached yet.
+    OperationNotReady,
+    InvalidTimelockDelay,
+    /// The pair rejected the LP permit, or did not answer when `error` is
+    /// `None`.
+    PairPermitFailed {
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    /// The relayer asked more than the intent's `max_relayer_fee`.
+    ExcessiveRelayerFee,
 }
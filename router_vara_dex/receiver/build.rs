@@ -0,0 +1,3 @@
+fn main() {
+    gwasm_builder::build();
+}
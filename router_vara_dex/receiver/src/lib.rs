@@ -0,0 +1,71 @@
+#![no_std]
+// Swap recipient for the router tests. It answers `on_varswap_received`
+// by accepting the tokens, by sending them back to the router and
+// returning `false`, by trapping, or by pausing the router, as its Pauser,
+// before accepting.
+
+// exposes `WASM_BINARY_OPT` to the router tests
+#[cfg(not(target_arch = "wasm32"))]
+include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+
+use sails_rs::{gstd::msg, prelude::*};
+
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Copy)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ReceiverMode {
+    Accept,
+    Reject,
+    Trap,
+    Pause,
+}
+
+static mut MODE: ReceiverMode = ReceiverMode::Accept;
+
+pub struct VarswapReceiver(ReceiverMode);
+
+#[service]
+impl VarswapReceiver {
+    pub async fn on_varswap_received(
+        &mut self,
+        token: ActorId,
+        amount: U256,
+        _sender: ActorId,
+        _data: Vec<u8>,
+    ) -> bool {
+        match self.0 {
+            ReceiverMode::Accept => true,
+            ReceiverMode::Reject => {
+                let transfer = ("Vft", "Transfer", msg::source(), amount).encode();
+                msg::send_bytes_for_reply(token, transfer, 0, 0)
+                    .expect("Failed to return the tokens")
+                    .await
+                    .expect("Failed to return the tokens");
+                false
+            }
+            ReceiverMode::Trap => panic!("Recipient trapped"),
+            ReceiverMode::Pause => {
+                let lock = ("RouterService", "LockRouter").encode();
+                msg::send_bytes_for_reply(msg::source(), lock, 0, 0)
+                    .expect("Failed to pause the router")
+                    .await
+                    .expect("Failed to pause the router");
+                true
+            }
+        }
+    }
+}
+
+pub struct VarswapReceiverProgram;
+
+#[program]
+impl VarswapReceiverProgram {
+    pub fn new(mode: ReceiverMode) -> Self {
+        unsafe { MODE = mode };
+        Self
+    }
+
+    pub fn varswap_receiver(&self) -> VarswapReceiver {
+        VarswapReceiver(unsafe { MODE })
+    }
+}
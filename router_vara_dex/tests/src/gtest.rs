@@ -5,7 +5,7 @@ mod tests {
     use std::{env, path::PathBuf};
     // use gstd::{ActorId, CodeId};
     use sails_rs::{
-        calls::{Activation, Call, Query},
+        calls::{Action, Activation, Call, Query, Remoting},
         futures::future::Remote,
         gtest::{calls::*, System},
         prelude::*,
@@ -31,6 +31,8 @@ mod tests {
         RouterService as RouterClient, RouterVaraDexFactory as RouterFactory, *,
     };
 
+    use varswap_receiver::ReceiverMode;
+
     use crate::utils;
 
     const ADMIN_ID: u64 = 10;
@@ -57,6 +59,7 @@ mod tests {
         let factory_code_id = program_space.system().submit_code_file(
             "./factory_wasm.opt.wasm"
         );
+        let lp_code_id = program_space.system().submit_code_file("./lp_wasm.opt.wasm");
 
         // Deploy wvara
         let wvara_factory = WvaraVftFactory::new(program_space.clone());
@@ -70,7 +73,7 @@ mod tests {
         let factory_factory = FactoryVaraDexFactory::new(program_space.clone());
         let factory_id = factory_factory
             .new(
-                CodeId::zero(), // hoặc code_id cho LP pair nếu cần
+                lp_code_id,
                 ADMIN_ID.into(),
                 ADMIN_ID.into(),
                 ADMIN_ID.into(),
@@ -79,6 +82,11 @@ mod tests {
             .await
             .unwrap();
 
+        // creating a pair program costs the factory the existential deposit
+        program_space
+            .system()
+            .transfer(ADMIN_ID, factory_id, 100 * utils::ONE_TOKEN, true);
+
         // Deploy router
         let router_factory = RouterFactory::new(program_space.clone());
         let router_id = router_factory
@@ -95,16 +103,109 @@ mod tests {
 
         // set router cho factory nếu cần
         let mut factory = FactoryService::new(program_space.clone());
-        factory
+        let id = factory
             .set_router(router_id)
-            .send(factory_id)
+            .send_recv(factory_id)
             .await
+            .unwrap()
             .unwrap();
+        factory.execute_operation(id).send(factory_id).await.unwrap();
 
         (program_space, router_id, factory_id, wvara_id)
     }
 
 
+    /// Deploys a wVARA instance to serve as a test token.
+    async fn deploy_token(remoting: &GTestRemoting, symbol: &str) -> ActorId {
+        let code_id = remoting.system().submit_code_file("./wvara_vft_wasm.opt.wasm");
+        WvaraVftFactory::new(remoting.clone())
+            .new(symbol.to_string(), symbol.to_string(), 12)
+            .send_recv(code_id, symbol)
+            .await
+            .unwrap()
+    }
+
+    /// Deploys a swap recipient answering `on_varswap_received` as `mode`
+    /// says.
+    async fn deploy_receiver(remoting: &GTestRemoting, mode: ReceiverMode, salt: &str) -> ActorId {
+        let code_id = remoting.system().submit_code(varswap_receiver::WASM_BINARY_OPT);
+        let (receiver, _) = remoting
+            .clone()
+            .activate(code_id, salt, ("New", mode).encode(), None, 0, GTestArgs::default())
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+        receiver
+    }
+
+    /// Claims the VARA `source` sent `user` with `payload`, which gtest keeps
+    /// in the mailbox until then.
+    fn claim_vara(system: &System, source: ActorId, user: u64, payload: &str) {
+        system
+            .get_mailbox(user)
+            .claim_value((source, user, payload.encode()))
+            .unwrap();
+    }
+
+    /// Wraps `amount` of the remoting actor's VARA into `token` and approves
+    /// the router for it.
+    async fn fund(remoting: &GTestRemoting, router_id: ActorId, token: ActorId, amount: u128) {
+        let mut vft = Vft::new(remoting.clone());
+        vft.deposit().with_value(amount).send_recv(token).await.unwrap();
+        let allowance = vft
+            .allowance(remoting.actor_id(), router_id)
+            .recv(token)
+            .await
+            .unwrap();
+        vft.approve(router_id, allowance + U256::from(amount))
+            .send_recv(token)
+            .await
+            .unwrap();
+    }
+
+    /// Creates the `token_a`/`token_b` pool with the admin's funds and
+    /// returns its pair.
+    async fn create_pool(
+        remoting: &GTestRemoting,
+        router_id: ActorId,
+        token_a: ActorId,
+        amount_a: u128,
+        token_b: ActorId,
+        amount_b: u128,
+    ) -> ActorId {
+        let mut router = RouterClient::new(remoting.clone());
+        fund(remoting, router_id, token_a, amount_a).await;
+        fund(remoting, router_id, token_b, amount_b).await;
+        router
+            .create_pair(token_a, token_b)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        router
+            .add_liquidity(
+                token_a,
+                token_b,
+                amount_a.into(),
+                amount_b.into(),
+                0.into(),
+                0.into(),
+                ADMIN_ID.into(),
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        router
+            .pair_for(token_a, token_b)
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
     #[tokio::test]
     async fn test_initialization_and_getters() {
         
@@ -134,21 +235,25 @@ mod tests {
         let mut router = RouterClient::new(remoting.clone());
         // update_new_factorty
         let new_factory: ActorId = 98.into();
-        router
+        let id = router
             .update_new_factorty(new_factory)
-            .send(router_id)
+            .send_recv(router_id)
             .await
+            .unwrap()
             .unwrap();
+        router.execute_operation(id).send(router_id).await.unwrap();
         let factory = router.get_factory().recv(router_id).await.unwrap();
         assert_eq!(factory, new_factory);
 
         // update_new_wrapvara
         let new_wvara: ActorId = 97.into();
-        router
+        let id = router
             .update_new_wrapvara(new_wvara)
-            .send(router_id)
+            .send_recv(router_id)
             .await
+            .unwrap()
             .unwrap();
+        router.execute_operation(id).send(router_id).await.unwrap();
         let wvara = router.get_wvara().recv(router_id).await.unwrap();
         assert_eq!(wvara, new_wvara);
 
@@ -164,23 +269,1857 @@ mod tests {
 
         // update_swap_fee_bps
         let new_fee: u128 = 500; // 0.5%
-        router
+        let id = router
             .update_swap_fee_bps(new_fee)
-            .send(router_id)
+            .send_recv(router_id)
             .await
+            .unwrap()
             .unwrap();
+        router.execute_operation(id).send(router_id).await.unwrap();
         let fee = router.get_swap_fee_bps().recv(router_id).await.unwrap();
         assert_eq!(fee, new_fee);
 
-        // update_new_admin
-        let new_admin: ActorId = 99.into();
+        // propose_admin / accept_admin
+        let new_admin: ActorId = USER_ID.into();
         router
-            .update_new_admin(new_admin)
+            .propose_admin(new_admin)
+            .send(router_id)
+            .await
+            .unwrap();
+        let admin = router.get_admin().recv(router_id).await.unwrap();
+        assert_eq!(admin, ADMIN_ID.into());
+        let pending = router.get_pending_admin().recv(router_id).await.unwrap();
+        assert_eq!(pending, Some(new_admin));
+        let mut new_admin_router = RouterClient::new(remoting.with_actor_id(new_admin));
+        new_admin_router
+            .accept_admin()
             .send(router_id)
             .await
             .unwrap();
         let admin = router.get_admin().recv(router_id).await.unwrap();
         assert_eq!(admin, new_admin);
+        let pending = router.get_pending_admin().recv(router_id).await.unwrap();
+        assert_eq!(pending, None);
+    }
+
+    #[tokio::test]
+    async fn test_timelock() {
+        let (remoting, router_id, _, _) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let mut user_router = RouterClient::new(remoting.clone().with_actor_id(USER_ID.into()));
+        let delay = 20 * 3000; // 20 blocks
+
+        let id = router
+            .update_timelock_delay(delay)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        router
+            .execute_operation(id)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(router.get_timelock_delay().recv(router_id).await.unwrap(), delay);
+
+        let id = router
+            .update_swap_fee_bps(500)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let queued = router.get_queued_operations().recv(router_id).await.unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].change, client::ConfigChange::SwapFeeBps(500));
+        let res = user_router
+            .execute_operation(id)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::OperationNotReady)));
+        let res = user_router
+            .cancel_operation(id)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+
+        let system = remoting.system();
+        system.run_to_block(system.block_height() + 20);
+        user_router
+            .execute_operation(id)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(router.get_swap_fee_bps().recv(router_id).await.unwrap(), 500);
+
+        // a cancelled change never applies
+        let id = router
+            .update_swap_fee_bps(700)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        router
+            .cancel_operation(id)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_router
+            .execute_operation(id)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::OperationNotFound)));
+        assert!(router.get_queued_operations().recv(router_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_admin_functions_reject_non_admin() {
+        let (remoting, router_id, factory_id, _) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone().with_actor_id(USER_ID.into()));
+
+        let res = router
+            .update_new_factorty(98.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+
+        let res = router
+            .update_swap_fee_bps(500)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+
+        let res = router
+            .propose_admin(USER_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+
+        let res = router
+            .accept_admin()
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NoPendingTransfer)));
+
+        // a proposal only the proposed account can accept, until cancelled
+        let mut admin_router = RouterClient::new(remoting.clone());
+        let res = admin_router
+            .propose_admin(ActorId::zero())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::ZeroAddress)));
+        admin_router
+            .propose_admin(FUND_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = router
+            .accept_admin()
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotPendingAccount)));
+        let res = router
+            .cancel_admin_transfer()
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+        admin_router
+            .cancel_admin_transfer()
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let pending = router.get_pending_admin().recv(router_id).await.unwrap();
+        assert_eq!(pending, None);
+
+        // nothing changed
+        let factory = router.get_factory().recv(router_id).await.unwrap();
+        assert_eq!(factory, factory_id);
+        let admin = router.get_admin().recv(router_id).await.unwrap();
+        assert_eq!(admin, ADMIN_ID.into());
+    }
+
+    #[tokio::test]
+    async fn test_roles() {
+        let (remoting, router_id, _, _) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let mut user_router = RouterClient::new(remoting.clone().with_actor_id(USER_ID.into()));
+
+        router
+            .grant_role(client::Role::Pauser, USER_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        user_router.lock_router().send_recv(router_id).await.unwrap().unwrap();
+        assert!(router.get_lock().recv(router_id).await.unwrap());
+        user_router.unlock_router().send_recv(router_id).await.unwrap().unwrap();
+
+        // a pauser cannot touch fees or funds
+        let res = user_router
+            .update_swap_fee_bps(500)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+        let res = user_router
+            .refund_vara(1)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+
+        router
+            .grant_role(client::Role::FeeManager, USER_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        user_router
+            .update_swap_fee_bps(500)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let res = user_router
+            .grant_role(client::Role::Treasurer, USER_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(client::AccessControlError::MissingAdminRole)));
+        let roles = router
+            .get_account_roles(USER_ID.into())
+            .recv(router_id)
+            .await
+            .unwrap();
+        assert_eq!(roles, vec![client::Role::Pauser, client::Role::FeeManager]);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_swap_reports_failures() {
+        let (remoting, router_id, _, wvara_id) = init_router().await;
+        let router = RouterClient::new(remoting.clone());
+
+        let res = router
+            .simulate_swap(vec![wvara_id], 1000.into(), true, u64::MAX)
+            .recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            res.error,
+            Some(SimulationError::Router(RouterError::InvalidPath))
+        ));
+
+        let path = vec![wvara_id, 77.into(), 78.into()];
+        let res = router
+            .simulate_swap(path.clone(), 1000.into(), true, 0)
+            .recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            res.error,
+            Some(SimulationError::Router(RouterError::Expired))
+        ));
+        assert_eq!(res.failed_hop, None);
+
+        // no pair has been created for the first hop
+        let res = router
+            .simulate_swap(path, 1000.into(), false, u64::MAX)
+            .recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            res.error,
+            Some(SimulationError::Router(RouterError::PairNotFound))
+        ));
+        assert_eq!(res.failed_hop, Some(0));
+        assert!(res.hops.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_simulate_swap_matches_swap() {
+        let (remoting, router_id, _, _) = init_router().await;
+        let swapper_remoting = remoting.clone().with_actor_id(SWAPPER_ID.into());
+        let mut swapper = RouterClient::new(swapper_remoting.clone());
+        let vft = Vft::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        let token_c = deploy_token(&remoting, "TKC").await;
+        let pair_ab = create_pool(
+            &remoting,
+            router_id,
+            token_a,
+            100 * utils::ONE_TOKEN,
+            token_b,
+            200 * utils::ONE_TOKEN,
+        )
+        .await;
+        create_pool(
+            &remoting,
+            router_id,
+            token_b,
+            100 * utils::ONE_TOKEN,
+            token_c,
+            100 * utils::ONE_TOKEN,
+        )
+        .await;
+
+        let path = vec![token_a, token_b, token_c];
+        let amount_in = U256::from(utils::ONE_TOKEN);
+        let simulation = swapper
+            .simulate_swap(path.clone(), amount_in, true, u64::MAX)
+            .recv(router_id)
+            .await
+            .unwrap();
+        assert!(simulation.error.is_none());
+        assert_eq!(simulation.failed_hop, None);
+        assert_eq!(simulation.hops.len(), 2);
+        let hop = &simulation.hops[0];
+        assert_eq!(hop.pair, pair_ab);
+        assert_eq!(hop.reserve_in, U256::from(100 * utils::ONE_TOKEN));
+        assert_eq!(hop.reserve_out, U256::from(200 * utils::ONE_TOKEN));
+        assert_eq!(hop.reserve_in_after, hop.reserve_in + amount_in);
+        assert!(hop.k_after >= hop.k_before);
+        let quoted = swapper
+            .get_amounts_out(amount_in, path.clone())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(simulation.amounts, quoted);
+
+        // the real swap pays out exactly the simulated amount
+        fund(&swapper_remoting, router_id, token_a, utils::ONE_TOKEN).await;
+        swapper
+            .swap_exact_tokens_for_tokens(amount_in, 1.into(), path, SWAPPER_ID.into(), u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let balance = vft.balance_of(SWAPPER_ID.into()).recv(token_c).await.unwrap();
+        assert_eq!(balance, simulation.amounts[2]);
+    }
+
+    #[tokio::test]
+    async fn test_pair_cache_sync() {
+        let (remoting, router_id, _, _) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let token_a: ActorId = 71.into();
+        let token_b: ActorId = 72.into();
+        let pair: ActorId = 73.into();
+
+        // only the admin or the factory may sync
+        let mut user_router = RouterClient::new(remoting.clone().with_actor_id(USER_ID.into()));
+        let res = user_router
+            .sync_pair(token_a, token_b, pair)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+
+        router
+            .sync_pair(token_a, token_b, pair)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let cached = router
+            .get_cached_pair(token_a, token_b)
+            .recv(router_id)
+            .await
+            .unwrap();
+        assert_eq!(cached, Some(pair));
+        let cache = router.get_pair_cache().recv(router_id).await.unwrap();
+        assert_eq!(cache, vec![(token_b, token_a, pair)]);
+
+        // a zero address evicts the entry
+        router
+            .sync_pair(token_b, token_a, ActorId::zero())
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let cached = router
+            .get_cached_pair(token_a, token_b)
+            .recv(router_id)
+            .await
+            .unwrap();
+        assert_eq!(cached, None);
+
+        router
+            .sync_pair(token_a, token_b, pair)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        router
+            .clear_pair_cache()
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let cache = router.get_pair_cache().recv(router_id).await.unwrap();
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pair_cache_follows_factory() {
+        let (remoting, router_id, factory_id, _) = init_router().await;
+        let swapper_remoting = remoting.clone().with_actor_id(SWAPPER_ID.into());
+        let mut swapper = RouterClient::new(swapper_remoting.clone());
+        let mut factory = FactoryService::new(remoting.clone());
+        let vft = Vft::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        let pair = create_pool(
+            &remoting,
+            router_id,
+            token_a,
+            100 * utils::ONE_TOKEN,
+            token_b,
+            100 * utils::ONE_TOKEN,
+        )
+        .await;
+
+        // filled when the router created the pair
+        let cached = swapper.get_cached_pair(token_a, token_b).recv(router_id).await.unwrap();
+        assert_eq!(cached, Some(pair));
+
+        // the factory evicts a removed pair, so swaps no longer find it
+        factory
+            .remove_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let cached = swapper.get_cached_pair(token_a, token_b).recv(router_id).await.unwrap();
+        assert_eq!(cached, None);
+        fund(&swapper_remoting, router_id, token_a, 2 * utils::ONE_TOKEN).await;
+        let path = vec![token_a, token_b];
+        let res = swapper
+            .swap_exact_tokens_for_tokens(
+                utils::ONE_TOKEN.into(),
+                1.into(),
+                path.clone(),
+                SWAPPER_ID.into(),
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(res.is_err());
+
+        // and pushes it back once re-added
+        factory
+            .add_pair(token_a, token_b, pair)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let cached = swapper.get_cached_pair(token_a, token_b).recv(router_id).await.unwrap();
+        assert_eq!(cached, Some(pair));
+        let amounts = swapper
+            .swap_exact_tokens_for_tokens(
+                utils::ONE_TOKEN.into(),
+                1.into(),
+                path,
+                SWAPPER_ID.into(),
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let balance = vft.balance_of(SWAPPER_ID.into()).recv(token_b).await.unwrap();
+        assert_eq!(balance, amounts[1]);
+        assert!(!balance.is_zero());
+    }
+
+    #[tokio::test]
+    async fn test_multiple_factories() {
+        let (remoting, router_id, factory_id, wvara_id) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let stable_factory: ActorId = 81.into();
+
+        // no factory has a pair for the path
+        let res = router
+            .get_best_amounts_out(1000.into(), vec![wvara_id, 77.into()])
+            .recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairNotFound)));
+
+        router
+            .add_factory(stable_factory, 400)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let factories = router.get_factories().recv(router_id).await.unwrap();
+        assert_eq!(factories.len(), 2);
+        assert_eq!(factories[0].address, factory_id);
+        assert_eq!(factories[0].fee_ppm, 3_000);
+        assert_eq!(factories[1].address, stable_factory);
+        assert_eq!(factories[1].fee_ppm, 400);
+        // new pairs still go to the primary factory
+        let factory = router.get_factory().recv(router_id).await.unwrap();
+        assert_eq!(factory, factory_id);
+
+        let res = router
+            .add_factory(stable_factory, 400)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::FactoryAlreadyRegistered)));
+        let res = router
+            .update_factory_fee(stable_factory, 1_000_000)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InvalidFee)));
+        let res = router
+            .remove_factory(factory_id)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::CannotRemovePrimaryFactory)));
+
+        let mut user_router = RouterClient::new(remoting.clone().with_actor_id(USER_ID.into()));
+        let res = user_router
+            .add_factory(82.into(), 400)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::NotAdmin)));
+
+        // promoting the secondary factory does not list it twice
+        let id = router
+            .update_new_factorty(stable_factory)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        router
+            .execute_operation(id)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let factories = router.get_factories().recv(router_id).await.unwrap();
+        assert_eq!(factories.len(), 1);
+        assert_eq!(factories[0].address, stable_factory);
+        assert_eq!(factories[0].fee_ppm, 400);
+
+        router
+            .add_factory(factory_id, 3_000)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        router
+            .remove_factory(factory_id)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let factories = router.get_factories().recv(router_id).await.unwrap();
+        assert_eq!(factories.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_best_quote_across_factories() {
+        let (remoting, router_id, factory_id, _) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let swapper_remoting = remoting.clone().with_actor_id(SWAPPER_ID.into());
+        let mut swapper = RouterClient::new(swapper_remoting.clone());
+        let mut vft = Vft::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        create_pool(
+            &remoting,
+            router_id,
+            token_a,
+            100 * utils::ONE_TOKEN,
+            token_b,
+            100 * utils::ONE_TOKEN,
+        )
+        .await;
+
+        // a second factory with a deeper pool of the same tokens
+        let factory_code_id = remoting.system().submit_code_file("./factory_wasm.opt.wasm");
+        let lp_code_id = remoting.system().submit_code_file("./lp_wasm.opt.wasm");
+        let stable_factory = FactoryVaraDexFactory::new(remoting.clone())
+            .new(lp_code_id, ADMIN_ID.into(), ADMIN_ID.into(), ADMIN_ID.into())
+            .send_recv(factory_code_id, "init stable factory")
+            .await
+            .unwrap();
+        remoting
+            .system()
+            .transfer(ADMIN_ID, stable_factory, 100 * utils::ONE_TOKEN, true);
+        let mut factory = FactoryService::new(remoting.clone());
+        let stable_pair = factory
+            .create_pair(token_a, token_b)
+            .send_recv(stable_factory)
+            .await
+            .unwrap()
+            .unwrap();
+        for token in [token_a, token_b] {
+            vft.deposit()
+                .with_value(1000 * utils::ONE_TOKEN)
+                .send_recv(token)
+                .await
+                .unwrap();
+            vft.transfer(stable_pair, (1000 * utils::ONE_TOKEN).into())
+                .send_recv(token)
+                .await
+                .unwrap();
+        }
+        LpService::new(remoting.clone())
+            .mint(ADMIN_ID.into())
+            .send_recv(stable_pair)
+            .await
+            .unwrap()
+            .unwrap();
+        router
+            .add_factory(stable_factory, 3_000)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let amount_in = U256::from(utils::ONE_TOKEN);
+        let path = vec![token_a, token_b];
+        let primary_quote = router
+            .get_amounts_out(amount_in, path.clone())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let quote = router
+            .get_best_amounts_out(amount_in, path.clone())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(quote.factories, vec![stable_factory]);
+        assert!(quote.amounts[1] > primary_quote[1]);
+        assert_ne!(stable_factory, factory_id);
+
+        fund(&swapper_remoting, router_id, token_a, utils::ONE_TOKEN).await;
+        swapper
+            .swap_exact_tokens_for_tokens_via(
+                amount_in,
+                quote.amounts[1],
+                path,
+                quote.factories,
+                SWAPPER_ID.into(),
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let balance = vft.balance_of(SWAPPER_ID.into()).recv(token_b).await.unwrap();
+        assert_eq!(balance, quote.amounts[1]);
+        let (reserve_0, reserve_1, _) = LpService::new(remoting.clone())
+            .get_reserves()
+            .recv(stable_pair)
+            .await
+            .unwrap();
+        assert_eq!(
+            reserve_0 + reserve_1,
+            U256::from(2001 * utils::ONE_TOKEN) - quote.amounts[1]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_migrate_liquidity_rejects() {
+        let (remoting, router_id, _, wvara_id) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let token_b: ActorId = 77.into();
+        let old_pair: ActorId = 78.into();
+
+        let res = router
+            .migrate_liquidity(wvara_id, token_b, old_pair, 1.into(), ADMIN_ID.into(), 0)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::Expired)));
+
+        // the primary factory has no pair to migrate into
+        let res = router
+            .migrate_liquidity(wvara_id, token_b, old_pair, 1.into(), ADMIN_ID.into(), u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairNotFound)));
+
+        let lock = router.get_lock().recv(router_id).await.unwrap();
+        assert!(!lock);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_liquidity() {
+        let (remoting, router_id, factory_id, _) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let mut vft = Vft::new(remoting.clone());
+        let mut lp = LpService::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        let old_pair = create_pool(
+            &remoting,
+            router_id,
+            token_a,
+            100 * utils::ONE_TOKEN,
+            token_b,
+            100 * utils::ONE_TOKEN,
+        )
+        .await;
+        let liquidity = lp.balance_of(ADMIN_ID.into()).recv(old_pair).await.unwrap();
+
+        let new_pair = FactoryService::new(remoting.clone())
+            .migrate_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_ne!(new_pair, old_pair);
+        // the new pair already trades at one A for two B
+        for (token, amount) in [(token_a, 10 * utils::ONE_TOKEN), (token_b, 20 * utils::ONE_TOKEN)] {
+            vft.deposit().with_value(amount).send_recv(token).await.unwrap();
+            vft.transfer(new_pair, amount.into()).send_recv(token).await.unwrap();
+        }
+        lp.mint(USER_ID.into()).send_recv(new_pair).await.unwrap().unwrap();
+
+        let balance_a = vft.balance_of(ADMIN_ID.into()).recv(token_a).await.unwrap();
+        let balance_b = vft.balance_of(ADMIN_ID.into()).recv(token_b).await.unwrap();
+        lp.approve(router_id, liquidity).send_recv(old_pair).await.unwrap();
+        let (amount_a, amount_b, minted) = router
+            .migrate_liquidity(token_a, token_b, old_pair, liquidity, ADMIN_ID.into(), u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // the burn returns `liquidity` of each token; the new price takes
+        // all of B and half as much A, and the rest of A goes back
+        assert_eq!(amount_b, liquidity);
+        assert_eq!(amount_a, liquidity / 2);
+        let balance = vft.balance_of(ADMIN_ID.into()).recv(token_a).await.unwrap();
+        assert_eq!(balance, balance_a + liquidity - amount_a);
+        let balance = vft.balance_of(ADMIN_ID.into()).recv(token_b).await.unwrap();
+        assert_eq!(balance, balance_b);
+        assert!(!minted.is_zero());
+        let lp_balance = lp.balance_of(ADMIN_ID.into()).recv(new_pair).await.unwrap();
+        assert_eq!(lp_balance, minted);
+        let lp_balance = lp.balance_of(ADMIN_ID.into()).recv(old_pair).await.unwrap();
+        assert!(lp_balance.is_zero());
+
+        let refunds = router.get_refunds(ADMIN_ID.into()).recv(router_id).await.unwrap();
+        assert!(refunds.is_empty());
+        let res = router.claim_refunds().send_recv(router_id).await.unwrap();
+        assert!(matches!(res, Err(RouterError::NoRefunds)));
+        let cached = router.get_cached_pair(token_a, token_b).recv(router_id).await.unwrap();
+        assert_eq!(cached, Some(new_pair));
+        let lock = router.get_lock().recv(router_id).await.unwrap();
+        assert!(!lock);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_liquidity_partial_transfer() {
+        let (remoting, router_id, factory_id, _) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let mut vft = Vft::new(remoting.clone());
+        let mut lp = LpService::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        let old_pair = create_pool(
+            &remoting,
+            router_id,
+            token_a,
+            100 * utils::ONE_TOKEN,
+            token_b,
+            100 * utils::ONE_TOKEN,
+        )
+        .await;
+        let new_pair = FactoryService::new(remoting.clone())
+            .migrate_pair(token_a, token_b)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        // the new pair prices B so high that a small position quotes no B
+        for (token, amount) in [(token_a, 10 * utils::ONE_TOKEN), (token_b, 1000)] {
+            vft.deposit().with_value(amount).send_recv(token).await.unwrap();
+            vft.transfer(new_pair, amount.into()).send_recv(token).await.unwrap();
+        }
+        lp.mint(USER_ID.into()).send_recv(new_pair).await.unwrap().unwrap();
+        let (reserve0, reserve1, _) = lp.get_reserves().recv(new_pair).await.unwrap();
+
+        // A reaches the new pair, the empty B transfer fails
+        let liquidity = U256::from(1000);
+        lp.approve(router_id, liquidity).send_recv(old_pair).await.unwrap();
+        let res = router
+            .migrate_liquidity(token_a, token_b, old_pair, liquidity, ADMIN_ID.into(), u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::TokenTransferFailed { token, to }) if token == token_b && to == new_pair));
+
+        // the A already sent is taken back and owed with the B
+        let refunds = router.get_refunds(ADMIN_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(refunds, vec![(token_a, liquidity), (token_b, liquidity)]);
+        let (reserve0_after, reserve1_after, _) = lp.get_reserves().recv(new_pair).await.unwrap();
+        assert_eq!((reserve0_after, reserve1_after), (reserve0, reserve1));
+        let balance = vft.balance_of(new_pair).recv(token_a).await.unwrap();
+        assert_eq!(balance, (10 * utils::ONE_TOKEN).into());
+        let balance = vft.balance_of(router_id).recv(token_a).await.unwrap();
+        assert_eq!(balance, liquidity);
+        assert!(!router.get_lock().recv(router_id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_remove_liquidity_with_front_run_permit() {
+        use ed25519_dalek::Signer;
+        use router_vara_dex::clients::lp_vara_dex_client::PermitSignature as LpPermitSignature;
+
+        let (remoting, router_id, _, _) = init_router().await;
+        let mut vft = Vft::new(remoting.clone());
+        let mut lp = LpService::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        let pair = create_pool(
+            &remoting,
+            router_id,
+            token_a,
+            100 * utils::ONE_TOKEN,
+            token_b,
+            100 * utils::ONE_TOKEN,
+        )
+        .await;
+        let key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+        let owner = ActorId::from(key.verifying_key().to_bytes());
+        remoting.system().mint_to(owner, 1000 * utils::ONE_TOKEN);
+        let liquidity = U256::from(utils::ONE_TOKEN);
+        lp.transfer(owner, liquidity).send_recv(pair).await.unwrap();
+
+        let payload = (b"VarSwap Permit".as_slice(), pair, owner, router_id, liquidity, 0u64, u64::MAX).encode();
+        let message = [b"<Bytes>".as_slice(), &payload, b"</Bytes>".as_slice()].concat();
+        let signature = key.sign(&message).to_bytes();
+
+        // someone submits the permit to the pair first
+        LpService::new(remoting.clone().with_actor_id(SWAPPER_ID.into()))
+            .permit(owner, router_id, liquidity, u64::MAX, LpPermitSignature::Ed25519(signature))
+            .send_recv(pair)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // the allowance it set is enough for the removal
+        let mut owner_router = RouterClient::new(remoting.clone().with_actor_id(owner));
+        let (amount_a, amount_b) = owner_router
+            .remove_liquidity_with_permit(
+                token_a,
+                token_b,
+                liquidity,
+                0.into(),
+                0.into(),
+                owner,
+                u64::MAX,
+                client::PermitSignature::Ed25519(signature),
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(vft.balance_of(owner).recv(token_a).await.unwrap(), amount_a);
+        assert_eq!(vft.balance_of(owner).recv(token_b).await.unwrap(), amount_b);
+        assert!(lp.balance_of(owner).recv(pair).await.unwrap().is_zero());
+
+        // once it is spent, the used permit fails again
+        lp.transfer(owner, liquidity).send_recv(pair).await.unwrap();
+        let res = owner_router
+            .remove_liquidity_with_permit(
+                token_a,
+                token_b,
+                liquidity,
+                0.into(),
+                0.into(),
+                owner,
+                u64::MAX,
+                client::PermitSignature::Ed25519(signature),
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairPermitFailed { pair: failed, .. }) if failed == pair));
+    }
+
+    /// `SwapIntent` payload wrapped the way wallets sign raw data.
+    fn sign_intent(
+        key: &ed25519_dalek::SigningKey,
+        router_id: ActorId,
+        intent: &client::SwapIntent,
+    ) -> client::PermitSignature {
+        use ed25519_dalek::Signer;
+        let payload = (b"VarSwap SwapIntent".as_slice(), router_id, intent).encode();
+        let message = [b"<Bytes>".as_slice(), &payload, b"</Bytes>".as_slice()].concat();
+        client::PermitSignature::Ed25519(key.sign(&message).to_bytes())
+    }
+
+    #[tokio::test]
+    async fn test_signed_swap_checks() {
+        let (remoting, router_id, _, wvara_id) = init_router().await;
+        let mut relayer = RouterClient::new(remoting.clone().with_actor_id(SWAPPER_ID.into()));
+        let key = ed25519_dalek::SigningKey::from_bytes(&[5; 32]);
+        let owner = ActorId::from(key.verifying_key().to_bytes());
+        remoting.system().mint_to(owner, 1000 * utils::ONE_TOKEN);
+        let mut owner_router = RouterClient::new(remoting.clone().with_actor_id(owner));
+
+        let mut intent = client::SwapIntent {
+            owner,
+            path: vec![wvara_id, 77.into()],
+            amount_in: 1000.into(),
+            amount_out_min: 1.into(),
+            to: owner,
+            deadline: u64::MAX,
+            nonce: 0,
+            max_relayer_fee: 10.into(),
+        };
+        let signature = sign_intent(&key, router_id, &intent);
+
+        // signed by someone else, or altered after signing
+        let other = ed25519_dalek::SigningKey::from_bytes(&[6; 32]);
+        let res = relayer
+            .execute_signed_swap(intent.clone(), sign_intent(&other, router_id, &intent), 10.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InvalidSignature)));
+        let mut altered = intent.clone();
+        altered.max_relayer_fee = 1000.into();
+        let res = relayer
+            .execute_signed_swap(altered, signature.clone(), 10.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InvalidSignature)));
+        let res = relayer
+            .execute_signed_swap(intent.clone(), signature.clone(), 11.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::ExcessiveRelayerFee)));
+
+        // a valid intent reaches the swap, which fails without an allowance
+        // and leaves the nonce unused
+        let res = relayer
+            .execute_signed_swap(intent.clone(), signature.clone(), 10.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InsufficientAllowance)));
+        let nonce = relayer.get_intent_nonce(owner).recv(router_id).await.unwrap();
+        assert_eq!(nonce, 0);
+        assert!(!relayer.get_lock().recv(router_id).await.unwrap());
+
+        let res = owner_router.cancel_nonce(1).send_recv(router_id).await.unwrap();
+        assert!(matches!(res, Err(RouterError::InvalidNonce)));
+        owner_router.cancel_nonce(0).send_recv(router_id).await.unwrap().unwrap();
+        let nonce = relayer.get_intent_nonce(owner).recv(router_id).await.unwrap();
+        assert_eq!(nonce, 1);
+        let res = relayer
+            .execute_signed_swap(intent.clone(), signature, 10.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InvalidNonce)));
+
+        intent.nonce = 1;
+        intent.deadline = 0;
+        let res = relayer
+            .execute_signed_swap(intent.clone(), sign_intent(&key, router_id, &intent), 10.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::Expired)));
+    }
+
+    #[tokio::test]
+    async fn test_signed_swap() {
+        let (remoting, router_id, _, _) = init_router().await;
+        let mut relayer = RouterClient::new(remoting.clone().with_actor_id(SWAPPER_ID.into()));
+        let mut vft = Vft::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        create_pool(
+            &remoting,
+            router_id,
+            token_a,
+            100 * utils::ONE_TOKEN,
+            token_b,
+            100 * utils::ONE_TOKEN,
+        )
+        .await;
+
+        let key = ed25519_dalek::SigningKey::from_bytes(&[5; 32]);
+        let owner = ActorId::from(key.verifying_key().to_bytes());
+        remoting.system().mint_to(owner, 1000 * utils::ONE_TOKEN);
+        let amount_in = U256::from(utils::ONE_TOKEN);
+        let max_fee = U256::from(utils::ONE_TOKEN / 10);
+        let fee = max_fee / 2;
+        let owner_remoting = remoting.clone().with_actor_id(owner);
+        fund(&owner_remoting, router_id, token_a, 2 * utils::ONE_TOKEN).await;
+
+        let path = vec![token_a, token_b];
+        let quote = relayer
+            .get_amounts_out(amount_in, path.clone())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let intent = client::SwapIntent {
+            owner,
+            path,
+            amount_in,
+            amount_out_min: quote[1],
+            to: owner,
+            deadline: u64::MAX,
+            nonce: 0,
+            max_relayer_fee: max_fee,
+        };
+        let signature = sign_intent(&key, router_id, &intent);
+        let amounts = relayer
+            .execute_signed_swap(intent.clone(), signature.clone(), fee)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(amounts, quote);
+
+        let balance = vft.balance_of(owner).recv(token_b).await.unwrap();
+        assert_eq!(balance, quote[1]);
+        let balance = vft.balance_of(owner).recv(token_a).await.unwrap();
+        assert_eq!(balance, U256::from(2 * utils::ONE_TOKEN) - amount_in - fee);
+        let balance = vft.balance_of(SWAPPER_ID.into()).recv(token_a).await.unwrap();
+        assert_eq!(balance, fee);
+
+        let nonce = relayer.get_intent_nonce(owner).recv(router_id).await.unwrap();
+        assert_eq!(nonce, 1);
+        let res = relayer
+            .execute_signed_swap(intent, signature, fee)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InvalidNonce)));
+    }
+
+    #[tokio::test]
+    async fn test_swap_with_callback_checks() {
+        let (remoting, router_id, _, wvara_id) = init_router().await;
+        let mut swapper = RouterClient::new(remoting.clone().with_actor_id(SWAPPER_ID.into()));
+        let receiver: ActorId = 78.into();
+
+        let res = swapper
+            .swap_exact_tokens_for_tokens_with_callback(
+                1000.into(),
+                1.into(),
+                vec![wvara_id],
+                receiver,
+                u64::MAX,
+                true,
+                b"deposit".to_vec(),
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InvalidPath)));
+
+        let res = swapper
+            .swap_exact_tokens_for_tokens_with_callback(
+                1000.into(),
+                1.into(),
+                vec![wvara_id, 77.into()],
+                receiver,
+                0,
+                true,
+                b"deposit".to_vec(),
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::Expired)));
+
+        // the swap itself runs before the recipient is called
+        let res = swapper
+            .swap_exact_tokens_for_tokens_with_callback(
+                1000.into(),
+                1.into(),
+                vec![wvara_id, 77.into()],
+                receiver,
+                u64::MAX,
+                true,
+                b"deposit".to_vec(),
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InsufficientAllowance)));
+        assert!(!swapper.get_lock().recv(router_id).await.unwrap());
+    }
+
+    /// Swaps `amount_in` along `path` for `to`, notifying it.
+    async fn swap_to(
+        router: &mut RouterClient<GTestRemoting>,
+        router_id: ActorId,
+        amount_in: U256,
+        path: &[ActorId],
+        to: ActorId,
+    ) -> Result<Vec<U256>, RouterError> {
+        router
+            .swap_exact_tokens_for_tokens_with_callback(
+                amount_in,
+                0.into(),
+                path.to_vec(),
+                to,
+                u64::MAX,
+                true,
+                b"deposit".to_vec(),
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_swap_with_callback() {
+        let (remoting, router_id, _, wvara_id) = init_router().await;
+        let swapper_remoting = remoting.clone().with_actor_id(SWAPPER_ID.into());
+        let mut swapper = RouterClient::new(swapper_remoting.clone());
+        let mut vft = Vft::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        for (token, amount) in [(token_a, 100), (wvara_id, 100)] {
+            create_pool(
+                &remoting,
+                router_id,
+                token,
+                amount * utils::ONE_TOKEN,
+                token_b,
+                100 * utils::ONE_TOKEN,
+            )
+            .await;
+        }
+        fund(&swapper_remoting, router_id, token_a, 10 * utils::ONE_TOKEN).await;
+        let accepting = deploy_receiver(&remoting, ReceiverMode::Accept, "accept").await;
+        let rejecting = deploy_receiver(&remoting, ReceiverMode::Reject, "reject").await;
+        let trapping = deploy_receiver(&remoting, ReceiverMode::Trap, "trap").await;
+
+        let amount_in = U256::from(utils::ONE_TOKEN);
+        let path = vec![token_a, token_b];
+        // the recipient holds the output once it accepts
+        let amounts = swap_to(&mut swapper, router_id, amount_in, &path, accepting).await.unwrap();
+        let balance = vft.balance_of(accepting).recv(token_b).await.unwrap();
+        assert_eq!(balance, amounts[1]);
+
+        // a rejecting recipient sends the output back, owed to the sender
+        let res = swap_to(&mut swapper, router_id, amount_in, &path, rejecting).await;
+        assert!(matches!(res, Err(RouterError::RecipientCallbackFailed { to }) if to == rejecting));
+        let balance = vft.balance_of(rejecting).recv(token_b).await.unwrap();
+        assert!(balance.is_zero());
+        let refunds = swapper.get_refunds(SWAPPER_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(refunds.len(), 1);
+        assert_eq!(refunds[0].0, token_b);
+        let refunded = refunds[0].1;
+        assert!(!refunded.is_zero());
+
+        // a trapping recipient keeps what it was sent
+        let res = swap_to(&mut swapper, router_id, amount_in, &path, trapping).await;
+        assert!(matches!(res, Err(RouterError::RecipientCallbackFailed { to }) if to == trapping));
+        let balance = vft.balance_of(trapping).recv(token_b).await.unwrap();
+        assert!(!balance.is_zero());
+        let refunds = swapper.get_refunds(SWAPPER_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(refunds, vec![(token_b, refunded)]);
+        assert!(!swapper.get_lock().recv(router_id).await.unwrap());
+
+        let claimed = swapper.claim_refunds().send_recv(router_id).await.unwrap().unwrap();
+        assert_eq!(claimed, vec![(token_b, refunded)]);
+        let balance = vft.balance_of(SWAPPER_ID.into()).recv(token_b).await.unwrap();
+        assert_eq!(balance, refunded);
+        let balance = vft.balance_of(router_id).recv(token_b).await.unwrap();
+        assert!(balance.is_zero());
+
+        // exact output and VARA input
+        let before = vft.balance_of(accepting).recv(token_b).await.unwrap();
+        let amount_out = U256::from(utils::ONE_TOKEN / 2);
+        swapper
+            .swap_tokens_for_exact_tokens_with_callback(
+                amount_out,
+                amount_in,
+                path.clone(),
+                accepting,
+                u64::MAX,
+                true,
+                vec![],
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let amounts = swapper
+            .swap_exact_vara_for_tokens_with_callback(
+                0.into(),
+                vec![wvara_id, token_b],
+                accepting,
+                u64::MAX,
+                true,
+                vec![],
+            )
+            .with_value(utils::ONE_TOKEN)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let balance = vft.balance_of(accepting).recv(token_b).await.unwrap();
+        assert_eq!(balance, before + amount_out + amounts[1]);
+
+        // a pause applied while a recipient is notified stays in place
+        let pausing = deploy_receiver(&remoting, ReceiverMode::Pause, "pause").await;
+        RouterClient::new(remoting.clone())
+            .grant_role(client::Role::Pauser, pausing)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        swap_to(&mut swapper, router_id, amount_in, &path, pausing).await.unwrap();
+        assert!(swapper.get_lock().recv(router_id).await.unwrap());
+        let res = swap_to(&mut swapper, router_id, amount_in, &path, accepting).await;
+        assert!(matches!(res, Err(RouterError::IncorrectState)));
+    }
+
+    #[tokio::test]
+    async fn test_failed_hop_refund() {
+        let (remoting, router_id, factory_id, _) = init_router().await;
+        let swapper_remoting = remoting.clone().with_actor_id(SWAPPER_ID.into());
+        let mut swapper = RouterClient::new(swapper_remoting.clone());
+        let mut vft = Vft::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        let token_c = deploy_token(&remoting, "TKC").await;
+        let amount = 100 * utils::ONE_TOKEN;
+        create_pool(&remoting, router_id, token_a, amount, token_b, amount).await;
+        let pair_bc = create_pool(&remoting, router_id, token_b, amount, token_c, amount).await;
+        fund(&swapper_remoting, router_id, token_a, 10 * utils::ONE_TOKEN).await;
+
+        // the second pair is paused once the first hop has paid into it
+        FactoryService::new(remoting.clone())
+            .pause_pair(pair_bc)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let amount_in = U256::from(utils::ONE_TOKEN);
+        let path = vec![token_a, token_b, token_c];
+        let quote = swapper
+            .get_amounts_out(amount_in, path.clone())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = swapper
+            .swap_exact_tokens_for_tokens(amount_in, 0.into(), path.clone(), SWAPPER_ID.into(), u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairSwapFailed { hop: 1, pair, .. }) if pair == pair_bc));
+
+        // the paused pair gives the first hop's output back to the swapper
+        let refunds = swapper.get_refunds(SWAPPER_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(refunds, vec![(token_b, quote[1])]);
+        let balance = vft.balance_of(pair_bc).recv(token_b).await.unwrap();
+        assert_eq!(balance, amount.into());
+        let claimed = swapper.claim_refunds().send_recv(router_id).await.unwrap().unwrap();
+        assert_eq!(claimed, vec![(token_b, quote[1])]);
+        let balance = vft.balance_of(SWAPPER_ID.into()).recv(token_b).await.unwrap();
+        assert_eq!(balance, quote[1]);
+
+        // the same through explicitly chosen factories
+        let quote = swapper
+            .get_amounts_out(amount_in, path.clone())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = swapper
+            .swap_exact_tokens_for_tokens_via(
+                amount_in,
+                0.into(),
+                path,
+                vec![factory_id, factory_id],
+                SWAPPER_ID.into(),
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairSwapFailed { hop: 1, pair, .. }) if pair == pair_bc));
+        let refunds = swapper.get_refunds(SWAPPER_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(refunds, vec![(token_b, quote[1])]);
+        assert!(!swapper.get_lock().recv(router_id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_create_pair_and_add_liquidity() {
+        let (remoting, router_id, _, _) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let lp = LpService::new(remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        let amount_a = 10 * utils::ONE_TOKEN;
+        let amount_b = 40 * utils::ONE_TOKEN;
+        fund(&remoting, router_id, token_a, amount_a).await;
+        fund(&remoting, router_id, token_b, amount_b).await;
+
+        let (pair, liquidity) = router
+            .create_pair_and_add_liquidity(
+                token_a,
+                token_b,
+                amount_a.into(),
+                amount_b.into(),
+                USER_ID.into(),
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        // sqrt(10 * 40) tokens minus the locked minimum liquidity
+        assert_eq!(liquidity, U256::from(20 * utils::ONE_TOKEN - 1000));
+        let balance = lp.balance_of(USER_ID.into()).recv(pair).await.unwrap();
+        assert_eq!(balance, liquidity);
+        let (reserve_0, reserve_1, _) = lp.get_reserves().recv(pair).await.unwrap();
+        assert_eq!(reserve_0 + reserve_1, U256::from(amount_a + amount_b));
+
+        fund(&remoting, router_id, token_a, amount_a).await;
+        fund(&remoting, router_id, token_b, amount_b).await;
+        let res = router
+            .create_pair_and_add_liquidity(
+                token_a,
+                token_b,
+                amount_a.into(),
+                amount_b.into(),
+                USER_ID.into(),
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairAlreadyExists)));
+    }
+
+    #[tokio::test]
+    async fn test_create_pair_and_add_liquidity_fails_atomically() {
+        let (remoting, router_id, factory_id, wvara_id) = init_router().await;
+        let mut router = RouterClient::new(remoting.clone());
+        let mut vft = Vft::new(remoting.clone());
+
+        // a second wrapped token to pair with
+        let wvara_code_id = remoting.system().submit_code_file("./wvara_vft_wasm.opt.wasm");
+        let token_b = WvaraVftFactory::new(remoting.clone())
+            .new("Wrapped VARA B".to_string(), "WVARAB".to_string(), 18)
+            .send_recv(wvara_code_id, "init token b")
+            .await
+            .unwrap();
+
+        let amount = U256::from(10 * utils::ONE_TOKEN);
+        for token in [wvara_id, token_b] {
+            vft.deposit()
+                .with_value(10 * utils::ONE_TOKEN)
+                .send_recv(token)
+                .await
+                .unwrap();
+            vft.approve(router_id, amount)
+                .send_recv(token)
+                .await
+                .unwrap();
+        }
+
+        // the factory refuses to create a pair of a blocked token
+        FactoryService::new(remoting.clone())
+            .set_token_status(token_b, TokenStatus::Blocked)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = router
+            .create_pair_and_add_liquidity(
+                wvara_id,
+                token_b,
+                amount,
+                amount,
+                ADMIN_ID.into(),
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            res,
+            Err(RouterError::FactoryCallFailed {
+                error: Some(client::FactoryError::TokenBlocked),
+                ..
+            })
+        ));
+
+        // nothing was moved and the router is usable again
+        for token in [wvara_id, token_b] {
+            let balance = vft.balance_of(ADMIN_ID.into()).recv(token).await.unwrap();
+            assert_eq!(balance, amount);
+        }
+        let lock = router.get_lock().recv(router_id).await.unwrap();
+        assert!(!lock);
+        let cache = router.get_pair_cache().recv(router_id).await.unwrap();
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_vault() {
+        let (remoting, router_id, _, wvara_id) = init_router().await;
+        let user_remoting = remoting.clone().with_actor_id(USER_ID.into());
+        let mut router = RouterClient::new(user_remoting.clone());
+        let mut admin_router = RouterClient::new(remoting.clone());
+        let mut vft = Vft::new(user_remoting.clone());
+
+        let amount = U256::from(10 * utils::ONE_TOKEN);
+        vft.deposit()
+            .with_value(10 * utils::ONE_TOKEN)
+            .send_recv(wvara_id)
+            .await
+            .unwrap();
+        vft.approve(router_id, amount)
+            .send_recv(wvara_id)
+            .await
+            .unwrap();
+        let balance = router
+            .deposit_to_vault(wvara_id, amount)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(balance, amount);
+        // VARA is wrapped once into the same wVARA balance
+        let balance = router
+            .deposit_vara_to_vault()
+            .with_value(5 * utils::ONE_TOKEN)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(balance, U256::from(15 * utils::ONE_TOKEN));
+        let balances = router.get_vault_balances(USER_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(balances, vec![(wvara_id, balance)]);
+
+        // the router only holds vault funds, none of which it can refund
+        let res = admin_router
+            .refund_token(wvara_id, 1.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InsufficientVaultBalance)));
+
+        // no pair exists, so the internal swap fails before touching the vault
+        let res = router
+            .swap_exact_tokens_for_tokens_internal(
+                amount,
+                0.into(),
+                vec![wvara_id, 77.into()],
+                u64::MAX,
+            )
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(res.is_err());
+        let balance = router.get_vault_balance(USER_ID.into(), wvara_id).recv(router_id).await.unwrap();
+        assert_eq!(balance, U256::from(15 * utils::ONE_TOKEN));
+        assert!(!router.get_lock().recv(router_id).await.unwrap());
+
+        let res = router
+            .withdraw_from_vault(wvara_id, U256::from(16 * utils::ONE_TOKEN), USER_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InsufficientVaultBalance)));
+        let left = router
+            .withdraw_from_vault(wvara_id, amount, FUND_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(left, U256::from(5 * utils::ONE_TOKEN));
+        let fund_balance = vft.balance_of(FUND_ID.into()).recv(wvara_id).await.unwrap();
+        assert_eq!(fund_balance, amount);
+
+        let left = router
+            .withdraw_vara_from_vault(left, USER_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(left.is_zero());
+        assert!(router.get_vault_balances(USER_ID.into()).recv(router_id).await.unwrap().is_empty());
+        let router_balance = vft.balance_of(router_id).recv(wvara_id).await.unwrap();
+        assert!(router_balance.is_zero());
+    }
+
+    #[tokio::test]
+    async fn test_vault_swap() {
+        let (remoting, router_id, _, _) = init_router().await;
+        let user_remoting = remoting.clone().with_actor_id(USER_ID.into());
+        let mut router = RouterClient::new(user_remoting.clone());
+        let mut vft = Vft::new(user_remoting.clone());
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        create_pool(
+            &remoting,
+            router_id,
+            token_a,
+            100 * utils::ONE_TOKEN,
+            token_b,
+            100 * utils::ONE_TOKEN,
+        )
+        .await;
+
+        let amount = U256::from(10 * utils::ONE_TOKEN);
+        fund(&user_remoting, router_id, token_a, 10 * utils::ONE_TOKEN).await;
+        router
+            .deposit_to_vault(token_a, amount)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let amount_in = U256::from(4 * utils::ONE_TOKEN);
+        let path = vec![token_a, token_b];
+        let quote = router
+            .get_amounts_out(amount_in, path.clone())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let amounts = router
+            .swap_exact_tokens_for_tokens_internal(amount_in, quote[1], path, u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(amounts, quote);
+
+        // the output stays in the router, credited to the vault
+        let balance = router.get_vault_balance(USER_ID.into(), token_a).recv(router_id).await.unwrap();
+        assert_eq!(balance, amount - amount_in);
+        let balance = router.get_vault_balance(USER_ID.into(), token_b).recv(router_id).await.unwrap();
+        assert_eq!(balance, quote[1]);
+        let router_balance = vft.balance_of(router_id).recv(token_b).await.unwrap();
+        assert_eq!(router_balance, quote[1]);
+        let user_balance = vft.balance_of(USER_ID.into()).recv(token_b).await.unwrap();
+        assert!(user_balance.is_zero());
+        assert!(!router.get_lock().recv(router_id).await.unwrap());
+
+        let left = router
+            .withdraw_from_vault(token_b, quote[1], USER_ID.into())
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(left.is_zero());
+        let user_balance = vft.balance_of(USER_ID.into()).recv(token_b).await.unwrap();
+        assert_eq!(user_balance, quote[1]);
+    }
+
+    #[tokio::test]
+    async fn test_create_pair_fee() {
+        let (remoting, router_id, factory_id, _) = init_router().await;
+        let system = remoting.system();
+        let mut factory = FactoryService::new(remoting.clone());
+        let mut user_router = RouterClient::new(remoting.clone().with_actor_id(USER_ID.into()));
+        let token_a = deploy_token(&remoting, "TKA").await;
+        let token_b = deploy_token(&remoting, "TKB").await;
+        let creation_fee = 10 * utils::ONE_TOKEN;
+        factory
+            .set_creation_fee(creation_fee)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // the factory gets its fee, the rest is owed to the creator
+        let extra = 3 * utils::ONE_TOKEN;
+        user_router
+            .create_pair(token_a, token_b)
+            .with_value(creation_fee + extra)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let policy = factory.get_pair_creation_policy().recv(factory_id).await.unwrap();
+        assert_eq!(policy.accrued_fees, creation_fee);
+        let refunds = user_router.get_refunds(USER_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(refunds, vec![(ActorId::zero(), extra.into())]);
+
+        // a rejected creation comes back with the factory's reply
+        let res = user_router
+            .create_pair(token_a, token_b)
+            .with_value(creation_fee)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairAlreadyExists)));
+        let token_c = deploy_token(&remoting, "TKC").await;
+        let res = user_router
+            .create_pair(token_a, token_c)
+            .with_value(creation_fee - 1)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            res,
+            Err(RouterError::FactoryCallFailed {
+                error: Some(client::FactoryError::InsufficientCreationFee),
+                ..
+            })
+        ));
+        let refunds = user_router.get_refunds(USER_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(refunds, vec![(ActorId::zero(), (extra + 2 * creation_fee - 1).into())]);
+
+        let router_vara = system.balance_of(router_id);
+        user_router.claim_refunds().send_recv(router_id).await.unwrap().unwrap();
+        let user_vara = system.balance_of(USER_ID);
+        claim_vara(system, router_id, USER_ID, "Refund Vara");
+        assert!(system.balance_of(USER_ID) >= user_vara + extra + 2 * creation_fee - 1);
+        assert_eq!(system.balance_of(router_id), router_vara - extra - 2 * creation_fee + 1);
+    }
+
+    #[tokio::test]
+    async fn test_native_vara_pair() {
+        let (remoting, router_id, factory_id, wvara_id) = init_router().await;
+        let system = remoting.system();
+        let mut router = RouterClient::new(remoting.clone());
+        let mut user_router = RouterClient::new(remoting.clone().with_actor_id(USER_ID.into()));
+        let mut vft = Vft::new(remoting.clone());
+        let mut lp = LpService::new(remoting.clone());
+        let token = deploy_token(&remoting, "TKA").await;
+        let amount = 100 * utils::ONE_TOKEN;
+
+        // no native pair yet: the miss is cached and the wVARA route is kept
+        let res = user_router
+            .swap_exact_vara_for_tokens(0.into(), vec![wvara_id, token], USER_ID.into(), u64::MAX)
+            .with_value(utils::ONE_TOKEN)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairNotFound)));
+        let cached = router.get_cached_pair(token, ActorId::zero()).recv(router_id).await.unwrap();
+        assert_eq!(cached, Some(ActorId::zero()));
+
+        // creating the pair replaces the cached miss
+        fund(&remoting, router_id, token, amount).await;
+        router
+            .create_pair(token, ActorId::zero())
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let pair = router.pair_for(token, ActorId::zero()).recv(router_id).await.unwrap().unwrap();
+        assert!(!pair.is_zero());
+        let cached = router.get_cached_pair(token, ActorId::zero()).recv(router_id).await.unwrap();
+        assert_eq!(cached, Some(pair));
+
+        let pair_vara = system.balance_of(pair);
+        let (amount_token, amount_vara, liquidity) = router
+            .add_liquidity_vara(token, amount.into(), 0.into(), 0.into(), ADMIN_ID.into(), u64::MAX)
+            .with_value(amount)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((amount_token, amount_vara), (amount.into(), amount.into()));
+        assert!(!liquidity.is_zero());
+        // the VARA side is the pair's own value, no wVARA is minted
+        assert_eq!(system.balance_of(pair), pair_vara + amount);
+        assert!(vft.total_supply().recv(wvara_id).await.unwrap().is_zero());
+        let (reserve_token, reserve_vara, _) = router
+            .get_reserves(token, ActorId::zero())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((reserve_token, reserve_vara), (amount.into(), amount.into()));
+
+        // stray VARA held by the pair stays out of the reserves
+        lp.sync().with_value(utils::ONE_TOKEN).send_recv(pair).await.unwrap().unwrap();
+        let pair_vara = pair_vara + utils::ONE_TOKEN;
+        assert_eq!(system.balance_of(pair), pair_vara + amount);
+        let (_, reserve_vara, _) = lp.get_reserves().recv(pair).await.unwrap();
+        assert_eq!(reserve_vara, amount.into());
+
+        // a rejected deposit sends the attached VARA back
+        Vft::new(remoting.clone().with_actor_id(USER_ID.into()))
+            .approve(router_id, utils::ONE_TOKEN.into())
+            .send_recv(token)
+            .await
+            .unwrap();
+        let res = user_router
+            .add_liquidity_vara(
+                token,
+                utils::ONE_TOKEN.into(),
+                0.into(),
+                (2 * utils::ONE_TOKEN).into(),
+                USER_ID.into(),
+                u64::MAX,
+            )
+            .with_value(utils::ONE_TOKEN)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InsufficientBAmount)));
+        let user_vara = system.balance_of(USER_ID);
+        claim_vara(system, router_id, USER_ID, "Refund Vara");
+        assert!(system.balance_of(USER_ID) >= user_vara + utils::ONE_TOKEN);
+
+        // VARA in, through the native pair
+        let vara_in = 10 * utils::ONE_TOKEN;
+        let quote = router
+            .get_amounts_out(vara_in.into(), vec![ActorId::zero(), token])
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let amounts = user_router
+            .swap_exact_vara_for_tokens(quote[1], vec![wvara_id, token], USER_ID.into(), u64::MAX)
+            .with_value(vara_in)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(amounts, quote);
+        let balance = vft.balance_of(USER_ID.into()).recv(token).await.unwrap();
+        assert_eq!(balance, quote[1]);
+        assert_eq!(system.balance_of(pair), pair_vara + amount + vara_in);
+
+        // tokens in, VARA out to a third account
+        let token_in = U256::from(utils::ONE_TOKEN);
+        let quote = router
+            .get_amounts_out(token_in, vec![token, ActorId::zero()])
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        Vft::new(remoting.clone().with_actor_id(USER_ID.into()))
+            .approve(router_id, token_in)
+            .send_recv(token)
+            .await
+            .unwrap();
+        let fund_vara = system.balance_of(FUND_ID);
+        let amounts = user_router
+            .swap_exact_tokens_for_vara(token_in, quote[1], vec![token, wvara_id], FUND_ID.into(), u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(amounts, quote);
+        claim_vara(system, pair, FUND_ID, "Transfer Vara");
+        assert_eq!(system.balance_of(FUND_ID), fund_vara + quote[1].as_u128());
+
+        // a failed first hop sends all the attached VARA back
+        let router_vara = system.balance_of(router_id);
+        let pair_vara = system.balance_of(pair);
+        FactoryService::new(remoting.clone())
+            .pause_pair(pair)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let res = user_router
+            .swap_exact_vara_for_tokens(0.into(), vec![wvara_id, token], USER_ID.into(), u64::MAX)
+            .with_value(vara_in)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::PairSwapFailed { hop: 0, .. })));
+        assert_eq!(system.balance_of(router_id), router_vara);
+        assert_eq!(system.balance_of(pair), pair_vara);
+        let user_vara = system.balance_of(USER_ID);
+        claim_vara(system, router_id, USER_ID, "Refund Vara");
+        // the claim also settles the user's unused gas
+        assert!(system.balance_of(USER_ID) >= user_vara + vara_in);
+        assert!(user_router.get_refunds(USER_ID.into()).recv(router_id).await.unwrap().is_empty());
+        FactoryService::new(remoting.clone())
+            .unpause_pair(pair)
+            .send_recv(factory_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // the burn goes through the router, which checks what it received;
+        // a short burn is owed to the caller
+        let burned = liquidity / 2;
+        lp.approve(router_id, liquidity).send_recv(pair).await.unwrap();
+        let res = router
+            .remove_liquidity_vara(token, burned, 0.into(), amount.into(), FUND_ID.into(), u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap();
+        assert!(matches!(res, Err(RouterError::InsufficientVaraAmount)));
+        let refunds = router.get_refunds(ADMIN_ID.into()).recv(router_id).await.unwrap();
+        assert_eq!(refunds.len(), 2);
+        assert!(refunds.iter().all(|(_, amount)| !amount.is_zero()));
+        let admin_token = vft.balance_of(ADMIN_ID.into()).recv(token).await.unwrap();
+        let claimed = router.claim_refunds().send_recv(router_id).await.unwrap().unwrap();
+        assert_eq!(claimed.len(), 2);
+        assert!(claimed.iter().all(|refund| refunds.contains(refund)));
+        let (_, refund_token) = refunds.iter().find(|(t, _)| *t == token).unwrap();
+        let balance = vft.balance_of(ADMIN_ID.into()).recv(token).await.unwrap();
+        assert_eq!(balance, admin_token + *refund_token);
+
+        let fund_token = vft.balance_of(FUND_ID.into()).recv(token).await.unwrap();
+        let fund_vara = system.balance_of(FUND_ID);
+        let router_vara = system.balance_of(router_id);
+        let (reserve_token, reserve_vara, _) = router
+            .get_reserves(token, ActorId::zero())
+            .recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let total_supply = lp.total_supply().recv(pair).await.unwrap();
+        let expected = (burned * reserve_token / total_supply, burned * reserve_vara / total_supply);
+        let amounts = router
+            .remove_liquidity_vara(token, burned, expected.0, expected.1, FUND_ID.into(), u64::MAX)
+            .send_recv(router_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(amounts, expected);
+        let balance = vft.balance_of(FUND_ID.into()).recv(token).await.unwrap();
+        assert_eq!(balance, fund_token + expected.0);
+        claim_vara(system, router_id, FUND_ID, "Transfer Vara");
+        assert_eq!(system.balance_of(FUND_ID), fund_vara + expected.1.as_u128());
+        assert_eq!(system.balance_of(router_id), router_vara);
+        assert!(!router.get_lock().recv(router_id).await.unwrap());
     }
 
     // #[tokio::test]
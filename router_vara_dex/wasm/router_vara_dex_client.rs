@@ -75,6 +75,20 @@ impl<R> RouterService<R> {
 }
 impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
     type Args = R::Args;
+    fn accept_admin(&mut self) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::AcceptAdmin>::new(self.remoting.clone(), ())
+    }
+    /// Registers a factory with the lowest lookup priority.
+    fn add_factory(
+        &mut self,
+        factory: ActorId,
+        fee_ppm: u128,
+    ) -> impl Call<Output = Result<(), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::AddFactory>::new(
+            self.remoting.clone(),
+            (factory, fee_ppm),
+        )
+    }
     fn add_liquidity(
         &mut self,
         token_a: ActorId,
@@ -121,6 +135,37 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             ),
         )
     }
+    fn cancel_admin_transfer(
+        &mut self,
+    ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::CancelAdminTransfer>::new(self.remoting.clone(), ())
+    }
+    /// Invalidates the caller's signed swap intent with `nonce`, which must
+    /// be its current one, so no relayer can execute it.
+    fn cancel_nonce(
+        &mut self,
+        nonce: u64,
+    ) -> impl Call<Output = Result<(), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::CancelNonce>::new(self.remoting.clone(), nonce)
+    }
+    fn cancel_operation(
+        &mut self,
+        id: u64,
+    ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::CancelOperation>::new(self.remoting.clone(), id)
+    }
+    /// Sends the caller every refund recorded for it and returns what was
+    /// sent, as (token, amount). Refunds whose transfer fails stay recorded.
+    fn claim_refunds(
+        &mut self,
+    ) -> impl Call<Output = Result<Vec<(ActorId, U256)>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::ClaimRefunds>::new(self.remoting.clone(), ())
+    }
+    fn clear_pair_cache(&mut self) -> impl Call<Output = Result<(), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::ClearPairCache>::new(self.remoting.clone(), ())
+    }
+    /// `msg::value()` pays the factory's creation fee; the rest is recorded
+    /// as a refund.
     fn create_pair(
         &mut self,
         token_a: ActorId,
@@ -131,9 +176,114 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             (token_a, token_b),
         )
     }
+    /// Creates the pair and mints its first liquidity in one message, so the
+    /// initial price cannot be front-run: it fails with `PairNotEmpty`, and
+    /// skims the deposit back to the caller, if the pair got reserves before
+    /// the mint. `msg::value()` pays the creation fee; the rest is recorded
+    /// as a refund.
+    fn create_pair_and_add_liquidity(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        amount_a: U256,
+        amount_b: U256,
+        to: ActorId,
+        deadline: u64,
+    ) -> impl Call<Output = Result<(ActorId, U256), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::CreatePairAndAddLiquidity>::new(
+            self.remoting.clone(),
+            (token_a, token_b, amount_a, amount_b, to, deadline),
+        )
+    }
+    /// VARA variant of `create_pair_and_add_liquidity`. `amount_vara` of the
+    /// attached value is wrapped and deposited, the rest pays the creation fee
+    /// with any change recorded as a refund.
+    fn create_pair_and_add_liquidity_vara(
+        &mut self,
+        token: ActorId,
+        amount_token: U256,
+        amount_vara: u128,
+        to: ActorId,
+        deadline: u64,
+    ) -> impl Call<Output = Result<(ActorId, U256), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::CreatePairAndAddLiquidityVara>::new(
+            self.remoting.clone(),
+            (token, amount_token, amount_vara, to, deadline),
+        )
+    }
+    /// Moves `amount` of `token` from the caller, who must have approved
+    /// the router, into its vault and returns the new vault balance.
+    fn deposit_to_vault(
+        &mut self,
+        token: ActorId,
+        amount: U256,
+    ) -> impl Call<Output = Result<U256, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::DepositToVault>::new(
+            self.remoting.clone(),
+            (token, amount),
+        )
+    }
+    /// Wraps the attached VARA once and credits it to the caller's wVARA
+    /// vault balance, which it returns.
+    fn deposit_vara_to_vault(
+        &mut self,
+    ) -> impl Call<Output = Result<U256, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::DepositVaraToVault>::new(self.remoting.clone(), ())
+    }
+    /// Applies a queued change once its `eta` has passed; callable by anyone.
+    fn execute_operation(
+        &mut self,
+        id: u64,
+    ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::ExecuteOperation>::new(self.remoting.clone(), id)
+    }
+    /// Runs `intent` as its owner's `swap_exact_tokens_for_tokens`, for any
+    /// relayer that submits it with the owner's `signature` over
+    /// `SwapIntent::payload`. The owner's allowance must cover `amount_in`
+    /// plus the relayer's `fee`, at most `max_relayer_fee`, which goes to the
+    /// relayer once the swap is done. Each nonce runs at most once, and is
+    /// used up once the owner's tokens have left, even if a hop then fails.
+    fn execute_signed_swap(
+        &mut self,
+        intent: SwapIntent,
+        signature: PermitSignature,
+        fee: U256,
+    ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::ExecuteSignedSwap>::new(
+            self.remoting.clone(),
+            (intent, signature, fee),
+        )
+    }
     fn lock_router(&mut self) -> impl Call<Output = Result<(), RouterError>, Args = R::Args> {
         RemotingAction::<_, router_service::io::LockRouter>::new(self.remoting.clone(), ())
     }
+    /// Moves `liquidity` from the deprecated `old_pair` into the pair the
+    /// primary factory now registers for `token_a`/`token_b`, minting the new
+    /// LP to `to`. Token amounts the new pair's price does not take are sent
+    /// back to the caller; whatever the router holds when the migration stops
+    /// halfway is recorded for `claim_refunds`.
+    fn migrate_liquidity(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        old_pair: ActorId,
+        liquidity: U256,
+        to: ActorId,
+        deadline: u64,
+    ) -> impl Call<Output = Result<(U256, U256, U256), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::MigrateLiquidity>::new(
+            self.remoting.clone(),
+            (token_a, token_b, old_pair, liquidity, to, deadline),
+        )
+    }
+    /// The proposed account becomes admin, with the old admin's roles, once
+    /// it calls `accept_admin`.
+    fn propose_admin(
+        &mut self,
+        new_addr: ActorId,
+    ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::ProposeAdmin>::new(self.remoting.clone(), new_addr)
+    }
     fn recover_pending_liquidity(
         &mut self,
         user: ActorId,
@@ -159,6 +309,12 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
     ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
         RemotingAction::<_, router_service::io::RefundVara>::new(self.remoting.clone(), amount)
     }
+    fn remove_factory(
+        &mut self,
+        factory: ActorId,
+    ) -> impl Call<Output = Result<(), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::RemoveFactory>::new(self.remoting.clone(), factory)
+    }
     fn remove_liquidity(
         &mut self,
         token_a: ActorId,
@@ -203,6 +359,57 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             ),
         )
     }
+    /// `remove_liquidity_vara` with a permit in place of the LP `approve`.
+    fn remove_liquidity_vara_with_permit(
+        &mut self,
+        token: ActorId,
+        liquidity: U256,
+        amount_token_min: U256,
+        amount_vara_min: U256,
+        to: ActorId,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> impl Call<Output = Result<(U256, U256), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::RemoveLiquidityVaraWithPermit>::new(
+            self.remoting.clone(),
+            (
+                token,
+                liquidity,
+                amount_token_min,
+                amount_vara_min,
+                to,
+                deadline,
+                signature,
+            ),
+        )
+    }
+    /// `remove_liquidity` with the router's LP allowance set by a permit the
+    /// caller signed for `liquidity` and `deadline`, instead of an `approve`.
+    fn remove_liquidity_with_permit(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        liquidity: U256,
+        amount_a_min: U256,
+        amount_b_min: U256,
+        to: ActorId,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> impl Call<Output = Result<(U256, U256), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::RemoveLiquidityWithPermit>::new(
+            self.remoting.clone(),
+            (
+                token_a,
+                token_b,
+                liquidity,
+                amount_a_min,
+                amount_b_min,
+                to,
+                deadline,
+                signature,
+            ),
+        )
+    }
     fn skim_pair_liquidity(
         &mut self,
         pair: ActorId,
@@ -222,6 +429,58 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             (amount_in, amount_out_min, path, to, deadline),
         )
     }
+    /// `swap_exact_tokens_for_tokens` paid from the caller's vault balance
+    /// of `path[0]`, with the output credited to its vault balance of the
+    /// last token. Skips the allowance query and the `transfer_from`.
+    fn swap_exact_tokens_for_tokens_internal(
+        &mut self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        deadline: u64,
+    ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SwapExactTokensForTokensInternal>::new(
+            self.remoting.clone(),
+            (amount_in, amount_out_min, path, deadline),
+        )
+    }
+    /// Like `swap_exact_tokens_for_tokens`, but hop `i` goes through the pair
+    /// of `factories[i]`, e.g. as returned by `get_best_amounts_out`. The
+    /// input a failed hop's pair held is recorded as the caller's refund.
+    fn swap_exact_tokens_for_tokens_via(
+        &mut self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        factories: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+    ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SwapExactTokensForTokensVia>::new(
+            self.remoting.clone(),
+            (amount_in, amount_out_min, path, factories, to, deadline),
+        )
+    }
+    /// `swap_exact_tokens_for_tokens` for a program recipient. With `notify`
+    /// the output goes through the router, which sends it to `to` and then
+    /// calls `on_varswap_received(token, amount, sender, data)` on it; the
+    /// call fails with `RecipientCallbackFailed` unless that returns `true`.
+    /// VARA outputs have no callback: they reach `to` as message value.
+    fn swap_exact_tokens_for_tokens_with_callback(
+        &mut self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+        notify: bool,
+        data: Vec<u8>,
+    ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SwapExactTokensForTokensWithCallback>::new(
+            self.remoting.clone(),
+            (amount_in, amount_out_min, path, to, deadline, notify, data),
+        )
+    }
     fn swap_exact_tokens_for_vara(
         &mut self,
         amount_in: U256,
@@ -247,6 +506,22 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             (amount_out_min, path, to, deadline),
         )
     }
+    /// `swap_exact_vara_for_tokens` with the recipient callback of
+    /// `swap_exact_tokens_for_tokens_with_callback`.
+    fn swap_exact_vara_for_tokens_with_callback(
+        &mut self,
+        amount_out_min: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+        notify: bool,
+        data: Vec<u8>,
+    ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SwapExactVaraForTokensWithCallback>::new(
+            self.remoting.clone(),
+            (amount_out_min, path, to, deadline, notify, data),
+        )
+    }
     fn swap_tokens_for_exact_tokens(
         &mut self,
         amount_out: U256,
@@ -260,6 +535,36 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             (amount_out, amount_in_max, path, to, deadline),
         )
     }
+    /// `swap_tokens_for_exact_tokens` between the caller's vault balances.
+    fn swap_tokens_for_exact_tokens_internal(
+        &mut self,
+        amount_out: U256,
+        amount_in_max: U256,
+        path: Vec<ActorId>,
+        deadline: u64,
+    ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SwapTokensForExactTokensInternal>::new(
+            self.remoting.clone(),
+            (amount_out, amount_in_max, path, deadline),
+        )
+    }
+    /// `swap_tokens_for_exact_tokens` with the recipient callback of
+    /// `swap_exact_tokens_for_tokens_with_callback`.
+    fn swap_tokens_for_exact_tokens_with_callback(
+        &mut self,
+        amount_out: U256,
+        amount_in_max: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+        notify: bool,
+        data: Vec<u8>,
+    ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SwapTokensForExactTokensWithCallback>::new(
+            self.remoting.clone(),
+            (amount_out, amount_in_max, path, to, deadline, notify, data),
+        )
+    }
     fn swap_tokens_for_exact_vara(
         &mut self,
         amount_out: U256,
@@ -285,54 +590,153 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             (amount_out, path, to, deadline),
         )
     }
+    /// `swap_vara_for_exact_tokens` with the recipient callback of
+    /// `swap_exact_tokens_for_tokens_with_callback`.
+    fn swap_vara_for_exact_tokens_with_callback(
+        &mut self,
+        amount_out: U256,
+        path: Vec<ActorId>,
+        to: ActorId,
+        deadline: u64,
+        notify: bool,
+        data: Vec<u8>,
+    ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SwapVaraForExactTokensWithCallback>::new(
+            self.remoting.clone(),
+            (amount_out, path, to, deadline, notify, data),
+        )
+    }
+    /// Called by a registered factory when a pair is added, replaced or
+    /// removed, or by the admin to repair the cache; admin syncs apply to the
+    /// primary factory. A zero `pair_address` evicts the entry.
+    fn sync_pair(
+        &mut self,
+        token_a: ActorId,
+        token_b: ActorId,
+        pair_address: ActorId,
+    ) -> impl Call<Output = Result<(), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SyncPair>::new(
+            self.remoting.clone(),
+            (token_a, token_b, pair_address),
+        )
+    }
     fn unlock_router(&mut self) -> impl Call<Output = Result<(), RouterError>, Args = R::Args> {
         RemotingAction::<_, router_service::io::UnlockRouter>::new(self.remoting.clone(), ())
     }
-    fn update_fund_addr(
+    fn update_factory_fee(
         &mut self,
-        new_fund_addr: ActorId,
-    ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
-        RemotingAction::<_, router_service::io::UpdateFundAddr>::new(
+        factory: ActorId,
+        fee_ppm: u128,
+    ) -> impl Call<Output = Result<(), RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::UpdateFactoryFee>::new(
             self.remoting.clone(),
-            new_fund_addr,
+            (factory, fee_ppm),
         )
     }
-    fn update_new_admin(
+    fn update_fund_addr(
         &mut self,
-        new_addr: ActorId,
+        new_fund_addr: ActorId,
     ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
-        RemotingAction::<_, router_service::io::UpdateNewAdmin>::new(
+        RemotingAction::<_, router_service::io::UpdateFundAddr>::new(
             self.remoting.clone(),
-            new_addr,
+            new_fund_addr,
         )
     }
+    /// Queues the change and returns its operation id; see `execute_operation`.
     fn update_new_factorty(
         &mut self,
         new_factory_addr: ActorId,
-    ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
+    ) -> impl Call<Output = Result<u64, RouterError>, Args = R::Args> {
         RemotingAction::<_, router_service::io::UpdateNewFactorty>::new(
             self.remoting.clone(),
             new_factory_addr,
         )
     }
+    /// Queues the change and returns its operation id.
     fn update_new_wrapvara(
         &mut self,
         new_wvara_addr: ActorId,
-    ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
+    ) -> impl Call<Output = Result<u64, RouterError>, Args = R::Args> {
         RemotingAction::<_, router_service::io::UpdateNewWrapvara>::new(
             self.remoting.clone(),
             new_wvara_addr,
         )
     }
+    /// Queues the change and returns its operation id.
     fn update_swap_fee_bps(
         &mut self,
         new_swap_fee_bps: u128,
-    ) -> impl Call<Output = Result<bool, RouterError>, Args = R::Args> {
+    ) -> impl Call<Output = Result<u64, RouterError>, Args = R::Args> {
         RemotingAction::<_, router_service::io::UpdateSwapFeeBps>::new(
             self.remoting.clone(),
             new_swap_fee_bps,
         )
     }
+    /// Lowering the delay waits out the current one like any other change.
+    fn update_timelock_delay(
+        &mut self,
+        delay: u64,
+    ) -> impl Call<Output = Result<u64, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::UpdateTimelockDelay>::new(
+            self.remoting.clone(),
+            delay,
+        )
+    }
+    /// Sends `amount` of `token` from the caller's vault to `to` and returns
+    /// what is left in the vault.
+    fn withdraw_from_vault(
+        &mut self,
+        token: ActorId,
+        amount: U256,
+        to: ActorId,
+    ) -> impl Call<Output = Result<U256, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::WithdrawFromVault>::new(
+            self.remoting.clone(),
+            (token, amount, to),
+        )
+    }
+    /// Unwraps `amount` of the caller's wVARA vault balance and sends it to
+    /// `to` as VARA.
+    fn withdraw_vara_from_vault(
+        &mut self,
+        amount: U256,
+        to: ActorId,
+    ) -> impl Call<Output = Result<U256, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::WithdrawVaraFromVault>::new(
+            self.remoting.clone(),
+            (amount, to),
+        )
+    }
+    /// Grants any role but `DefaultAdmin`, which the new holder has to
+    /// accept through the program's `propose_admin`/`accept_admin`.
+    fn grant_role(
+        &mut self,
+        role: Role,
+        account: ActorId,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GrantRole>::new(
+            self.remoting.clone(),
+            (role, account),
+        )
+    }
+    /// Drops a role of the caller. Renouncing the last `DefaultAdmin` leaves
+    /// the roles fixed for good.
+    fn renounce_role(
+        &mut self,
+        role: Role,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::RenounceRole>::new(self.remoting.clone(), role)
+    }
+    fn revoke_role(
+        &mut self,
+        role: Role,
+        account: ActorId,
+    ) -> impl Call<Output = Result<(), AccessControlError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::RevokeRole>::new(
+            self.remoting.clone(),
+            (role, account),
+        )
+    }
     fn get_admin(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, router_service::io::GetAdmin>::new(self.remoting.clone(), ())
     }
@@ -378,12 +782,41 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             (amount_in, path),
         )
     }
+    /// Quotes `path` hop by hop across all registered factories, picking for
+    /// each hop the pair with the best output.
+    fn get_best_amounts_out(
+        &self,
+        amount_in: U256,
+        path: Vec<ActorId>,
+    ) -> impl Query<Output = Result<PathQuote, RouterError>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetBestAmountsOut>::new(
+            self.remoting.clone(),
+            (amount_in, path),
+        )
+    }
+    fn get_cached_pair(
+        &self,
+        token_a: ActorId,
+        token_b: ActorId,
+    ) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetCachedPair>::new(
+            self.remoting.clone(),
+            (token_a, token_b),
+        )
+    }
+    fn get_factories(&self) -> impl Query<Output = Vec<FactoryInfo>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetFactories>::new(self.remoting.clone(), ())
+    }
     fn get_factory(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, router_service::io::GetFactory>::new(self.remoting.clone(), ())
     }
     fn get_fund_addr(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, router_service::io::GetFundAddr>::new(self.remoting.clone(), ())
     }
+    /// Nonce the next signed swap intent of `owner` must carry.
+    fn get_intent_nonce(&self, owner: ActorId) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetIntentNonce>::new(self.remoting.clone(), owner)
+    }
     fn get_liquidity_join(
         &self,
         user: ActorId,
@@ -393,6 +826,24 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
     fn get_lock(&self) -> impl Query<Output = bool, Args = R::Args> {
         RemotingAction::<_, router_service::io::GetLock>::new(self.remoting.clone(), ())
     }
+    fn get_pair_cache(
+        &self,
+    ) -> impl Query<Output = Vec<(ActorId, ActorId, ActorId)>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetPairCache>::new(self.remoting.clone(), ())
+    }
+    fn get_pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetPendingAdmin>::new(self.remoting.clone(), ())
+    }
+    fn get_queued_operations(&self) -> impl Query<Output = Vec<QueuedOperation>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetQueuedOperations>::new(self.remoting.clone(), ())
+    }
+    /// Refunds `claim_refunds` would send `user`, as (token, amount).
+    fn get_refunds(
+        &self,
+        user: ActorId,
+    ) -> impl Query<Output = Vec<(ActorId, U256)>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetRefunds>::new(self.remoting.clone(), user)
+    }
     fn get_reserves(
         &self,
         token_a: ActorId,
@@ -406,9 +857,30 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
     fn get_swap_fee_bps(&self) -> impl Query<Output = u128, Args = R::Args> {
         RemotingAction::<_, router_service::io::GetSwapFeeBps>::new(self.remoting.clone(), ())
     }
+    fn get_timelock_delay(&self) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetTimelockDelay>::new(self.remoting.clone(), ())
+    }
+    fn get_vault_balance(
+        &self,
+        owner: ActorId,
+        token: ActorId,
+    ) -> impl Query<Output = U256, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetVaultBalance>::new(
+            self.remoting.clone(),
+            (owner, token),
+        )
+    }
+    /// Every non-zero vault balance of `owner`, as (token, amount).
+    fn get_vault_balances(
+        &self,
+        owner: ActorId,
+    ) -> impl Query<Output = Vec<(ActorId, U256)>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetVaultBalances>::new(self.remoting.clone(), owner)
+    }
     fn get_wvara(&self) -> impl Query<Output = ActorId, Args = R::Args> {
         RemotingAction::<_, router_service::io::GetWvara>::new(self.remoting.clone(), ())
     }
+    /// Resolves the pair from the first registered factory that has one.
     fn pair_for(
         &self,
         token_a: ActorId,
@@ -430,7 +902,22 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             (amount_a, reserve_a, reserve_b),
         )
     }
-    fn sort_tokens(
+    /// Dry-runs a swap along `path` against the live reserves without moving
+    /// any funds. `amount` is the input amount when `exact_in` is set and the
+    /// desired output amount otherwise.
+    fn simulate_swap(
+        &self,
+        path: Vec<ActorId>,
+        amount: U256,
+        exact_in: bool,
+        deadline: u64,
+    ) -> impl Query<Output = SwapSimulation, Args = R::Args> {
+        RemotingAction::<_, router_service::io::SimulateSwap>::new(
+            self.remoting.clone(),
+            (path, amount, exact_in, deadline),
+        )
+    }
+    fn sort_tokens(
         &self,
         token_a: ActorId,
         token_b: ActorId,
@@ -440,6 +927,24 @@ impl<R: Remoting + Clone> traits::RouterService for RouterService<R> {
             (token_a, token_b),
         )
     }
+    fn get_account_roles(
+        &self,
+        account: ActorId,
+    ) -> impl Query<Output = Vec<Role>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetAccountRoles>::new(
+            self.remoting.clone(),
+            account,
+        )
+    }
+    fn get_role_members(&self, role: Role) -> impl Query<Output = Vec<ActorId>, Args = R::Args> {
+        RemotingAction::<_, router_service::io::GetRoleMembers>::new(self.remoting.clone(), role)
+    }
+    fn has_role(&self, role: Role, account: ActorId) -> impl Query<Output = bool, Args = R::Args> {
+        RemotingAction::<_, router_service::io::HasRole>::new(
+            self.remoting.clone(),
+            (role, account),
+        )
+    }
 }
 
 pub mod router_service {
@@ -448,6 +953,36 @@ pub mod router_service {
     pub mod io {
         use super::*;
         use sails_rs::calls::ActionIo;
+        pub struct AcceptAdmin(());
+        impl AcceptAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <AcceptAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for AcceptAdmin {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 44, 65, 99, 99,
+                101, 112, 116, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Result<bool, super::RouterError>;
+        }
+        pub struct AddFactory(());
+        impl AddFactory {
+            #[allow(dead_code)]
+            pub fn encode_call(factory: ActorId, fee_ppm: u128) -> Vec<u8> {
+                <AddFactory as ActionIo>::encode_call(&(factory, fee_ppm))
+            }
+        }
+        impl ActionIo for AddFactory {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 40, 65, 100, 100,
+                70, 97, 99, 116, 111, 114, 121,
+            ];
+            type Params = (ActorId, u128);
+            type Reply = Result<(), super::RouterError>;
+        }
         pub struct AddLiquidity(());
         impl AddLiquidity {
             #[allow(dead_code)]
@@ -510,6 +1045,81 @@ pub mod router_service {
             type Params = (ActorId, U256, U256, U256, ActorId, u64);
             type Reply = Result<(U256, U256, U256), super::RouterError>;
         }
+        pub struct CancelAdminTransfer(());
+        impl CancelAdminTransfer {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <CancelAdminTransfer as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for CancelAdminTransfer {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 76, 67, 97, 110,
+                99, 101, 108, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114,
+            ];
+            type Params = ();
+            type Reply = Result<bool, super::RouterError>;
+        }
+        pub struct CancelNonce(());
+        impl CancelNonce {
+            #[allow(dead_code)]
+            pub fn encode_call(nonce: u64) -> Vec<u8> {
+                <CancelNonce as ActionIo>::encode_call(&nonce)
+            }
+        }
+        impl ActionIo for CancelNonce {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 44, 67, 97, 110,
+                99, 101, 108, 78, 111, 110, 99, 101,
+            ];
+            type Params = u64;
+            type Reply = Result<(), super::RouterError>;
+        }
+        pub struct CancelOperation(());
+        impl CancelOperation {
+            #[allow(dead_code)]
+            pub fn encode_call(id: u64) -> Vec<u8> {
+                <CancelOperation as ActionIo>::encode_call(&id)
+            }
+        }
+        impl ActionIo for CancelOperation {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 60, 67, 97, 110,
+                99, 101, 108, 79, 112, 101, 114, 97, 116, 105, 111, 110,
+            ];
+            type Params = u64;
+            type Reply = Result<bool, super::RouterError>;
+        }
+        pub struct ClaimRefunds(());
+        impl ClaimRefunds {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <ClaimRefunds as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for ClaimRefunds {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 48, 67, 108, 97,
+                105, 109, 82, 101, 102, 117, 110, 100, 115,
+            ];
+            type Params = ();
+            type Reply = Result<Vec<(ActorId, U256)>, super::RouterError>;
+        }
+        pub struct ClearPairCache(());
+        impl ClearPairCache {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <ClearPairCache as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for ClearPairCache {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 56, 67, 108, 101,
+                97, 114, 80, 97, 105, 114, 67, 97, 99, 104, 101,
+            ];
+            type Params = ();
+            type Reply = Result<(), super::RouterError>;
+        }
         pub struct CreatePair(());
         impl CreatePair {
             #[allow(dead_code)]
@@ -525,6 +1135,123 @@ pub mod router_service {
             type Params = (ActorId, ActorId);
             type Reply = Result<(), super::RouterError>;
         }
+        pub struct CreatePairAndAddLiquidity(());
+        impl CreatePairAndAddLiquidity {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                token_a: ActorId,
+                token_b: ActorId,
+                amount_a: U256,
+                amount_b: U256,
+                to: ActorId,
+                deadline: u64,
+            ) -> Vec<u8> {
+                <CreatePairAndAddLiquidity as ActionIo>::encode_call(&(
+                    token_a, token_b, amount_a, amount_b, to, deadline,
+                ))
+            }
+        }
+        impl ActionIo for CreatePairAndAddLiquidity {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 100, 67, 114,
+                101, 97, 116, 101, 80, 97, 105, 114, 65, 110, 100, 65, 100, 100, 76, 105, 113, 117,
+                105, 100, 105, 116, 121,
+            ];
+            type Params = (ActorId, ActorId, U256, U256, ActorId, u64);
+            type Reply = Result<(ActorId, U256), super::RouterError>;
+        }
+        pub struct CreatePairAndAddLiquidityVara(());
+        impl CreatePairAndAddLiquidityVara {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                token: ActorId,
+                amount_token: U256,
+                amount_vara: u128,
+                to: ActorId,
+                deadline: u64,
+            ) -> Vec<u8> {
+                <CreatePairAndAddLiquidityVara as ActionIo>::encode_call(&(
+                    token,
+                    amount_token,
+                    amount_vara,
+                    to,
+                    deadline,
+                ))
+            }
+        }
+        impl ActionIo for CreatePairAndAddLiquidityVara {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 116, 67, 114,
+                101, 97, 116, 101, 80, 97, 105, 114, 65, 110, 100, 65, 100, 100, 76, 105, 113, 117,
+                105, 100, 105, 116, 121, 86, 97, 114, 97,
+            ];
+            type Params = (ActorId, U256, u128, ActorId, u64);
+            type Reply = Result<(ActorId, U256), super::RouterError>;
+        }
+        pub struct DepositToVault(());
+        impl DepositToVault {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, amount: U256) -> Vec<u8> {
+                <DepositToVault as ActionIo>::encode_call(&(token, amount))
+            }
+        }
+        impl ActionIo for DepositToVault {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 56, 68, 101, 112,
+                111, 115, 105, 116, 84, 111, 86, 97, 117, 108, 116,
+            ];
+            type Params = (ActorId, U256);
+            type Reply = Result<U256, super::RouterError>;
+        }
+        pub struct DepositVaraToVault(());
+        impl DepositVaraToVault {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <DepositVaraToVault as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for DepositVaraToVault {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 72, 68, 101, 112,
+                111, 115, 105, 116, 86, 97, 114, 97, 84, 111, 86, 97, 117, 108, 116,
+            ];
+            type Params = ();
+            type Reply = Result<U256, super::RouterError>;
+        }
+        pub struct ExecuteOperation(());
+        impl ExecuteOperation {
+            #[allow(dead_code)]
+            pub fn encode_call(id: u64) -> Vec<u8> {
+                <ExecuteOperation as ActionIo>::encode_call(&id)
+            }
+        }
+        impl ActionIo for ExecuteOperation {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 64, 69, 120, 101,
+                99, 117, 116, 101, 79, 112, 101, 114, 97, 116, 105, 111, 110,
+            ];
+            type Params = u64;
+            type Reply = Result<bool, super::RouterError>;
+        }
+        pub struct ExecuteSignedSwap(());
+        impl ExecuteSignedSwap {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                intent: super::SwapIntent,
+                signature: super::PermitSignature,
+                fee: U256,
+            ) -> Vec<u8> {
+                <ExecuteSignedSwap as ActionIo>::encode_call(&(intent, signature, fee))
+            }
+        }
+        impl ActionIo for ExecuteSignedSwap {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 68, 69, 120, 101,
+                99, 117, 116, 101, 83, 105, 103, 110, 101, 100, 83, 119, 97, 112,
+            ];
+            type Params = (super::SwapIntent, super::PermitSignature, U256);
+            type Reply = Result<Vec<U256>, super::RouterError>;
+        }
         pub struct LockRouter(());
         impl LockRouter {
             #[allow(dead_code)]
@@ -540,6 +1267,45 @@ pub mod router_service {
             type Params = ();
             type Reply = Result<(), super::RouterError>;
         }
+        pub struct MigrateLiquidity(());
+        impl MigrateLiquidity {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                token_a: ActorId,
+                token_b: ActorId,
+                old_pair: ActorId,
+                liquidity: U256,
+                to: ActorId,
+                deadline: u64,
+            ) -> Vec<u8> {
+                <MigrateLiquidity as ActionIo>::encode_call(&(
+                    token_a, token_b, old_pair, liquidity, to, deadline,
+                ))
+            }
+        }
+        impl ActionIo for MigrateLiquidity {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 64, 77, 105, 103,
+                114, 97, 116, 101, 76, 105, 113, 117, 105, 100, 105, 116, 121,
+            ];
+            type Params = (ActorId, ActorId, ActorId, U256, ActorId, u64);
+            type Reply = Result<(U256, U256, U256), super::RouterError>;
+        }
+        pub struct ProposeAdmin(());
+        impl ProposeAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call(new_addr: ActorId) -> Vec<u8> {
+                <ProposeAdmin as ActionIo>::encode_call(&new_addr)
+            }
+        }
+        impl ActionIo for ProposeAdmin {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 48, 80, 114, 111,
+                112, 111, 115, 101, 65, 100, 109, 105, 110,
+            ];
+            type Params = ActorId;
+            type Reply = Result<bool, super::RouterError>;
+        }
         pub struct RecoverPendingLiquidity(());
         impl RecoverPendingLiquidity {
             #[allow(dead_code)]
@@ -586,6 +1352,21 @@ pub mod router_service {
             type Params = u128;
             type Reply = Result<bool, super::RouterError>;
         }
+        pub struct RemoveFactory(());
+        impl RemoveFactory {
+            #[allow(dead_code)]
+            pub fn encode_call(factory: ActorId) -> Vec<u8> {
+                <RemoveFactory as ActionIo>::encode_call(&factory)
+            }
+        }
+        impl ActionIo for RemoveFactory {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 52, 82, 101, 109,
+                111, 118, 101, 70, 97, 99, 116, 111, 114, 121,
+            ];
+            type Params = ActorId;
+            type Reply = Result<(), super::RouterError>;
+        }
         pub struct RemoveLiquidity(());
         impl RemoveLiquidity {
             #[allow(dead_code)]
@@ -646,6 +1427,89 @@ pub mod router_service {
             type Params = (ActorId, U256, U256, U256, ActorId, u64);
             type Reply = Result<(U256, U256), super::RouterError>;
         }
+        pub struct RemoveLiquidityVaraWithPermit(());
+        impl RemoveLiquidityVaraWithPermit {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                token: ActorId,
+                liquidity: U256,
+                amount_token_min: U256,
+                amount_vara_min: U256,
+                to: ActorId,
+                deadline: u64,
+                signature: super::PermitSignature,
+            ) -> Vec<u8> {
+                <RemoveLiquidityVaraWithPermit as ActionIo>::encode_call(&(
+                    token,
+                    liquidity,
+                    amount_token_min,
+                    amount_vara_min,
+                    to,
+                    deadline,
+                    signature,
+                ))
+            }
+        }
+        impl ActionIo for RemoveLiquidityVaraWithPermit {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 116, 82, 101,
+                109, 111, 118, 101, 76, 105, 113, 117, 105, 100, 105, 116, 121, 86, 97, 114, 97,
+                87, 105, 116, 104, 80, 101, 114, 109, 105, 116,
+            ];
+            type Params = (
+                ActorId,
+                U256,
+                U256,
+                U256,
+                ActorId,
+                u64,
+                super::PermitSignature,
+            );
+            type Reply = Result<(U256, U256), super::RouterError>;
+        }
+        pub struct RemoveLiquidityWithPermit(());
+        impl RemoveLiquidityWithPermit {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                token_a: ActorId,
+                token_b: ActorId,
+                liquidity: U256,
+                amount_a_min: U256,
+                amount_b_min: U256,
+                to: ActorId,
+                deadline: u64,
+                signature: super::PermitSignature,
+            ) -> Vec<u8> {
+                <RemoveLiquidityWithPermit as ActionIo>::encode_call(&(
+                    token_a,
+                    token_b,
+                    liquidity,
+                    amount_a_min,
+                    amount_b_min,
+                    to,
+                    deadline,
+                    signature,
+                ))
+            }
+        }
+        impl ActionIo for RemoveLiquidityWithPermit {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 100, 82, 101,
+                109, 111, 118, 101, 76, 105, 113, 117, 105, 100, 105, 116, 121, 87, 105, 116, 104,
+                80, 101, 114, 109, 105, 116,
+            ];
+            type Params = (
+                ActorId,
+                ActorId,
+                U256,
+                U256,
+                U256,
+                ActorId,
+                u64,
+                super::PermitSignature,
+            );
+            type Reply = Result<(U256, U256), super::RouterError>;
+        }
         pub struct SkimPairLiquidity(());
         impl SkimPairLiquidity {
             #[allow(dead_code)]
@@ -689,91 +1553,267 @@ pub mod router_service {
             type Params = (U256, U256, Vec<ActorId>, ActorId, u64);
             type Reply = Result<Vec<U256>, super::RouterError>;
         }
-        pub struct SwapExactTokensForVara(());
-        impl SwapExactTokensForVara {
+        pub struct SwapExactTokensForTokensInternal(());
+        impl SwapExactTokensForTokensInternal {
             #[allow(dead_code)]
             pub fn encode_call(
                 amount_in: U256,
                 amount_out_min: U256,
                 path: Vec<ActorId>,
-                to: ActorId,
                 deadline: u64,
             ) -> Vec<u8> {
-                <SwapExactTokensForVara as ActionIo>::encode_call(&(
+                <SwapExactTokensForTokensInternal as ActionIo>::encode_call(&(
                     amount_in,
                     amount_out_min,
                     path,
-                    to,
                     deadline,
                 ))
             }
         }
-        impl ActionIo for SwapExactTokensForVara {
+        impl ActionIo for SwapExactTokensForTokensInternal {
             const ROUTE: &'static [u8] = &[
-                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 88, 83, 119, 97,
-                112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70, 111, 114, 86, 97, 114,
-                97,
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 128, 83, 119, 97,
+                112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70, 111, 114, 84, 111, 107,
+                101, 110, 115, 73, 110, 116, 101, 114, 110, 97, 108,
             ];
-            type Params = (U256, U256, Vec<ActorId>, ActorId, u64);
+            type Params = (U256, U256, Vec<ActorId>, u64);
             type Reply = Result<Vec<U256>, super::RouterError>;
         }
-        pub struct SwapExactVaraForTokens(());
-        impl SwapExactVaraForTokens {
+        pub struct SwapExactTokensForTokensVia(());
+        impl SwapExactTokensForTokensVia {
             #[allow(dead_code)]
             pub fn encode_call(
+                amount_in: U256,
                 amount_out_min: U256,
                 path: Vec<ActorId>,
+                factories: Vec<ActorId>,
                 to: ActorId,
                 deadline: u64,
             ) -> Vec<u8> {
-                <SwapExactVaraForTokens as ActionIo>::encode_call(&(
+                <SwapExactTokensForTokensVia as ActionIo>::encode_call(&(
+                    amount_in,
                     amount_out_min,
                     path,
+                    factories,
                     to,
                     deadline,
                 ))
             }
         }
-        impl ActionIo for SwapExactVaraForTokens {
+        impl ActionIo for SwapExactTokensForTokensVia {
             const ROUTE: &'static [u8] = &[
-                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 88, 83, 119, 97,
-                112, 69, 120, 97, 99, 116, 86, 97, 114, 97, 70, 111, 114, 84, 111, 107, 101, 110,
-                115,
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 108, 83, 119, 97,
+                112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70, 111, 114, 84, 111, 107,
+                101, 110, 115, 86, 105, 97,
             ];
-            type Params = (U256, Vec<ActorId>, ActorId, u64);
+            type Params = (U256, U256, Vec<ActorId>, Vec<ActorId>, ActorId, u64);
             type Reply = Result<Vec<U256>, super::RouterError>;
         }
-        pub struct SwapTokensForExactTokens(());
-        impl SwapTokensForExactTokens {
+        pub struct SwapExactTokensForTokensWithCallback(());
+        impl SwapExactTokensForTokensWithCallback {
             #[allow(dead_code)]
             pub fn encode_call(
-                amount_out: U256,
-                amount_in_max: U256,
+                amount_in: U256,
+                amount_out_min: U256,
                 path: Vec<ActorId>,
                 to: ActorId,
                 deadline: u64,
+                notify: bool,
+                data: Vec<u8>,
             ) -> Vec<u8> {
-                <SwapTokensForExactTokens as ActionIo>::encode_call(&(
-                    amount_out,
-                    amount_in_max,
+                <SwapExactTokensForTokensWithCallback as ActionIo>::encode_call(&(
+                    amount_in,
+                    amount_out_min,
                     path,
                     to,
                     deadline,
+                    notify,
+                    data,
                 ))
             }
         }
-        impl ActionIo for SwapTokensForExactTokens {
+        impl ActionIo for SwapExactTokensForTokensWithCallback {
             const ROUTE: &'static [u8] = &[
-                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 96, 83, 119, 97,
-                112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97, 99, 116, 84, 111, 107,
-                101, 110, 115,
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 144, 83, 119, 97,
+                112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70, 111, 114, 84, 111, 107,
+                101, 110, 115, 87, 105, 116, 104, 67, 97, 108, 108, 98, 97, 99, 107,
             ];
-            type Params = (U256, U256, Vec<ActorId>, ActorId, u64);
+            type Params = (U256, U256, Vec<ActorId>, ActorId, u64, bool, Vec<u8>);
             type Reply = Result<Vec<U256>, super::RouterError>;
         }
-        pub struct SwapTokensForExactVara(());
-        impl SwapTokensForExactVara {
-            #[allow(dead_code)]
+        pub struct SwapExactTokensForVara(());
+        impl SwapExactTokensForVara {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                amount_in: U256,
+                amount_out_min: U256,
+                path: Vec<ActorId>,
+                to: ActorId,
+                deadline: u64,
+            ) -> Vec<u8> {
+                <SwapExactTokensForVara as ActionIo>::encode_call(&(
+                    amount_in,
+                    amount_out_min,
+                    path,
+                    to,
+                    deadline,
+                ))
+            }
+        }
+        impl ActionIo for SwapExactTokensForVara {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 88, 83, 119, 97,
+                112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70, 111, 114, 86, 97, 114,
+                97,
+            ];
+            type Params = (U256, U256, Vec<ActorId>, ActorId, u64);
+            type Reply = Result<Vec<U256>, super::RouterError>;
+        }
+        pub struct SwapExactVaraForTokens(());
+        impl SwapExactVaraForTokens {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                amount_out_min: U256,
+                path: Vec<ActorId>,
+                to: ActorId,
+                deadline: u64,
+            ) -> Vec<u8> {
+                <SwapExactVaraForTokens as ActionIo>::encode_call(&(
+                    amount_out_min,
+                    path,
+                    to,
+                    deadline,
+                ))
+            }
+        }
+        impl ActionIo for SwapExactVaraForTokens {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 88, 83, 119, 97,
+                112, 69, 120, 97, 99, 116, 86, 97, 114, 97, 70, 111, 114, 84, 111, 107, 101, 110,
+                115,
+            ];
+            type Params = (U256, Vec<ActorId>, ActorId, u64);
+            type Reply = Result<Vec<U256>, super::RouterError>;
+        }
+        pub struct SwapExactVaraForTokensWithCallback(());
+        impl SwapExactVaraForTokensWithCallback {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                amount_out_min: U256,
+                path: Vec<ActorId>,
+                to: ActorId,
+                deadline: u64,
+                notify: bool,
+                data: Vec<u8>,
+            ) -> Vec<u8> {
+                <SwapExactVaraForTokensWithCallback as ActionIo>::encode_call(&(
+                    amount_out_min,
+                    path,
+                    to,
+                    deadline,
+                    notify,
+                    data,
+                ))
+            }
+        }
+        impl ActionIo for SwapExactVaraForTokensWithCallback {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 136, 83, 119, 97,
+                112, 69, 120, 97, 99, 116, 86, 97, 114, 97, 70, 111, 114, 84, 111, 107, 101, 110,
+                115, 87, 105, 116, 104, 67, 97, 108, 108, 98, 97, 99, 107,
+            ];
+            type Params = (U256, Vec<ActorId>, ActorId, u64, bool, Vec<u8>);
+            type Reply = Result<Vec<U256>, super::RouterError>;
+        }
+        pub struct SwapTokensForExactTokens(());
+        impl SwapTokensForExactTokens {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                amount_out: U256,
+                amount_in_max: U256,
+                path: Vec<ActorId>,
+                to: ActorId,
+                deadline: u64,
+            ) -> Vec<u8> {
+                <SwapTokensForExactTokens as ActionIo>::encode_call(&(
+                    amount_out,
+                    amount_in_max,
+                    path,
+                    to,
+                    deadline,
+                ))
+            }
+        }
+        impl ActionIo for SwapTokensForExactTokens {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 96, 83, 119, 97,
+                112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97, 99, 116, 84, 111, 107,
+                101, 110, 115,
+            ];
+            type Params = (U256, U256, Vec<ActorId>, ActorId, u64);
+            type Reply = Result<Vec<U256>, super::RouterError>;
+        }
+        pub struct SwapTokensForExactTokensInternal(());
+        impl SwapTokensForExactTokensInternal {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                amount_out: U256,
+                amount_in_max: U256,
+                path: Vec<ActorId>,
+                deadline: u64,
+            ) -> Vec<u8> {
+                <SwapTokensForExactTokensInternal as ActionIo>::encode_call(&(
+                    amount_out,
+                    amount_in_max,
+                    path,
+                    deadline,
+                ))
+            }
+        }
+        impl ActionIo for SwapTokensForExactTokensInternal {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 128, 83, 119, 97,
+                112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97, 99, 116, 84, 111, 107,
+                101, 110, 115, 73, 110, 116, 101, 114, 110, 97, 108,
+            ];
+            type Params = (U256, U256, Vec<ActorId>, u64);
+            type Reply = Result<Vec<U256>, super::RouterError>;
+        }
+        pub struct SwapTokensForExactTokensWithCallback(());
+        impl SwapTokensForExactTokensWithCallback {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                amount_out: U256,
+                amount_in_max: U256,
+                path: Vec<ActorId>,
+                to: ActorId,
+                deadline: u64,
+                notify: bool,
+                data: Vec<u8>,
+            ) -> Vec<u8> {
+                <SwapTokensForExactTokensWithCallback as ActionIo>::encode_call(&(
+                    amount_out,
+                    amount_in_max,
+                    path,
+                    to,
+                    deadline,
+                    notify,
+                    data,
+                ))
+            }
+        }
+        impl ActionIo for SwapTokensForExactTokensWithCallback {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 144, 83, 119, 97,
+                112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97, 99, 116, 84, 111, 107,
+                101, 110, 115, 87, 105, 116, 104, 67, 97, 108, 108, 98, 97, 99, 107,
+            ];
+            type Params = (U256, U256, Vec<ActorId>, ActorId, u64, bool, Vec<u8>);
+            type Reply = Result<Vec<U256>, super::RouterError>;
+        }
+        pub struct SwapTokensForExactVara(());
+        impl SwapTokensForExactVara {
+            #[allow(dead_code)]
             pub fn encode_call(
                 amount_out: U256,
                 amount_in_max: U256,
@@ -820,6 +1860,50 @@ pub mod router_service {
             type Params = (U256, Vec<ActorId>, ActorId, u64);
             type Reply = Result<Vec<U256>, super::RouterError>;
         }
+        pub struct SwapVaraForExactTokensWithCallback(());
+        impl SwapVaraForExactTokensWithCallback {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                amount_out: U256,
+                path: Vec<ActorId>,
+                to: ActorId,
+                deadline: u64,
+                notify: bool,
+                data: Vec<u8>,
+            ) -> Vec<u8> {
+                <SwapVaraForExactTokensWithCallback as ActionIo>::encode_call(&(
+                    amount_out, path, to, deadline, notify, data,
+                ))
+            }
+        }
+        impl ActionIo for SwapVaraForExactTokensWithCallback {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 136, 83, 119, 97,
+                112, 86, 97, 114, 97, 70, 111, 114, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110,
+                115, 87, 105, 116, 104, 67, 97, 108, 108, 98, 97, 99, 107,
+            ];
+            type Params = (U256, Vec<ActorId>, ActorId, u64, bool, Vec<u8>);
+            type Reply = Result<Vec<U256>, super::RouterError>;
+        }
+        pub struct SyncPair(());
+        impl SyncPair {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                token_a: ActorId,
+                token_b: ActorId,
+                pair_address: ActorId,
+            ) -> Vec<u8> {
+                <SyncPair as ActionIo>::encode_call(&(token_a, token_b, pair_address))
+            }
+        }
+        impl ActionIo for SyncPair {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 32, 83, 121, 110,
+                99, 80, 97, 105, 114,
+            ];
+            type Params = (ActorId, ActorId, ActorId);
+            type Reply = Result<(), super::RouterError>;
+        }
         pub struct UnlockRouter(());
         impl UnlockRouter {
             #[allow(dead_code)]
@@ -835,32 +1919,32 @@ pub mod router_service {
             type Params = ();
             type Reply = Result<(), super::RouterError>;
         }
-        pub struct UpdateFundAddr(());
-        impl UpdateFundAddr {
+        pub struct UpdateFactoryFee(());
+        impl UpdateFactoryFee {
             #[allow(dead_code)]
-            pub fn encode_call(new_fund_addr: ActorId) -> Vec<u8> {
-                <UpdateFundAddr as ActionIo>::encode_call(&new_fund_addr)
+            pub fn encode_call(factory: ActorId, fee_ppm: u128) -> Vec<u8> {
+                <UpdateFactoryFee as ActionIo>::encode_call(&(factory, fee_ppm))
             }
         }
-        impl ActionIo for UpdateFundAddr {
+        impl ActionIo for UpdateFactoryFee {
             const ROUTE: &'static [u8] = &[
-                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 56, 85, 112, 100,
-                97, 116, 101, 70, 117, 110, 100, 65, 100, 100, 114,
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 64, 85, 112, 100,
+                97, 116, 101, 70, 97, 99, 116, 111, 114, 121, 70, 101, 101,
             ];
-            type Params = ActorId;
-            type Reply = Result<bool, super::RouterError>;
+            type Params = (ActorId, u128);
+            type Reply = Result<(), super::RouterError>;
         }
-        pub struct UpdateNewAdmin(());
-        impl UpdateNewAdmin {
+        pub struct UpdateFundAddr(());
+        impl UpdateFundAddr {
             #[allow(dead_code)]
-            pub fn encode_call(new_addr: ActorId) -> Vec<u8> {
-                <UpdateNewAdmin as ActionIo>::encode_call(&new_addr)
+            pub fn encode_call(new_fund_addr: ActorId) -> Vec<u8> {
+                <UpdateFundAddr as ActionIo>::encode_call(&new_fund_addr)
             }
         }
-        impl ActionIo for UpdateNewAdmin {
+        impl ActionIo for UpdateFundAddr {
             const ROUTE: &'static [u8] = &[
                 52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 56, 85, 112, 100,
-                97, 116, 101, 78, 101, 119, 65, 100, 109, 105, 110,
+                97, 116, 101, 70, 117, 110, 100, 65, 100, 100, 114,
             ];
             type Params = ActorId;
             type Reply = Result<bool, super::RouterError>;
@@ -878,7 +1962,7 @@ pub mod router_service {
                 97, 116, 101, 78, 101, 119, 70, 97, 99, 116, 111, 114, 116, 121,
             ];
             type Params = ActorId;
-            type Reply = Result<bool, super::RouterError>;
+            type Reply = Result<u64, super::RouterError>;
         }
         pub struct UpdateNewWrapvara(());
         impl UpdateNewWrapvara {
@@ -893,7 +1977,7 @@ pub mod router_service {
                 97, 116, 101, 78, 101, 119, 87, 114, 97, 112, 118, 97, 114, 97,
             ];
             type Params = ActorId;
-            type Reply = Result<bool, super::RouterError>;
+            type Reply = Result<u64, super::RouterError>;
         }
         pub struct UpdateSwapFeeBps(());
         impl UpdateSwapFeeBps {
@@ -908,7 +1992,97 @@ pub mod router_service {
                 97, 116, 101, 83, 119, 97, 112, 70, 101, 101, 66, 112, 115,
             ];
             type Params = u128;
-            type Reply = Result<bool, super::RouterError>;
+            type Reply = Result<u64, super::RouterError>;
+        }
+        pub struct UpdateTimelockDelay(());
+        impl UpdateTimelockDelay {
+            #[allow(dead_code)]
+            pub fn encode_call(delay: u64) -> Vec<u8> {
+                <UpdateTimelockDelay as ActionIo>::encode_call(&delay)
+            }
+        }
+        impl ActionIo for UpdateTimelockDelay {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 76, 85, 112, 100,
+                97, 116, 101, 84, 105, 109, 101, 108, 111, 99, 107, 68, 101, 108, 97, 121,
+            ];
+            type Params = u64;
+            type Reply = Result<u64, super::RouterError>;
+        }
+        pub struct WithdrawFromVault(());
+        impl WithdrawFromVault {
+            #[allow(dead_code)]
+            pub fn encode_call(token: ActorId, amount: U256, to: ActorId) -> Vec<u8> {
+                <WithdrawFromVault as ActionIo>::encode_call(&(token, amount, to))
+            }
+        }
+        impl ActionIo for WithdrawFromVault {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 68, 87, 105, 116,
+                104, 100, 114, 97, 119, 70, 114, 111, 109, 86, 97, 117, 108, 116,
+            ];
+            type Params = (ActorId, U256, ActorId);
+            type Reply = Result<U256, super::RouterError>;
+        }
+        pub struct WithdrawVaraFromVault(());
+        impl WithdrawVaraFromVault {
+            #[allow(dead_code)]
+            pub fn encode_call(amount: U256, to: ActorId) -> Vec<u8> {
+                <WithdrawVaraFromVault as ActionIo>::encode_call(&(amount, to))
+            }
+        }
+        impl ActionIo for WithdrawVaraFromVault {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 84, 87, 105, 116,
+                104, 100, 114, 97, 119, 86, 97, 114, 97, 70, 114, 111, 109, 86, 97, 117, 108, 116,
+            ];
+            type Params = (U256, ActorId);
+            type Reply = Result<U256, super::RouterError>;
+        }
+        pub struct GrantRole(());
+        impl GrantRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <GrantRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for GrantRole {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 36, 71, 114, 97,
+                110, 116, 82, 111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct RenounceRole(());
+        impl RenounceRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role) -> Vec<u8> {
+                <RenounceRole as ActionIo>::encode_call(&role)
+            }
+        }
+        impl ActionIo for RenounceRole {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 48, 82, 101, 110,
+                111, 117, 110, 99, 101, 82, 111, 108, 101,
+            ];
+            type Params = super::Role;
+            type Reply = Result<(), super::AccessControlError>;
+        }
+        pub struct RevokeRole(());
+        impl RevokeRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <RevokeRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for RevokeRole {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 40, 82, 101, 118,
+                111, 107, 101, 82, 111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = Result<(), super::AccessControlError>;
         }
         pub struct GetAdmin(());
         impl GetAdmin {
@@ -985,6 +2159,51 @@ pub mod router_service {
             type Params = (U256, Vec<ActorId>);
             type Reply = Result<Vec<U256>, super::RouterError>;
         }
+        pub struct GetBestAmountsOut(());
+        impl GetBestAmountsOut {
+            #[allow(dead_code)]
+            pub fn encode_call(amount_in: U256, path: Vec<ActorId>) -> Vec<u8> {
+                <GetBestAmountsOut as ActionIo>::encode_call(&(amount_in, path))
+            }
+        }
+        impl ActionIo for GetBestAmountsOut {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 68, 71, 101, 116,
+                66, 101, 115, 116, 65, 109, 111, 117, 110, 116, 115, 79, 117, 116,
+            ];
+            type Params = (U256, Vec<ActorId>);
+            type Reply = Result<super::PathQuote, super::RouterError>;
+        }
+        pub struct GetCachedPair(());
+        impl GetCachedPair {
+            #[allow(dead_code)]
+            pub fn encode_call(token_a: ActorId, token_b: ActorId) -> Vec<u8> {
+                <GetCachedPair as ActionIo>::encode_call(&(token_a, token_b))
+            }
+        }
+        impl ActionIo for GetCachedPair {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 52, 71, 101, 116,
+                67, 97, 99, 104, 101, 100, 80, 97, 105, 114,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = Option<ActorId>;
+        }
+        pub struct GetFactories(());
+        impl GetFactories {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetFactories as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetFactories {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 48, 71, 101, 116,
+                70, 97, 99, 116, 111, 114, 105, 101, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<super::FactoryInfo>;
+        }
         pub struct GetFactory(());
         impl GetFactory {
             #[allow(dead_code)]
@@ -1015,6 +2234,21 @@ pub mod router_service {
             type Params = ();
             type Reply = ActorId;
         }
+        pub struct GetIntentNonce(());
+        impl GetIntentNonce {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <GetIntentNonce as ActionIo>::encode_call(&owner)
+            }
+        }
+        impl ActionIo for GetIntentNonce {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101, 116,
+                73, 110, 116, 101, 110, 116, 78, 111, 110, 99, 101,
+            ];
+            type Params = ActorId;
+            type Reply = u64;
+        }
         pub struct GetLiquidityJoin(());
         impl GetLiquidityJoin {
             #[allow(dead_code)]
@@ -1045,6 +2279,66 @@ pub mod router_service {
             type Params = ();
             type Reply = bool;
         }
+        pub struct GetPairCache(());
+        impl GetPairCache {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPairCache as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPairCache {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 48, 71, 101, 116,
+                80, 97, 105, 114, 67, 97, 99, 104, 101,
+            ];
+            type Params = ();
+            type Reply = Vec<(ActorId, ActorId, ActorId)>;
+        }
+        pub struct GetPendingAdmin(());
+        impl GetPendingAdmin {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetPendingAdmin as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetPendingAdmin {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101, 116,
+                80, 101, 110, 100, 105, 110, 103, 65, 100, 109, 105, 110,
+            ];
+            type Params = ();
+            type Reply = Option<ActorId>;
+        }
+        pub struct GetQueuedOperations(());
+        impl GetQueuedOperations {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetQueuedOperations as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetQueuedOperations {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 76, 71, 101, 116,
+                81, 117, 101, 117, 101, 100, 79, 112, 101, 114, 97, 116, 105, 111, 110, 115,
+            ];
+            type Params = ();
+            type Reply = Vec<super::QueuedOperation>;
+        }
+        pub struct GetRefunds(());
+        impl GetRefunds {
+            #[allow(dead_code)]
+            pub fn encode_call(user: ActorId) -> Vec<u8> {
+                <GetRefunds as ActionIo>::encode_call(&user)
+            }
+        }
+        impl ActionIo for GetRefunds {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 40, 71, 101, 116,
+                82, 101, 102, 117, 110, 100, 115,
+            ];
+            type Params = ActorId;
+            type Reply = Vec<(ActorId, U256)>;
+        }
         pub struct GetReserves(());
         impl GetReserves {
             #[allow(dead_code)]
@@ -1075,6 +2369,51 @@ pub mod router_service {
             type Params = ();
             type Reply = u128;
         }
+        pub struct GetTimelockDelay(());
+        impl GetTimelockDelay {
+            #[allow(dead_code)]
+            pub fn encode_call() -> Vec<u8> {
+                <GetTimelockDelay as ActionIo>::encode_call(&())
+            }
+        }
+        impl ActionIo for GetTimelockDelay {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 64, 71, 101, 116,
+                84, 105, 109, 101, 108, 111, 99, 107, 68, 101, 108, 97, 121,
+            ];
+            type Params = ();
+            type Reply = u64;
+        }
+        pub struct GetVaultBalance(());
+        impl GetVaultBalance {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId, token: ActorId) -> Vec<u8> {
+                <GetVaultBalance as ActionIo>::encode_call(&(owner, token))
+            }
+        }
+        impl ActionIo for GetVaultBalance {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101, 116,
+                86, 97, 117, 108, 116, 66, 97, 108, 97, 110, 99, 101,
+            ];
+            type Params = (ActorId, ActorId);
+            type Reply = U256;
+        }
+        pub struct GetVaultBalances(());
+        impl GetVaultBalances {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <GetVaultBalances as ActionIo>::encode_call(&owner)
+            }
+        }
+        impl ActionIo for GetVaultBalances {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 64, 71, 101, 116,
+                86, 97, 117, 108, 116, 66, 97, 108, 97, 110, 99, 101, 115,
+            ];
+            type Params = ActorId;
+            type Reply = Vec<(ActorId, U256)>;
+        }
         pub struct GetWvara(());
         impl GetWvara {
             #[allow(dead_code)]
@@ -1120,6 +2459,26 @@ pub mod router_service {
             type Params = (U256, U256, U256);
             type Reply = Result<U256, super::RouterError>;
         }
+        pub struct SimulateSwap(());
+        impl SimulateSwap {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                path: Vec<ActorId>,
+                amount: U256,
+                exact_in: bool,
+                deadline: u64,
+            ) -> Vec<u8> {
+                <SimulateSwap as ActionIo>::encode_call(&(path, amount, exact_in, deadline))
+            }
+        }
+        impl ActionIo for SimulateSwap {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 48, 83, 105, 109,
+                117, 108, 97, 116, 101, 83, 119, 97, 112,
+            ];
+            type Params = (Vec<ActorId>, U256, bool, u64);
+            type Reply = super::SwapSimulation;
+        }
         pub struct SortTokens(());
         impl SortTokens {
             #[allow(dead_code)]
@@ -1135,6 +2494,51 @@ pub mod router_service {
             type Params = (ActorId, ActorId);
             type Reply = Result<(ActorId, ActorId), super::RouterError>;
         }
+        pub struct GetAccountRoles(());
+        impl GetAccountRoles {
+            #[allow(dead_code)]
+            pub fn encode_call(account: ActorId) -> Vec<u8> {
+                <GetAccountRoles as ActionIo>::encode_call(&account)
+            }
+        }
+        impl ActionIo for GetAccountRoles {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 60, 71, 101, 116,
+                65, 99, 99, 111, 117, 110, 116, 82, 111, 108, 101, 115,
+            ];
+            type Params = ActorId;
+            type Reply = Vec<super::Role>;
+        }
+        pub struct GetRoleMembers(());
+        impl GetRoleMembers {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role) -> Vec<u8> {
+                <GetRoleMembers as ActionIo>::encode_call(&role)
+            }
+        }
+        impl ActionIo for GetRoleMembers {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 56, 71, 101, 116,
+                82, 111, 108, 101, 77, 101, 109, 98, 101, 114, 115,
+            ];
+            type Params = super::Role;
+            type Reply = Vec<ActorId>;
+        }
+        pub struct HasRole(());
+        impl HasRole {
+            #[allow(dead_code)]
+            pub fn encode_call(role: super::Role, account: ActorId) -> Vec<u8> {
+                <HasRole as ActionIo>::encode_call(&(role, account))
+            }
+        }
+        impl ActionIo for HasRole {
+            const ROUTE: &'static [u8] = &[
+                52, 82, 111, 117, 116, 101, 114, 83, 101, 114, 118, 105, 99, 101, 28, 72, 97, 115,
+                82, 111, 108, 101,
+            ];
+            type Params = (super::Role, ActorId);
+            type Reply = bool;
+        }
     }
 
     #[allow(dead_code)]
@@ -1180,6 +2584,15 @@ pub mod router_service {
                 to: ActorId,
                 liquidity: U256,
             },
+            LiquidityMigrated {
+                token_a: ActorId,
+                token_b: ActorId,
+                old_pair: ActorId,
+                new_pair: ActorId,
+                liquidity_burned: U256,
+                liquidity_minted: U256,
+                to: ActorId,
+            },
             SwapExactTokensForTokens {
                 amount_in: U256,
                 amount_out: U256,
@@ -1216,6 +2629,109 @@ pub mod router_service {
                 path: Vec<ActorId>,
                 to: ActorId,
             },
+            PairCacheSynced {
+                token_a: ActorId,
+                token_b: ActorId,
+                pair_address: ActorId,
+            },
+            PairCacheCleared,
+            FactoryAdded {
+                factory: ActorId,
+                fee_ppm: u128,
+            },
+            FactoryRemoved {
+                factory: ActorId,
+            },
+            FactoryFeeUpdated {
+                factory: ActorId,
+                fee_ppm: u128,
+            },
+            AdminTransferProposed {
+                new_admin: ActorId,
+            },
+            AdminTransferCancelled {
+                pending_admin: ActorId,
+            },
+            AdminTransferred {
+                old_admin: ActorId,
+                new_admin: ActorId,
+            },
+            OperationQueued {
+                id: u64,
+                change: ConfigChange,
+                eta: u64,
+            },
+            OperationExecuted {
+                id: u64,
+                change: ConfigChange,
+            },
+            OperationCancelled {
+                id: u64,
+            },
+            SignedSwapExecuted {
+                owner: ActorId,
+                relayer: ActorId,
+                nonce: u64,
+                amount_in: U256,
+                amount_out: U256,
+                path: Vec<ActorId>,
+                to: ActorId,
+                /// Fee the relayer received, zero if its transfer failed.
+                relayer_fee: U256,
+            },
+            IntentNonceCancelled {
+                owner: ActorId,
+                nonce: u64,
+            },
+            VaultDeposited {
+                owner: ActorId,
+                token: ActorId,
+                amount: U256,
+            },
+            VaultWithdrawn {
+                owner: ActorId,
+                token: ActorId,
+                amount: U256,
+                to: ActorId,
+            },
+            SwapExactTokensForTokensInternal {
+                owner: ActorId,
+                amount_in: U256,
+                amount_out: U256,
+                path: Vec<ActorId>,
+            },
+            SwapTokensForExactTokensInternal {
+                owner: ActorId,
+                amount_out: U256,
+                amount_in: U256,
+                path: Vec<ActorId>,
+            },
+            RecipientNotified {
+                to: ActorId,
+                token: ActorId,
+                amount: U256,
+                sender: ActorId,
+            },
+            RefundRecorded {
+                user: ActorId,
+                token: ActorId,
+                amount: U256,
+            },
+            RefundClaimed {
+                user: ActorId,
+                token: ActorId,
+                amount: U256,
+            },
+            RoleGranted {
+                role: Role,
+                account: ActorId,
+                sender: ActorId,
+            },
+            RoleRevoked {
+                role: Role,
+                account: ActorId,
+                sender: ActorId,
+            },
         }
         impl EventIo for RouterServiceEvents {
             const ROUTE: &'static [u8] = &[
@@ -1225,39 +2741,113 @@ pub mod router_service {
                 &[40, 67, 114, 101, 97, 116, 101, 80, 97, 105, 114],
                 &[48, 65, 100, 100, 76, 105, 113, 117, 105, 100, 105, 116, 121],
                 &[
-                    64, 65, 100, 100, 76, 105, 113, 117, 105, 100, 105, 116, 121, 86, 65, 82, 65,
+                    64, 65, 100, 100, 76, 105, 113, 117, 105, 100, 105, 116, 121, 86, 65, 82, 65,
+                ],
+                &[
+                    60, 82, 101, 109, 111, 118, 101, 76, 105, 113, 117, 105, 100, 105, 116, 121,
+                ],
+                &[
+                    76, 82, 101, 109, 111, 118, 101, 76, 105, 113, 117, 105, 100, 105, 116, 121,
+                    86, 65, 82, 65,
+                ],
+                &[
+                    68, 76, 105, 113, 117, 105, 100, 105, 116, 121, 77, 105, 103, 114, 97, 116,
+                    101, 100,
+                ],
+                &[
+                    96, 83, 119, 97, 112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70,
+                    111, 114, 84, 111, 107, 101, 110, 115,
+                ],
+                &[
+                    96, 83, 119, 97, 112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97,
+                    99, 116, 84, 111, 107, 101, 110, 115,
+                ],
+                &[
+                    88, 83, 119, 97, 112, 69, 120, 97, 99, 116, 86, 65, 82, 65, 70, 111, 114, 84,
+                    111, 107, 101, 110, 115,
+                ],
+                &[
+                    88, 83, 119, 97, 112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97,
+                    99, 116, 86, 65, 82, 65,
+                ],
+                &[
+                    88, 83, 119, 97, 112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70,
+                    111, 114, 86, 65, 82, 65,
+                ],
+                &[
+                    88, 83, 119, 97, 112, 86, 65, 82, 65, 70, 111, 114, 69, 120, 97, 99, 116, 84,
+                    111, 107, 101, 110, 115,
+                ],
+                &[
+                    60, 80, 97, 105, 114, 67, 97, 99, 104, 101, 83, 121, 110, 99, 101, 100,
+                ],
+                &[
+                    64, 80, 97, 105, 114, 67, 97, 99, 104, 101, 67, 108, 101, 97, 114, 101, 100,
+                ],
+                &[48, 70, 97, 99, 116, 111, 114, 121, 65, 100, 100, 101, 100],
+                &[
+                    56, 70, 97, 99, 116, 111, 114, 121, 82, 101, 109, 111, 118, 101, 100,
                 ],
                 &[
-                    60, 82, 101, 109, 111, 118, 101, 76, 105, 113, 117, 105, 100, 105, 116, 121,
+                    68, 70, 97, 99, 116, 111, 114, 121, 70, 101, 101, 85, 112, 100, 97, 116, 101,
+                    100,
                 ],
                 &[
-                    76, 82, 101, 109, 111, 118, 101, 76, 105, 113, 117, 105, 100, 105, 116, 121,
-                    86, 65, 82, 65,
+                    84, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 80, 114, 111,
+                    112, 111, 115, 101, 100,
                 ],
                 &[
-                    96, 83, 119, 97, 112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70,
-                    111, 114, 84, 111, 107, 101, 110, 115,
+                    88, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 67, 97, 110,
+                    99, 101, 108, 108, 101, 100,
                 ],
                 &[
-                    96, 83, 119, 97, 112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97,
-                    99, 116, 84, 111, 107, 101, 110, 115,
+                    64, 65, 100, 109, 105, 110, 84, 114, 97, 110, 115, 102, 101, 114, 114, 101, 100,
                 ],
                 &[
-                    88, 83, 119, 97, 112, 69, 120, 97, 99, 116, 86, 65, 82, 65, 70, 111, 114, 84,
-                    111, 107, 101, 110, 115,
+                    60, 79, 112, 101, 114, 97, 116, 105, 111, 110, 81, 117, 101, 117, 101, 100,
                 ],
                 &[
-                    88, 83, 119, 97, 112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97,
-                    99, 116, 86, 65, 82, 65,
+                    68, 79, 112, 101, 114, 97, 116, 105, 111, 110, 69, 120, 101, 99, 117, 116, 101,
+                    100,
                 ],
                 &[
-                    88, 83, 119, 97, 112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70,
-                    111, 114, 86, 65, 82, 65,
+                    72, 79, 112, 101, 114, 97, 116, 105, 111, 110, 67, 97, 110, 99, 101, 108, 108,
+                    101, 100,
                 ],
                 &[
-                    88, 83, 119, 97, 112, 86, 65, 82, 65, 70, 111, 114, 69, 120, 97, 99, 116, 84,
-                    111, 107, 101, 110, 115,
+                    72, 83, 105, 103, 110, 101, 100, 83, 119, 97, 112, 69, 120, 101, 99, 117, 116,
+                    101, 100,
+                ],
+                &[
+                    80, 73, 110, 116, 101, 110, 116, 78, 111, 110, 99, 101, 67, 97, 110, 99, 101,
+                    108, 108, 101, 100,
+                ],
+                &[
+                    56, 86, 97, 117, 108, 116, 68, 101, 112, 111, 115, 105, 116, 101, 100,
+                ],
+                &[
+                    56, 86, 97, 117, 108, 116, 87, 105, 116, 104, 100, 114, 97, 119, 110,
+                ],
+                &[
+                    128, 83, 119, 97, 112, 69, 120, 97, 99, 116, 84, 111, 107, 101, 110, 115, 70,
+                    111, 114, 84, 111, 107, 101, 110, 115, 73, 110, 116, 101, 114, 110, 97, 108,
+                ],
+                &[
+                    128, 83, 119, 97, 112, 84, 111, 107, 101, 110, 115, 70, 111, 114, 69, 120, 97,
+                    99, 116, 84, 111, 107, 101, 110, 115, 73, 110, 116, 101, 114, 110, 97, 108,
+                ],
+                &[
+                    68, 82, 101, 99, 105, 112, 105, 101, 110, 116, 78, 111, 116, 105, 102, 105,
+                    101, 100,
+                ],
+                &[
+                    56, 82, 101, 102, 117, 110, 100, 82, 101, 99, 111, 114, 100, 101, 100,
+                ],
+                &[
+                    52, 82, 101, 102, 117, 110, 100, 67, 108, 97, 105, 109, 101, 100,
                 ],
+                &[44, 82, 111, 108, 101, 71, 114, 97, 110, 116, 101, 100],
+                &[44, 82, 111, 108, 101, 82, 101, 118, 111, 107, 101, 100],
             ];
             type Event = Self;
         }
@@ -1305,6 +2895,247 @@ pub enum RouterError {
     NotAdmin,
     NoPendingFunds,
     SkimPairLiquidityFailed,
+    /// The pair of hop `hop` (index into the swap path) rejected the swap.
+    /// `error` is `None` when the message itself failed, e.g. ran out of gas.
+    PairSwapFailed {
+        hop: u32,
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    PairMintFailed {
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    PairBurnFailed {
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    PairSkimFailed {
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    /// The pair did not answer a reserves query.
+    PairQueryFailed {
+        pair: ActorId,
+    },
+    /// The factory rejected the call, or did not answer when `error` is `None`.
+    FactoryCallFailed {
+        factory: ActorId,
+        error: Option<FactoryError>,
+    },
+    TokenTransferFailed {
+        token: ActorId,
+        to: ActorId,
+    },
+    TokenTransferFromFailed {
+        token: ActorId,
+        from: ActorId,
+        to: ActorId,
+    },
+    FactoryAlreadyRegistered,
+    FactoryNotRegistered,
+    CannotRemovePrimaryFactory,
+    InvalidFee,
+    /// The pair is not a deprecated predecessor of the current pair.
+    PairNotDeprecated,
+    /// The intent's nonce is not the owner's current one: it already ran,
+    /// was cancelled, or is ahead of it.
+    InvalidNonce,
+    InvalidSignature,
+    /// The owner's vault balance is too low, or for `refund_token` and
+    /// `refund_vara`, the router holds no more than its vaults and refunds
+    /// owe.
+    InsufficientVaultBalance,
+    /// `to` rejected, trapped on or did not answer `on_varswap_received`.
+    /// It keeps the swap output unless it sent it back to the router,
+    /// which then records it as a refund of the sender.
+    RecipientCallbackFailed {
+        to: ActorId,
+    },
+    /// A pair the caller just created already has reserves.
+    PairNotEmpty,
+    /// The caller has no refund to claim.
+    NoRefunds,
+    /// There is no proposed admin to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin.
+    NotPendingAccount,
+    OperationNotFound,
+    /// The operation's `eta` has not been reached yet.
+    OperationNotReady,
+    InvalidTimelockDelay,
+    /// The pair rejected the LP permit, or did not answer when `error` is
+    /// `None`.
+    PairPermitFailed {
+        pair: ActorId,
+        error: Option<LpError>,
+    },
+    /// The relayer asked more than the intent's `max_relayer_fee`.
+    ExcessiveRelayerFee,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum LpError {
+    /// An insufficient amount of the A or B token was provided.
+    InsufficientAmount,
+    /// A specified amount limit of the former tokens has been exceeded.
+    InsufficientFormerAmount,
+    /// A specified amount limit of the latter tokens has been exceeded.
+    InsufficientLatterAmount,
+    /// An insufficient amount of liquidity tokens was provided, or the contract
+    /// doesn't have enough of them to continue an action.
+    InsufficientLiquidity,
+    /// An invalid recipient was specified.
+    InvalidRecipient,
+    ZeroActorId,
+    /// One of the contract's FT contracts failed to complete a transfer
+    /// action.
+    ///
+    /// Most often, the reason is that a user didn't give an approval to the
+    /// contract or didn't have enough tokens to transfer.
+    TransferFailed,
+    /// An overflow occurred during calculations.
+    Overflow,
+    /// A specified deadline for an action was exceeded.
+    DeadlineExceeded,
+    IdenticalTokens,
+    /// linked Factory contract.
+    FeeToGettingFailed,
+    InvalidTokens,
+    InvalidRouter,
+    CanNotConnectToVft,
+    InsufficientLiquidityMinted,
+    InsufficientLiquidityBurned,
+    InsufficientOutputAmount,
+    InsufficientInputAmount,
+    KConstant,
+    InvalidTo,
+    CanNotConnectToFactory,
+    StatusIncorrect,
+    Unauthorized,
+    EmitEventFailed,
+    InvalidReserves,
+    InvalidAmount,
+    InvalidAdmin,
+    /// The factory has blocked one of the pair's tokens.
+    TokenBlocked,
+    /// The factory has paused the pair, or all pairs.
+    Paused,
+    /// There is no proposed admin to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin.
+    NotPendingAccount,
+    /// The permit signature is not the owner's over the permit payload
+    /// with its current nonce.
+    InvalidSignature,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum FactoryError {
+    Unauthorized,
+    UnexpectedFTEvent,
+    MessageSendError,
+    NotFound,
+    PairExist,
+    PairCreationFailed,
+    PairNotExist,
+    VFTError,
+    BridgedAssetExist,
+    /// The candidate of `add_pair` did not answer the pair queries.
+    PairQueryFailed,
+    PairTokensMismatch,
+    PairFactoryMismatch,
+    PairRouterMismatch,
+    CreatorNotAllowed,
+    InsufficientCreationFee,
+    NoFeesToWithdraw,
+    TokenBlocked,
+    AlreadyPaused,
+    NotPaused,
+    ZeroAddress,
+    /// There is no proposed admin or `fee_to_setter` to accept or cancel.
+    NoPendingTransfer,
+    /// The caller is not the proposed admin or `fee_to_setter`.
+    NotPendingAccount,
+    OperationNotFound,
+    /// The operation's `eta` has not been reached yet.
+    OperationNotReady,
+    InvalidTimelockDelay,
+    /// `create_pair` was given the same token twice.
+    IdenticalAddresses,
+}
+/// An `owner`'s signed order to swap exactly `amount_in` along `path`,
+/// submitted on its behalf by a relayer through `execute_signed_swap`.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SwapIntent {
+    pub owner: ActorId,
+    pub path: Vec<ActorId>,
+    pub amount_in: U256,
+    pub amount_out_min: U256,
+    pub to: ActorId,
+    pub deadline: u64,
+    /// Must equal the owner's current intent nonce.
+    pub nonce: u64,
+    /// Most the relayer may ask, paid in `path[0]` on top of `amount_in`.
+    pub max_relayer_fee: U256,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PermitSignature {
+    /// Made with the `substrate` signing context, as wallets sign raw data.
+    Sr25519([u8; 64]),
+    Ed25519([u8; 64]),
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum Role {
+    /// Grants and revokes roles and changes the program's configuration.
+    DefaultAdmin,
+    /// Pauses and unpauses trading.
+    Pauser,
+    /// Sets fees and where they go.
+    FeeManager,
+    /// Lists tokens and edits their metadata and status.
+    AssetManager,
+    /// Moves funds held by the program.
+    Treasurer,
+    /// Sets staking rewards.
+    RewardManager,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum AccessControlError {
+    /// The caller does not hold `Role::DefaultAdmin`.
+    MissingAdminRole,
+    /// The account does not hold the role.
+    RoleNotHeld,
+    /// `DefaultAdmin` only changes hands through the program's
+    /// `propose_admin`/`accept_admin`.
+    AdminRoleNotGrantable,
+}
+/// Result of `get_best_amounts_out`: `factories[i]` is the factory whose pair
+/// gives the best output for hop `i`.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PathQuote {
+    pub amounts: Vec<U256>,
+    pub factories: Vec<ActorId>,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct FactoryInfo {
+    pub address: ActorId,
+    /// Swap fee charged by the factory's pairs, in parts per million.
+    pub fee_ppm: u128,
 }
 #[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
@@ -1314,6 +3145,63 @@ pub struct LiquidityJoin {
     pub token_b: ActorId,
     pub pair: ActorId,
 }
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct QueuedOperation {
+    pub id: u64,
+    pub change: ConfigChange,
+    /// Block timestamp from which anyone can execute the change.
+    pub eta: u64,
+}
+/// A configuration change held back by the timelock.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ConfigChange {
+    /// Replaces the primary factory and clears the pair cache. A registered
+    /// secondary factory is moved up instead of being listed twice.
+    Factory(ActorId),
+    Wvara(ActorId),
+    SwapFeeBps(u128),
+    /// Applies to operations queued after it executes.
+    TimelockDelay(u64),
+}
+/// Result of `simulate_swap`. `hops` stops at the first failing hop, whose
+/// index is `failed_hop`; it is `None` for path-level errors such as `Expired`.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SwapSimulation {
+    pub amounts: Vec<U256>,
+    pub hops: Vec<HopSimulation>,
+    pub failed_hop: Option<u32>,
+    pub error: Option<SimulationError>,
+}
+/// One hop of a simulated swap, computed against the pair's current reserves.
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct HopSimulation {
+    pub pair: ActorId,
+    pub token_in: ActorId,
+    pub token_out: ActorId,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub reserve_in: U256,
+    pub reserve_out: U256,
+    pub reserve_in_after: U256,
+    pub reserve_out_after: U256,
+    pub k_before: U256,
+    pub k_after: U256,
+}
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum SimulationError {
+    Router(RouterError),
+    Pair(LpError),
+}
 
 pub mod traits {
     use super::*;
@@ -1335,6 +3223,14 @@ pub mod traits {
     #[allow(clippy::type_complexity)]
     pub trait RouterService {
         type Args;
+        fn accept_admin(
+            &mut self,
+        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
+        fn add_factory(
+            &mut self,
+            factory: ActorId,
+            fee_ppm: u128,
+        ) -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
         fn add_liquidity(
             &mut self,
             token_a: ActorId,
@@ -1355,13 +3251,78 @@ pub mod traits {
             to: ActorId,
             deadline: u64,
         ) -> impl Call<Output = Result<(U256, U256, U256), RouterError>, Args = Self::Args>;
+        fn cancel_admin_transfer(
+            &mut self,
+        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
+        fn cancel_nonce(
+            &mut self,
+            nonce: u64,
+        ) -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
+        fn cancel_operation(
+            &mut self,
+            id: u64,
+        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
+        fn claim_refunds(
+            &mut self,
+        ) -> impl Call<Output = Result<Vec<(ActorId, U256)>, RouterError>, Args = Self::Args>;
+        fn clear_pair_cache(
+            &mut self,
+        ) -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
         fn create_pair(
             &mut self,
             token_a: ActorId,
             token_b: ActorId,
         ) -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
+        fn create_pair_and_add_liquidity(
+            &mut self,
+            token_a: ActorId,
+            token_b: ActorId,
+            amount_a: U256,
+            amount_b: U256,
+            to: ActorId,
+            deadline: u64,
+        ) -> impl Call<Output = Result<(ActorId, U256), RouterError>, Args = Self::Args>;
+        fn create_pair_and_add_liquidity_vara(
+            &mut self,
+            token: ActorId,
+            amount_token: U256,
+            amount_vara: u128,
+            to: ActorId,
+            deadline: u64,
+        ) -> impl Call<Output = Result<(ActorId, U256), RouterError>, Args = Self::Args>;
+        fn deposit_to_vault(
+            &mut self,
+            token: ActorId,
+            amount: U256,
+        ) -> impl Call<Output = Result<U256, RouterError>, Args = Self::Args>;
+        fn deposit_vara_to_vault(
+            &mut self,
+        ) -> impl Call<Output = Result<U256, RouterError>, Args = Self::Args>;
+        fn execute_operation(
+            &mut self,
+            id: u64,
+        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
+        fn execute_signed_swap(
+            &mut self,
+            intent: SwapIntent,
+            signature: PermitSignature,
+            fee: U256,
+        ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
         fn lock_router(&mut self)
             -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
+        fn migrate_liquidity(
+            &mut self,
+            token_a: ActorId,
+            token_b: ActorId,
+            old_pair: ActorId,
+            liquidity: U256,
+            to: ActorId,
+            deadline: u64,
+        ) -> impl Call<Output = Result<(U256, U256, U256), RouterError>, Args = Self::Args>;
+        fn propose_admin(
+            &mut self,
+            new_addr: ActorId,
+        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
         fn recover_pending_liquidity(
             &mut self,
             user: ActorId,
@@ -1375,6 +3336,10 @@ pub mod traits {
             &mut self,
             amount: u128,
         ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
+        fn remove_factory(
+            &mut self,
+            factory: ActorId,
+        ) -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
         fn remove_liquidity(
             &mut self,
             token_a: ActorId,
@@ -1394,6 +3359,27 @@ pub mod traits {
             to: ActorId,
             deadline: u64,
         ) -> impl Call<Output = Result<(U256, U256), RouterError>, Args = Self::Args>;
+        fn remove_liquidity_vara_with_permit(
+            &mut self,
+            token: ActorId,
+            liquidity: U256,
+            amount_token_min: U256,
+            amount_vara_min: U256,
+            to: ActorId,
+            deadline: u64,
+            signature: PermitSignature,
+        ) -> impl Call<Output = Result<(U256, U256), RouterError>, Args = Self::Args>;
+        fn remove_liquidity_with_permit(
+            &mut self,
+            token_a: ActorId,
+            token_b: ActorId,
+            liquidity: U256,
+            amount_a_min: U256,
+            amount_b_min: U256,
+            to: ActorId,
+            deadline: u64,
+            signature: PermitSignature,
+        ) -> impl Call<Output = Result<(U256, U256), RouterError>, Args = Self::Args>;
         fn skim_pair_liquidity(
             &mut self,
             pair: ActorId,
@@ -1406,6 +3392,32 @@ pub mod traits {
             to: ActorId,
             deadline: u64,
         ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn swap_exact_tokens_for_tokens_internal(
+            &mut self,
+            amount_in: U256,
+            amount_out_min: U256,
+            path: Vec<ActorId>,
+            deadline: u64,
+        ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn swap_exact_tokens_for_tokens_via(
+            &mut self,
+            amount_in: U256,
+            amount_out_min: U256,
+            path: Vec<ActorId>,
+            factories: Vec<ActorId>,
+            to: ActorId,
+            deadline: u64,
+        ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn swap_exact_tokens_for_tokens_with_callback(
+            &mut self,
+            amount_in: U256,
+            amount_out_min: U256,
+            path: Vec<ActorId>,
+            to: ActorId,
+            deadline: u64,
+            notify: bool,
+            data: Vec<u8>,
+        ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
         fn swap_exact_tokens_for_vara(
             &mut self,
             amount_in: U256,
@@ -1421,6 +3433,15 @@ pub mod traits {
             to: ActorId,
             deadline: u64,
         ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn swap_exact_vara_for_tokens_with_callback(
+            &mut self,
+            amount_out_min: U256,
+            path: Vec<ActorId>,
+            to: ActorId,
+            deadline: u64,
+            notify: bool,
+            data: Vec<u8>,
+        ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
         fn swap_tokens_for_exact_tokens(
             &mut self,
             amount_out: U256,
@@ -1429,6 +3450,23 @@ pub mod traits {
             to: ActorId,
             deadline: u64,
         ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn swap_tokens_for_exact_tokens_internal(
+            &mut self,
+            amount_out: U256,
+            amount_in_max: U256,
+            path: Vec<ActorId>,
+            deadline: u64,
+        ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn swap_tokens_for_exact_tokens_with_callback(
+            &mut self,
+            amount_out: U256,
+            amount_in_max: U256,
+            path: Vec<ActorId>,
+            to: ActorId,
+            deadline: u64,
+            notify: bool,
+            data: Vec<u8>,
+        ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
         fn swap_tokens_for_exact_vara(
             &mut self,
             amount_out: U256,
@@ -1444,29 +3482,74 @@ pub mod traits {
             to: ActorId,
             deadline: u64,
         ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn swap_vara_for_exact_tokens_with_callback(
+            &mut self,
+            amount_out: U256,
+            path: Vec<ActorId>,
+            to: ActorId,
+            deadline: u64,
+            notify: bool,
+            data: Vec<u8>,
+        ) -> impl Call<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn sync_pair(
+            &mut self,
+            token_a: ActorId,
+            token_b: ActorId,
+            pair_address: ActorId,
+        ) -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
         fn unlock_router(
             &mut self,
         ) -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
+        fn update_factory_fee(
+            &mut self,
+            factory: ActorId,
+            fee_ppm: u128,
+        ) -> impl Call<Output = Result<(), RouterError>, Args = Self::Args>;
         fn update_fund_addr(
             &mut self,
             new_fund_addr: ActorId,
         ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
-        fn update_new_admin(
-            &mut self,
-            new_addr: ActorId,
-        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
         fn update_new_factorty(
             &mut self,
             new_factory_addr: ActorId,
-        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
+        ) -> impl Call<Output = Result<u64, RouterError>, Args = Self::Args>;
         fn update_new_wrapvara(
             &mut self,
             new_wvara_addr: ActorId,
-        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
+        ) -> impl Call<Output = Result<u64, RouterError>, Args = Self::Args>;
         fn update_swap_fee_bps(
             &mut self,
             new_swap_fee_bps: u128,
-        ) -> impl Call<Output = Result<bool, RouterError>, Args = Self::Args>;
+        ) -> impl Call<Output = Result<u64, RouterError>, Args = Self::Args>;
+        fn update_timelock_delay(
+            &mut self,
+            delay: u64,
+        ) -> impl Call<Output = Result<u64, RouterError>, Args = Self::Args>;
+        fn withdraw_from_vault(
+            &mut self,
+            token: ActorId,
+            amount: U256,
+            to: ActorId,
+        ) -> impl Call<Output = Result<U256, RouterError>, Args = Self::Args>;
+        fn withdraw_vara_from_vault(
+            &mut self,
+            amount: U256,
+            to: ActorId,
+        ) -> impl Call<Output = Result<U256, RouterError>, Args = Self::Args>;
+        fn grant_role(
+            &mut self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn renounce_role(
+            &mut self,
+            role: Role,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
+        fn revoke_role(
+            &mut self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Call<Output = Result<(), AccessControlError>, Args = Self::Args>;
         fn get_admin(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
         fn get_amount_in(
             &self,
@@ -1490,19 +3573,52 @@ pub mod traits {
             amount_in: U256,
             path: Vec<ActorId>,
         ) -> impl Query<Output = Result<Vec<U256>, RouterError>, Args = Self::Args>;
+        fn get_best_amounts_out(
+            &self,
+            amount_in: U256,
+            path: Vec<ActorId>,
+        ) -> impl Query<Output = Result<PathQuote, RouterError>, Args = Self::Args>;
+        fn get_cached_pair(
+            &self,
+            token_a: ActorId,
+            token_b: ActorId,
+        ) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn get_factories(&self) -> impl Query<Output = Vec<FactoryInfo>, Args = Self::Args>;
         fn get_factory(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
         fn get_fund_addr(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
+        fn get_intent_nonce(&self, owner: ActorId) -> impl Query<Output = u64, Args = Self::Args>;
         fn get_liquidity_join(
             &self,
             user: ActorId,
         ) -> impl Query<Output = Vec<LiquidityJoin>, Args = Self::Args>;
         fn get_lock(&self) -> impl Query<Output = bool, Args = Self::Args>;
+        fn get_pair_cache(
+            &self,
+        ) -> impl Query<Output = Vec<(ActorId, ActorId, ActorId)>, Args = Self::Args>;
+        fn get_pending_admin(&self) -> impl Query<Output = Option<ActorId>, Args = Self::Args>;
+        fn get_queued_operations(
+            &self,
+        ) -> impl Query<Output = Vec<QueuedOperation>, Args = Self::Args>;
+        fn get_refunds(
+            &self,
+            user: ActorId,
+        ) -> impl Query<Output = Vec<(ActorId, U256)>, Args = Self::Args>;
         fn get_reserves(
             &self,
             token_a: ActorId,
             token_b: ActorId,
         ) -> impl Query<Output = Result<(U256, U256, ActorId), RouterError>, Args = Self::Args>;
         fn get_swap_fee_bps(&self) -> impl Query<Output = u128, Args = Self::Args>;
+        fn get_timelock_delay(&self) -> impl Query<Output = u64, Args = Self::Args>;
+        fn get_vault_balance(
+            &self,
+            owner: ActorId,
+            token: ActorId,
+        ) -> impl Query<Output = U256, Args = Self::Args>;
+        fn get_vault_balances(
+            &self,
+            owner: ActorId,
+        ) -> impl Query<Output = Vec<(ActorId, U256)>, Args = Self::Args>;
         fn get_wvara(&self) -> impl Query<Output = ActorId, Args = Self::Args>;
         fn pair_for(
             &self,
@@ -1515,11 +3631,31 @@ pub mod traits {
             reserve_a: U256,
             reserve_b: U256,
         ) -> impl Query<Output = Result<U256, RouterError>, Args = Self::Args>;
+        fn simulate_swap(
+            &self,
+            path: Vec<ActorId>,
+            amount: U256,
+            exact_in: bool,
+            deadline: u64,
+        ) -> impl Query<Output = SwapSimulation, Args = Self::Args>;
         fn sort_tokens(
             &self,
             token_a: ActorId,
             token_b: ActorId,
         ) -> impl Query<Output = Result<(ActorId, ActorId), RouterError>, Args = Self::Args>;
+        fn get_account_roles(
+            &self,
+            account: ActorId,
+        ) -> impl Query<Output = Vec<Role>, Args = Self::Args>;
+        fn get_role_members(
+            &self,
+            role: Role,
+        ) -> impl Query<Output = Vec<ActorId>, Args = Self::Args>;
+        fn has_role(
+            &self,
+            role: Role,
+            account: ActorId,
+        ) -> impl Query<Output = bool, Args = Self::Args>;
     }
 }
 
@@ -1532,5 +3668,5 @@ extern crate std;
 pub mod mockall {
     use super::*;
     use sails_rs::mockall::*;
-    mock! { pub RouterService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::RouterService for RouterService<A> { type Args = A; fn add_liquidity (&mut self, token_a: ActorId,token_b: ActorId,amount_a_desired: U256,amount_b_desired: U256,amount_a_min: U256,amount_b_min: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,U256,), RouterError>>;fn add_liquidity_vara (&mut self, token: ActorId,amount_token_desired: U256,amount_token_min: U256,amount_vara_min: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,U256,), RouterError>>;fn create_pair (&mut self, token_a: ActorId,token_b: ActorId,) -> MockCall<A, Result<(), RouterError>>;fn lock_router (&mut self, ) -> MockCall<A, Result<(), RouterError>>;fn recover_pending_liquidity (&mut self, user: ActorId,) -> MockCall<A, Result<(), RouterError>>;fn refund_token (&mut self, token_addr: ActorId,amount: U256,) -> MockCall<A, Result<bool, RouterError>>;fn refund_vara (&mut self, amount: u128,) -> MockCall<A, Result<bool, RouterError>>;fn remove_liquidity (&mut self, token_a: ActorId,token_b: ActorId,liquidity: U256,amount_a_min: U256,amount_b_min: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,), RouterError>>;fn remove_liquidity_vara (&mut self, token: ActorId,liquidity: U256,amount_token_min: U256,amount_vara_min: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,), RouterError>>;fn skim_pair_liquidity (&mut self, pair: ActorId,) -> MockCall<A, Result<(), RouterError>>;fn swap_exact_tokens_for_tokens (&mut self, amount_in: U256,amount_out_min: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_exact_tokens_for_vara (&mut self, amount_in: U256,amount_out_min: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_exact_vara_for_tokens (&mut self, amount_out_min: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_tokens_for_exact_tokens (&mut self, amount_out: U256,amount_in_max: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_tokens_for_exact_vara (&mut self, amount_out: U256,amount_in_max: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_vara_for_exact_tokens (&mut self, amount_out: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn unlock_router (&mut self, ) -> MockCall<A, Result<(), RouterError>>;fn update_fund_addr (&mut self, new_fund_addr: ActorId,) -> MockCall<A, Result<bool, RouterError>>;fn update_new_admin (&mut self, new_addr: ActorId,) -> MockCall<A, Result<bool, RouterError>>;fn update_new_factorty (&mut self, new_factory_addr: ActorId,) -> MockCall<A, Result<bool, RouterError>>;fn update_new_wrapvara (&mut self, new_wvara_addr: ActorId,) -> MockCall<A, Result<bool, RouterError>>;fn update_swap_fee_bps (&mut self, new_swap_fee_bps: u128,) -> MockCall<A, Result<bool, RouterError>>;fn get_admin (& self, ) -> MockQuery<A, ActorId>;fn get_amount_in (& self, amount_out: U256,reserve_in: U256,reserve_out: U256,) -> MockQuery<A, Result<U256, RouterError>>;fn get_amount_out (& self, amount_in: U256,reserve_in: U256,reserve_out: U256,) -> MockQuery<A, Result<U256, RouterError>>;fn get_amounts_in (& self, amount_out: U256,path: Vec<ActorId>,) -> MockQuery<A, Result<Vec<U256>, RouterError>>;fn get_amounts_out (& self, amount_in: U256,path: Vec<ActorId>,) -> MockQuery<A, Result<Vec<U256>, RouterError>>;fn get_factory (& self, ) -> MockQuery<A, ActorId>;fn get_fund_addr (& self, ) -> MockQuery<A, ActorId>;fn get_liquidity_join (& self, user: ActorId,) -> MockQuery<A, Vec<LiquidityJoin>>;fn get_lock (& self, ) -> MockQuery<A, bool>;fn get_reserves (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, Result<(U256,U256,ActorId,), RouterError>>;fn get_swap_fee_bps (& self, ) -> MockQuery<A, u128>;fn get_wvara (& self, ) -> MockQuery<A, ActorId>;fn pair_for (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, Result<ActorId, RouterError>>;fn quote (& self, amount_a: U256,reserve_a: U256,reserve_b: U256,) -> MockQuery<A, Result<U256, RouterError>>;fn sort_tokens (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, Result<(ActorId,ActorId,), RouterError>>; } }
+    mock! { pub RouterService<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::RouterService for RouterService<A> { type Args = A; fn accept_admin (&mut self, ) -> MockCall<A, Result<bool, RouterError>>;fn add_factory (&mut self, factory: ActorId,fee_ppm: u128,) -> MockCall<A, Result<(), RouterError>>;fn add_liquidity (&mut self, token_a: ActorId,token_b: ActorId,amount_a_desired: U256,amount_b_desired: U256,amount_a_min: U256,amount_b_min: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,U256,), RouterError>>;fn add_liquidity_vara (&mut self, token: ActorId,amount_token_desired: U256,amount_token_min: U256,amount_vara_min: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,U256,), RouterError>>;fn cancel_admin_transfer (&mut self, ) -> MockCall<A, Result<bool, RouterError>>;fn cancel_nonce (&mut self, nonce: u64,) -> MockCall<A, Result<(), RouterError>>;fn cancel_operation (&mut self, id: u64,) -> MockCall<A, Result<bool, RouterError>>;fn claim_refunds (&mut self, ) -> MockCall<A, Result<Vec<(ActorId,U256,)>, RouterError>>;fn clear_pair_cache (&mut self, ) -> MockCall<A, Result<(), RouterError>>;fn create_pair (&mut self, token_a: ActorId,token_b: ActorId,) -> MockCall<A, Result<(), RouterError>>;fn create_pair_and_add_liquidity (&mut self, token_a: ActorId,token_b: ActorId,amount_a: U256,amount_b: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(ActorId,U256,), RouterError>>;fn create_pair_and_add_liquidity_vara (&mut self, token: ActorId,amount_token: U256,amount_vara: u128,to: ActorId,deadline: u64,) -> MockCall<A, Result<(ActorId,U256,), RouterError>>;fn deposit_to_vault (&mut self, token: ActorId,amount: U256,) -> MockCall<A, Result<U256, RouterError>>;fn deposit_vara_to_vault (&mut self, ) -> MockCall<A, Result<U256, RouterError>>;fn execute_operation (&mut self, id: u64,) -> MockCall<A, Result<bool, RouterError>>;fn execute_signed_swap (&mut self, intent: SwapIntent,signature: PermitSignature,fee: U256,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn lock_router (&mut self, ) -> MockCall<A, Result<(), RouterError>>;fn migrate_liquidity (&mut self, token_a: ActorId,token_b: ActorId,old_pair: ActorId,liquidity: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,U256,), RouterError>>;fn propose_admin (&mut self, new_addr: ActorId,) -> MockCall<A, Result<bool, RouterError>>;fn recover_pending_liquidity (&mut self, user: ActorId,) -> MockCall<A, Result<(), RouterError>>;fn refund_token (&mut self, token_addr: ActorId,amount: U256,) -> MockCall<A, Result<bool, RouterError>>;fn refund_vara (&mut self, amount: u128,) -> MockCall<A, Result<bool, RouterError>>;fn remove_factory (&mut self, factory: ActorId,) -> MockCall<A, Result<(), RouterError>>;fn remove_liquidity (&mut self, token_a: ActorId,token_b: ActorId,liquidity: U256,amount_a_min: U256,amount_b_min: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,), RouterError>>;fn remove_liquidity_vara (&mut self, token: ActorId,liquidity: U256,amount_token_min: U256,amount_vara_min: U256,to: ActorId,deadline: u64,) -> MockCall<A, Result<(U256,U256,), RouterError>>;fn remove_liquidity_vara_with_permit (&mut self, token: ActorId,liquidity: U256,amount_token_min: U256,amount_vara_min: U256,to: ActorId,deadline: u64,signature: PermitSignature,) -> MockCall<A, Result<(U256,U256,), RouterError>>;fn remove_liquidity_with_permit (&mut self, token_a: ActorId,token_b: ActorId,liquidity: U256,amount_a_min: U256,amount_b_min: U256,to: ActorId,deadline: u64,signature: PermitSignature,) -> MockCall<A, Result<(U256,U256,), RouterError>>;fn skim_pair_liquidity (&mut self, pair: ActorId,) -> MockCall<A, Result<(), RouterError>>;fn swap_exact_tokens_for_tokens (&mut self, amount_in: U256,amount_out_min: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_exact_tokens_for_tokens_internal (&mut self, amount_in: U256,amount_out_min: U256,path: Vec<ActorId>,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_exact_tokens_for_tokens_via (&mut self, amount_in: U256,amount_out_min: U256,path: Vec<ActorId>,factories: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_exact_tokens_for_tokens_with_callback (&mut self, amount_in: U256,amount_out_min: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,notify: bool,data: Vec<u8>,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_exact_tokens_for_vara (&mut self, amount_in: U256,amount_out_min: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_exact_vara_for_tokens (&mut self, amount_out_min: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_exact_vara_for_tokens_with_callback (&mut self, amount_out_min: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,notify: bool,data: Vec<u8>,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_tokens_for_exact_tokens (&mut self, amount_out: U256,amount_in_max: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_tokens_for_exact_tokens_internal (&mut self, amount_out: U256,amount_in_max: U256,path: Vec<ActorId>,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_tokens_for_exact_tokens_with_callback (&mut self, amount_out: U256,amount_in_max: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,notify: bool,data: Vec<u8>,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_tokens_for_exact_vara (&mut self, amount_out: U256,amount_in_max: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_vara_for_exact_tokens (&mut self, amount_out: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn swap_vara_for_exact_tokens_with_callback (&mut self, amount_out: U256,path: Vec<ActorId>,to: ActorId,deadline: u64,notify: bool,data: Vec<u8>,) -> MockCall<A, Result<Vec<U256>, RouterError>>;fn sync_pair (&mut self, token_a: ActorId,token_b: ActorId,pair_address: ActorId,) -> MockCall<A, Result<(), RouterError>>;fn unlock_router (&mut self, ) -> MockCall<A, Result<(), RouterError>>;fn update_factory_fee (&mut self, factory: ActorId,fee_ppm: u128,) -> MockCall<A, Result<(), RouterError>>;fn update_fund_addr (&mut self, new_fund_addr: ActorId,) -> MockCall<A, Result<bool, RouterError>>;fn update_new_factorty (&mut self, new_factory_addr: ActorId,) -> MockCall<A, Result<u64, RouterError>>;fn update_new_wrapvara (&mut self, new_wvara_addr: ActorId,) -> MockCall<A, Result<u64, RouterError>>;fn update_swap_fee_bps (&mut self, new_swap_fee_bps: u128,) -> MockCall<A, Result<u64, RouterError>>;fn update_timelock_delay (&mut self, delay: u64,) -> MockCall<A, Result<u64, RouterError>>;fn withdraw_from_vault (&mut self, token: ActorId,amount: U256,to: ActorId,) -> MockCall<A, Result<U256, RouterError>>;fn withdraw_vara_from_vault (&mut self, amount: U256,to: ActorId,) -> MockCall<A, Result<U256, RouterError>>;fn grant_role (&mut self, role: Role,account: ActorId,) -> MockCall<A, Result<(), AccessControlError>>;fn renounce_role (&mut self, role: Role,) -> MockCall<A, Result<(), AccessControlError>>;fn revoke_role (&mut self, role: Role,account: ActorId,) -> MockCall<A, Result<(), AccessControlError>>;fn get_admin (& self, ) -> MockQuery<A, ActorId>;fn get_amount_in (& self, amount_out: U256,reserve_in: U256,reserve_out: U256,) -> MockQuery<A, Result<U256, RouterError>>;fn get_amount_out (& self, amount_in: U256,reserve_in: U256,reserve_out: U256,) -> MockQuery<A, Result<U256, RouterError>>;fn get_amounts_in (& self, amount_out: U256,path: Vec<ActorId>,) -> MockQuery<A, Result<Vec<U256>, RouterError>>;fn get_amounts_out (& self, amount_in: U256,path: Vec<ActorId>,) -> MockQuery<A, Result<Vec<U256>, RouterError>>;fn get_best_amounts_out (& self, amount_in: U256,path: Vec<ActorId>,) -> MockQuery<A, Result<PathQuote, RouterError>>;fn get_cached_pair (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, Option<ActorId>>;fn get_factories (& self, ) -> MockQuery<A, Vec<FactoryInfo>>;fn get_factory (& self, ) -> MockQuery<A, ActorId>;fn get_fund_addr (& self, ) -> MockQuery<A, ActorId>;fn get_intent_nonce (& self, owner: ActorId,) -> MockQuery<A, u64>;fn get_liquidity_join (& self, user: ActorId,) -> MockQuery<A, Vec<LiquidityJoin>>;fn get_lock (& self, ) -> MockQuery<A, bool>;fn get_pair_cache (& self, ) -> MockQuery<A, Vec<(ActorId,ActorId,ActorId,)>>;fn get_pending_admin (& self, ) -> MockQuery<A, Option<ActorId>>;fn get_queued_operations (& self, ) -> MockQuery<A, Vec<QueuedOperation>>;fn get_refunds (& self, user: ActorId,) -> MockQuery<A, Vec<(ActorId,U256,)>>;fn get_reserves (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, Result<(U256,U256,ActorId,), RouterError>>;fn get_swap_fee_bps (& self, ) -> MockQuery<A, u128>;fn get_timelock_delay (& self, ) -> MockQuery<A, u64>;fn get_vault_balance (& self, owner: ActorId,token: ActorId,) -> MockQuery<A, U256>;fn get_vault_balances (& self, owner: ActorId,) -> MockQuery<A, Vec<(ActorId,U256,)>>;fn get_wvara (& self, ) -> MockQuery<A, ActorId>;fn pair_for (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, Result<ActorId, RouterError>>;fn quote (& self, amount_a: U256,reserve_a: U256,reserve_b: U256,) -> MockQuery<A, Result<U256, RouterError>>;fn simulate_swap (& self, path: Vec<ActorId>,amount: U256,exact_in: bool,deadline: u64,) -> MockQuery<A, SwapSimulation>;fn sort_tokens (& self, token_a: ActorId,token_b: ActorId,) -> MockQuery<A, Result<(ActorId,ActorId,), RouterError>>;fn get_account_roles (& self, account: ActorId,) -> MockQuery<A, Vec<Role>>;fn get_role_members (& self, role: Role,) -> MockQuery<A, Vec<ActorId>>;fn has_role (& self, role: Role,account: ActorId,) -> MockQuery<A, bool>; } }
 }
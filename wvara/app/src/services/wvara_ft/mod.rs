@@ -1,5 +1,6 @@
 
 use gstd::msg;
+use permit::PermitSignature;
 use sails_rs::{gstd::service, prelude::*};
 mod funcs;
 use crate::services;
@@ -10,6 +11,7 @@ use vft_service::{Service as VftService, Storage};
 pub enum Event {
     Deposit { dst: ActorId, wad: U256 },
     Withdraw { src: ActorId, wad: U256 },
+    Permit { owner: ActorId, spender: ActorId, value: U256 },
 }
 #[derive(Clone)]
 pub struct WvaraService {
@@ -56,6 +58,30 @@ impl WvaraService {
         }
         mutated
     }
+
+    /// Sets `spender`'s allowance over `owner`'s wVARA to `value` from
+    /// `owner`'s signature of the permit payload. Panics on an expired
+    /// permit or a wrong signature.
+    pub fn permit(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> bool {
+        services::utils::panicking(|| {
+            permit::use_permit(owner, spender, value, deadline, &signature)
+        });
+        let mutated = vft_service::funcs::approve(Storage::allowances(), owner, spender, value);
+        let _ = self.emit_event(Event::Permit { owner, spender, value });
+        mutated
+    }
+
+    /// Current nonce of `owner`, which its next permit signature must cover.
+    pub fn nonces(&self, owner: ActorId) -> u64 {
+        permit::nonce_of(owner)
+    }
     
 }
 
@@ -59,6 +59,22 @@ impl<R: Remoting + Clone> traits::Vft for Vft<R> {
     fn deposit(&mut self) -> impl Call<Output = bool, Args = R::Args> {
         RemotingAction::<_, vft::io::Deposit>::new(self.remoting.clone(), ())
     }
+    /// Sets `spender`'s allowance over `owner`'s wVARA to `value` from
+    /// `owner`'s signature of the permit payload. Panics on an expired
+    /// permit or a wrong signature.
+    fn permit(
+        &mut self,
+        owner: ActorId,
+        spender: ActorId,
+        value: U256,
+        deadline: u64,
+        signature: PermitSignature,
+    ) -> impl Call<Output = bool, Args = R::Args> {
+        RemotingAction::<_, vft::io::Permit>::new(
+            self.remoting.clone(),
+            (owner, spender, value, deadline, signature),
+        )
+    }
     fn withdraw(&mut self, value: U256) -> impl Call<Output = bool, Args = R::Args> {
         RemotingAction::<_, vft::io::Withdraw>::new(self.remoting.clone(), value)
     }
@@ -80,6 +96,10 @@ impl<R: Remoting + Clone> traits::Vft for Vft<R> {
     ) -> impl Call<Output = bool, Args = R::Args> {
         RemotingAction::<_, vft::io::TransferFrom>::new(self.remoting.clone(), (from, to, value))
     }
+    /// Current nonce of `owner`, which its next permit signature must cover.
+    fn nonces(&self, owner: ActorId) -> impl Query<Output = u64, Args = R::Args> {
+        RemotingAction::<_, vft::io::Nonces>::new(self.remoting.clone(), owner)
+    }
     fn allowance(
         &self,
         owner: ActorId,
@@ -122,6 +142,24 @@ pub mod vft {
             type Params = ();
             type Reply = bool;
         }
+        pub struct Permit(());
+        impl Permit {
+            #[allow(dead_code)]
+            pub fn encode_call(
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+                deadline: u64,
+                signature: super::PermitSignature,
+            ) -> Vec<u8> {
+                <Permit as ActionIo>::encode_call(&(owner, spender, value, deadline, signature))
+            }
+        }
+        impl ActionIo for Permit {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 24, 80, 101, 114, 109, 105, 116];
+            type Params = (ActorId, ActorId, U256, u64, super::PermitSignature);
+            type Reply = bool;
+        }
         pub struct Withdraw(());
         impl Withdraw {
             #[allow(dead_code)]
@@ -174,6 +212,18 @@ pub mod vft {
             type Params = (ActorId, ActorId, U256);
             type Reply = bool;
         }
+        pub struct Nonces(());
+        impl Nonces {
+            #[allow(dead_code)]
+            pub fn encode_call(owner: ActorId) -> Vec<u8> {
+                <Nonces as ActionIo>::encode_call(&owner)
+            }
+        }
+        impl ActionIo for Nonces {
+            const ROUTE: &'static [u8] = &[12, 86, 102, 116, 24, 78, 111, 110, 99, 101, 115];
+            type Params = ActorId;
+            type Reply = u64;
+        }
         pub struct Allowance(());
         impl Allowance {
             #[allow(dead_code)]
@@ -270,6 +320,11 @@ pub mod vft {
                 src: ActorId,
                 wad: U256,
             },
+            Permit {
+                owner: ActorId,
+                spender: ActorId,
+                value: U256,
+            },
             Approval {
                 owner: ActorId,
                 spender: ActorId,
@@ -286,6 +341,7 @@ pub mod vft {
             const EVENT_NAMES: &'static [&'static [u8]] = &[
                 &[28, 68, 101, 112, 111, 115, 105, 116],
                 &[32, 87, 105, 116, 104, 100, 114, 97, 119],
+                &[24, 80, 101, 114, 109, 105, 116],
                 &[32, 65, 112, 112, 114, 111, 118, 97, 108],
                 &[32, 84, 114, 97, 110, 115, 102, 101, 114],
             ];
@@ -296,6 +352,14 @@ pub mod vft {
         }
     }
 }
+#[derive(PartialEq, Clone, Debug, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum PermitSignature {
+    /// Made with the `substrate` signing context, as wallets sign raw data.
+    Sr25519([u8; 64]),
+    Ed25519([u8; 64]),
+}
 
 pub mod traits {
     use super::*;
@@ -316,6 +380,14 @@ pub mod traits {
     pub trait Vft {
         type Args;
         fn deposit(&mut self) -> impl Call<Output = bool, Args = Self::Args>;
+        fn permit(
+            &mut self,
+            owner: ActorId,
+            spender: ActorId,
+            value: U256,
+            deadline: u64,
+            signature: PermitSignature,
+        ) -> impl Call<Output = bool, Args = Self::Args>;
         fn withdraw(&mut self, value: U256) -> impl Call<Output = bool, Args = Self::Args>;
         fn approve(
             &mut self,
@@ -333,6 +405,7 @@ pub mod traits {
             to: ActorId,
             value: U256,
         ) -> impl Call<Output = bool, Args = Self::Args>;
+        fn nonces(&self, owner: ActorId) -> impl Query<Output = u64, Args = Self::Args>;
         fn allowance(
             &self,
             owner: ActorId,
@@ -355,5 +428,5 @@ extern crate std;
 pub mod mockall {
     use super::*;
     use sails_rs::mockall::*;
-    mock! { pub Vft<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Vft for Vft<A> { type Args = A; fn deposit (&mut self, ) -> MockCall<A, bool>;fn withdraw (&mut self, value: U256,) -> MockCall<A, bool>;fn approve (&mut self, spender: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer_from (&mut self, from: ActorId,to: ActorId,value: U256,) -> MockCall<A, bool>;fn allowance (& self, owner: ActorId,spender: ActorId,) -> MockQuery<A, U256>;fn balance_of (& self, account: ActorId,) -> MockQuery<A, U256>;fn decimals (& self, ) -> MockQuery<A, u8>;fn name (& self, ) -> MockQuery<A, String>;fn symbol (& self, ) -> MockQuery<A, String>;fn total_supply (& self, ) -> MockQuery<A, U256>; } }
+    mock! { pub Vft<A> {} #[allow(refining_impl_trait)] #[allow(clippy::type_complexity)] impl<A> traits::Vft for Vft<A> { type Args = A; fn deposit (&mut self, ) -> MockCall<A, bool>;fn permit (&mut self, owner: ActorId,spender: ActorId,value: U256,deadline: u64,signature: PermitSignature,) -> MockCall<A, bool>;fn withdraw (&mut self, value: U256,) -> MockCall<A, bool>;fn approve (&mut self, spender: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer (&mut self, to: ActorId,value: U256,) -> MockCall<A, bool>;fn transfer_from (&mut self, from: ActorId,to: ActorId,value: U256,) -> MockCall<A, bool>;fn nonces (& self, owner: ActorId,) -> MockQuery<A, u64>;fn allowance (& self, owner: ActorId,spender: ActorId,) -> MockQuery<A, U256>;fn balance_of (& self, account: ActorId,) -> MockQuery<A, U256>;fn decimals (& self, ) -> MockQuery<A, u8>;fn name (& self, ) -> MockQuery<A, String>;fn symbol (& self, ) -> MockQuery<A, String>;fn total_supply (& self, ) -> MockQuery<A, U256>; } }
 }